@@ -0,0 +1,36 @@
+//! The async filesystem operations used by the client. They are those of
+//! tokio, except on wasm32, where tokio has no filesystem support and the
+//! blocking operations of std are used instead.
+#[cfg(not(target_family = "wasm"))]
+pub use tokio::fs::{canonicalize, create_dir_all, read, rename};
+#[cfg(target_family = "wasm")]
+pub use wasm::{canonicalize, create_dir_all, read, rename};
+
+#[cfg(target_family = "wasm")]
+mod wasm {
+    use std::io;
+    use std::path::{Path, PathBuf};
+
+    /// Get the canonical, absolute form of a path
+    pub async fn canonicalize(path: impl AsRef<Path>) -> io::Result<PathBuf> {
+        std::fs::canonicalize(path)
+    }
+
+    /// Create a dir and all its missing parents
+    pub async fn create_dir_all(path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::create_dir_all(path)
+    }
+
+    /// Read the contents of a file
+    pub async fn read(path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+        std::fs::read(path)
+    }
+
+    /// Rename a file or dir, replacing the destination if it exists
+    pub async fn rename(
+        from: impl AsRef<Path>,
+        to: impl AsRef<Path>,
+    ) -> io::Result<()> {
+        std::fs::rename(from, to)
+    }
+}
@@ -13,11 +13,11 @@ pub mod relayer;
 mod utils;
 pub mod wallet;
 
-use clap::{ArgGroup, ArgMatches, ColorChoice};
+use clap::{ArgGroup, ArgMatches};
 use color_eyre::eyre::Result;
 use namada_sdk::io::StdIo;
 use utils::*;
-pub use utils::{safe_exit, Cmd};
+pub use utils::{assume_tty_env, redact_base_dir, safe_exit, tty_enabled, Cmd};
 
 pub use self::context::Context;
 use crate::cli::api::CliIo;
@@ -278,6 +278,7 @@ pub mod cmds {
                 .subcommand(QuerySlashes::def().display_order(5))
                 .subcommand(QueryDelegations::def().display_order(5))
                 .subcommand(QueryFindValidator::def().display_order(5))
+                .subcommand(QueryValidatorKeys::def().display_order(5))
                 .subcommand(QueryResult::def().display_order(5))
                 .subcommand(QueryRawBytes::def().display_order(5))
                 .subcommand(QueryProposal::def().display_order(5))
@@ -298,6 +299,7 @@ pub mod cmds {
                 .subcommand(GenIbcShieldingTransfer::def().display_order(6))
                 // Utils
                 .subcommand(ClientUtils::def().display_order(7))
+                .subcommand(DumpCli::def().hide(true))
         }
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
@@ -371,6 +373,8 @@ pub mod cmds {
                 Self::parse_with_ctx(matches, QueryStakingRewardsRate);
             let query_find_validator =
                 Self::parse_with_ctx(matches, QueryFindValidator);
+            let query_validator_keys =
+                Self::parse_with_ctx(matches, QueryValidatorKeys);
             let query_result = Self::parse_with_ctx(matches, QueryResult);
             let query_raw_bytes = Self::parse_with_ctx(matches, QueryRawBytes);
             let query_proposal = Self::parse_with_ctx(matches, QueryProposal);
@@ -393,6 +397,8 @@ pub mod cmds {
             let gen_ibc_shielding =
                 Self::parse_with_ctx(matches, GenIbcShieldingTransfer);
             let utils = SubCmd::parse(matches).map(Self::WithoutContext);
+            let dump_cli = SubCmd::parse(matches)
+                .map(|sub| Self::WithoutContext(ClientUtils::DumpCli(sub)));
             tx_custom
                 .or(tx_transparent_transfer)
                 .or(tx_shielded_transfer)
@@ -433,6 +439,7 @@ pub mod cmds {
                 .or(query_rewards)
                 .or(query_delegations)
                 .or(query_find_validator)
+                .or(query_validator_keys)
                 .or(query_result)
                 .or(query_raw_bytes)
                 .or(query_proposal)
@@ -451,6 +458,7 @@ pub mod cmds {
                 .or(shielded_sync)
                 .or(gen_ibc_shielding)
                 .or(utils)
+                .or(dump_cli)
         }
     }
 
@@ -533,6 +541,7 @@ pub mod cmds {
         QueryEffNativeSupply(QueryEffNativeSupply),
         QueryStakingRewardsRate(QueryStakingRewardsRate),
         QueryFindValidator(QueryFindValidator),
+        QueryValidatorKeys(QueryValidatorKeys),
         QueryRawBytes(QueryRawBytes),
         QueryProposal(QueryProposal),
         QueryProposalVotes(QueryProposalVotes),
@@ -553,6 +562,10 @@ pub mod cmds {
         KeyGen(WalletGen),
         /// Key derivation
         KeyDerive(WalletDerive),
+        /// Batch key derivation from one mnemonic
+        KeyDeriveBatch(WalletDeriveBatch),
+        /// Mnemonic code preview
+        KeyPreviewMnemonic(WalletPreviewMnemonic),
         /// Payment address generation
         PayAddrGen(WalletGenPaymentAddress),
         /// Key / address list
@@ -565,47 +578,148 @@ pub mod cmds {
         KeyConvert(WalletConvertKey),
         /// Key import
         KeyImport(WalletImportKey),
+        /// Bulk key import from mnemonics
+        KeyImportMnemonicFile(WalletImportMnemonicFile),
+        /// Alias validation, before a bulk import
+        KeyValidateAliases(WalletValidateAliases),
         /// Key / address add
         KeyAddrAdd(WalletAddKeyAddress),
         /// Key / address remove
         KeyAddrRemove(WalletRemoveKeyAddress),
+        /// Key encryption policy
+        KeySetPolicy(WalletSetKeyPolicy),
+        /// Key / address tagging
+        KeyAddrTag(WalletTagKeyAddress),
+        /// Alias case preservation
+        AliasCaseSet(WalletSetAliasCase),
+        /// Validator key rotation
+        ValidatorKeyRotate(WalletRotateValidatorKey),
+        /// Address book encryption
+        AddressBookEncrypt(WalletEncryptAddressBook),
+        /// Wallet password check
+        KeyCheckPassword(WalletCheckPasswordFile),
+        /// Key encryption audit
+        KeyAuditEncryption(WalletAuditEncryption),
+        /// Encryption password test
+        KeyTestPassword(WalletTestPassword),
+        /// Message signing
+        KeySignMessage(WalletSignMessage),
+        /// Message signature verification
+        KeyVerifyMessage(WalletVerifyMessage),
+        /// Encrypted wallet store backup
+        Backup(WalletBackup),
+        /// Wallet store restore from a backup
+        Restore(WalletRestore),
+        /// Wallet initialization wizard
+        Init(WalletInitWizard),
+        /// Wallet store path
+        StorePath(WalletStorePath),
+        /// Wallet store compaction
+        Compact(WalletCompact),
     }
 
     impl Cmd for NamadaWallet {
         fn add_sub(app: App) -> App {
             app.subcommand(WalletGen::def())
                 .subcommand(WalletDerive::def())
+                .subcommand(WalletDeriveBatch::def())
+                .subcommand(WalletPreviewMnemonic::def())
                 .subcommand(WalletGenPaymentAddress::def())
                 .subcommand(WalletListKeysAddresses::def())
                 .subcommand(WalletFindKeysAddresses::def())
                 .subcommand(WalletExportKey::def())
                 .subcommand(WalletConvertKey::def())
                 .subcommand(WalletImportKey::def())
+                .subcommand(WalletImportMnemonicFile::def())
+                .subcommand(WalletValidateAliases::def())
                 .subcommand(WalletAddKeyAddress::def())
                 .subcommand(WalletRemoveKeyAddress::def())
+                .subcommand(WalletSetKeyPolicy::def())
+                .subcommand(WalletTagKeyAddress::def())
+                .subcommand(WalletSetAliasCase::def())
+                .subcommand(WalletRotateValidatorKey::def())
+                .subcommand(WalletEncryptAddressBook::def())
+                .subcommand(WalletCheckPasswordFile::def())
+                .subcommand(WalletAuditEncryption::def())
+                .subcommand(WalletTestPassword::def())
+                .subcommand(WalletSignMessage::def())
+                .subcommand(WalletVerifyMessage::def())
+                .subcommand(WalletBackup::def())
+                .subcommand(WalletRestore::def())
+                .subcommand(WalletInitWizard::def())
+                .subcommand(WalletStorePath::def())
+                .subcommand(WalletCompact::def())
         }
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             let gen = SubCmd::parse(matches).map(Self::KeyGen);
             let derive = SubCmd::parse(matches).map(Self::KeyDerive);
+            let derive_batch = SubCmd::parse(matches).map(Self::KeyDeriveBatch);
+            let preview_mnemonic =
+                SubCmd::parse(matches).map(Self::KeyPreviewMnemonic);
             let pay_addr_gen = SubCmd::parse(matches).map(Self::PayAddrGen);
             let key_addr_list = SubCmd::parse(matches).map(Self::KeyAddrList);
             let key_addr_find = SubCmd::parse(matches).map(Self::KeyAddrFind);
             let export = SubCmd::parse(matches).map(Self::KeyExport);
             let convert = SubCmd::parse(matches).map(Self::KeyConvert);
             let import = SubCmd::parse(matches).map(Self::KeyImport);
+            let import_mnemonic_file =
+                SubCmd::parse(matches).map(Self::KeyImportMnemonicFile);
+            let validate_aliases =
+                SubCmd::parse(matches).map(Self::KeyValidateAliases);
             let key_addr_add = SubCmd::parse(matches).map(Self::KeyAddrAdd);
             let key_addr_remove =
                 SubCmd::parse(matches).map(Self::KeyAddrRemove);
+            let key_set_policy = SubCmd::parse(matches).map(Self::KeySetPolicy);
+            let key_addr_tag = SubCmd::parse(matches).map(Self::KeyAddrTag);
+            let alias_case_set = SubCmd::parse(matches).map(Self::AliasCaseSet);
+            let validator_key_rotate =
+                SubCmd::parse(matches).map(Self::ValidatorKeyRotate);
+            let address_book_encrypt =
+                SubCmd::parse(matches).map(Self::AddressBookEncrypt);
+            let key_check_password =
+                SubCmd::parse(matches).map(Self::KeyCheckPassword);
+            let key_audit_encryption =
+                SubCmd::parse(matches).map(Self::KeyAuditEncryption);
+            let key_test_password =
+                SubCmd::parse(matches).map(Self::KeyTestPassword);
+            let key_sign_message =
+                SubCmd::parse(matches).map(Self::KeySignMessage);
+            let key_verify_message =
+                SubCmd::parse(matches).map(Self::KeyVerifyMessage);
+            let backup = SubCmd::parse(matches).map(Self::Backup);
+            let restore = SubCmd::parse(matches).map(Self::Restore);
+            let init = SubCmd::parse(matches).map(Self::Init);
+            let store_path = SubCmd::parse(matches).map(Self::StorePath);
+            let compact = SubCmd::parse(matches).map(Self::Compact);
             gen.or(derive)
+                .or(derive_batch)
+                .or(preview_mnemonic)
                 .or(pay_addr_gen)
                 .or(key_addr_list)
                 .or(key_addr_find)
                 .or(export)
                 .or(convert)
                 .or(import)
+                .or(import_mnemonic_file)
+                .or(validate_aliases)
                 .or(key_addr_add)
                 .or(key_addr_remove)
+                .or(key_set_policy)
+                .or(key_addr_tag)
+                .or(alias_case_set)
+                .or(validator_key_rotate)
+                .or(address_book_encrypt)
+                .or(key_check_password)
+                .or(key_audit_encryption)
+                .or(key_test_password)
+                .or(key_sign_message)
+                .or(key_verify_message)
+                .or(backup)
+                .or(restore)
+                .or(init)
+                .or(store_path)
+                .or(compact)
         }
     }
 
@@ -695,6 +809,68 @@ pub mod cmds {
         }
     }
 
+    /// Derive several keypairs and implicit addresses from one mnemonic code
+    #[derive(Clone, Debug)]
+    pub struct WalletDeriveBatch(pub args::KeyDeriveBatch);
+
+    impl SubCmd for WalletDeriveBatch {
+        const CMD: &'static str = "derive-batch";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::KeyDeriveBatch::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Derive several transparent keys from one mnemonic code."
+                ))
+                .long_about(wrap!(
+                    "Derive a keypair at each of the given HD derivation \
+                     paths from a single mnemonic code, and store each of \
+                     them with its implicit address under its alias. The \
+                     mnemonic code and the encryption password are only \
+                     prompted for once. Nothing is stored if any path is \
+                     malformed or any key fails to be stored, e.g. to restore \
+                     all the keys of a validator at once."
+                ))
+                .add_args::<args::KeyDeriveBatch>()
+        }
+    }
+
+    /// Show the key and address derived from a mnemonic code, without
+    /// storing them
+    #[derive(Clone, Debug)]
+    pub struct WalletPreviewMnemonic(pub args::KeyPreviewMnemonic);
+
+    impl SubCmd for WalletPreviewMnemonic {
+        const CMD: &'static str = "preview-mnemonic";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::KeyPreviewMnemonic::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Show the public key and address derived from a mnemonic \
+                     code, without storing anything."
+                ))
+                .long_about(wrap!(
+                    "Derive a transparent keypair from the given mnemonic \
+                     code and HD derivation path, and show only its public \
+                     key and implicit address, e.g. to verify a backup. The \
+                     secret key is discarded right away and the wallet is \
+                     neither read nor modified."
+                ))
+                .add_args::<args::KeyPreviewMnemonic>()
+        }
+    }
+
     /// List known keys and addresses
     #[derive(Clone, Debug)]
     pub struct WalletListKeysAddresses(pub args::KeyAddressList);
@@ -817,6 +993,70 @@ pub mod cmds {
         }
     }
 
+    /// Import keys derived from the mnemonics in a file
+    #[derive(Clone, Debug)]
+    pub struct WalletImportMnemonicFile(pub args::KeyImportMnemonicFile);
+
+    impl SubCmd for WalletImportMnemonicFile {
+        const CMD: &'static str = "import-mnemonic-file";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                Self(args::KeyImportMnemonicFile::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Derive and import keys from the mnemonic codes in a TOML \
+                     file."
+                ))
+                .long_about(wrap!(
+                    "Derive and import keys from the mnemonic codes in a TOML \
+                     file, for offline bulk recovery. Every `[[key]]` entry \
+                     of the file has an `alias` and a `mnemonic`, and \
+                     optionally a `derivation_path` (\"default\" if not \
+                     given), a `scheme` (\"ed25519\" if not given), a BIP39 \
+                     `passphrase` and a `shielded` flag to derive a spending \
+                     key. Entries that fail to be imported are reported at \
+                     the end, without aborting the import of the others."
+                ))
+                .add_args::<args::KeyImportMnemonicFile>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct WalletValidateAliases(pub args::KeyValidateAliases);
+
+    impl SubCmd for WalletValidateAliases {
+        const CMD: &'static str = "validate-aliases";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::KeyValidateAliases::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Check the aliases of a file of keys to import against \
+                     the wallet's alias rules, without importing anything."
+                ))
+                .long_about(wrap!(
+                    "Check the aliases of the `[[key]]` entries of a TOML \
+                     file, as read by `import-mnemonic-file`, without \
+                     importing anything. The normalized form of every alias \
+                     is reported, along with whether normalizing changed it, \
+                     whether it is empty or reserved, and whether it collides \
+                     with another entry of the file or an alias of the \
+                     wallet."
+                ))
+                .add_args::<args::KeyValidateAliases>()
+        }
+    }
+
     /// Add public / payment address to the wallet
     #[derive(Clone, Debug)]
     pub struct WalletAddKeyAddress(pub args::KeyAddressAdd);
@@ -860,310 +1100,746 @@ pub mod cmds {
         }
     }
 
-    /// Generate a payment address from a viewing key or payment address
+    /// Set the encryption policy of a key
     #[derive(Clone, Debug)]
-    pub struct WalletGenPaymentAddress(pub args::PayAddressGen<args::CliTypes>);
+    pub struct WalletSetKeyPolicy(pub args::KeySetPolicy);
 
-    impl SubCmd for WalletGenPaymentAddress {
-        const CMD: &'static str = "gen-payment-addr";
+    impl SubCmd for WalletSetKeyPolicy {
+        const CMD: &'static str = "set-policy";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Self(args::PayAddressGen::parse(matches)))
+                .map(|matches| Self(args::KeySetPolicy::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Generates a payment address from the given spending key."
+                    "Set the encryption policy of a transparent secret key or \
+                     a shielded spending key."
                 ))
-                .add_args::<args::PayAddressGen<args::CliTypes>>()
+                .long_about(wrap!(
+                    "Set the encryption policy of a transparent secret key or \
+                     a shielded spending key. The stored key is re-encrypted \
+                     or decrypted in place to satisfy the policy. The policy \
+                     is stored in the wallet and whenever a key that violates \
+                     it is found, a warning is displayed."
+                ))
+                .add_args::<args::KeySetPolicy>()
         }
     }
 
+    /// Tag the keys and addresses of an alias
     #[derive(Clone, Debug)]
-    pub enum Ledger {
-        Run(LedgerRun),
-        RunUntil(LedgerRunUntil),
-        Reset(LedgerReset),
-        DumpDb(LedgerDumpDb),
-        UpdateDB(LedgerUpdateDB),
-        QueryDB(LedgerQueryDB),
-        RollBack(LedgerRollBack),
-    }
+    pub struct WalletTagKeyAddress(pub args::KeyAddressTag);
 
-    impl SubCmd for Ledger {
-        const CMD: &'static str = "ledger";
+    impl SubCmd for WalletTagKeyAddress {
+        const CMD: &'static str = "tag";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
-            matches.subcommand_matches(Self::CMD).and_then(|matches| {
-                let run = SubCmd::parse(matches).map(Self::Run);
-                let reset = SubCmd::parse(matches).map(Self::Reset);
-                let dump_db = SubCmd::parse(matches).map(Self::DumpDb);
-                let update_db = SubCmd::parse(matches).map(Self::UpdateDB);
-                let query_db = SubCmd::parse(matches).map(Self::QueryDB);
-                let rollback = SubCmd::parse(matches).map(Self::RollBack);
-                let run_until = SubCmd::parse(matches).map(Self::RunUntil);
-                run.or(reset)
-                    .or(dump_db)
-                    .or(update_db)
-                    .or(query_db)
-                    .or(rollback)
-                    .or(run_until)
-                    // The `run` command is the default if no sub-command given
-                    .or(Some(Self::Run(LedgerRun(args::LedgerRun {
-                        start_time: None,
-                        migration_path: None,
-                        migration_hash: None,
-                        migration_height: None,
-                    }))))
-            })
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::KeyAddressTag::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Ledger node sub-commands. If no sub-command specified, \
-                     defaults to run the node."
+                    "Tag the keys and addresses of an alias in the wallet."
                 ))
-                .subcommand(LedgerRun::def())
-                .subcommand(LedgerRunUntil::def())
-                .subcommand(LedgerReset::def())
-                .subcommand(LedgerDumpDb::def())
-                .subcommand(LedgerUpdateDB::def())
-                .subcommand(LedgerQueryDB::def())
-                .subcommand(LedgerRollBack::def())
+                .long_about(wrap!(
+                    "Tag the keys and addresses of an alias in the wallet \
+                     with free-form tags to organize them. The listed \
+                     entries can then be filtered by tag with `list --tag`."
+                ))
+                .add_args::<args::KeyAddressTag>()
         }
     }
 
+    /// Set whether the case of the wallet aliases is preserved
     #[derive(Clone, Debug)]
-    pub struct LedgerRun(pub args::LedgerRun);
+    pub struct WalletSetAliasCase(pub args::AliasCaseSet);
 
-    impl SubCmd for LedgerRun {
-        const CMD: &'static str = "run";
+    impl SubCmd for WalletSetAliasCase {
+        const CMD: &'static str = "set-alias-case";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Self(args::LedgerRun::parse(matches)))
+                .map(|matches| Self(args::AliasCaseSet::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Run Namada ledger node."))
-                .add_args::<args::LedgerRun>()
+                .about(wrap!(
+                    "Set whether the case of the aliases added to the wallet \
+                     is preserved."
+                ))
+                .long_about(wrap!(
+                    "Set whether the case of the aliases added to the wallet \
+                     is preserved. By default, aliases are normalized to \
+                     lower-case. With `--preserve`, the aliases added from \
+                     then on are displayed in the case they were given in. \
+                     Either way, aliases are looked up case-insensitively. \
+                     The setting is stored in the wallet."
+                ))
+                .add_args::<args::AliasCaseSet>()
         }
     }
 
+    /// Rotate one of the validator keys, keeping the other one
     #[derive(Clone, Debug)]
-    pub struct LedgerRunUntil(pub args::LedgerRunUntil);
+    pub struct WalletRotateValidatorKey(pub args::ValidatorKeyRotate);
 
-    impl SubCmd for LedgerRunUntil {
-        const CMD: &'static str = "run-until";
+    impl SubCmd for WalletRotateValidatorKey {
+        const CMD: &'static str = "rotate-validator-key";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Self(args::LedgerRunUntil::parse(matches)))
+                .map(|matches| Self(args::ValidatorKeyRotate::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Run Namada ledger node until a given height. Then halt \
-                     or suspend."
+                    "Regenerate one of the validator keys, keeping the other \
+                     one."
                 ))
-                .add_args::<args::LedgerRunUntil>()
+                .long_about(wrap!(
+                    "Regenerate one of the protocol and Ethereum bridge keys \
+                     of the validator whose keys are stored in the wallet, \
+                     keeping the other one. The new key is stored in the \
+                     wallet in place of the old one. Note that the new key \
+                     must still be registered on chain."
+                ))
+                .add_args::<args::ValidatorKeyRotate>()
         }
     }
 
+    /// Encrypt the address book of the wallet at rest
     #[derive(Clone, Debug)]
-    pub struct LedgerReset;
+    pub struct WalletEncryptAddressBook(pub args::AddressBookEncrypt);
 
-    impl SubCmd for LedgerReset {
-        const CMD: &'static str = "reset";
+    impl SubCmd for WalletEncryptAddressBook {
+        const CMD: &'static str = "encrypt-address-book";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
-            matches.subcommand_matches(Self::CMD).map(|_matches| Self)
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::AddressBookEncrypt::parse(matches)))
         }
 
         fn def() -> App {
-            App::new(Self::CMD).about(wrap!(
-                "Delete Namada ledger node's and Tendermint node's storage \
-                 data."
-            ))
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Encrypt the transparent and shielded address books of \
+                     the wallet at rest."
+                ))
+                .long_about(wrap!(
+                    "Encrypt the transparent and shielded address books of \
+                     the wallet at rest under a password, which is then \
+                     prompted for whenever the wallet is loaded. Running the \
+                     command on an encrypted address book changes its \
+                     password. Wallets with a plaintext address book keep \
+                     loading as before."
+                ))
+                .add_args::<args::AddressBookEncrypt>()
         }
     }
 
+    /// Print the wallet store directory
     #[derive(Clone, Debug)]
-    pub struct LedgerDumpDb(pub args::LedgerDumpDb);
+    pub struct WalletStorePath(pub args::WalletPath);
 
-    impl SubCmd for LedgerDumpDb {
-        const CMD: &'static str = "dump-db";
+    impl SubCmd for WalletStorePath {
+        const CMD: &'static str = "path";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Self(args::LedgerDumpDb::parse(matches)))
+                .map(|matches| Self(args::WalletPath::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Dump Namada ledger node's DB from a block into a file."
+                    "Print the path of the wallet file that other wallet \
+                     commands would use."
                 ))
-                .add_args::<args::LedgerDumpDb>()
+                .long_about(wrap!(
+                    "Print the path of the wallet file that other wallet \
+                     commands would use, as resolved from the base \
+                     directory, the chain ID (from NAMADA_CHAIN_ID, \
+                     --chain-id or the global config), --pre-genesis and \
+                     --wallet-file-name. Nothing is loaded from it, and \
+                     neither it nor its directory need exist."
+                ))
+                .add_args::<args::WalletPath>()
         }
     }
 
+    /// Rewrite the wallet store without its dead data
     #[derive(Clone, Debug)]
-    pub struct LedgerUpdateDB(pub args::LedgerUpdateDb);
+    pub struct WalletCompact(pub args::WalletCompact);
 
-    impl SubCmd for LedgerUpdateDB {
-        const CMD: &'static str = "update-db";
+    impl SubCmd for WalletCompact {
+        const CMD: &'static str = "compact";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Self(args::LedgerUpdateDb::parse(matches)))
+                .map(|matches| Self(args::WalletCompact::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Applies a set of updates to the DB for hard forking. The \
-                     input should be a path to a file dictating a set of keys \
-                     and their new values. Can be dry-run for testing."
+                    "Rewrite the wallet store without its dead data and report \
+                     the size reduction."
                 ))
-                .add_args::<args::LedgerUpdateDb>()
+                .long_about(wrap!(
+                    "Load the wallet store, remove the data left behind by \
+                     removed or replaced entries, such as the tags and \
+                     encryption policies of aliases that no longer exist, and \
+                     save it in canonical form. The store is written to a \
+                     temporary file that then replaces it, so it is never \
+                     left partially written. The live entries, including how \
+                     each key is encrypted, are preserved."
+                ))
+                .add_args::<args::WalletCompact>()
         }
     }
 
+    /// Check that the wallet password decrypts a stored key
     #[derive(Clone, Debug)]
-    pub struct LedgerQueryDB(pub args::LedgerQueryDb);
+    pub struct WalletCheckPasswordFile(pub args::KeyCheckPassword);
 
-    impl SubCmd for LedgerQueryDB {
-        const CMD: &'static str = "query-db";
+    impl SubCmd for WalletCheckPasswordFile {
+        const CMD: &'static str = "check-password-file";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Self(args::LedgerQueryDb::parse(matches)))
+                .map(|matches| Self(args::KeyCheckPassword::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Query the value of keys from the DB while the ledger is \
-                     not running."
+                    "Check that the wallet password decrypts a stored key."
                 ))
-                .add_args::<args::LedgerQueryDb>()
+                .long_about(wrap!(
+                    "Check that the wallet password decrypts a stored key, \
+                     without using the key for anything else. The password \
+                     is read the same way as whenever a key is decrypted: \
+                     from the file at NAMADA_WALLET_PASSWORD_FILE, else from \
+                     NAMADA_WALLET_PASSWORD, else from a prompt."
+                ))
+                .add_args::<args::KeyCheckPassword>()
         }
     }
 
+    /// List the encryption status of the stored keys
     #[derive(Clone, Debug)]
-    pub struct LedgerRollBack;
+    pub struct WalletAuditEncryption(pub args::KeyAuditEncryption);
 
-    impl SubCmd for LedgerRollBack {
-        const CMD: &'static str = "rollback";
+    impl SubCmd for WalletAuditEncryption {
+        const CMD: &'static str = "audit-encryption";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
-            matches.subcommand_matches(Self::CMD).map(|_matches| Self)
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::KeyAuditEncryption::parse(matches)))
         }
 
         fn def() -> App {
-            App::new(Self::CMD).about(wrap!(
-                "Roll Namada state back to the previous height. This command \
-                 does not create a backup of neither the Namada nor the \
-                 Tendermint state before execution: for extra safety, it is \
-                 recommended to make a backup in advance."
-            ))
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "List whether each stored key is encrypted at rest."
+                ))
+                .long_about(wrap!(
+                    "List whether each stored transparent secret key and \
+                     shielded spending key is encrypted at rest, flagging \
+                     the ones stored in plaintext. No key is decrypted."
+                ))
+                .add_args::<args::KeyAuditEncryption>()
         }
     }
 
+    /// Check that a candidate password encrypts and decrypts a key
     #[derive(Clone, Debug)]
-    pub enum Config {
-        Gen(ConfigGen),
-        UpdateValidatorLocalConfig(ValidatorLocalConfig),
-        UpdateLocalConfig(LocalConfig),
-    }
+    pub struct WalletTestPassword(pub args::KeyTestPassword);
 
-    impl SubCmd for Config {
-        const CMD: &'static str = "config";
+    impl SubCmd for WalletTestPassword {
+        const CMD: &'static str = "test-password";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
-            matches.subcommand_matches(Self::CMD).and_then(|matches| {
-                let gen = SubCmd::parse(matches).map(Self::Gen);
-                let validator_cfg = SubCmd::parse(matches)
-                    .map(Self::UpdateValidatorLocalConfig);
-                let local_cfg =
-                    SubCmd::parse(matches).map(Self::UpdateLocalConfig);
-                gen.or(validator_cfg).or(local_cfg)
-            })
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::KeyTestPassword::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .subcommand_required(true)
-                .arg_required_else_help(true)
-                .about(wrap!("Configuration sub-commands."))
-                .subcommand(ConfigGen::def())
-                .subcommand(ValidatorLocalConfig::def())
-                .subcommand(LocalConfig::def())
+                .about(wrap!(
+                    "Check that a candidate password round-trips the \
+                     encryption of a key."
+                ))
+                .long_about(wrap!(
+                    "Prompt for a candidate encryption password, encrypt a \
+                     throwaway key with it as the wallet would store it and \
+                     decrypt it back. The wallet itself is neither read nor \
+                     modified."
+                ))
+                .add_args::<args::KeyTestPassword>()
         }
     }
 
+    /// Sign a message with a stored key
     #[derive(Clone, Debug)]
-    pub struct ConfigGen;
+    pub struct WalletSignMessage(pub args::KeySignMessage);
 
-    impl SubCmd for ConfigGen {
-        const CMD: &'static str = "gen";
+    impl SubCmd for WalletSignMessage {
+        const CMD: &'static str = "sign-message";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
-            matches.subcommand_matches(Self::CMD).map(|_matches| Self)
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::KeySignMessage::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Generate the default configuration file."))
+                .about(wrap!(
+                    "Sign a message with a stored key to prove control of it."
+                ))
+                .long_about(wrap!(
+                    "Sign a message, such as a challenge string, with a \
+                     stored transparent secret key and print the signature \
+                     along with the public key. The message is prefixed \
+                     before signing, so that the signature can't be passed \
+                     off as one over a transaction. Check it with \
+                     `verify-message`."
+                ))
+                .add_args::<args::KeySignMessage>()
         }
     }
 
+    /// Verify the signature over a message
     #[derive(Clone, Debug)]
-    pub struct ValidatorLocalConfig(pub args::UpdateValidatorLocalConfig);
+    pub struct WalletVerifyMessage(pub args::KeyVerifyMessage);
 
-    impl SubCmd for ValidatorLocalConfig {
-        const CMD: &'static str = "update-validator-local-config";
+    impl SubCmd for WalletVerifyMessage {
+        const CMD: &'static str = "verify-message";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
-            matches.subcommand_matches(Self::CMD).map(|matches| {
-                Self(args::UpdateValidatorLocalConfig::parse(matches))
-            })
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::KeyVerifyMessage::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Update the validator's local configuration."))
-                .add_args::<args::UpdateValidatorLocalConfig>()
+                .about(wrap!(
+                    "Verify a signature made over a message with \
+                     `sign-message`."
+                ))
+                .add_args::<args::KeyVerifyMessage>()
         }
     }
 
+    /// Back up the wallet store to an encrypted file
     #[derive(Clone, Debug)]
-    pub struct LocalConfig(pub args::UpdateLocalConfig);
+    pub struct WalletBackup(pub args::WalletBackup);
 
-    impl SubCmd for LocalConfig {
-        const CMD: &'static str = "update-local-config";
+    impl SubCmd for WalletBackup {
+        const CMD: &'static str = "backup";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Self(args::UpdateLocalConfig::parse(matches)))
+                .map(|matches| Self(args::WalletBackup::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Update the node's local configuration."))
+                .about(wrap!(
+                    "Back up the wallet store to a password encrypted file."
+                ))
+                .long_about(wrap!(
+                    "Bundle the wallet store, including its address book, \
+                     into an archive encrypted under a prompted password. \
+                     Keys are archived as they are stored, so encrypted keys \
+                     still need their own password after a `restore`."
+                ))
+                .add_args::<args::WalletBackup>()
+        }
+    }
+
+    /// Restore the wallet store from an encrypted backup
+    #[derive(Clone, Debug)]
+    pub struct WalletRestore(pub args::WalletRestore);
+
+    impl SubCmd for WalletRestore {
+        const CMD: &'static str = "restore";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::WalletRestore::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Restore the wallet store from a file written by `backup`."
+                ))
+                .long_about(wrap!(
+                    "Decrypt a backup written by `backup` and write the \
+                     wallet store it holds into the base directory. An \
+                     existing wallet store is only replaced with --force."
+                ))
+                .add_args::<args::WalletRestore>()
+        }
+    }
+
+    /// Interactively create a key in the wallet
+    #[derive(Clone, Debug)]
+    pub struct WalletInitWizard(pub args::WalletInit);
+
+    impl SubCmd for WalletInitWizard {
+        const CMD: &'static str = "init";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::WalletInit::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Walk through creating a key in the wallet, step by step."
+                ))
+                .long_about(wrap!(
+                    "Walk through creating a key in the wallet: choosing its \
+                     scheme and alias, generating it or restoring it from a \
+                     mnemonic, setting its encryption password and \
+                     optionally adding the established accounts that it \
+                     controls in a genesis txs file. Every choice that is \
+                     given with an option is not prompted for, and \
+                     `--non-interactive` takes the defaults of the others."
+                ))
+                .add_args::<args::WalletInit>()
+        }
+    }
+
+    /// Generate a payment address from a viewing key or payment address
+    #[derive(Clone, Debug)]
+    pub struct WalletGenPaymentAddress(pub args::PayAddressGen<args::CliTypes>);
+
+    impl SubCmd for WalletGenPaymentAddress {
+        const CMD: &'static str = "gen-payment-addr";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::PayAddressGen::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Generates a payment address from the given spending key."
+                ))
+                .add_args::<args::PayAddressGen<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub enum Ledger {
+        Run(LedgerRun),
+        RunUntil(LedgerRunUntil),
+        Reset(LedgerReset),
+        DumpDb(LedgerDumpDb),
+        UpdateDB(LedgerUpdateDB),
+        QueryDB(LedgerQueryDB),
+        RollBack(LedgerRollBack),
+    }
+
+    impl SubCmd for Ledger {
+        const CMD: &'static str = "ledger";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).and_then(|matches| {
+                let run = SubCmd::parse(matches).map(Self::Run);
+                let reset = SubCmd::parse(matches).map(Self::Reset);
+                let dump_db = SubCmd::parse(matches).map(Self::DumpDb);
+                let update_db = SubCmd::parse(matches).map(Self::UpdateDB);
+                let query_db = SubCmd::parse(matches).map(Self::QueryDB);
+                let rollback = SubCmd::parse(matches).map(Self::RollBack);
+                let run_until = SubCmd::parse(matches).map(Self::RunUntil);
+                run.or(reset)
+                    .or(dump_db)
+                    .or(update_db)
+                    .or(query_db)
+                    .or(rollback)
+                    .or(run_until)
+                    // The `run` command is the default if no sub-command given
+                    .or(Some(Self::Run(LedgerRun(args::LedgerRun {
+                        start_time: None,
+                        migration_path: None,
+                        migration_hash: None,
+                        migration_height: None,
+                    }))))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Ledger node sub-commands. If no sub-command specified, \
+                     defaults to run the node."
+                ))
+                .subcommand(LedgerRun::def())
+                .subcommand(LedgerRunUntil::def())
+                .subcommand(LedgerReset::def())
+                .subcommand(LedgerDumpDb::def())
+                .subcommand(LedgerUpdateDB::def())
+                .subcommand(LedgerQueryDB::def())
+                .subcommand(LedgerRollBack::def())
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerRun(pub args::LedgerRun);
+
+    impl SubCmd for LedgerRun {
+        const CMD: &'static str = "run";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::LedgerRun::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Run Namada ledger node."))
+                .add_args::<args::LedgerRun>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerRunUntil(pub args::LedgerRunUntil);
+
+    impl SubCmd for LedgerRunUntil {
+        const CMD: &'static str = "run-until";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::LedgerRunUntil::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Run Namada ledger node until a given height. Then halt \
+                     or suspend."
+                ))
+                .add_args::<args::LedgerRunUntil>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerReset;
+
+    impl SubCmd for LedgerReset {
+        const CMD: &'static str = "reset";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|_matches| Self)
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD).about(wrap!(
+                "Delete Namada ledger node's and Tendermint node's storage \
+                 data."
+            ))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerDumpDb(pub args::LedgerDumpDb);
+
+    impl SubCmd for LedgerDumpDb {
+        const CMD: &'static str = "dump-db";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::LedgerDumpDb::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Dump Namada ledger node's DB from a block into a file."
+                ))
+                .add_args::<args::LedgerDumpDb>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerUpdateDB(pub args::LedgerUpdateDb);
+
+    impl SubCmd for LedgerUpdateDB {
+        const CMD: &'static str = "update-db";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::LedgerUpdateDb::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Applies a set of updates to the DB for hard forking. The \
+                     input should be a path to a file dictating a set of keys \
+                     and their new values. Can be dry-run for testing."
+                ))
+                .add_args::<args::LedgerUpdateDb>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerQueryDB(pub args::LedgerQueryDb);
+
+    impl SubCmd for LedgerQueryDB {
+        const CMD: &'static str = "query-db";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::LedgerQueryDb::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Query the value of keys from the DB while the ledger is \
+                     not running."
+                ))
+                .add_args::<args::LedgerQueryDb>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerRollBack;
+
+    impl SubCmd for LedgerRollBack {
+        const CMD: &'static str = "rollback";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|_matches| Self)
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD).about(wrap!(
+                "Roll Namada state back to the previous height. This command \
+                 does not create a backup of neither the Namada nor the \
+                 Tendermint state before execution: for extra safety, it is \
+                 recommended to make a backup in advance."
+            ))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub enum Config {
+        Gen(ConfigGen),
+        UpdateValidatorLocalConfig(ValidatorLocalConfig),
+        UpdateLocalConfig(LocalConfig),
+    }
+
+    impl SubCmd for Config {
+        const CMD: &'static str = "config";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).and_then(|matches| {
+                let gen = SubCmd::parse(matches).map(Self::Gen);
+                let validator_cfg = SubCmd::parse(matches)
+                    .map(Self::UpdateValidatorLocalConfig);
+                let local_cfg =
+                    SubCmd::parse(matches).map(Self::UpdateLocalConfig);
+                gen.or(validator_cfg).or(local_cfg)
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .subcommand_required(true)
+                .arg_required_else_help(true)
+                .about(wrap!("Configuration sub-commands."))
+                .subcommand(ConfigGen::def())
+                .subcommand(ValidatorLocalConfig::def())
+                .subcommand(LocalConfig::def())
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct ConfigGen;
+
+    impl SubCmd for ConfigGen {
+        const CMD: &'static str = "gen";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|_matches| Self)
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Generate the default configuration file."))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct ValidatorLocalConfig(pub args::UpdateValidatorLocalConfig);
+
+    impl SubCmd for ValidatorLocalConfig {
+        const CMD: &'static str = "update-validator-local-config";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                Self(args::UpdateValidatorLocalConfig::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Update the validator's local configuration."))
+                .add_args::<args::UpdateValidatorLocalConfig>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LocalConfig(pub args::UpdateLocalConfig);
+
+    impl SubCmd for LocalConfig {
+        const CMD: &'static str = "update-local-config";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::UpdateLocalConfig::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Update the node's local configuration."))
                 .add_args::<args::UpdateLocalConfig>()
         }
     }
@@ -2171,6 +2847,32 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct QueryValidatorKeys(pub args::QueryValidatorKeys<args::CliTypes>);
+
+    impl SubCmd for QueryValidatorKeys {
+        const CMD: &'static str = "check-validator-keys";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryValidatorKeys(args::QueryValidatorKeys::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Compare the consensus and protocol keys of a validator \
+                     on chain with the keys of its alias in the wallet, e.g. \
+                     to check a restored wallet before starting the node."
+                ))
+                .add_args::<args::QueryValidatorKeys<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct QueryRawBytes(pub args::QueryRawBytes<args::CliTypes>);
 
@@ -2481,19 +3183,39 @@ pub mod cmds {
     pub enum ClientUtils {
         JoinNetwork(JoinNetwork),
         ValidateWasm(ValidateWasm),
+        CheckWasm(CheckWasm),
         InitNetwork(InitNetwork),
         DeriveGenesisAddresses(DeriveGenesisAddresses),
         GenesisBond(GenesisBond),
+        GenBondsTemplate(GenBondsTemplate),
+        BondsTotal(BondsTotal),
+        EstimateDeviceTime(EstimateDeviceTime),
         InitGenesisEstablishedAccount(InitGenesisEstablishedAccount),
         InitGenesisValidator(InitGenesisValidator),
         PkToTmAddress(PkToTmAddress),
+        RecoverAddress(RecoverAddress),
         SignOffline(SignOffline),
+        SignOfflineTx(SignOfflineTx),
         DefaultBaseDir(DefaultBaseDir),
+        ShowConfig(ShowConfig),
+        ListChains(ListChains),
+        ListProfiles(ListProfiles),
+        ListDevices(ListDevices),
+        SelfTest(SelfTest),
+        ExplainError(ExplainError),
         EpochSleep(EpochSleep),
         ValidateGenesisTemplates(ValidateGenesisTemplates),
+        CheckConsistency(CheckConsistency),
         SignGenesisTxs(SignGenesisTxs),
+        VerifyGenesisTxs(VerifyGenesisTxs),
+        Canonicalize(Canonicalize),
         ByteGenesisTxs(ByteGenesisTxs),
+        SubmitGenesisTxs(SubmitGenesisTxs),
+        AttachSignatures(AttachSignatures),
+        MergeSigned(MergeSigned),
+        SignersManifest(SignersManifest),
         ParseMigrationJson(MigrationJson),
+        DumpCli(DumpCli),
     }
 
     impl SubCmd for ClientUtils {
@@ -2505,46 +3227,98 @@ pub mod cmds {
                     SubCmd::parse(matches).map(Self::JoinNetwork);
                 let validate_wasm =
                     SubCmd::parse(matches).map(Self::ValidateWasm);
+                let check_wasm = SubCmd::parse(matches).map(Self::CheckWasm);
                 let init_network =
                     SubCmd::parse(matches).map(Self::InitNetwork);
                 let derive_addresses =
                     SubCmd::parse(matches).map(Self::DeriveGenesisAddresses);
                 let genesis_bond =
                     SubCmd::parse(matches).map(Self::GenesisBond);
+                let gen_bonds_template =
+                    SubCmd::parse(matches).map(Self::GenBondsTemplate);
+                let bonds_total = SubCmd::parse(matches).map(Self::BondsTotal);
+                let estimate_device_time =
+                    SubCmd::parse(matches).map(Self::EstimateDeviceTime);
                 let init_established = SubCmd::parse(matches)
                     .map(Self::InitGenesisEstablishedAccount);
                 let init_genesis =
                     SubCmd::parse(matches).map(Self::InitGenesisValidator);
                 let pk_to_tm_address =
                     SubCmd::parse(matches).map(Self::PkToTmAddress);
+                let recover_address =
+                    SubCmd::parse(matches).map(Self::RecoverAddress);
                 let sign_offline =
                     SubCmd::parse(matches).map(Self::SignOffline);
+                let sign_offline_tx =
+                    SubCmd::parse(matches).map(Self::SignOfflineTx);
                 let default_base_dir =
                     SubCmd::parse(matches).map(Self::DefaultBaseDir);
+                let show_config = SubCmd::parse(matches).map(Self::ShowConfig);
+                let list_chains = SubCmd::parse(matches).map(Self::ListChains);
+                let list_profiles =
+                    SubCmd::parse(matches).map(Self::ListProfiles);
+                let list_devices =
+                    SubCmd::parse(matches).map(Self::ListDevices);
+                let self_test = SubCmd::parse(matches).map(Self::SelfTest);
+                let explain_error =
+                    SubCmd::parse(matches).map(Self::ExplainError);
                 let epoch_sleep = SubCmd::parse(matches).map(Self::EpochSleep);
                 let validate_genesis_templates =
                     SubCmd::parse(matches).map(Self::ValidateGenesisTemplates);
+                let check_consistency =
+                    SubCmd::parse(matches).map(Self::CheckConsistency);
                 let genesis_tx =
                     SubCmd::parse(matches).map(Self::SignGenesisTxs);
+                let verify_genesis_txs =
+                    SubCmd::parse(matches).map(Self::VerifyGenesisTxs);
+                let canonicalize =
+                    SubCmd::parse(matches).map(Self::Canonicalize);
                 let byte_genesis_tx =
                     SubCmd::parse(matches).map(Self::ByteGenesisTxs);
+                let submit_genesis_txs =
+                    SubCmd::parse(matches).map(Self::SubmitGenesisTxs);
+                let attach_signatures =
+                    SubCmd::parse(matches).map(Self::AttachSignatures);
+                let merge_signed =
+                    SubCmd::parse(matches).map(Self::MergeSigned);
+                let signers_manifest =
+                    SubCmd::parse(matches).map(Self::SignersManifest);
                 let parse_migrations_json =
                     SubCmd::parse(matches).map(Self::ParseMigrationJson);
                 join_network
                     .or(validate_wasm)
+                    .or(check_wasm)
                     .or(init_network)
                     .or(derive_addresses)
                     .or(genesis_bond)
+                    .or(gen_bonds_template)
+                    .or(bonds_total)
+                    .or(estimate_device_time)
                     .or(init_established)
                     .or(init_genesis)
                     .or(pk_to_tm_address)
+                    .or(recover_address)
                     .or(default_base_dir)
+                    .or(show_config)
+                    .or(list_chains)
+                    .or(list_profiles)
+                    .or(list_devices)
+                    .or(self_test)
+                    .or(explain_error)
                     .or(epoch_sleep)
                     .or(validate_genesis_templates)
+                    .or(check_consistency)
                     .or(genesis_tx)
+                    .or(verify_genesis_txs)
+                    .or(canonicalize)
                     .or(byte_genesis_tx)
+                    .or(submit_genesis_txs)
+                    .or(attach_signatures)
+                    .or(merge_signed)
+                    .or(signers_manifest)
                     .or(parse_migrations_json)
                     .or(sign_offline)
+                    .or(sign_offline_tx)
             })
         }
 
@@ -2553,18 +3327,37 @@ pub mod cmds {
                 .about(wrap!("Utilities."))
                 .subcommand(JoinNetwork::def())
                 .subcommand(ValidateWasm::def())
+                .subcommand(CheckWasm::def())
                 .subcommand(InitNetwork::def())
                 .subcommand(DeriveGenesisAddresses::def())
                 .subcommand(GenesisBond::def())
+                .subcommand(GenBondsTemplate::def())
+                .subcommand(BondsTotal::def())
+                .subcommand(EstimateDeviceTime::def())
                 .subcommand(InitGenesisEstablishedAccount::def())
                 .subcommand(InitGenesisValidator::def())
                 .subcommand(PkToTmAddress::def())
+                .subcommand(RecoverAddress::def())
                 .subcommand(SignOffline::def())
+                .subcommand(SignOfflineTx::def())
                 .subcommand(DefaultBaseDir::def())
+                .subcommand(ShowConfig::def())
+                .subcommand(ListChains::def())
+                .subcommand(ListProfiles::def())
+                .subcommand(ListDevices::def())
+                .subcommand(SelfTest::def())
+                .subcommand(ExplainError::def())
                 .subcommand(EpochSleep::def())
                 .subcommand(ValidateGenesisTemplates::def())
+                .subcommand(CheckConsistency::def())
                 .subcommand(SignGenesisTxs::def())
+                .subcommand(VerifyGenesisTxs::def())
+                .subcommand(Canonicalize::def())
                 .subcommand(ByteGenesisTxs::def())
+                .subcommand(SubmitGenesisTxs::def())
+                .subcommand(AttachSignatures::def())
+                .subcommand(MergeSigned::def())
+                .subcommand(SignersManifest::def())
                 .subcommand(MigrationJson::def())
                 .subcommand_required(true)
                 .arg_required_else_help(true)
@@ -2612,6 +3405,28 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct CheckWasm(pub args::CheckWasm);
+
+    impl SubCmd for CheckWasm {
+        const CMD: &'static str = "check-wasm";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::CheckWasm::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Check that the WASM dir in use contains the WASMs of all \
+                     the transactions that the client can submit."
+                ))
+                .add_args::<args::CheckWasm>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct InitNetwork(pub args::InitNetwork);
 
@@ -2661,139 +3476,411 @@ pub mod cmds {
         const CMD: &'static str = "init-genesis-established-account";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
-            matches.subcommand_matches(Self::CMD).map(|matches| {
-                Self(args::InitGenesisEstablishedAccount::parse(matches))
-            })
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                Self(args::InitGenesisEstablishedAccount::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Initialize an established account available at genesis."
+                ))
+                .add_args::<args::InitGenesisEstablishedAccount>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct GenesisBond(pub args::GenesisBond);
+
+    impl SubCmd for GenesisBond {
+        const CMD: &'static str = "genesis-bond";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::GenesisBond::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Bond to a validator at pre-genesis."))
+                .add_args::<args::GenesisBond>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct InitGenesisValidator(pub args::InitGenesisValidator);
+
+    impl SubCmd for InitGenesisValidator {
+        const CMD: &'static str = "init-genesis-validator";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::InitGenesisValidator::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Initialize genesis validator's address, consensus key \
+                     and validator account key and use it in the ledger's \
+                     node. Appends validator creation and self-bond txs to a \
+                     .toml file containing an established account tx."
+                ))
+                .add_args::<args::InitGenesisValidator>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct GenBondsTemplate(pub args::GenBondsTemplate);
+
+    impl SubCmd for GenBondsTemplate {
+        const CMD: &'static str = "gen-bonds-template";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::GenBondsTemplate::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Generate a template of genesis bonds to be filled in and \
+                     signed with `sign-genesis-txs`. The validators are taken \
+                     from the genesis transactions of a templates directory, \
+                     or left as placeholders for a given number of bonds."
+                ))
+                .add_args::<args::GenBondsTemplate>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct BondsTotal(pub args::BondsTotal);
+
+    impl SubCmd for BondsTotal {
+        const CMD: &'static str = "bonds-total";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::BondsTotal::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Print the total stake of the bonds in a genesis \
+                     transactions file, with the subtotal of every validator \
+                     and the number of distinct sources."
+                ))
+                .add_args::<args::BondsTotal>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct EstimateDeviceTime(pub args::EstimateDeviceTime);
+
+    impl SubCmd for EstimateDeviceTime {
+        const CMD: &'static str = "estimate-device-time";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::EstimateDeviceTime::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Estimate how long signing a number of transactions with \
+                     a hardware wallet will take."
+                ))
+                .long_about(wrap!(
+                    "Estimate how long signing a number of transactions with \
+                     a hardware wallet will take. Unless a per-signature \
+                     latency is given, it is calibrated with a few round \
+                     trips to the connected device, which don't include the \
+                     time taken to approve each signature on the device."
+                ))
+                .add_args::<args::EstimateDeviceTime>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct ValidateGenesisTemplates(pub args::ValidateGenesisTemplates);
+
+    impl SubCmd for ValidateGenesisTemplates {
+        const CMD: &'static str = "validate-genesis-templates";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                Self(args::ValidateGenesisTemplates::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Validate genesis templates."))
+                .add_args::<args::ValidateGenesisTemplates>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct CheckConsistency(pub args::CheckConsistency);
+
+    impl SubCmd for CheckConsistency {
+        const CMD: &'static str = "check-consistency";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::CheckConsistency::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Check that the pre-genesis wallets of the base directory \
+                     are consistent with the genesis config."
+                ))
+                .long_about(wrap!(
+                    "Check that the pre-genesis wallet and validator wallets \
+                     of the base directory are consistent with the \
+                     transactions of the genesis config. Reports the \
+                     validators of the base directory without a validator \
+                     account transaction, the genesis validators that the \
+                     wallet knows of without their validator wallet, the \
+                     established accounts controlled by keys of the wallet \
+                     without an alias in it, the established addresses of \
+                     the wallet missing from the genesis config and the \
+                     aliases whose address disagrees with the genesis \
+                     config. Nothing is decrypted, so no password is asked \
+                     for."
+                ))
+                .add_args::<args::CheckConsistency>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct VerifyGenesisTxs(pub args::VerifyGenesisTxs);
+
+    impl SubCmd for VerifyGenesisTxs {
+        const CMD: &'static str = "verify-genesis-txs";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::VerifyGenesisTxs::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Verify the signatures of genesis transactions."))
+                .long_about(wrap!(
+                    "Verify the signatures of the validator account and bond \
+                     transactions of a genesis transactions TOML file \
+                     against the thresholds of their source accounts. The \
+                     result of each transaction is printed in the order of \
+                     the file, however many jobs verify them."
+                ))
+                .add_args::<args::VerifyGenesisTxs>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct Canonicalize(pub args::Canonicalize);
+
+    impl SubCmd for Canonicalize {
+        const CMD: &'static str = "canonicalize";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::Canonicalize::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Re-emit a genesis transactions TOML file in a canonical \
+                     form and print its SHA-256 digest."
+                ))
+                .long_about(wrap!(
+                    "Parse a signed or unsigned genesis transactions TOML \
+                     file and re-emit it with sorted keys and normalized \
+                     whitespace, so that files holding the same \
+                     transactions have the same bytes, whichever tool wrote \
+                     them. The SHA-256 digest of the canonical form is \
+                     printed to stderr, leaving stdout for the document \
+                     unless --output is given, and can be attested to."
+                ))
+                .add_args::<args::Canonicalize>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TestGenesis(pub args::TestGenesis);
+
+    impl SubCmd for TestGenesis {
+        const CMD: &'static str = "test-genesis";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::TestGenesis::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Initialize an established account available at genesis."
+                    "Dry run genesis files and get a report on problems that \
+                     may be found."
                 ))
-                .add_args::<args::InitGenesisEstablishedAccount>()
+                .add_args::<args::TestGenesis>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct GenesisBond(pub args::GenesisBond);
+    pub struct SignGenesisTxs(pub args::SignGenesisTxs);
 
-    impl SubCmd for GenesisBond {
-        const CMD: &'static str = "genesis-bond";
+    impl SubCmd for SignGenesisTxs {
+        const CMD: &'static str = "sign-genesis-txs";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Self(args::GenesisBond::parse(matches)))
+                .map(|matches| Self(args::SignGenesisTxs::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Bond to a validator at pre-genesis."))
-                .add_args::<args::GenesisBond>()
+                .about(wrap!("Sign genesis transaction(s)."))
+                .add_args::<args::SignGenesisTxs>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct InitGenesisValidator(pub args::InitGenesisValidator);
+    pub struct ByteGenesisTxs(pub args::ByteGenesisTxs);
 
-    impl SubCmd for InitGenesisValidator {
-        const CMD: &'static str = "init-genesis-validator";
+    impl SubCmd for ByteGenesisTxs {
+        const CMD: &'static str = "byte-genesis-txs";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Self(args::InitGenesisValidator::parse(matches)))
+                .map(|matches| Self(args::ByteGenesisTxs::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!(
-                    "Initialize genesis validator's address, consensus key \
-                     and validator account key and use it in the ledger's \
-                     node. Appends validator creation and self-bond txs to a \
-                     .toml file containing an established account tx."
-                ))
-                .add_args::<args::InitGenesisValidator>()
+                .about(wrap!("Byte genesis transaction(s)."))
+                .add_args::<args::ByteGenesisTxs>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct ValidateGenesisTemplates(pub args::ValidateGenesisTemplates);
+    pub struct SubmitGenesisTxs(pub args::SubmitGenesisTxs);
 
-    impl SubCmd for ValidateGenesisTemplates {
-        const CMD: &'static str = "validate-genesis-templates";
+    impl SubCmd for SubmitGenesisTxs {
+        const CMD: &'static str = "submit-genesis-txs";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
-            matches.subcommand_matches(Self::CMD).map(|matches| {
-                Self(args::ValidateGenesisTemplates::parse(matches))
-            })
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::SubmitGenesisTxs::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Validate genesis templates."))
-                .add_args::<args::ValidateGenesisTemplates>()
+                .about(wrap!(
+                    "Submit signed genesis transaction(s) to a coordinator \
+                     endpoint."
+                ))
+                .add_args::<args::SubmitGenesisTxs>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct TestGenesis(pub args::TestGenesis);
+    pub struct AttachSignatures(pub args::AttachSignatures);
 
-    impl SubCmd for TestGenesis {
-        const CMD: &'static str = "test-genesis";
+    impl SubCmd for AttachSignatures {
+        const CMD: &'static str = "attach-signatures";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Self(args::TestGenesis::parse(matches)))
+                .map(|matches| Self(args::AttachSignatures::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Dry run genesis files and get a report on problems that \
-                     may be found."
+                    "Attach detached signatures to the unsigned genesis \
+                     transaction(s) that they sign."
                 ))
-                .add_args::<args::TestGenesis>()
+                .add_args::<args::AttachSignatures>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct SignGenesisTxs(pub args::SignGenesisTxs);
+    pub struct MergeSigned(pub args::MergeSigned);
 
-    impl SubCmd for SignGenesisTxs {
-        const CMD: &'static str = "sign-genesis-txs";
+    impl SubCmd for MergeSigned {
+        const CMD: &'static str = "merge-signed";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Self(args::SignGenesisTxs::parse(matches)))
+                .map(|matches| Self(args::MergeSigned::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Sign genesis transaction(s)."))
-                .add_args::<args::SignGenesisTxs>()
+                .about(wrap!(
+                    "Merge signed genesis transactions files, taking the \
+                     union of the signatures of each transaction."
+                ))
+                .long_about(wrap!(
+                    "Merge signed genesis transactions files, such as the \
+                     ones that different signers of the same transactions \
+                     returned, into a single file. The signatures of each \
+                     transaction are combined, and two files holding \
+                     different signatures of a transaction by the same \
+                     signer are rejected."
+                ))
+                .add_args::<args::MergeSigned>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct ByteGenesisTxs(pub args::ByteGenesisTxs);
+    pub struct SignersManifest(pub args::SignersManifest);
 
-    impl SubCmd for ByteGenesisTxs {
-        const CMD: &'static str = "byte-genesis-txs";
+    impl SubCmd for SignersManifest {
+        const CMD: &'static str = "signers-manifest";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Self(args::ByteGenesisTxs::parse(matches)))
+                .map(|matches| Self(args::SignersManifest::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Byte genesis transaction(s)."))
-                .add_args::<args::ByteGenesisTxs>()
+                .about(wrap!(
+                    "Output a manifest of the public keys that signed each of \
+                     the signed genesis transaction(s)."
+                ))
+                .long_about(wrap!(
+                    "Output a manifest of the public keys that signed each of \
+                     the signed genesis transaction(s), along with their \
+                     aliases in the pre-genesis wallet, if any. The manifest \
+                     has the format read by `sign-genesis-txs \
+                     --expected-signers`."
+                ))
+                .add_args::<args::SignersManifest>()
         }
     }
 
@@ -3174,118 +4261,353 @@ pub mod cmds {
                     "Query the Governance validator set in Namada, at the \
                      given epoch, or the latest one, if none is provided."
                 ))
-                .add_args::<args::GovernanceValidatorSet<args::CliTypes>>()
+                .add_args::<args::GovernanceValidatorSet<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct ValidatorSetProof(pub args::ValidatorSetProof<args::CliTypes>);
+
+    impl SubCmd for ValidatorSetProof {
+        const CMD: &'static str = "proof";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::ValidatorSetProof::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Query an Ethereum ABI encoding of a proof of the \
+                     consensus validator set in Namada, at the requested \
+                     epoch, or the next one, if no epoch is provided."
+                ))
+                .add_args::<args::ValidatorSetProof<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct ValidatorSetUpdateRelay(
+        pub args::ValidatorSetUpdateRelay<args::CliTypes>,
+    );
+
+    impl SubCmd for ValidatorSetUpdateRelay {
+        const CMD: &'static str = "relay";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                Self(args::ValidatorSetUpdateRelay::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Relay a validator set update to Namada's Ethereum bridge \
+                     smart contracts."
+                ))
+                .add_args::<args::ValidatorSetUpdateRelay<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct PkToTmAddress(pub args::PkToTmAddress);
+
+    impl SubCmd for PkToTmAddress {
+        const CMD: &'static str = "pk-to-tm";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::PkToTmAddress::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Convert a validator's consensus public key to a \
+                     Tendermint address."
+                ))
+                .add_args::<args::PkToTmAddress>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct RecoverAddress(pub args::RecoverAddress);
+
+    impl SubCmd for RecoverAddress {
+        const CMD: &'static str = "recover-address";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::RecoverAddress::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Derive the implicit address of a public key offline, \
+                     without a wallet or a connection to a node."
+                ))
+                .add_args::<args::RecoverAddress>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct SignOfflineTx(pub args::SignOfflineTx);
+
+    impl SubCmd for SignOfflineTx {
+        const CMD: &'static str = "sign-offline-tx";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::SignOfflineTx::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Sign a serialized transaction offline with a key from \
+                     the wallet or a hardware wallet, without connecting to \
+                     a node."
+                ))
+                .long_about(wrap!(
+                    "Sign a serialized transaction offline with a key from \
+                     the wallet or a hardware wallet, without connecting to \
+                     a node. The inner transaction is always signed. The \
+                     wrapper is also signed when the signer is the fee payer \
+                     of the transaction, and left for the fee payer to sign \
+                     otherwise."
+                ))
+                .add_args::<args::SignOfflineTx>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct SignOffline(pub args::SignOffline<CliTypes>);
+
+    impl SubCmd for SignOffline {
+        const CMD: &'static str = "sign-offline";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                Self(args::SignOffline::<CliTypes>::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Offlne sign a transaction."))
+                .add_args::<args::SignOffline<CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct SelfTest(pub args::SelfTest);
+
+    impl SubCmd for SelfTest {
+        const CMD: &'static str = "self-test";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::SelfTest::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Check that genesis transactions can be signed and \
+                     verified on this installation."
+                ))
+                .long_about(wrap!(
+                    "Check that genesis transactions can be signed and \
+                     verified on this installation. For every key scheme, a \
+                     throwaway key is generated in memory and used to sign \
+                     a bond transaction, whose signature is then verified, \
+                     and checked to be rejected once the transaction is \
+                     altered. Nothing is read from or written to disk."
+                ))
+                .add_args::<args::SelfTest>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct ExplainError(pub args::ExplainError);
+
+    impl SubCmd for ExplainError {
+        const CMD: &'static str = "explain-error";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::ExplainError::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Explain a failure of the client and how to remedy it."
+                ))
+                .long_about(wrap!(
+                    "Explain a failure of the client and how to remedy it. \
+                     The failure is given either by the exit code of the \
+                     client or by the name of a common failure, such as \
+                     \"no-matching-keys\" or \"device-rejected\". An \
+                     unknown code lists all the known ones."
+                ))
+                .add_args::<args::ExplainError>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct DefaultBaseDir(pub args::DefaultBaseDir);
+
+    impl SubCmd for DefaultBaseDir {
+        const CMD: &'static str = "default-base-dir";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::DefaultBaseDir::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Print the default base directory that would be used if \
+                     --base-dir or NAMADA_BASE_DIR were not used to set the \
+                     base directory."
+                ))
+                .add_args::<args::DefaultBaseDir>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct ValidatorSetProof(pub args::ValidatorSetProof<args::CliTypes>);
+    pub struct ShowConfig(pub args::ShowConfig);
 
-    impl SubCmd for ValidatorSetProof {
-        const CMD: &'static str = "proof";
+    impl SubCmd for ShowConfig {
+        const CMD: &'static str = "show-config";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Self(args::ValidatorSetProof::parse(matches)))
+                .map(|matches| Self(args::ShowConfig::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Query an Ethereum ABI encoding of a proof of the \
-                     consensus validator set in Namada, at the requested \
-                     epoch, or the next one, if no epoch is provided."
+                    "Print the resolved global arguments and where each of \
+                     them came from."
                 ))
-                .add_args::<args::ValidatorSetProof<args::CliTypes>>()
+                .long_about(wrap!(
+                    "Print the base directory, chain ID, WASM directory and \
+                     pre-genesis mode in effect, each with its source: a \
+                     command line argument, an environment variable, the \
+                     global config of the base directory or the default. \
+                     Nothing is read from the network."
+                ))
+                .add_args::<args::ShowConfig>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct ValidatorSetUpdateRelay(
-        pub args::ValidatorSetUpdateRelay<args::CliTypes>,
-    );
+    pub struct ListChains(pub args::ListChains);
 
-    impl SubCmd for ValidatorSetUpdateRelay {
-        const CMD: &'static str = "relay";
+    impl SubCmd for ListChains {
+        const CMD: &'static str = "list-chains";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
-            matches.subcommand_matches(Self::CMD).map(|matches| {
-                Self(args::ValidatorSetUpdateRelay::parse(matches))
-            })
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::ListChains::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Relay a validator set update to Namada's Ethereum bridge \
-                     smart contracts."
+                    "List the chains present in the base directory and \
+                     whether a wallet and a config exist for each of them."
                 ))
-                .add_args::<args::ValidatorSetUpdateRelay<args::CliTypes>>()
+                .add_args::<args::ListChains>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct PkToTmAddress(pub args::PkToTmAddress);
+    pub struct ListProfiles(pub args::ListProfiles);
 
-    impl SubCmd for PkToTmAddress {
-        const CMD: &'static str = "pk-to-tm";
+    impl SubCmd for ListProfiles {
+        const CMD: &'static str = "list-profiles";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Self(args::PkToTmAddress::parse(matches)))
+                .map(|matches| Self(args::ListProfiles::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Convert a validator's consensus public key to a \
-                     Tendermint address."
+                    "List the profiles present in the base directory, which \
+                     can be selected with --profile or NAMADA_PROFILE."
                 ))
-                .add_args::<args::PkToTmAddress>()
+                .add_args::<args::ListProfiles>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct SignOffline(pub args::SignOffline<CliTypes>);
+    pub struct ListDevices(pub args::ListDevices);
 
-    impl SubCmd for SignOffline {
-        const CMD: &'static str = "sign-offline";
+    impl SubCmd for ListDevices {
+        const CMD: &'static str = "list-devices";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
-            matches.subcommand_matches(Self::CMD).map(|matches| {
-                Self(args::SignOffline::<CliTypes>::parse(matches))
-            })
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::ListDevices::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Offlne sign a transaction."))
-                .add_args::<args::SignOffline<CliTypes>>()
+                .about(wrap!(
+                    "List the hardware wallets that can be reached with the \
+                     given device transport."
+                ))
+                .long_about(wrap!(
+                    "List the hardware wallets that can be reached with the \
+                     given device transport. For \"hid\", the connected \
+                     Ledger devices are listed, while for \"tcp\", the \
+                     device emulator at `LEDGER_PROXY_ADDRESS` and \
+                     `LEDGER_PROXY_PORT` is checked to accept connections."
+                ))
+                .add_args::<args::ListDevices>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct DefaultBaseDir(pub args::DefaultBaseDir);
+    pub struct DumpCli(pub args::DumpCli);
 
-    impl SubCmd for DefaultBaseDir {
-        const CMD: &'static str = "default-base-dir";
+    impl SubCmd for DumpCli {
+        const CMD: &'static str = "dump-cli";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Self(args::DefaultBaseDir::parse(matches)))
+                .map(|matches| Self(args::DumpCli::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Print the default base directory that would be used if \
-                     --base-dir or NAMADA_BASE_DIR were not used to set the \
-                     base directory."
+                    "Print the tree of the client commands as JSON, with \
+                     their arguments, defaults and help."
                 ))
-                .add_args::<args::DefaultBaseDir>()
+                .add_args::<args::DumpCli>()
         }
     }
 }
@@ -3293,9 +4615,10 @@ pub mod cmds {
 pub mod args {
     use std::env;
     use std::net::SocketAddr;
-    use std::path::PathBuf;
+    use std::path::{Path, PathBuf};
     use std::str::FromStr;
 
+    use color_eyre::owo_colors::Style;
     use data_encoding::HEXUPPER;
     use namada_core::masp::{MaspEpoch, PaymentAddress};
     use namada_sdk::address::{Address, EstablishedAddress};
@@ -3324,16 +4647,48 @@ pub mod args {
         TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM,
         VP_USER_WASM,
     };
+
+    /// The WASMs of the txs that the client can build
+    pub const CLIENT_WASMS: [&str; 23] = [
+        TX_BECOME_VALIDATOR_WASM,
+        TX_BOND_WASM,
+        TX_BRIDGE_POOL_WASM,
+        TX_CHANGE_COMMISSION_WASM,
+        TX_CHANGE_CONSENSUS_KEY_WASM,
+        TX_CHANGE_METADATA_WASM,
+        TX_CLAIM_REWARDS_WASM,
+        TX_DEACTIVATE_VALIDATOR_WASM,
+        TX_IBC_WASM,
+        TX_INIT_ACCOUNT_WASM,
+        TX_INIT_PROPOSAL,
+        TX_REACTIVATE_VALIDATOR_WASM,
+        TX_REDELEGATE_WASM,
+        TX_RESIGN_STEWARD,
+        TX_REVEAL_PK,
+        TX_TRANSFER_WASM,
+        TX_UNBOND_WASM,
+        TX_UNJAIL_VALIDATOR_WASM,
+        TX_UPDATE_ACCOUNT_WASM,
+        TX_UPDATE_STEWARD_COMMISSION,
+        TX_VOTE_PROPOSAL,
+        TX_WITHDRAW_WASM,
+        VP_USER_WASM,
+    ];
+    use namada_sdk::wallet::{KdfParams, KeyEncryptionPolicy};
     use namada_sdk::{token, DEFAULT_GAS_LIMIT};
 
     use super::context::*;
     use super::utils::*;
     use super::{ArgGroup, ArgMatches};
-    use crate::client::utils::PRE_GENESIS_DIR;
+    use crate::config::genesis::transactions::{
+        SignErrorMode, SignatureEncoding, SignerBackendKind,
+    };
+    use crate::config::genesis::utils::{AmountUnit, TomlKeyOrder};
     use crate::config::genesis::AddrOrPk;
     use crate::config::{self, Action, ActionAtHeight};
     use crate::tendermint::Timeout;
     use crate::tendermint_rpc::Url;
+    use crate::wallet::WalletOptions;
     use crate::wrap;
 
     pub const ADDRESS: Arg<WalletAddress> = arg("address");
@@ -3342,23 +4697,30 @@ pub mod args {
     pub const ALIAS: Arg<String> = arg("alias");
     pub const ALIAS_FORCE: ArgFlag = flag("alias-force");
     pub const ALIAS_MANY: ArgMulti<String, GlobPlus> = arg_multi("aliases");
+    pub const ALIAS_PREFIX: ArgOpt<String> = arg_opt("alias-prefix");
     pub const ALLOW_DUPLICATE_IP: ArgFlag = flag("allow-duplicate-ip");
     pub const AMOUNT: Arg<token::DenominatedAmount> = arg("amount");
     pub const AMOUNT_STR: Arg<String> = arg("amount");
     pub const ARCHIVE_DIR: ArgOpt<PathBuf> = arg_opt("archive-dir");
+    pub const ASSUME_BASE_UNITS: ArgFlag = flag("assume-base-units");
+    pub const ASSUME_DISPLAY_UNITS: ArgFlag = flag("assume-display-units");
+    pub const ASSUME_TTY: ArgFlag = flag("assume-tty");
+    pub const AUDIT_LOG: ArgOpt<PathBuf> = arg_opt("audit-log");
     pub const AVATAR_OPT: ArgOpt<String> = arg_opt("avatar");
     pub const BALANCE_OWNER: Arg<WalletBalanceOwner> = arg("owner");
     pub const BASE_DIR: ArgDefault<PathBuf> = arg_default(
         "base-dir",
-        DefaultFn(|| match env::var("NAMADA_BASE_DIR") {
+        DefaultFn(|| match env::var(BASE_DIR_ENV_VAR) {
             Ok(dir) => PathBuf::from(dir),
             Err(_) => config::get_default_namada_folder(),
         }),
     );
+    pub const BASE_DIR_ENV_VAR: &str = "NAMADA_BASE_DIR";
     pub const BIRTHDAY: ArgOpt<BlockHeight> = arg_opt("birthday");
     pub const BLOCK_HEIGHT: Arg<BlockHeight> = arg("block-height");
     pub const BLOCK_HEIGHT_OPT: ArgOpt<BlockHeight> = arg_opt("height");
     pub const BLOCK_HEIGHT_TO_OPT: ArgOpt<BlockHeight> = arg_opt("to-height");
+    pub const BOND_COUNT: ArgOpt<usize> = arg_opt("count");
     pub const BRIDGE_POOL_GAS_AMOUNT: ArgDefault<token::DenominatedAmount> =
         arg_default(
             "pool-gas-amount",
@@ -3383,6 +4745,7 @@ pub mod args {
     pub const CHAIN_ID_OPT: ArgOpt<ChainId> = CHAIN_ID.opt();
     pub const CHAIN_ID_PREFIX: Arg<ChainIdPrefix> = arg("chain-prefix");
     pub const CHANNEL_ID: Arg<ChannelId> = arg("channel-id");
+    pub const CODE: Arg<String> = arg("code");
     pub const CODE_PATH: Arg<PathBuf> = arg("code-path");
     pub const CODE_PATH_OPT: ArgOpt<PathBuf> = CODE_PATH.opt();
     pub const COMMISSION_RATE: Arg<Dec> = arg("commission-rate");
@@ -3390,8 +4753,13 @@ pub mod args {
     pub const CONSENSUS_TIMEOUT_COMMIT: ArgDefault<Timeout> = arg_default(
         "consensus-timeout-commit",
         DefaultFn(|| Timeout::from_str("1s").unwrap()),
-    );
+    )
+    .show_default("1s");
+    pub const CONTINUE_ON_ERROR: ArgFlag = flag("continue-on-error");
     pub const CONVERSION_TABLE: Arg<PathBuf> = arg("conversion-table");
+    pub const COORDINATOR_TOKEN: ArgOpt<String> = arg_opt("token");
+    pub const COORDINATOR_TOKEN_ENV_VAR: &str = "NAMADA_COORDINATOR_TOKEN";
+    pub const COORDINATOR_URL: Arg<String> = arg("coordinator-url");
     pub const DAEMON_MODE: ArgFlag = flag("daemon");
     pub const DAEMON_MODE_RETRY_DUR: ArgOpt<Duration> = arg_opt("retry-sleep");
     pub const DAEMON_MODE_SUCCESS_DUR: ArgOpt<Duration> =
@@ -3403,21 +4771,29 @@ pub mod args {
     pub const DATED_VIEWING_KEYS: ArgMulti<WalletDatedViewingKey, GlobStar> =
         arg_multi("viewing-keys");
     pub const DB_KEY: Arg<String> = arg("db-key");
+    pub const DEBUG_APDU: ArgFlag = flag("debug-apdu");
     pub const DB_COLUMN_FAMILY: ArgDefault<String> = arg_default(
         "db-column-family",
         DefaultFn(|| storage::SUBSPACE_CF.to_string()),
-    );
+    )
+    .show_default(storage::SUBSPACE_CF);
     pub const DECRYPT: ArgFlag = flag("decrypt");
+    pub const DERIVE_KEYS: ArgMulti<String, GlobPlus> = arg_multi("keys");
     pub const DESCRIPTION_OPT: ArgOpt<String> = arg_opt("description");
     pub const DISPOSABLE_SIGNING_KEY: ArgFlag = flag("disposable-gas-payer");
     pub const DESTINATION_VALIDATOR: Arg<WalletAddress> =
         arg("destination-validator");
+    pub const DETACHED: ArgFlag = flag("detached");
     pub const DISCORD_OPT: ArgOpt<String> = arg_opt("discord-handle");
     pub const DO_IT: ArgFlag = flag("do-it");
     pub const DRY_RUN_TX: ArgFlag = flag("dry-run");
     pub const DRY_RUN_WRAPPER_TX: ArgFlag = flag("dry-run-wrapper");
     pub const DUMP_TX: ArgFlag = flag("dump-tx");
     pub const DUMP_CONVERSION_TREE: ArgFlag = flag("dump-conversion-tree");
+    pub const ENCODED_PUBLIC_KEY: Arg<String> = arg("public-key");
+    pub const ENCODING: ArgDefault<WalletEncoding> =
+        arg_default("encoding", DefaultFn(|| WalletEncoding::Bech32m))
+            .show_default("bech32m");
     pub const EPOCH: ArgOpt<Epoch> = arg_opt("epoch");
     pub const ERC20: Arg<EthAddress> = arg("erc20");
     pub const ETH_CONFIRMATIONS: Arg<u64> = arg("confirmations");
@@ -3428,15 +4804,24 @@ pub mod args {
     pub const ETH_RPC_ENDPOINT: ArgDefault<String> = arg_default(
         "eth-rpc-endpoint",
         DefaultFn(|| "http://localhost:8545".into()),
-    );
+    )
+    .show_default("http://localhost:8545");
     pub const ETH_SYNC: ArgFlag = flag("sync");
+    pub const EXCLUDE_ALIAS: ArgMulti<String, GlobStar> =
+        arg_multi("exclude-alias");
+    pub const EXPECTED_SIGNERS: ArgOpt<PathBuf> = arg_opt("expected-signers");
+    pub const EXTRA_WALLET_DIR: ArgMulti<PathBuf, GlobStar> =
+        arg_multi("extra-wallet-dir");
     pub const EXPIRATION_OPT: ArgOpt<DateTimeUtc> = arg_opt("expiration");
     pub const EMAIL: Arg<String> = arg("email");
     pub const EMAIL_OPT: ArgOpt<String> = EMAIL.opt();
     pub const FEE_AMOUNT_OPT: ArgOpt<token::DenominatedAmount> =
         arg_opt("gas-price");
     pub const FEE_PAYER_OPT: ArgOpt<WalletPublicKey> = arg_opt("gas-payer");
+    pub const FAIL_FAST: ArgFlag = flag("fail-fast");
+    pub const FAIL_ON_PLAINTEXT: ArgFlag = flag("fail-on-plaintext");
     pub const FILE_PATH: Arg<String> = arg("file");
+    pub const FILL_MISSING: ArgFlag = flag("fill-missing");
     pub const FORCE: ArgFlag = flag("force");
     pub const GAS_LIMIT: ArgDefault<GasLimit> = arg_default(
         "gas-limit",
@@ -3458,6 +4843,8 @@ pub mod args {
     );
     pub const GENESIS_BOND_SOURCE: ArgOpt<AddrOrPk> = arg_opt("source");
     pub const GENESIS_PATH: Arg<PathBuf> = arg("genesis-path");
+    pub const GENESIS_TXS_PATH_OPT: ArgOpt<PathBuf> =
+        arg_opt("genesis-txs-path");
     pub const GENESIS_TIME: Arg<DateTimeUtc> = arg("genesis-time");
     pub const GENESIS_VALIDATOR: ArgOpt<String> =
         arg("genesis-validator").opt();
@@ -3468,15 +4855,34 @@ pub mod args {
     pub const HASH_OPT: ArgOpt<String> = arg_opt("hash");
     pub const HASH_LIST: Arg<String> = arg("hash-list");
     pub const HD_DERIVATION_PATH: ArgDefault<String> =
-        arg_default("hd-path", DefaultFn(|| "default".to_string()));
+        arg_default("hd-path", DefaultFn(|| "default".to_string()))
+            .show_default("default");
     pub const HD_ALLOW_NON_COMPLIANT_DERIVATION_PATH: ArgFlag =
         flag("allow-non-compliant");
     pub const HD_PROMPT_BIP39_PASSPHRASE: ArgFlag = flag("bip39-passphrase");
+    pub const HIDE_AMOUNTS: ArgFlag = flag("hide-amounts");
     pub const HISTORIC: ArgFlag = flag("historic");
     pub const IBC_SHIELDING_DATA_PATH: ArgOpt<PathBuf> =
         arg_opt("ibc-shielding-data");
     pub const IBC_MEMO: ArgOpt<String> = arg_opt("ibc-memo");
+    pub const JOBS: ArgDefault<usize> = arg_default(
+        "jobs",
+        DefaultFn(|| {
+            std::thread::available_parallelism()
+                .map(usize::from)
+                .unwrap_or(1)
+        }),
+    );
+    pub const INCLUDE_ALIAS: ArgMulti<String, GlobStar> =
+        arg_multi("include-alias");
+    pub const INCLUDE_SIGNER_ADDRESS: ArgFlag = flag("include-signer-address");
     pub const INPUT_OPT: ArgOpt<PathBuf> = arg_opt("input");
+    pub const INTERACTIVE_SELECT: ArgFlag = flag("interactive-select");
+    pub const KDF_ITERATIONS: ArgOpt<u32> = arg_opt("kdf-iterations");
+    pub const KDF_MEMORY: ArgOpt<u32> = arg_opt("kdf-memory");
+    pub const KEEP: Arg<ValidatorKeyKind> = arg("keep");
+    pub const KEY_FILE: ArgOpt<PathBuf> = arg_opt("key-file");
+    pub const KEY_POLICY: Arg<KeyEncryptionPolicy> = arg("policy");
     pub const LEDGER_ADDRESS_ABOUT: &str = textwrap_macros::fill!(
         "Address of a ledger node as \"{scheme}://{host}:{port}\". If the \
          scheme is not supplied, it is assumed to be TCP.",
@@ -3487,28 +4893,41 @@ pub mod args {
     pub const CONFIG_RPC_LEDGER_ADDRESS: ArgDefaultFromCtx<ConfigRpcAddress> =
         arg_default_from_ctx("node", DefaultFn(|| "".to_string()));
 
-    pub const LEDGER_ADDRESS: ArgDefault<Url> =
-        arg("node").default(DefaultFn(|| {
+    pub const LEDGER_ADDRESS: ArgDefault<Url> = arg("node")
+        .default(DefaultFn(|| {
             let raw = "http://127.0.0.1:26657";
             Url::from_str(raw).unwrap()
-        }));
+        }))
+        .show_default("http://127.0.0.1:26657");
     pub const LIST_FIND_ADDRESSES_ONLY: ArgFlag = flag("addr");
     pub const LIST_FIND_KEYS_ONLY: ArgFlag = flag("keys");
     pub const LOCALHOST: ArgFlag = flag("localhost");
+    pub const LOCK_KEYS_IN_MEMORY: ArgFlag = flag("lock-keys-in-memory");
+    pub const MACHINE_SUMMARY: ArgFlag = flag("machine-summary");
     pub const MASP_EPOCH: ArgOpt<MaspEpoch> = arg_opt("masp-epoch");
     pub const MAX_COMMISSION_RATE_CHANGE: Arg<Dec> =
         arg("max-commission-rate-change");
     pub const MAX_CONCURRENT_FETCHES: ArgDefault<usize> =
-        arg_default("max-concurrent-fetches", DefaultFn(|| 100));
+        arg_default("max-concurrent-fetches", DefaultFn(|| 100))
+            .show_default("100");
     pub const MAX_ETH_GAS: ArgOpt<u64> = arg_opt("max_eth-gas");
+    pub const MAX_TXS: ArgDefault<usize> =
+        arg_default("max-txs", DefaultFn(|| 100)).show_default("100");
     pub const MEMO_OPT: ArgOpt<String> = arg_opt("memo");
+    pub const MESSAGE: Arg<String> = arg("message");
     pub const MIGRATION_PATH: ArgOpt<PathBuf> = arg_opt("migration-path");
     pub const MODE: ArgOpt<String> = arg_opt("mode");
     pub const NET_ADDRESS: Arg<SocketAddr> = arg("net-address");
     pub const NAMADA_START_TIME: ArgOpt<DateTimeUtc> = arg_opt("time");
+    pub const NO_COLOR: ArgFlag = flag("no-color");
     pub const NO_CONVERSIONS: ArgFlag = flag("no-conversions");
     pub const NO_EXPIRATION: ArgFlag = flag("no-expiration");
+    pub const NO_RETRY: ArgFlag = flag("no-retry");
+    pub const NO_SAVE: ArgFlag = flag("no-save");
+    pub const NO_TTY: ArgFlag = flag("no-tty");
+    pub const NON_INTERACTIVE: ArgFlag = flag("non-interactive");
     pub const NUT: ArgFlag = flag("nut");
+    pub const ONLY_VALIDATOR: ArgOpt<Address> = arg_opt("only-validator");
     pub const OUT_FILE_PATH_OPT: ArgOpt<PathBuf> = arg_opt("out-file-path");
     pub const OUTPUT: ArgOpt<PathBuf> = arg_opt("output");
     pub const OUTPUT_FOLDER_PATH: ArgOpt<PathBuf> =
@@ -3517,14 +4936,25 @@ pub mod args {
     pub const OWNER_OPT: ArgOpt<WalletAddress> = OWNER.opt();
     pub const PATH: Arg<PathBuf> = arg("path");
     pub const PATH_OPT: ArgOpt<PathBuf> = arg_opt("path");
+    pub const PATHS: ArgMulti<PathBuf, GlobStar> = arg_multi("paths");
     pub const PAYMENT_ADDRESS_TARGET: Arg<WalletPaymentAddr> = arg("target");
+    pub const PER_SIG_MS: ArgOpt<u64> = arg_opt("per-sig-ms");
+    pub const PKCS11_KEY_LABEL: ArgOpt<String> = arg_opt("pkcs11-key-label");
+    pub const PKCS11_MODULE: ArgOpt<PathBuf> = arg_opt("pkcs11-module");
+    pub const PKCS11_PIN: ArgOpt<String> = arg_opt("pkcs11-pin");
+    pub const PKCS11_SLOT: ArgOpt<u64> = arg_opt("pkcs11-slot");
     pub const PORT_ID: ArgDefault<PortId> = arg_default(
         "port-id",
         DefaultFn(|| PortId::from_str("transfer").unwrap()),
-    );
+    )
+    .show_default("transfer");
     pub const PRE_GENESIS: ArgFlag = flag("pre-genesis");
+    pub const PRESERVE: ArgFlag = flag("preserve");
+    pub const PRINT_UNSIGNED: ArgFlag = flag("print-unsigned");
     pub const PRIVATE_KEYS: ArgMulti<WalletKeypair, GlobStar> =
         arg_multi("secret-keys");
+    pub const PROFILE: ArgOpt<String> = arg_opt("profile");
+    pub const PROFILE_ENV_VAR: &str = "NAMADA_PROFILE";
     pub const PROPOSAL_PGF_STEWARD: ArgFlag = flag("pgf-stewards");
     pub const PROPOSAL_PGF_FUNDING: ArgFlag = flag("pgf-funding");
     pub const PROTOCOL_KEY: ArgOpt<WalletPublicKey> = arg_opt("protocol-key");
@@ -3551,12 +4981,17 @@ pub mod args {
     pub const RAW_PUBLIC_KEY_HASH_OPT: ArgOpt<String> =
         RAW_PUBLIC_KEY_HASH.opt();
     pub const RECEIVER: Arg<String> = arg("receiver");
+    pub const REDACT: ArgFlag = flag("redact");
     pub const REFUND_TARGET: ArgOpt<WalletTransferTarget> =
         arg_opt("refund-target");
     pub const RELAYER: Arg<Address> = arg("relayer");
+    pub const REQUIRE_INTEGRITY: ArgFlag = flag("require-integrity");
+    pub const RESTORE: ArgFlag = flag("restore");
     pub const RETRIES: ArgOpt<u64> = arg_opt("retries");
     pub const SCHEME: ArgDefault<SchemeType> =
-        arg_default("scheme", DefaultFn(|| SchemeType::Ed25519));
+        arg_default("scheme", DefaultFn(|| SchemeType::Ed25519))
+            .show_default("ed25519");
+    pub const SCHEME_OPT: ArgOpt<SchemeType> = arg_opt("scheme");
     pub const SHELL: Arg<Shell> = arg("shell");
     pub const SELF_BOND_AMOUNT: Arg<token::DenominatedAmount> =
         arg("self-bond-amount");
@@ -3564,10 +4999,27 @@ pub mod args {
     pub const SHIELDED: ArgFlag = flag("shielded");
     pub const SHOW_IBC_TOKENS: ArgFlag = flag("show-ibc-tokens");
     pub const SIGNER: ArgOpt<WalletAddress> = arg_opt("signer");
+    pub const SIGNER_KEY: Arg<String> = arg("signer");
+    pub const SIGNER_BACKEND: ArgDefault<SignerBackendKind> =
+        arg_default("signer-backend", DefaultFn(|| SignerBackendKind::Wallet))
+            .show_default("wallet");
     pub const SIGNING_KEYS: ArgMulti<WalletPublicKey, GlobStar> =
         arg_multi("signing-keys");
+    pub const SIGNATURE: Arg<common::Signature> = arg("signature");
     pub const SIGNATURES: ArgMulti<PathBuf, GlobStar> = arg_multi("signatures");
+    pub const SIGNATURE_ENCODING: ArgDefault<SignatureEncoding> = arg_default(
+        "signature-encoding",
+        DefaultFn(|| SignatureEncoding::Default),
+    )
+    .show_default("default");
+    pub const SINCE_OPT: ArgOpt<WalletListTime> = arg_opt("since");
+    pub const SKIP_DEVICE_VERIFY: ArgFlag = flag("skip-device-verify");
+    pub const SKIP_VERSION_CHECK: ArgFlag = flag("skip-version-check");
+    pub const SORT: ArgDefault<WalletListSort> =
+        arg_default("sort", DefaultFn(|| WalletListSort::Alias))
+            .show_default("alias");
     pub const SOURCE: Arg<WalletAddress> = arg("source");
+    pub const SOURCE_SHA256: ArgOpt<String> = arg_opt("source-sha256");
     pub const SOURCE_STR: Arg<String> = arg("source");
     pub const SOURCE_OPT: ArgOpt<WalletAddress> = SOURCE.opt();
     pub const SOURCE_VALIDATOR: Arg<WalletAddress> = arg("source-validator");
@@ -3575,10 +5027,14 @@ pub mod args {
     pub const SPENDING_KEYS: ArgMulti<WalletSpendingKey, GlobStar> =
         arg_multi("spending-keys");
     pub const STEWARD: Arg<WalletAddress> = arg("steward");
+    pub const STREAM: ArgFlag = flag("stream");
     pub const STORAGE_KEY: Arg<storage::Key> = arg("storage-key");
     pub const SUSPEND_ACTION: ArgFlag = flag("suspend");
+    pub const TAG_OPT: ArgOpt<String> = arg_opt("tag");
+    pub const TAGS: ArgMulti<String, GlobPlus> = arg_multi("tags");
     pub const TARGET: Arg<WalletAddress> = arg("target");
     pub const TEMPLATES_PATH: Arg<PathBuf> = arg("templates-path");
+    pub const TEMPLATES_PATH_OPT: ArgOpt<PathBuf> = TEMPLATES_PATH.opt();
     pub const TIMEOUT_HEIGHT: ArgOpt<u64> = arg_opt("timeout-height");
     pub const TIMEOUT_SEC_OFFSET: ArgOpt<u64> = arg_opt("timeout-sec-offset");
     pub const TM_ADDRESS: ArgOpt<String> = arg_opt("tm-address");
@@ -3586,6 +5042,9 @@ pub mod args {
     pub const TOKEN_STR_OPT: ArgOpt<String> = TOKEN_STR.opt();
     pub const TOKEN: Arg<WalletAddress> = arg("token");
     pub const TOKEN_STR: Arg<String> = arg("token");
+    pub const TOML_KEY_ORDER: ArgDefault<TomlKeyOrder> =
+        arg_default("toml-key-order", DefaultFn(|| TomlKeyOrder::Sorted))
+            .show_default("sorted");
     pub const TRANSFER_SOURCE: Arg<WalletTransferSource> = arg("source");
     pub const TRANSFER_TARGET: Arg<WalletTransferTarget> = arg("target");
     pub const TRANSPARENT: ArgFlag = flag("transparent");
@@ -3593,6 +5052,7 @@ pub mod args {
     pub const THRESHOLD: ArgOpt<u8> = arg_opt("threshold");
     pub const UNSAFE_DONT_ENCRYPT: ArgFlag = flag("unsafe-dont-encrypt");
     pub const UNSAFE_SHOW_SECRET: ArgFlag = flag("unsafe-show-secret");
+    pub const UNTIL_OPT: ArgOpt<WalletListTime> = arg_opt("until");
     pub const USE_DEVICE: ArgFlag = flag("use-device");
     pub const VALIDATOR: Arg<WalletAddress> = arg("validator");
     pub const VALIDATOR_STR: Arg<String> = arg("validator");
@@ -3611,6 +5071,7 @@ pub mod args {
     pub const VALIDATOR_ETH_HOT_KEY: ArgOpt<WalletPublicKey> =
         arg_opt("eth-hot-key");
     pub const VALUE: Arg<String> = arg("value");
+    pub const VERBOSE: ArgFlag = flag("verbose");
     pub const VOTER_OPT: ArgOpt<WalletAddress> = arg_opt("voter");
     pub const VIEWING_KEY: Arg<WalletViewingKey> = arg("key");
     pub const VIEWING_KEYS: ArgMulti<WalletViewingKey, GlobStar> =
@@ -3619,10 +5080,13 @@ pub mod args {
     pub const WAIT_FOR_LAST_QUERY_HEIGHT: ArgFlag =
         flag("wait-for-last-query-height");
     pub const WALLET_ALIAS_FORCE: ArgFlag = flag("wallet-alias-force");
+    pub const WALLET_FILE_NAME: ArgOpt<String> = arg_opt("wallet-file-name");
+    pub const WALLET_FILE_NAME_ENV_VAR: &str = "NAMADA_WALLET_FILE_NAME";
     pub const WASM_CHECKSUMS_PATH: Arg<PathBuf> = arg("wasm-checksums-path");
     pub const WASM_DIR: ArgOpt<PathBuf> = arg_opt("wasm-dir");
     pub const WEBSITE_OPT: ArgOpt<String> = arg_opt("website");
     pub const WITH_INDEXER: ArgOpt<String> = arg_opt("with-indexer");
+    pub const YES: ArgFlag = flag("yes");
     pub const TX_PATH: Arg<PathBuf> = arg("tx-path");
     pub const TX_PATH_OPT: ArgOpt<PathBuf> = TX_PATH.opt();
     pub const DEVICE_TRANSPORT: ArgDefault<DeviceTransport> = arg_default(
@@ -3635,28 +5099,180 @@ pub mod args {
         }),
     );
     pub const DEVICE_TRANSPORT_ENV_VAR: &str = "NAMADA_DEVICE_TRANSPORT";
+    pub const DOWNLOAD_RETRIES: ArgDefault<u32> =
+        arg_default("download-retries", DefaultFn(|| 3)).show_default("3");
+    pub const DOWNLOAD_RETRY_DELAY: ArgDefault<Duration> = arg_default(
+        "download-retry-delay",
+        DefaultFn(|| Duration(std::time::Duration::from_secs(1))),
+    )
+    .show_default("1s");
+    pub const DOWNLOAD_TIMEOUT: ArgDefault<Duration> = arg_default(
+        "download-timeout",
+        DefaultFn(|| {
+            if let Ok(val) = std::env::var(DOWNLOAD_TIMEOUT_ENV_VAR) {
+                return Duration::from_str(&val).unwrap();
+            }
+            Duration(std::time::Duration::from_secs(60))
+        }),
+    );
+    pub const DOWNLOAD_TIMEOUT_ENV_VAR: &str = "NAMADA_DOWNLOAD_TIMEOUT";
 
     /// Global command arguments
     #[derive(Clone, Debug)]
     pub struct Global {
         pub is_pre_genesis: bool,
         pub chain_id: Option<ChainId>,
+        /// The base dir, which is that of the profile if one is selected
         pub base_dir: PathBuf,
+        pub profile: Option<String>,
         pub wasm_dir: Option<PathBuf>,
+        pub no_color: bool,
+        pub verbose: bool,
+        pub redact: bool,
+        /// The renderings of the base dir given before selecting a profile
+        /// to hide from output, if redacted
+        pub redacted_base_dirs: Vec<String>,
+        pub debug_apdu: bool,
+        pub assume_tty: bool,
+        pub no_tty: bool,
+        pub require_integrity: bool,
+        pub no_save: bool,
+        /// The name of the wallet files, if not the default one
+        pub wallet_file_name: Option<String>,
+        pub kdf_params: Option<KdfParams>,
     }
 
     impl Global {
-        /// Parse global arguments
+        /// Parse global arguments, resolving the base dir of the selected
+        /// profile, if any.
         pub fn parse(matches: &ArgMatches) -> Self {
             let is_pre_genesis = PRE_GENESIS.parse(matches);
             let chain_id = CHAIN_ID_OPT.parse(matches);
             let base_dir = BASE_DIR.parse(matches);
+            let profile = PROFILE.parse(matches).or_else(|| {
+                env::var(PROFILE_ENV_VAR)
+                    .ok()
+                    .filter(|profile| !profile.is_empty())
+            });
             let wasm_dir = WASM_DIR.parse(matches);
+            let no_color = NO_COLOR.parse(matches) || no_color_env();
+            let verbose = VERBOSE.parse(matches);
+            let redact = REDACT.parse(matches);
+            let redacted_base_dirs = if redact {
+                redacted_base_dirs(&base_dir)
+            } else {
+                vec![]
+            };
+            let base_dir = match &profile {
+                Some(profile) => config::profile_base_dir(&base_dir, profile)
+                    .unwrap_or_else(|err| {
+                        eprintln!("{err}");
+                        safe_exit(1)
+                    }),
+                None => base_dir,
+            };
+            let debug_apdu = DEBUG_APDU.parse(matches);
+            let assume_tty = ASSUME_TTY.parse(matches);
+            let no_tty = NO_TTY.parse(matches);
+            let require_integrity = REQUIRE_INTEGRITY.parse(matches);
+            let no_save = NO_SAVE.parse(matches);
+            let wallet_file_name =
+                WALLET_FILE_NAME.parse(matches).or_else(|| {
+                    env::var(WALLET_FILE_NAME_ENV_VAR)
+                        .ok()
+                        .filter(|name| !name.is_empty())
+                });
+            if let Some(name) = &wallet_file_name {
+                let is_plain_file_name = Path::new(name)
+                    .file_name()
+                    .is_some_and(|file_name| file_name == name.as_str());
+                if !is_plain_file_name {
+                    eprintln!(
+                        "The wallet file name \"{name}\" must be the name of \
+                         a file, without any directory."
+                    );
+                    safe_exit(1)
+                }
+            }
+            let kdf_params = match (
+                KDF_ITERATIONS.parse(matches),
+                KDF_MEMORY.parse(matches),
+            ) {
+                (None, None) => None,
+                (iterations, memory_kib) => Some(
+                    KdfParams::new(
+                        iterations.unwrap_or(KdfParams::DEFAULT.iterations),
+                        memory_kib.unwrap_or(KdfParams::DEFAULT.memory_kib),
+                    )
+                    .unwrap_or_else(|err| {
+                        eprintln!("{err}");
+                        safe_exit(1)
+                    }),
+                ),
+            };
             Global {
                 is_pre_genesis,
                 chain_id,
                 base_dir,
+                profile,
                 wasm_dir,
+                no_color,
+                verbose,
+                redact,
+                redacted_base_dirs,
+                debug_apdu,
+                assume_tty,
+                no_tty,
+                require_integrity,
+                no_save,
+                wallet_file_name,
+                kdf_params,
+            }
+        }
+
+        /// The options to load and save wallets with.
+        pub fn wallet_options(&self) -> WalletOptions {
+            WalletOptions {
+                file_name: self.wallet_file_name.clone(),
+                require_integrity: self.require_integrity,
+                no_save: self.no_save,
+                verbose: self.verbose,
+                tty_override: self.tty_override(),
+                redacted_base_dirs: self.redacted_base_dirs.clone(),
+                kdf_params: self.kdf_params,
+            }
+        }
+
+        /// Replace the base dir in the given text if redacted with
+        /// `--redact`.
+        pub fn redact_base_dir(&self, text: impl std::fmt::Display) -> String {
+            redact_base_dir(&self.redacted_base_dirs, text)
+        }
+
+        /// Whether there is a terminal, if its detection is overridden with
+        /// `--assume-tty` or `--no-tty`.
+        pub fn tty_override(&self) -> Option<bool> {
+            (self.assume_tty || self.no_tty).then_some(self.assume_tty)
+        }
+
+        /// Check if the user can be prompted for input.
+        pub fn tty_enabled(&self) -> bool {
+            tty_enabled(self.tty_override())
+        }
+
+        /// Check if the APDU exchanges with hardware wallets are logged,
+        /// with `--debug-apdu` or the env var.
+        pub fn debug_apdu_enabled(&self) -> bool {
+            self.debug_apdu || debug_apdu_env()
+        }
+
+        /// Get the given style to apply on output, or a plain style if
+        /// colored output is disabled.
+        pub fn output_style(&self, style: Style) -> Style {
+            if self.no_color {
+                Style::new()
+            } else {
+                style
             }
         }
 
@@ -3680,6 +5296,14 @@ pub mod args {
                      Unix,`$HOME/Library/Application Support/Namada` on Mac, \
                      and `%AppData%\\Namada` on Windows."
                 )))
+                .arg(PROFILE.def().global(true).help(wrap!(
+                    "Use the wallets and configs of this profile, which are \
+                     stored in the `profiles/<name>` sub-directory of the \
+                     base directory instead of the base directory itself. \
+                     This value can also be set via the `NAMADA_PROFILE` \
+                     environment variable, but the argument takes \
+                     precedence, if specified."
+                )))
                 .arg(WASM_DIR.def().global(true).help(wrap!(
                     "Directory with built WASM validity predicates, \
                      transactions. This value can also be set via \
@@ -3692,6 +5316,75 @@ pub mod args {
                         .global(true)
                         .help(wrap!("Dispatch pre-genesis specific logic.")),
                 )
+                .arg(NO_COLOR.def().global(true).help(wrap!(
+                    "Disable colored output. Colors are also disabled when \
+                     the `NO_COLOR` environment variable is set to a \
+                     non-empty value."
+                )))
+                .arg(VERBOSE.def().global(true).help(wrap!(
+                    "Print more details of what is being done, such as where \
+                     the wallet password was read from."
+                )))
+                .arg(REDACT.def().global(true).help(wrap!(
+                    "Replace the base directory in the paths printed by the \
+                     wallet and signing commands with \"<base-dir>\", so \
+                     that their output can be shared."
+                )))
+                .arg(DEBUG_APDU.def().global(true).help(wrap!(
+                    "Log the APDU commands sent to the hardware wallet and \
+                     its responses to stderr, with their payloads redacted. \
+                     Can also be enabled by setting the \
+                     `NAMADA_DEVICE_DEBUG` environment variable to 1."
+                )))
+                .arg(ASSUME_TTY.def().global(true).help(wrap!(
+                    "Prompt for passwords and confirmations as if there were \
+                     a terminal, for when it isn't detected. Can also be \
+                     enabled by setting the `NAMADA_ASSUME_TTY` environment \
+                     variable to 1."
+                )))
+                .arg(
+                    NO_TTY
+                        .def()
+                        .global(true)
+                        .conflicts_with(ASSUME_TTY.name)
+                        .help(wrap!(
+                            "Never prompt, as if there were no terminal, \
+                             failing instead where input is needed. Takes \
+                             precedence over `NAMADA_ASSUME_TTY`."
+                        )),
+                )
+                .arg(REQUIRE_INTEGRITY.def().global(true).help(wrap!(
+                    "Fail to load a wallet without an integrity MAC, instead \
+                     of only warning about it. The MAC is written whenever \
+                     the wallet is saved, and keyed by the \
+                     `NAMADA_WALLET_INTEGRITY_KEY` environment variable if \
+                     set."
+                )))
+                .arg(NO_SAVE.def().global(true).help(wrap!(
+                    "Make the changes of wallet commands, such as generating, \
+                     renaming or importing keys, in memory only, without \
+                     saving the wallet, to see what they would do."
+                )))
+                .arg(WALLET_FILE_NAME.def().global(true).help(wrap!(
+                    "Store the wallet in a file of this name in its \
+                     directory instead of `wallet.toml`, e.g. to keep several \
+                     wallets in one directory. This value can also be set \
+                     via the `NAMADA_WALLET_FILE_NAME` environment variable, \
+                     but the argument takes precedence, if specified."
+                )))
+                .arg(KDF_ITERATIONS.def().global(true).help(wrap!(
+                    "The number of iterations of the Argon2i KDF deriving the \
+                     encryption key of the keys encrypted by this command \
+                     from their password. The parameters are stored with \
+                     every key, so that it is decrypted with the same ones. \
+                     At most 64. Defaults to 3."
+                )))
+                .arg(KDF_MEMORY.def().global(true).help(wrap!(
+                    "The memory cost in KiB of the Argon2i KDF deriving the \
+                     encryption key of the keys encrypted by this command \
+                     from their password, at least 8 and at most 4194304 \
+                     (4 GiB). Defaults to 131072 (128 MiB)."
+                )))
         }
     }
 
@@ -7282,6 +8975,49 @@ pub mod args {
         }
     }
 
+    impl Args for QueryValidatorKeys<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let alias = ALIAS.parse(matches);
+            Self {
+                query,
+                validator,
+                alias,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(
+                    VALIDATOR
+                        .def()
+                        .help(wrap!("The native address of the validator.")),
+                )
+                .arg(ALIAS.def().help(wrap!(
+                    "The alias of the validator in the wallet. Its keys are \
+                     looked up under the aliases given to them by \
+                     `become-validator`, i.e. `<alias>-consensus-key` and \
+                     `<alias>-protocol-key`."
+                )))
+        }
+    }
+
+    impl CliToSdk<QueryValidatorKeys<SdkTypes>> for QueryValidatorKeys<CliTypes> {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryValidatorKeys<SdkTypes>, Self::Error> {
+            Ok(QueryValidatorKeys::<SdkTypes> {
+                query: self.query.to_sdk(ctx)?,
+                validator: ctx.borrow_chain_or_exit().get(&self.validator),
+                alias: self.alias,
+            })
+        }
+    }
+
     impl CliToSdk<QueryRawBytes<SdkTypes>> for QueryRawBytes<CliTypes> {
         type Error = std::convert::Infallible;
 
@@ -7342,6 +9078,7 @@ pub mod args {
             self,
             ctx: &mut Context,
         ) -> Result<Tx<SdkTypes>, Self::Error> {
+            let debug_apdu = ctx.global_args.debug_apdu_enabled();
             let ctx = ctx.borrow_mut_chain_or_exit();
 
             Ok(Tx::<SdkTypes> {
@@ -7384,6 +9121,7 @@ pub mod args {
                 memo: self.memo,
                 use_device: self.use_device,
                 device_transport: self.device_transport,
+                debug_apdu,
             })
         }
     }
@@ -7659,9 +9397,13 @@ pub mod args {
                     })
             };
             let viewing_key = if ctx.global_args.is_pre_genesis {
-                let wallet_path =
-                    ctx.global_args.base_dir.join(PRE_GENESIS_DIR);
-                let mut wallet = crate::wallet::load_or_new(&wallet_path);
+                let wallet_path = crate::wallet::pre_genesis_store_dir(
+                    &ctx.global_args.base_dir,
+                );
+                let mut wallet = crate::wallet::load_or_new_with_options(
+                    &wallet_path,
+                    &ctx.global_args.wallet_options(),
+                );
                 find_viewing_key(&mut wallet)
             } else {
                 find_viewing_key(&mut ctx.borrow_mut_chain_or_exit().wallet)
@@ -7723,65 +9465,166 @@ pub mod args {
                 derivation_path,
                 allow_non_compliant,
                 prompt_bip39_passphrase,
-                use_device,
-                device_transport,
-                birthday,
+                use_device,
+                device_transport,
+                birthday,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(SCHEME.def().conflicts_with(SHIELDED.name).help(wrap!(
+                "For the transparent pool, the type of key that should be \
+                 derived. Argument must be either ed25519 or secp256k1. If \
+                 none provided, the default key scheme is ed25519.\nNot \
+                 applicable for the shielded pool."
+            )))
+            .arg(
+                SHIELDED.def().help(wrap!(
+                    "Derive a spending key for the shielded pool."
+                )),
+            )
+            .arg(ALIAS.def().help(wrap!("The key and address alias.")))
+            .arg(
+                ALIAS_FORCE.def().help(wrap!(
+                    "Force overwrite the alias if it already exists."
+                )),
+            )
+            .arg(BIRTHDAY.def().help(wrap!(
+                "A block height after which this key is being created. Used \
+                 for optimizing MASP operations. If none is provided, \
+                 defaults to the first block height."
+            )))
+            .arg(UNSAFE_DONT_ENCRYPT.def().help(wrap!(
+                "UNSAFE: Do not encrypt the keypair. Do not use this for keys \
+                 used in a live network."
+            )))
+            .arg(USE_DEVICE.def().help(wrap!(
+                "Derive an address and public key from the seed stored on the \
+                 connected hardware wallet."
+            )))
+            .arg(DEVICE_TRANSPORT.def().help(wrap!(
+                "Select transport for hardware wallet from \"hid\" (default) \
+                 or \"tcp\"."
+            )))
+            .arg(HD_DERIVATION_PATH.def().help(wrap!(
+                "HD key derivation path. Use keyword `default` to refer to a \
+                 scheme default path:\n- m/44'/60'/0'/0/0 for the transparent \
+                 secp256k1 scheme\n- m/44'/877'/0'/0'/0' for the transparent \
+                 ed25519 scheme\n- m/32'/877'/0' for the shielded \
+                 setting\nFor ed25519 scheme, all path indices will be \
+                 promoted to hardened indexes. If none is specified, the \
+                 scheme default path is used."
+            )))
+            .arg(HD_ALLOW_NON_COMPLIANT_DERIVATION_PATH.def().help(wrap!(
+                "Allow non-compliant HD derivation path. The compliant \
+                 derivation path schemes include:\n- \
+                 m/44'/60'/account'/change/address_index for the transparent \
+                 secp256k1 scheme\n- \
+                 m/44'/877'/account'/change'/address_index' for the \
+                 transparent ed25519 scheme\n- m/32'/877'/account' and\n- \
+                 m/32'/877'/account'/address_index for the shielded setting"
+            )))
+            .group(
+                ArgGroup::new("requires_group")
+                    .args([HD_ALLOW_NON_COMPLIANT_DERIVATION_PATH.name])
+                    .requires(HD_DERIVATION_PATH.name),
+            )
+            .arg(HD_PROMPT_BIP39_PASSPHRASE.def().help(wrap!(
+                "Use an additional passphrase for HD-key generation."
+            )))
+        }
+    }
+
+    impl Args for KeyDeriveBatch {
+        fn parse(matches: &ArgMatches) -> Self {
+            let scheme = SCHEME.parse(matches);
+            let keys = DERIVE_KEYS.parse(matches);
+            let alias_force = ALIAS_FORCE.parse(matches);
+            let unsafe_dont_encrypt = UNSAFE_DONT_ENCRYPT.parse(matches);
+            let allow_non_compliant =
+                HD_ALLOW_NON_COMPLIANT_DERIVATION_PATH.parse(matches);
+            let prompt_bip39_passphrase =
+                HD_PROMPT_BIP39_PASSPHRASE.parse(matches);
+            Self {
+                scheme,
+                keys,
+                alias_force,
+                unsafe_dont_encrypt,
+                allow_non_compliant,
+                prompt_bip39_passphrase,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(SCHEME.def().help(wrap!(
+                "The type of the keys that should be derived. Argument must \
+                 be either ed25519 or secp256k1. If none provided, the \
+                 default key scheme is ed25519."
+            )))
+            .arg(
+                DERIVE_KEYS
+                    .def()
+                    .action(clap::ArgAction::Append)
+                    .help(wrap!(
+                        "The keys to derive, each as `<alias>=<path>`, e.g. \
+                     \"validator-1=m/44'/877'/0'/0'/1'\". Use the keyword \
+                     `default` as the path to refer to the scheme default \
+                     path. Can be comma-separated or repeated."
+                    )),
+            )
+            .arg(ALIAS_FORCE.def().help(wrap!(
+                "Force overwrite the aliases if they already exist."
+            )))
+            .arg(UNSAFE_DONT_ENCRYPT.def().help(wrap!(
+                "UNSAFE: Do not encrypt the keypairs. Do not use this for \
+                 keys used in a live network."
+            )))
+            .arg(
+                HD_ALLOW_NON_COMPLIANT_DERIVATION_PATH
+                    .def()
+                    .help(wrap!("Allow non-compliant HD derivation paths.")),
+            )
+            .arg(HD_PROMPT_BIP39_PASSPHRASE.def().help(wrap!(
+                "Use an additional passphrase for HD-key derivation."
+            )))
+        }
+    }
+
+    impl Args for KeyPreviewMnemonic {
+        fn parse(matches: &ArgMatches) -> Self {
+            let scheme = SCHEME.parse(matches);
+            let derivation_path = HD_DERIVATION_PATH.parse(matches);
+            let allow_non_compliant =
+                HD_ALLOW_NON_COMPLIANT_DERIVATION_PATH.parse(matches);
+            let prompt_bip39_passphrase =
+                HD_PROMPT_BIP39_PASSPHRASE.parse(matches);
+            Self {
+                scheme,
+                derivation_path,
+                allow_non_compliant,
+                prompt_bip39_passphrase,
             }
         }
 
         fn def(app: App) -> App {
-            app.arg(SCHEME.def().conflicts_with(SHIELDED.name).help(wrap!(
-                "For the transparent pool, the type of key that should be \
-                 derived. Argument must be either ed25519 or secp256k1. If \
-                 none provided, the default key scheme is ed25519.\nNot \
-                 applicable for the shielded pool."
-            )))
-            .arg(
-                SHIELDED.def().help(wrap!(
-                    "Derive a spending key for the shielded pool."
-                )),
-            )
-            .arg(ALIAS.def().help(wrap!("The key and address alias.")))
-            .arg(
-                ALIAS_FORCE.def().help(wrap!(
-                    "Force overwrite the alias if it already exists."
-                )),
-            )
-            .arg(BIRTHDAY.def().help(wrap!(
-                "A block height after which this key is being created. Used \
-                 for optimizing MASP operations. If none is provided, \
-                 defaults to the first block height."
-            )))
-            .arg(UNSAFE_DONT_ENCRYPT.def().help(wrap!(
-                "UNSAFE: Do not encrypt the keypair. Do not use this for keys \
-                 used in a live network."
-            )))
-            .arg(USE_DEVICE.def().help(wrap!(
-                "Derive an address and public key from the seed stored on the \
-                 connected hardware wallet."
-            )))
-            .arg(DEVICE_TRANSPORT.def().help(wrap!(
-                "Select transport for hardware wallet from \"hid\" (default) \
-                 or \"tcp\"."
+            app.arg(SCHEME.def().help(wrap!(
+                "The type of key that should be derived. Argument must be \
+                 either ed25519 or secp256k1. If none provided, the default \
+                 key scheme is ed25519."
             )))
             .arg(HD_DERIVATION_PATH.def().help(wrap!(
                 "HD key derivation path. Use keyword `default` to refer to a \
-                 scheme default path:\n- m/44'/60'/0'/0/0 for the transparent \
-                 secp256k1 scheme\n- m/44'/877'/0'/0'/0' for the transparent \
-                 ed25519 scheme\n- m/32'/877'/0' for the shielded \
-                 setting\nFor ed25519 scheme, all path indices will be \
-                 promoted to hardened indexes. If none is specified, the \
-                 scheme default path is used."
-            )))
-            .arg(HD_ALLOW_NON_COMPLIANT_DERIVATION_PATH.def().help(wrap!(
-                "Allow non-compliant HD derivation path. The compliant \
-                 derivation path schemes include:\n- \
-                 m/44'/60'/account'/change/address_index for the transparent \
-                 secp256k1 scheme\n- \
-                 m/44'/877'/account'/change'/address_index' for the \
-                 transparent ed25519 scheme\n- m/32'/877'/account' and\n- \
-                 m/32'/877'/account'/address_index for the shielded setting"
+                 scheme default path:\n- m/44'/60'/0'/0/0 for the secp256k1 \
+                 scheme\n- m/44'/877'/0'/0'/0' for the ed25519 scheme\nFor \
+                 ed25519 scheme, all path indices will be promoted to \
+                 hardened indexes. If none is specified, the scheme default \
+                 path is used."
             )))
+            .arg(
+                HD_ALLOW_NON_COMPLIANT_DERIVATION_PATH
+                    .def()
+                    .help(wrap!("Allow non-compliant HD derivation path.")),
+            )
             .group(
                 ArgGroup::new("requires_group")
                     .args([HD_ALLOW_NON_COMPLIANT_DERIVATION_PATH.name])
@@ -7892,6 +9735,11 @@ pub mod args {
             let addresses_only = LIST_FIND_ADDRESSES_ONLY.parse(matches);
             let decrypt = DECRYPT.parse(matches);
             let unsafe_show_secret = UNSAFE_SHOW_SECRET.parse(matches);
+            let sort = SORT.parse(matches);
+            let encoding = ENCODING.parse(matches);
+            let tag = TAG_OPT.parse(matches);
+            let since = SINCE_OPT.parse(matches);
+            let until = UNTIL_OPT.parse(matches);
             Self {
                 transparent_only,
                 shielded_only,
@@ -7899,6 +9747,11 @@ pub mod args {
                 addresses_only,
                 decrypt,
                 unsafe_show_secret,
+                sort,
+                encoding,
+                tag,
+                since,
+                until,
             }
         }
 
@@ -7935,6 +9788,32 @@ pub mod args {
                     .def()
                     .help(wrap!("UNSAFE: Print the secret / spending keys.")),
             )
+            .arg(SORT.def().help(wrap!(
+                "The order of the listed entries. Either \"alias\" \
+                 (default), \"address\" or \"created\". Entries that \
+                 compare equal are ordered by alias."
+            )))
+            .arg(ENCODING.def().help(wrap!(
+                "The encoding of the listed transparent addresses and public \
+                 keys. Either \"bech32m\" (default) or \"hex\", which \
+                 prints the raw hash of addresses and the serialized public \
+                 keys."
+            )))
+            .arg(TAG_OPT.def().help(wrap!(
+                "List only the entries tagged with this tag, as added with \
+                 the `tag` command."
+            )))
+            .arg(SINCE_OPT.def().help(wrap!(
+                "List only the entries created at or after this time. Either \
+                 an RFC3339 date, e.g. \"2024-01-31T00:00:00Z\", or a time \
+                 ago, e.g. \"30m\", \"12h\", \"7d\" or \"2w\". Entries \
+                 without a recorded creation time are skipped."
+            )))
+            .arg(UNTIL_OPT.def().help(wrap!(
+                "List only the entries created at or before this time, in \
+                 the same formats as `--since`. Entries without a recorded \
+                 creation time are skipped."
+            )))
         }
     }
 
@@ -7949,6 +9828,7 @@ pub mod args {
             let addresses_only = LIST_FIND_ADDRESSES_ONLY.parse(matches);
             let decrypt = DECRYPT.parse(matches);
             let unsafe_show_secret = UNSAFE_SHOW_SECRET.parse(matches);
+            let encoding = ENCODING.parse(matches);
             Self {
                 alias,
                 address,
@@ -7959,6 +9839,7 @@ pub mod args {
                 addresses_only,
                 decrypt,
                 unsafe_show_secret,
+                encoding,
             }
         }
 
@@ -8015,6 +9896,12 @@ pub mod args {
                     .def()
                     .help(wrap!("UNSAFE: Print the secret / spending key.")),
             )
+            .arg(ENCODING.def().help(wrap!(
+                "The encoding of the found transparent addresses and public \
+                 keys. Either \"bech32m\" (default) or \"hex\", which \
+                 prints the raw hash of addresses and the serialized public \
+                 keys."
+            )))
         }
     }
 
@@ -8079,57 +9966,353 @@ pub mod args {
         }
     }
 
-    impl Args for KeyExport {
+    impl Args for KeySetPolicy {
+        fn parse(matches: &ArgMatches) -> Self {
+            let alias = ALIAS.parse(matches);
+            let policy = KEY_POLICY.parse(matches);
+            Self { alias, policy }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(ALIAS.def().help(wrap!(
+                "The alias of the key whose encryption policy to set."
+            )))
+            .arg(KEY_POLICY.def().help(wrap!(
+                "The encryption policy of the key. Either \"encrypted\" or \
+                 \"plaintext\"."
+            )))
+        }
+    }
+
+    impl Args for ValidatorKeyRotate {
+        fn parse(matches: &ArgMatches) -> Self {
+            let keep = KEEP.parse(matches);
+            Self { keep }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(KEEP.def().help(wrap!(
+                "The validator key to keep. Either \"protocol\", to rotate \
+                 the Ethereum bridge key, or \"eth-bridge\", to rotate the \
+                 protocol key."
+            )))
+        }
+    }
+
+    impl Args for AliasCaseSet {
+        fn parse(matches: &ArgMatches) -> Self {
+            let preserve = PRESERVE.parse(matches);
+            Self { preserve }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(PRESERVE.def().help(wrap!(
+                "Preserve the case of the added aliases. Without this flag, \
+                 aliases are normalized to lower-case again."
+            )))
+        }
+    }
+
+    impl Args for KeyAddressTag {
         fn parse(matches: &ArgMatches) -> Self {
             let alias = ALIAS.parse(matches);
+            let tags = TAGS.parse(matches);
+            Self { alias, tags }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(ALIAS.def().help(wrap!("The alias of the entries to tag.")))
+                .arg(TAGS.def().help(wrap!(
+                    "Comma-separated tags to add to the entries. Tags are \
+                     case-sensitive and kept along with the existing ones."
+                )))
+        }
+    }
+
+    impl Args for WalletPath {
+        fn parse(_matches: &ArgMatches) -> Self {
+            Self {}
+        }
+
+        fn def(app: App) -> App {
+            app
+        }
+    }
+
+    impl Args for WalletCompact {
+        fn parse(_matches: &ArgMatches) -> Self {
+            Self {}
+        }
+
+        fn def(app: App) -> App {
+            app
+        }
+    }
+
+    impl Args for KeyCheckPassword {
+        fn parse(matches: &ArgMatches) -> Self {
+            let alias = ALIAS_OPT.parse(matches);
             Self { alias }
         }
 
+        fn def(app: App) -> App {
+            app.arg(ALIAS_OPT.def().help(wrap!(
+                "The alias or raw public key of the encrypted key to decrypt. \
+                 When not supplied, the first encrypted key by alias is used."
+            )))
+        }
+    }
+
+    impl Args for WalletInit {
+        fn parse(matches: &ArgMatches) -> Self {
+            let scheme = SCHEME_OPT.parse(matches);
+            let alias = ALIAS_OPT.parse(matches);
+            let restore = RESTORE.parse(matches);
+            let unsafe_dont_encrypt = UNSAFE_DONT_ENCRYPT.parse(matches);
+            let genesis_txs_path = GENESIS_TXS_PATH_OPT.parse(matches);
+            let non_interactive = NON_INTERACTIVE.parse(matches);
+            Self {
+                scheme,
+                alias,
+                restore,
+                unsafe_dont_encrypt,
+                genesis_txs_path,
+                non_interactive,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(SCHEME_OPT.def().help(wrap!(
+                "The key scheme, either \"ed25519\" or \"secp256k1\". \
+                 Defaults to ed25519 when not interactive."
+            )))
+            .arg(ALIAS_OPT.def().help(wrap!(
+                "The key and address alias. Required when not interactive."
+            )))
+            .arg(RESTORE.def().help(wrap!(
+                "Restore the key from a mnemonic, rather than generating a \
+                 new one."
+            )))
+            .arg(UNSAFE_DONT_ENCRYPT.def().help(wrap!(
+                "UNSAFE: Do not encrypt the key. Do not use this for keys \
+                 used in a live network."
+            )))
+            .arg(GENESIS_TXS_PATH_OPT.def().help(wrap!(
+                "Path to a genesis txs TOML file. The established accounts \
+                 in it that the new key is a public key of are added to the \
+                 wallet."
+            )))
+            .arg(
+                NON_INTERACTIVE.def().requires(ALIAS.name).help(wrap!(
+                    "Don't prompt for the choices that aren't given with an \
+                 option, taking their defaults instead. A password is still \
+                 read from the environment or prompted for, unless \
+                 `--unsafe-dont-encrypt` is used."
+                )),
+            )
+        }
+    }
+
+    impl Args for KeyTestPassword {
+        fn parse(_matches: &ArgMatches) -> Self {
+            Self {}
+        }
+
+        fn def(app: App) -> App {
+            app
+        }
+    }
+
+    impl Args for KeySignMessage {
+        fn parse(matches: &ArgMatches) -> Self {
+            let alias = ALIAS.parse(matches);
+            let message = MESSAGE.parse(matches);
+            Self { alias, message }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(ALIAS.def().help(wrap!(
+                "The alias of the key to sign with. A raw public key, bech32m \
+                 or hex encoded, is also accepted when no key has this alias."
+            )))
+            .arg(MESSAGE.def().help(wrap!("The message to sign.")))
+        }
+    }
+
+    impl Args for KeyVerifyMessage {
+        fn parse(matches: &ArgMatches) -> Self {
+            let public_key = RAW_PUBLIC_KEY.parse(matches);
+            let message = MESSAGE.parse(matches);
+            let signature = SIGNATURE.parse(matches);
+            Self {
+                public_key,
+                message,
+                signature,
+            }
+        }
+
         fn def(app: App) -> App {
             app.arg(
-                ALIAS
+                RAW_PUBLIC_KEY
+                    .def()
+                    .help(wrap!("The public key that signed the message.")),
+            )
+            .arg(MESSAGE.def().help(wrap!("The signed message.")))
+            .arg(
+                SIGNATURE
                     .def()
-                    .help(wrap!("The alias of the key you wish to export.")),
+                    .help(wrap!("The signature printed by `sign-message`.")),
             )
         }
     }
 
-    impl Args for KeyConvert {
+    impl Args for WalletBackup {
+        fn parse(matches: &ArgMatches) -> Self {
+            let path = PATH.parse(matches);
+            Self { path }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(
+                PATH.def()
+                    .help(wrap!("The path to write the encrypted backup to.")),
+            )
+        }
+    }
+
+    impl Args for WalletRestore {
+        fn parse(matches: &ArgMatches) -> Self {
+            let path = PATH.parse(matches);
+            let force = FORCE.parse(matches);
+            Self { path, force }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(PATH.def().help(wrap!("The path to the encrypted backup.")))
+                .arg(FORCE.def().help(wrap!(
+                    "Overwrite the wallet store if one already exists."
+                )))
+        }
+    }
+
+    impl Args for KeyAuditEncryption {
+        fn parse(matches: &ArgMatches) -> Self {
+            let fail_on_plaintext = FAIL_ON_PLAINTEXT.parse(matches);
+            Self { fail_on_plaintext }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(FAIL_ON_PLAINTEXT.def().help(wrap!(
+                "Exit with a non-zero status if any key is stored in \
+                 plaintext."
+            )))
+        }
+    }
+
+    impl Args for AddressBookEncrypt {
+        fn parse(matches: &ArgMatches) -> Self {
+            let decrypt = DECRYPT.parse(matches);
+            Self { decrypt }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(DECRYPT.def().help(wrap!(
+                "Decrypt the address book and store it in plaintext again."
+            )))
+        }
+    }
+
+    impl Args for KeyExport {
+        fn parse(matches: &ArgMatches) -> Self {
+            let alias = ALIAS.parse(matches);
+            Self { alias }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(ALIAS.def().help(wrap!(
+                "The alias of the key you wish to export. A raw public key, \
+                 bech32m or hex encoded, is also accepted when no key has \
+                 this alias."
+            )))
+        }
+    }
+
+    impl Args for KeyConvert {
+        fn parse(matches: &ArgMatches) -> Self {
+            let alias = ALIAS.parse(matches);
+            Self { alias }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(ALIAS.def().help(wrap!(
+                "The alias of the key you wish to export. A raw public key, \
+                 bech32m or hex encoded, is also accepted when no key has \
+                 this alias."
+            )))
+        }
+    }
+
+    impl Args for KeyImport {
+        fn parse(matches: &ArgMatches) -> Self {
+            let file_path = FILE_PATH.parse(matches);
+            let alias = ALIAS.parse(matches);
+            let alias_force = ALIAS_FORCE.parse(matches);
+            let unsafe_dont_encrypt = UNSAFE_DONT_ENCRYPT.parse(matches);
+            Self {
+                alias,
+                alias_force,
+                file_path,
+                unsafe_dont_encrypt,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(FILE_PATH.def().help(wrap!(
+                "Path to the file containing the key you wish to import."
+            )))
+            .arg(ALIAS.def().help(wrap!("The alias assigned to the.")))
+            .arg(ALIAS_FORCE.def().help(wrap!(
+                "An alias to be associated with the imported entry."
+            )))
+            .arg(UNSAFE_DONT_ENCRYPT.def().help(wrap!(
+                "UNSAFE: Do not encrypt the imported keys. Do not use this \
+                 for keys used in a live network."
+            )))
+        }
+    }
+
+    impl Args for KeyValidateAliases {
         fn parse(matches: &ArgMatches) -> Self {
-            let alias = ALIAS.parse(matches);
-            Self { alias }
+            let file_path = FILE_PATH.parse(matches);
+            Self { file_path }
         }
 
         fn def(app: App) -> App {
-            app.arg(
-                ALIAS
-                    .def()
-                    .help(wrap!("The alias of the key you wish to export.")),
-            )
+            app.arg(FILE_PATH.def().help(wrap!(
+                "Path to the TOML file of the keys whose aliases to validate."
+            )))
         }
     }
 
-    impl Args for KeyImport {
+    impl Args for KeyImportMnemonicFile {
         fn parse(matches: &ArgMatches) -> Self {
             let file_path = FILE_PATH.parse(matches);
-            let alias = ALIAS.parse(matches);
             let alias_force = ALIAS_FORCE.parse(matches);
             let unsafe_dont_encrypt = UNSAFE_DONT_ENCRYPT.parse(matches);
             Self {
-                alias,
-                alias_force,
                 file_path,
+                alias_force,
                 unsafe_dont_encrypt,
             }
         }
 
         fn def(app: App) -> App {
             app.arg(FILE_PATH.def().help(wrap!(
-                "Path to the file containing the key you wish to import."
+                "Path to the TOML file of the mnemonic codes to import."
             )))
-            .arg(ALIAS.def().help(wrap!("The alias assigned to the.")))
             .arg(ALIAS_FORCE.def().help(wrap!(
-                "An alias to be associated with the imported entry."
+                "Override the aliases without confirmation if they already \
+                 exist."
             )))
             .arg(UNSAFE_DONT_ENCRYPT.def().help(wrap!(
                 "UNSAFE: Do not encrypt the imported keys. Do not use this \
@@ -8145,6 +10328,13 @@ pub mod args {
         pub pre_genesis_path: Option<PathBuf>,
         pub allow_duplicate_ip: bool,
         pub add_persistent_peers: bool,
+        pub download_timeout: Duration,
+        pub download_retries: u32,
+        pub download_retry_delay: Duration,
+        pub alias_prefix: Option<String>,
+        pub include_aliases: Vec<String>,
+        pub exclude_aliases: Vec<String>,
+        pub dry_run: bool,
     }
 
     impl Args for JoinNetwork {
@@ -8154,12 +10344,26 @@ pub mod args {
             let pre_genesis_path = PRE_GENESIS_PATH.parse(matches);
             let allow_duplicate_ip = ALLOW_DUPLICATE_IP.parse(matches);
             let add_persistent_peers = ADD_PERSISTENT_PEERS.parse(matches);
+            let download_timeout = DOWNLOAD_TIMEOUT.parse(matches);
+            let download_retries = DOWNLOAD_RETRIES.parse(matches);
+            let download_retry_delay = DOWNLOAD_RETRY_DELAY.parse(matches);
+            let alias_prefix = ALIAS_PREFIX.parse(matches);
+            let include_aliases = INCLUDE_ALIAS.parse(matches);
+            let exclude_aliases = EXCLUDE_ALIAS.parse(matches);
+            let dry_run = DRY_RUN_TX.parse(matches);
             Self {
                 chain_id,
                 genesis_validator,
                 pre_genesis_path,
                 allow_duplicate_ip,
                 add_persistent_peers,
+                download_timeout,
+                download_retries,
+                download_retry_delay,
+                alias_prefix,
+                include_aliases,
+                exclude_aliases,
+                dry_run,
             }
         }
 
@@ -8176,6 +10380,42 @@ pub mod args {
                 "Whether to add persistent peers to the P2P config of CometBFT, \
                  derived from the list of genesis validators.",
             ))
+            .arg(DOWNLOAD_TIMEOUT.def().help(wrap!(
+                "Timeout for downloading the network config release, e.g. \
+                 \"90s\" or \"5m\". Defaults to 60 seconds. Can also be \
+                 set with the `NAMADA_DOWNLOAD_TIMEOUT` env var."
+            )))
+            .arg(DOWNLOAD_RETRIES.def().help(wrap!(
+                "How many times to retry downloading the network config \
+                 release after a timeout, a connection failure or a server \
+                 error. A missing release is not retried. Defaults to 3."
+            )))
+            .arg(DOWNLOAD_RETRY_DELAY.def().help(wrap!(
+                "The delay before the first download retry, doubled on \
+                 every further retry, e.g. \"500ms\" or \"2s\". Defaults \
+                 to 1 second."
+            )))
+            .arg(ALIAS_PREFIX.def().help(wrap!(
+                "Namespace the aliases of the genesis tokens added to the \
+                 wallet under this prefix, e.g. \"testnet\" for \
+                 \"testnet/nam\", to avoid collisions with the aliases \
+                 already in the wallet."
+            )))
+            .arg(INCLUDE_ALIAS.def().help(wrap!(
+                "Only add the genesis tokens with these comma-separated \
+                 aliases to the wallet, instead of all of them. The aliases \
+                 are matched before any `--alias-prefix` is added."
+            )))
+            .arg(EXCLUDE_ALIAS.def().help(wrap!(
+                "Don't add the genesis tokens with these comma-separated \
+                 aliases to the wallet. Takes precedence over \
+                 `--include-alias`."
+            )))
+            .arg(DRY_RUN_TX.def().help(wrap!(
+                "Fetch the network config archive and print the paths it \
+                 would write under the base directory, flagging the ones \
+                 that already exist, without writing anything."
+            )))
         }
     }
 
@@ -8198,6 +10438,80 @@ pub mod args {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct RecoverAddress {
+        pub public_key: String,
+        pub scheme: SchemeType,
+    }
+
+    impl Args for RecoverAddress {
+        fn parse(matches: &ArgMatches) -> Self {
+            let public_key = ENCODED_PUBLIC_KEY.parse(matches);
+            let scheme = SCHEME.parse(matches);
+            Self { public_key, scheme }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(ENCODED_PUBLIC_KEY.def().help(wrap!(
+                "The public key to derive the address of. Either bech32m \
+                 encoded, or hex encoded with or without the scheme tag."
+            )))
+            .arg(SCHEME.def().help(wrap!(
+                "The scheme of the public key. Currently supports ed25519 \
+                 and secp256k1."
+            )))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct SignOfflineTx {
+        pub tx_path: PathBuf,
+        pub signer: String,
+        pub use_device: bool,
+        pub device_transport: DeviceTransport,
+        pub output: Option<PathBuf>,
+    }
+
+    impl Args for SignOfflineTx {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx_path = DATA_PATH.parse(matches);
+            let signer = SIGNER_KEY.parse(matches);
+            let use_device = USE_DEVICE.parse(matches);
+            let device_transport = DEVICE_TRANSPORT.parse(matches);
+            let output = OUTPUT.parse(matches);
+            Self {
+                tx_path,
+                signer,
+                use_device,
+                device_transport,
+                output,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(DATA_PATH.def().help(wrap!(
+                "The path to the serialized transaction, as dumped with \
+                 `--dump-tx`."
+            )))
+            .arg(SIGNER_KEY.def().help(wrap!(
+                "The key to sign with, given by its alias in the wallet or \
+                 as a raw public key."
+            )))
+            .arg(USE_DEVICE.def().help(wrap!(
+                "Sign with the key of the connected hardware wallet instead \
+                 of a key stored in the wallet."
+            )))
+            .arg(DEVICE_TRANSPORT.def().help(wrap!(
+                "Select transport for hardware wallet from \"hid\" (default) \
+                 or \"tcp\"."
+            )))
+            .arg(OUTPUT.def().help(wrap!(
+                "Save the signed transaction to this file. If not given, it \
+                 is printed to stdout."
+            )))
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct SignOffline<C: NamadaTypes = SdkTypes> {
         pub tx_path: PathBuf,
@@ -8275,6 +10589,125 @@ pub mod args {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct ShowConfig {
+        /// The names of the global args given on the command line
+        pub given_args: Vec<&'static str>,
+    }
+
+    impl Args for ShowConfig {
+        fn parse(matches: &ArgMatches) -> Self {
+            let given_args = [
+                BASE_DIR.name,
+                CHAIN_ID_OPT.name,
+                WASM_DIR.name,
+                PRE_GENESIS.name,
+            ]
+            .into_iter()
+            .filter(|name| {
+                matches.value_source(name)
+                    == Some(clap::parser::ValueSource::CommandLine)
+            })
+            .collect();
+            Self { given_args }
+        }
+
+        fn def(app: App) -> App {
+            app
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct SelfTest {}
+
+    impl Args for SelfTest {
+        fn parse(_matches: &ArgMatches) -> Self {
+            Self {}
+        }
+
+        fn def(app: App) -> App {
+            app
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct ExplainError {
+        /// The exit code or name of the failure to explain
+        pub code: String,
+    }
+
+    impl Args for ExplainError {
+        fn parse(matches: &ArgMatches) -> Self {
+            let code = CODE.parse(matches);
+            Self { code }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(CODE.def().help(wrap!(
+                "The exit code of the client, or the name of a common \
+                 failure, to explain."
+            )))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct ListChains {}
+
+    impl Args for ListChains {
+        fn parse(_matches: &ArgMatches) -> Self {
+            Self {}
+        }
+
+        fn def(app: App) -> App {
+            app
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct ListProfiles {}
+
+    impl Args for ListProfiles {
+        fn parse(_matches: &ArgMatches) -> Self {
+            Self {}
+        }
+
+        fn def(app: App) -> App {
+            app
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct ListDevices {
+        pub device_transport: DeviceTransport,
+    }
+
+    impl Args for ListDevices {
+        fn parse(matches: &ArgMatches) -> Self {
+            let device_transport = DEVICE_TRANSPORT.parse(matches);
+            Self { device_transport }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(DEVICE_TRANSPORT.def().help(wrap!(
+                "Select transport for hardware wallet from \"hid\" (default) \
+                 or \"tcp\"."
+            )))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct DumpCli {}
+
+    impl Args for DumpCli {
+        fn parse(_matches: &ArgMatches) -> Self {
+            Self {}
+        }
+
+        fn def(app: App) -> App {
+            app
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct ValidateWasm {
         pub code_path: PathBuf,
@@ -8295,6 +10728,19 @@ pub mod args {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct CheckWasm {}
+
+    impl Args for CheckWasm {
+        fn parse(_matches: &ArgMatches) -> Self {
+            Self {}
+        }
+
+        fn def(app: App) -> App {
+            app
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct InitNetwork {
         pub templates_path: PathBuf,
@@ -8415,49 +10861,164 @@ pub mod args {
     }
 
     #[derive(Clone, Debug)]
-    pub struct GenesisBond {
-        pub source: AddrOrPk,
-        pub validator: EstablishedAddress,
-        pub bond_amount: token::DenominatedAmount,
-        pub output: PathBuf,
+    pub struct GenesisBond {
+        pub source: AddrOrPk,
+        pub validator: EstablishedAddress,
+        pub bond_amount: token::DenominatedAmount,
+        pub output: PathBuf,
+    }
+
+    impl Args for GenesisBond {
+        fn parse(matches: &ArgMatches) -> Self {
+            let validator = GENESIS_VALIDATOR_ADDRESS.parse(matches);
+            let source =
+                GENESIS_BOND_SOURCE.parse(matches).unwrap_or_else(|| {
+                    AddrOrPk::Address(Address::Established(validator.clone()))
+                });
+            let bond_amount = AMOUNT.parse(matches);
+            let output = PATH.parse(matches);
+            Self {
+                source,
+                validator,
+                bond_amount,
+                output,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(
+                GENESIS_VALIDATOR_ADDRESS
+                    .def()
+                    .help(wrap!("Validator address.")),
+            )
+            .arg(
+                AMOUNT
+                    .def()
+                    .help(wrap!("Amount of tokens to stake in a bond.")),
+            )
+            .arg(GENESIS_BOND_SOURCE.def().help(wrap!(
+                "Source address for delegations. For self-bonds, the \
+                 validator is also the source."
+            )))
+            .arg(
+                PATH.def()
+                    .help(wrap!("Output toml file to write transactions to.")),
+            )
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct GenBondsTemplate {
+        /// Templates dir to take the validators from
+        pub templates_path: Option<PathBuf>,
+        /// Number of bonds with placeholder validators
+        pub count: Option<usize>,
+        pub output: Option<PathBuf>,
+    }
+
+    impl Args for GenBondsTemplate {
+        fn parse(matches: &ArgMatches) -> Self {
+            let templates_path = TEMPLATES_PATH_OPT.parse(matches);
+            let count = BOND_COUNT.parse(matches);
+            let output = OUTPUT.parse(matches);
+            Self {
+                templates_path,
+                count,
+                output,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(TEMPLATES_PATH_OPT.def().help(wrap!(
+                "Path to the directory with the genesis template files. A \
+                 bond is generated for every validator account found in its \
+                 transactions file."
+            )))
+            .arg(BOND_COUNT.def().help(wrap!(
+                "Number of bonds to generate, with the validator left as a \
+                 placeholder."
+            )))
+            .group(
+                ArgGroup::new("bonds_template_source")
+                    .args([TEMPLATES_PATH_OPT.name, BOND_COUNT.name])
+                    .required(true),
+            )
+            .arg(OUTPUT.def().help(wrap!(
+                "Save the template to a TOML file. When not supplied, the \
+                 template will be printed to stdout instead."
+            )))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct BondsTotal {
+        /// Path to the genesis txs TOML file with the bonds
+        pub path: PathBuf,
+    }
+
+    impl Args for BondsTotal {
+        fn parse(matches: &ArgMatches) -> Self {
+            let path = PATH.parse(matches);
+            Self { path }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(PATH.def().help(wrap!(
+                "Path to the TOML file of genesis transactions with the bonds \
+                 to total, such as one generated with `gen-bonds-template`."
+            )))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct EstimateDeviceTime {
+        /// Path to the unsigned genesis txs to count
+        pub path: Option<PathBuf>,
+        /// Number of txs to sign
+        pub count: Option<usize>,
+        /// Device latency of a signature, in milliseconds
+        pub per_sig_ms: Option<u64>,
+        pub device_transport: DeviceTransport,
     }
 
-    impl Args for GenesisBond {
+    impl Args for EstimateDeviceTime {
         fn parse(matches: &ArgMatches) -> Self {
-            let validator = GENESIS_VALIDATOR_ADDRESS.parse(matches);
-            let source =
-                GENESIS_BOND_SOURCE.parse(matches).unwrap_or_else(|| {
-                    AddrOrPk::Address(Address::Established(validator.clone()))
-                });
-            let bond_amount = AMOUNT.parse(matches);
-            let output = PATH.parse(matches);
+            let path = PATH_OPT.parse(matches);
+            let count = BOND_COUNT.parse(matches);
+            let per_sig_ms = PER_SIG_MS.parse(matches);
+            let device_transport = DEVICE_TRANSPORT.parse(matches);
             Self {
-                source,
-                validator,
-                bond_amount,
-                output,
+                path,
+                count,
+                per_sig_ms,
+                device_transport,
             }
         }
 
         fn def(app: App) -> App {
-            app.arg(
-                GENESIS_VALIDATOR_ADDRESS
-                    .def()
-                    .help(wrap!("Validator address.")),
-            )
+            app.arg(PATH_OPT.def().help(wrap!(
+                "Path to a TOML file of unsigned genesis transactions, whose \
+                 transactions are counted."
+            )))
             .arg(
-                AMOUNT
+                BOND_COUNT
                     .def()
-                    .help(wrap!("Amount of tokens to stake in a bond.")),
+                    .help(wrap!("The number of transactions to sign.")),
             )
-            .arg(GENESIS_BOND_SOURCE.def().help(wrap!(
-                "Source address for delegations. For self-bonds, the \
-                 validator is also the source."
-            )))
-            .arg(
-                PATH.def()
-                    .help(wrap!("Output toml file to write transactions to.")),
+            .group(
+                ArgGroup::new("estimate_device_time_count")
+                    .args([PATH_OPT.name, BOND_COUNT.name])
+                    .required(true),
             )
+            .arg(PER_SIG_MS.def().help(wrap!(
+                "The time the device takes per signature, in milliseconds. \
+                 When not supplied, it is calibrated with the connected \
+                 device."
+            )))
+            .arg(DEVICE_TRANSPORT.def().help(wrap!(
+                "Select transport for hardware wallet from \"hid\" (default) \
+                 or \"tcp\"."
+            )))
         }
     }
 
@@ -8645,6 +11206,63 @@ pub mod args {
         pub validator_alias: Option<String>,
         pub use_device: bool,
         pub device_transport: DeviceTransport,
+        /// Manifest of the keys expected to sign the txs
+        pub expected_signers: Option<PathBuf>,
+        /// Don't check that the device holds the keys of the bond sources
+        pub skip_device_verify: bool,
+        /// Don't check that the Namada app of the device is recent enough
+        pub skip_version_check: bool,
+        /// The maximum number of txs to sign
+        pub max_txs: usize,
+        /// Output only the signatures, detached from the txs
+        pub detached: bool,
+        /// Only sign the bonds to this validator
+        pub only_validator: Option<Address>,
+        /// Don't ask to confirm the device addresses before signing
+        pub yes: bool,
+        /// Expected hex-encoded SHA-256 digest of the txs fetched from a
+        /// source URL
+        pub source_sha256: Option<String>,
+        /// Timeout for fetching the txs from a source URL
+        pub download_timeout: Duration,
+        /// The order of the keys of the output TOML tables
+        pub toml_key_order: TomlKeyOrder,
+        /// Whether to abort on the first tx that fails to be signed
+        pub on_error: SignErrorMode,
+        /// Read, sign and output the txs one at a time
+        pub stream: bool,
+        /// Only sign the txs of a signed document that lack a signature of
+        /// this wallet's keys
+        pub fill_missing: bool,
+        /// Sign with the key of this file instead of the wallet's
+        pub key_file: Option<PathBuf>,
+        /// Dirs of additional wallets to sign with, in order of preference
+        pub extra_wallet_dirs: Vec<PathBuf>,
+        /// Print the parsed unsigned txs before signing them
+        pub print_unsigned: bool,
+        /// Write the signed txs to this file instead of stdout
+        pub output: Option<PathBuf>,
+        /// Print a single summary line of the signing to stdout
+        pub machine_summary: bool,
+        /// The unit of the amount, if given as a bare number
+        pub assumed_amount_unit: Option<AmountUnit>,
+        /// Lock the decrypted keys in memory while signing
+        pub lock_keys_in_memory: bool,
+        /// Sign with the key of this PKCS#11 token
+        pub pkcs11: Option<crate::wallet::Pkcs11Config>,
+        /// Annotate each signature with the address of its signer
+        pub include_signer_address: bool,
+        /// Prompt for which of the txs to sign
+        pub interactive_select: bool,
+        /// Append a record of the run to this JSON-lines file
+        pub audit_log: Option<PathBuf>,
+        /// How to encode the signatures in the output
+        pub signature_encoding: SignatureEncoding,
+        /// Redact the amounts in the human-readable output
+        pub hide_amounts: bool,
+        /// Fail right away when a signature is rejected on the hardware
+        /// wallet, rather than offering to retry it
+        pub no_retry: bool,
     }
 
     impl Args for SignGenesisTxs {
@@ -8655,6 +11273,63 @@ pub mod args {
             let validator_alias = ALIAS_OPT.parse(matches);
             let use_device = USE_DEVICE.parse(matches);
             let device_transport = DEVICE_TRANSPORT.parse(matches);
+            let expected_signers = EXPECTED_SIGNERS.parse(matches);
+            let skip_device_verify = SKIP_DEVICE_VERIFY.parse(matches);
+            let skip_version_check = SKIP_VERSION_CHECK.parse(matches);
+            let max_txs = MAX_TXS.parse(matches);
+            let detached = DETACHED.parse(matches);
+            let only_validator = ONLY_VALIDATOR.parse(matches);
+            let yes = YES.parse(matches);
+            let source_sha256 = SOURCE_SHA256.parse(matches);
+            let download_timeout = DOWNLOAD_TIMEOUT.parse(matches);
+            let toml_key_order = TOML_KEY_ORDER.parse(matches);
+            let on_error = if CONTINUE_ON_ERROR.parse(matches) {
+                SignErrorMode::ContinueOnError
+            } else {
+                SignErrorMode::FailFast
+            };
+            let stream = STREAM.parse(matches);
+            let fill_missing = FILL_MISSING.parse(matches);
+            let key_file = KEY_FILE.parse(matches);
+            let extra_wallet_dirs = EXTRA_WALLET_DIR.parse(matches);
+            let print_unsigned = PRINT_UNSIGNED.parse(matches);
+            let output = OUTPUT.parse(matches);
+            let machine_summary = MACHINE_SUMMARY.parse(matches);
+            let assumed_amount_unit = if ASSUME_DISPLAY_UNITS.parse(matches) {
+                Some(AmountUnit::Display)
+            } else if ASSUME_BASE_UNITS.parse(matches) {
+                Some(AmountUnit::Base)
+            } else {
+                None
+            };
+            let lock_keys_in_memory = LOCK_KEYS_IN_MEMORY.parse(matches);
+            let pkcs11 = match SIGNER_BACKEND.parse(matches) {
+                SignerBackendKind::Wallet => None,
+                SignerBackendKind::Pkcs11 => {
+                    // These are required by clap with the PKCS#11 backend
+                    let module = PKCS11_MODULE.parse(matches);
+                    let slot = PKCS11_SLOT.parse(matches);
+                    let key_label = PKCS11_KEY_LABEL.parse(matches);
+                    module.zip(slot).zip(key_label).map(
+                        |((module, slot), key_label)| {
+                            crate::wallet::Pkcs11Config {
+                                module,
+                                slot,
+                                pin: PKCS11_PIN
+                                    .parse(matches)
+                                    .map(zeroize::Zeroizing::new),
+                                key_label,
+                            }
+                        },
+                    )
+                }
+            };
+            let include_signer_address = INCLUDE_SIGNER_ADDRESS.parse(matches);
+            let interactive_select = INTERACTIVE_SELECT.parse(matches);
+            let audit_log = AUDIT_LOG.parse(matches);
+            let signature_encoding = SIGNATURE_ENCODING.parse(matches);
+            let hide_amounts = HIDE_AMOUNTS.parse(matches);
+            let no_retry = NO_RETRY.parse(matches);
             Self {
                 source,
                 validator,
@@ -8662,22 +11337,78 @@ pub mod args {
                 validator_alias,
                 use_device,
                 device_transport,
+                expected_signers,
+                skip_device_verify,
+                skip_version_check,
+                max_txs,
+                detached,
+                only_validator,
+                yes,
+                source_sha256,
+                download_timeout,
+                toml_key_order,
+                on_error,
+                stream,
+                fill_missing,
+                key_file,
+                extra_wallet_dirs,
+                print_unsigned,
+                output,
+                machine_summary,
+                assumed_amount_unit,
+                lock_keys_in_memory,
+                pkcs11,
+                include_signer_address,
+                interactive_select,
+                audit_log,
+                signature_encoding,
+                hide_amounts,
+                no_retry,
             }
         }
 
         fn def(app: App) -> App {
-            app.arg(
-                SOURCE_STR.def().help(wrap!(
-                    "Path to the unsigned transactions TOML file."
-                )),
-            )
+            app.arg(SOURCE_STR.def().help(wrap!(
+                "Path to the unsigned transactions TOML file. An \
+                 `http://` or `https://` URL is fetched instead, in which \
+                 case the unsigned transactions published at it are signed. \
+                 Otherwise, the source of the bond to sign, as an alias of \
+                 the wallet, a public key or an established address. \
+                 Password-encrypted transactions are decrypted with a \
+                 prompted password."
+            )))
+            .arg(SOURCE_SHA256.def().help(wrap!(
+                "The expected hex-encoded SHA-256 digest of the unsigned \
+                 transactions fetched from a source URL. Signing is aborted \
+                 if the fetched file doesn't match it."
+            )))
+            .arg(DOWNLOAD_TIMEOUT.def().help(wrap!(
+                "How long to wait for the unsigned transactions to be \
+                 fetched from a source URL. Defaults to 1 minute. Can also \
+                 be set with the `NAMADA_DOWNLOAD_TIMEOUT` env var."
+            )))
             .arg(VALIDATOR_STR.def().help(wrap!(
-                "Save the output to a TOML file. When not supplied, the \
-                 signed transactions will be printed to stdout instead."
+                "The validator of the bond to sign, as an alias of the \
+                 wallet or an address."
             )))
             .arg(AMOUNT_STR.def().help(wrap!(
                 "The amount of native token to transfer to the validator. \
-                 This is a required parameter."
+                 This is a required parameter. Either in whole tokens with a \
+                 unit suffix, e.g. \"100NAM\", or a bare number with \
+                 `--assume-display-units` or `--assume-base-units`."
+            )))
+            .arg(
+                ASSUME_DISPLAY_UNITS
+                    .def()
+                    .conflicts_with(ASSUME_BASE_UNITS.name)
+                    .help(wrap!(
+                        "Read an amount given as a bare number in whole \
+                         tokens, e.g. \"100\" for 100 NAM."
+                    )),
+            )
+            .arg(ASSUME_BASE_UNITS.def().help(wrap!(
+                "Read an amount given as a bare number in the smallest unit \
+                 of the token, e.g. \"100\" for 0.0001 NAM."
             )))
             .arg(
                 ALIAS_OPT
@@ -8688,9 +11419,453 @@ pub mod args {
                 "Derive an address and public key from the seed stored on the \
                  connected hardware wallet."
             )))
-            .arg(DEVICE_TRANSPORT.def().help(wrap!(
-                "Select transport for hardware wallet from \"hid\" (default) \
-                 or \"tcp\"."
+            .arg(DEVICE_TRANSPORT.def().requires(USE_DEVICE.name).help(wrap!(
+                "Select transport for hardware wallet from \"hid\" \
+                 (default) or \"tcp\". Only valid with --use-device."
+            )))
+            .arg(SIGNER_BACKEND.def().conflicts_with(USE_DEVICE.name).help(
+                wrap!(
+                    "What to sign with besides the keys of the wallet. Either \
+                     \"wallet\" (default), for nothing else, or \"pkcs11\", \
+                     for a key pair of a PKCS#11 token such as a network \
+                     HSM. A hardware wallet is used with --use-device \
+                     instead."
+                ),
+            ))
+            .arg(
+                PKCS11_MODULE
+                    .def()
+                    .required_if_eq(SIGNER_BACKEND.name, "pkcs11")
+                    .requires(SIGNER_BACKEND.name)
+                    .help(wrap!(
+                        "Path to the PKCS#11 module of the token. Required \
+                         with `--signer-backend pkcs11`."
+                    )),
+            )
+            .arg(
+                PKCS11_SLOT
+                    .def()
+                    .required_if_eq(SIGNER_BACKEND.name, "pkcs11")
+                    .requires(SIGNER_BACKEND.name)
+                    .help(wrap!(
+                        "The ID of the slot holding the PKCS#11 token. \
+                         Required with `--signer-backend pkcs11`."
+                    )),
+            )
+            .arg(
+                PKCS11_KEY_LABEL
+                    .def()
+                    .required_if_eq(SIGNER_BACKEND.name, "pkcs11")
+                    .requires(SIGNER_BACKEND.name)
+                    .help(wrap!(
+                        "The label of the ed25519 key pair of the PKCS#11 \
+                         token to sign with. Required with `--signer-backend \
+                         pkcs11`."
+                    )),
+            )
+            .arg(PKCS11_PIN.def().requires(SIGNER_BACKEND.name).help(wrap!(
+                "The user PIN of the PKCS#11 token, which is otherwise \
+                 prompted for. Note that it is visible to the other users of \
+                 this machine while the command runs."
+            )))
+            .arg(EXPECTED_SIGNERS.def().help(wrap!(
+                "Path to a TOML manifest of the public keys expected to sign \
+                 each transaction. After signing, a warning is printed for \
+                 every transaction that a key of this wallet signed without \
+                 being expected to, or was expected to sign but did not."
+            )))
+            .arg(SKIP_DEVICE_VERIFY.def().requires(USE_DEVICE.name).help(
+                wrap!(
+                    "Skip checking that the connected hardware wallet \
+                     holds the public keys of each validator and bond \
+                     source at their stored derivation paths before \
+                     signing."
+                ),
+            ))
+            .arg(SKIP_VERSION_CHECK.def().requires(USE_DEVICE.name).help(
+                wrap!(
+                    "Skip checking that the Namada app of the connected \
+                     hardware wallet is recent enough to produce compatible \
+                     signatures before signing."
+                ),
+            ))
+            .arg(NO_RETRY.def().requires(USE_DEVICE.name).help(wrap!(
+                "Fail as soon as a signature is rejected on the hardware \
+                 wallet. Otherwise, the operator is asked whether to retry \
+                 signing the rejected transaction, up to 3 times."
+            )))
+            .arg(MAX_TXS.def().help(wrap!(
+                "The maximum number of transactions to sign. Signing is \
+                 aborted before any signature is made if there are more \
+                 transactions than this. Defaults to 100."
+            )))
+            .arg(DETACHED.def().help(wrap!(
+                "Output only the signatures, detached from the signed \
+                 transactions. They can be attached back to the unsigned \
+                 transactions with `attach-signatures`."
+            )))
+            .arg(ONLY_VALIDATOR.def().help(wrap!(
+                "Only sign the bonds whose validator is this address, \
+                 leaving out the others."
+            )))
+            .arg(YES.def().requires(USE_DEVICE.name).help(wrap!(
+                "Don't ask to confirm the addresses derived on the hardware \
+                 wallet before signing. Without this flag, the operator must \
+                 type \"yes\" to proceed, and signing is aborted when there \
+                 is no terminal to type it in."
+            )))
+            .arg(TOML_KEY_ORDER.def().help(wrap!(
+                "The order of the keys of the output TOML tables. Either \
+                 \"sorted\" (default), for the same bytes on every run with \
+                 the same inputs, or \"declared\", for the order of the \
+                 fields of the transactions."
+            )))
+            .arg(FAIL_FAST.def().conflicts_with(CONTINUE_ON_ERROR.name).help(
+                wrap!(
+                    "Abort signing on the first transaction that fails \
+                         to be signed, without outputting any. This is the \
+                         default."
+                ),
+            ))
+            .arg(CONTINUE_ON_ERROR.def().help(wrap!(
+                "Leave out the transactions that fail to be signed and \
+                 output the others. The failed transactions are then listed \
+                 with the reason of every failure, and the command exits \
+                 with a non-zero status."
+            )))
+            .arg(STREAM.def().conflicts_with(EXPECTED_SIGNERS.name).help(
+                wrap!(
+                    "Read the unsigned transactions from the source file \
+                         one at a time, outputting each as soon as it is \
+                         signed, instead of loading them all into memory \
+                         first. The established account transactions that \
+                         others need in order to be signed must come before \
+                         them."
+                ),
+            ))
+            .arg(
+                FILL_MISSING
+                    .def()
+                    .conflicts_with_all([
+                        STREAM.name,
+                        DETACHED.name,
+                        ONLY_VALIDATOR.name,
+                    ])
+                    .help(wrap!(
+                        "Read signed transactions from the source instead, \
+                         and only sign those that none of the keys of this \
+                         wallet has signed yet. The transactions are output \
+                         with the new signatures merged in, so none of them \
+                         can be left out with `--only-validator`."
+                    )),
+            )
+            .arg(KEY_FILE.def().conflicts_with(USE_DEVICE.name).help(wrap!(
+                "Sign with the secret key of this file instead of the keys \
+                 of the pre-genesis wallet, which then needn't exist. The \
+                 file holds either a key as exported by `namadaw export`, or \
+                 a key as stored in a wallet, in which case the password to \
+                 decrypt it is prompted for."
+            )))
+            .arg(EXTRA_WALLET_DIR.def().action(clap::ArgAction::Append).help(
+                wrap!(
+                    "The dir of an additional wallet whose keys are also \
+                         used to sign. Can be repeated. The wallets are only \
+                         read. When several wallets have a key of the same \
+                         alias, the key of the pre-genesis wallet or key \
+                         file is used, then that of the first-listed dir, \
+                         and differing keys are reported."
+                ),
+            ))
+            .arg(PRINT_UNSIGNED.def().conflicts_with(FILL_MISSING.name).help(
+                wrap!(
+                    "Print the parsed unsigned transactions to stderr in \
+                         a human-readable form before signing them, so that \
+                         they can be checked. Signing then proceeds as usual."
+                ),
+            ))
+            .arg(OUTPUT.def().conflicts_with(STREAM.name).help(wrap!(
+                "Write the signed transactions to this TOML file instead of \
+                 printing them to stdout."
+            )))
+            .arg(MACHINE_SUMMARY.def().requires(OUTPUT.name).help(wrap!(
+                "Print a single line summarizing the signing to stdout once \
+                 done, e.g. \"SUMMARY txs=12 signed=10 skipped=2 \
+                 device=true\", for scripts to check. Requires `--output`, \
+                 so that the summary is the only thing on stdout."
+            )))
+            .arg(LOCK_KEYS_IN_MEMORY.def().help(wrap!(
+                "Decrypt the signing keys of the wallet up front and lock \
+                 them in memory until signing is done, so that they can't be \
+                 swapped out to disk, then zeroize them. Where memory can't \
+                 be locked, a warning is printed and signing goes on."
+            )))
+            .arg(
+                INCLUDE_SIGNER_ADDRESS
+                    .def()
+                    .conflicts_with(DETACHED.name)
+                    .help(wrap!(
+                        "Annotate each signed transaction with the address \
+                         of each of its signers, derived from their public \
+                         key, in a `signer_addresses` table. The addresses \
+                         are ignored when the transactions are read back."
+                    )),
+            )
+            .arg(
+                INTERACTIVE_SELECT
+                    .def()
+                    .conflicts_with_all([STREAM.name, FILL_MISSING.name])
+                    .help(wrap!(
+                        "List the validator account and bond transactions \
+                         to stderr, and prompt for which of them to sign, \
+                         leaving out the others. Fails if there is no \
+                         terminal to answer in."
+                    )),
+            )
+            .arg(
+                AUDIT_LOG.def().conflicts_with(STREAM.name).help(wrap!(
+                    "Append a record of the run to this file as a line of \
+                     JSON, for audit trails. It holds the time, the SHA-256 \
+                     digest of the input transactions, the public keys that \
+                     signed, the number of transactions and signatures, and \
+                     whether a hardware wallet was used. No secret is ever \
+                     written to it."
+                )),
+            )
+            .arg(SIGNATURE_ENCODING.def().conflicts_with(DETACHED.name).help(
+                wrap!(
+                    "How to encode the signatures of the signed \
+                     transactions: \"default\" for their usual Bech32m \
+                     encoding, or \"hex\" or \"base64\" for their raw \
+                     bytes. Signatures in any of these encodings are \
+                     decoded when the transactions are read back."
+                ),
+            ))
+            .arg(HIDE_AMOUNTS.def().help(wrap!(
+                "Replace the amounts of the bonds with `***` in the \
+                 human-readable output, e.g. for screen-shared ceremonies. \
+                 The signed transactions keep their amounts."
+            )))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct AttachSignatures {
+        /// Path to the unsigned transactions TOML file
+        pub path: PathBuf,
+        /// Paths to the detached signatures TOML files
+        pub signatures: Vec<PathBuf>,
+        /// Path to save the signed transactions TOML file to
+        pub output: Option<PathBuf>,
+    }
+
+    impl Args for AttachSignatures {
+        fn parse(matches: &ArgMatches) -> Self {
+            let path = PATH.parse(matches);
+            let signatures = SIGNATURES.parse(matches);
+            let output = OUTPUT.parse(matches);
+            Self {
+                path,
+                signatures,
+                output,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(
+                PATH.def().help(wrap!(
+                    "Path to the unsigned transactions TOML file."
+                )),
+            )
+            .arg(SIGNATURES.def().help(wrap!(
+                "Paths to the TOML files of detached signatures, as output \
+                 by `sign-genesis-txs --detached`."
+            )))
+            .arg(OUTPUT.def().help(wrap!(
+                "Save the signed transactions to a TOML file. When not \
+                 supplied, they will be printed to stdout instead."
+            )))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct MergeSigned {
+        /// Paths to the signed transactions TOML files
+        pub paths: Vec<PathBuf>,
+        /// Path to save the merged transactions TOML file to
+        pub output: Option<PathBuf>,
+    }
+
+    impl Args for MergeSigned {
+        fn parse(matches: &ArgMatches) -> Self {
+            let paths = PATHS.parse(matches);
+            let output = OUTPUT.parse(matches);
+            Self { paths, output }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(PATHS.def().action(clap::ArgAction::Append).help(wrap!(
+                "Paths to the signed transactions TOML files to \
+                 merge, comma-separated or given repeatedly."
+            )))
+            .arg(OUTPUT.def().help(wrap!(
+                "Save the merged transactions to a TOML file. When not \
+                 supplied, they will be printed to stdout instead."
+            )))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct CheckConsistency {
+        /// Path to the templates dir or its transactions TOML file
+        pub path: PathBuf,
+    }
+
+    impl Args for CheckConsistency {
+        fn parse(matches: &ArgMatches) -> Self {
+            let path = PATH.parse(matches);
+            Self { path }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(PATH.def().help(wrap!(
+                "Path to the directory with the genesis template files, or \
+                 to the genesis transactions TOML file."
+            )))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct VerifyGenesisTxs {
+        /// Path to the signed transactions TOML file
+        pub path: PathBuf,
+        /// The number of worker threads verifying the signatures
+        pub jobs: usize,
+    }
+
+    impl Args for VerifyGenesisTxs {
+        fn parse(matches: &ArgMatches) -> Self {
+            let path = PATH.parse(matches);
+            let jobs = JOBS.parse(matches);
+            Self { path, jobs }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(
+                PATH.def()
+                    .help(wrap!("Path to the signed transactions TOML file.")),
+            )
+            .arg(JOBS.def().help(wrap!(
+                "The number of worker threads verifying the signatures. \
+                 Defaults to the number of CPUs."
+            )))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct Canonicalize {
+        /// Path to the signed or unsigned transactions TOML file
+        pub path: PathBuf,
+        /// Path to write the canonical form to, instead of stdout
+        pub output: Option<PathBuf>,
+    }
+
+    impl Args for Canonicalize {
+        fn parse(matches: &ArgMatches) -> Self {
+            let path = PATH.parse(matches);
+            let output = OUTPUT.parse(matches);
+            Self { path, output }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(PATH.def().help(wrap!(
+                "Path to the signed or unsigned transactions TOML file."
+            )))
+            .arg(OUTPUT.def().help(wrap!(
+                "Path to write the canonical form to. Defaults to stdout."
+            )))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct SignersManifest {
+        /// Path to the signed transactions TOML file
+        pub path: PathBuf,
+        /// Path to save the manifest TOML file to
+        pub output: Option<PathBuf>,
+    }
+
+    impl Args for SignersManifest {
+        fn parse(matches: &ArgMatches) -> Self {
+            let path = PATH.parse(matches);
+            let output = OUTPUT.parse(matches);
+            Self { path, output }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(
+                PATH.def()
+                    .help(wrap!("Path to the signed transactions TOML file.")),
+            )
+            .arg(OUTPUT.def().help(wrap!(
+                "Save the manifest to a TOML file. When not supplied, it \
+                 will be printed to stdout instead."
+            )))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct SubmitGenesisTxs {
+        /// Path to the signed transactions TOML file
+        pub path: PathBuf,
+        /// URL of the coordinator endpoint to post the txs to
+        pub coordinator_url: String,
+        /// Token to authenticate with the coordinator
+        pub token: Option<String>,
+        /// Print the request instead of sending it
+        pub dry_run: bool,
+        /// The order of the keys of the posted TOML tables
+        pub toml_key_order: TomlKeyOrder,
+    }
+
+    impl Args for SubmitGenesisTxs {
+        fn parse(matches: &ArgMatches) -> Self {
+            let path = PATH.parse(matches);
+            let coordinator_url = COORDINATOR_URL.parse(matches);
+            let token = COORDINATOR_TOKEN
+                .parse(matches)
+                .or_else(|| std::env::var(COORDINATOR_TOKEN_ENV_VAR).ok());
+            let dry_run = DRY_RUN_TX.parse(matches);
+            let toml_key_order = TOML_KEY_ORDER.parse(matches);
+            Self {
+                path,
+                coordinator_url,
+                token,
+                dry_run,
+                toml_key_order,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(
+                PATH.def()
+                    .help(wrap!("Path to the signed transactions TOML file.")),
+            )
+            .arg(COORDINATOR_URL.def().help(wrap!(
+                "URL of the coordinator endpoint to POST the signed \
+                 transactions to."
+            )))
+            .arg(COORDINATOR_TOKEN.def().help(wrap!(
+                "Bearer token to authenticate with the coordinator. When not \
+                 supplied, the token is read from the \
+                 `NAMADA_COORDINATOR_TOKEN` env var, if set."
+            )))
+            .arg(DRY_RUN_TX.def().help(wrap!(
+                "Print the request that would be sent, without sending it."
+            )))
+            .arg(TOML_KEY_ORDER.def().help(wrap!(
+                "The order of the keys of the posted TOML tables, as with \
+                 `sign-genesis-txs`. Either \"sorted\" (default) or \
+                 \"declared\"."
             )))
         }
     }
@@ -8858,6 +12033,16 @@ pub fn namada_client_cli() -> Result<NamadaClient> {
 
 pub fn namada_wallet_cli() -> Result<(cmds::NamadaWallet, Context)> {
     let app = namada_wallet_app();
+    // Constructing the context loads the wallet, which printing its store
+    // dir must not do
+    let matches = app.clone().get_matches();
+    if let Some(cmds::NamadaWallet::StorePath(_)) =
+        cmds::NamadaWallet::parse(&matches)
+    {
+        let global_args = args::Global::parse(&matches);
+        wallet::wallet_store_path(&CliIo, &global_args);
+        safe_exit(0)
+    }
     cmds::NamadaWallet::parse_or_print_help(app)
 }
 
@@ -8900,7 +12085,7 @@ pub fn namada_app() -> App {
     let app = App::new(APP_NAME)
         .version(namada_version())
         .about("Namada command line interface.")
-        .color(ColorChoice::Auto)
+        .color(color_choice())
         .subcommand_required(true)
         .arg_required_else_help(true);
     cmds::Namada::add_sub(args::Global::def(app))
@@ -8910,7 +12095,7 @@ pub fn namada_node_app() -> App {
     let app = App::new(APP_NAME)
         .version(namada_version())
         .about("Namada node command line interface.")
-        .color(ColorChoice::Auto)
+        .color(color_choice())
         .subcommand_required(true)
         .arg_required_else_help(true);
     cmds::NamadaNode::add_sub(args::Global::def(app))
@@ -8920,7 +12105,7 @@ pub fn namada_client_app() -> App {
     let app = App::new(APP_NAME)
         .version(namada_version())
         .about("Namada client command line interface.")
-        .color(ColorChoice::Auto)
+        .color(color_choice())
         .subcommand_required(true)
         .arg_required_else_help(true);
     cmds::NamadaClient::add_sub(args::Global::def(app))
@@ -8930,7 +12115,7 @@ pub fn namada_wallet_app() -> App {
     let app = App::new(APP_NAME)
         .version(namada_version())
         .about("Namada wallet command line interface.")
-        .color(ColorChoice::Auto)
+        .color(color_choice())
         .subcommand_required(true)
         .arg_required_else_help(true);
     cmds::NamadaWallet::add_sub(args::Global::def(app))
@@ -8940,6 +12125,7 @@ pub fn namada_relayer_app() -> App {
     let app = App::new(APP_NAME)
         .version(namada_version())
         .about("Namada relayer command line interface.")
+        .color(color_choice())
         .subcommand_required(true);
     cmds::NamadaRelayer::add_sub(args::Global::def(app))
 }
@@ -2,8 +2,11 @@ use namada_sdk::error::Error;
 use namada_sdk::io::{Client, Io};
 use namada_sdk::rpc::wait_until_node_is_synched;
 
+#[cfg(not(target_family = "wasm"))]
 use crate::tendermint_rpc::client::CompatMode;
-use crate::tendermint_rpc::{HttpClient, Url as TendermintUrl};
+#[cfg(not(target_family = "wasm"))]
+use crate::tendermint_rpc::HttpClient;
+use crate::tendermint_rpc::Url as TendermintUrl;
 
 /// Trait for clients that can be used with the CLI.
 #[async_trait::async_trait(?Send)]
@@ -15,6 +18,7 @@ pub trait CliClient: Client + Send + Sync + 'static {
     ) -> Result<(), Error>;
 }
 
+#[cfg(not(target_family = "wasm"))]
 #[async_trait::async_trait(?Send)]
 impl CliClient for HttpClient {
     fn from_tendermint_address(address: &TendermintUrl) -> Self {
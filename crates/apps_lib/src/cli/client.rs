@@ -1,6 +1,7 @@
 use std::io::Read;
 
 use color_eyre::eyre::Result;
+use color_eyre::owo_colors::Style;
 use namada_sdk::io::{display_line, Io, NamadaIo};
 use namada_sdk::masp::ShieldedContext;
 use namada_sdk::{Namada, NamadaImpl};
@@ -338,6 +339,8 @@ impl CliApi {
                     }
                     Sub::ShieldedSync(ShieldedSync(args)) => {
                         let mut args = args.to_sdk(&mut ctx)?;
+                        let heading_style =
+                            ctx.global_args.output_style(Style::new().bold());
                         let chain_ctx = ctx.take_chain_or_exit();
                         let client = client.unwrap_or_else(|| {
                             C::from_tendermint_address(&args.ledger_address)
@@ -358,6 +361,7 @@ impl CliApi {
                             client,
                             args,
                             &io,
+                            heading_style,
                         )
                         .await?;
                     }
@@ -686,6 +690,18 @@ impl CliApi {
                         let namada = ctx.to_sdk(client, io);
                         rpc::query_find_validator(&namada, args).await;
                     }
+                    Sub::QueryValidatorKeys(QueryValidatorKeys(args)) => {
+                        let chain_ctx = ctx.borrow_mut_chain_or_exit();
+                        let ledger_address =
+                            chain_ctx.get(&args.query.ledger_address);
+                        let client = client.unwrap_or_else(|| {
+                            C::from_tendermint_address(&ledger_address)
+                        });
+                        client.wait_until_node_is_synced(&io).await?;
+                        let args = args.to_sdk(&mut ctx)?;
+                        let namada = ctx.to_sdk(client, io);
+                        rpc::query_validator_keys(&namada, args).await;
+                    }
                     Sub::QueryResult(QueryResult(args)) => {
                         let chain_ctx = ctx.borrow_mut_chain_or_exit();
                         let ledger_address =
@@ -808,6 +824,9 @@ impl CliApi {
                     ClientUtils::ValidateWasm(ValidateWasm(args)) => {
                         utils::validate_wasm(args)
                     }
+                    ClientUtils::CheckWasm(CheckWasm(args)) => {
+                        utils::check_wasm(global_args, args)
+                    }
                     ClientUtils::InitNetwork(InitNetwork(args)) => {
                         utils::init_network(global_args, args);
                     }
@@ -829,15 +848,39 @@ impl CliApi {
                     ClientUtils::PkToTmAddress(PkToTmAddress(args)) => {
                         utils::pk_to_tm_address(global_args, args)
                     }
+                    ClientUtils::RecoverAddress(RecoverAddress(args)) => {
+                        utils::recover_address(global_args, args)
+                    }
                     ClientUtils::SignOffline(SignOffline(args)) => {
                         let mut ctx = cli::Context::new::<IO>(global_args)
                             .expect("expected to construct a context");
                         let args = args.to_sdk(&mut ctx)?;
                         utils::sign_offline(args).await
                     }
+                    ClientUtils::SignOfflineTx(SignOfflineTx(args)) => {
+                        utils::sign_offline_tx(global_args, args).await
+                    }
                     ClientUtils::DefaultBaseDir(DefaultBaseDir(args)) => {
                         utils::default_base_dir(global_args, args)
                     }
+                    ClientUtils::ShowConfig(ShowConfig(args)) => {
+                        utils::show_config(global_args, args)
+                    }
+                    ClientUtils::ListChains(ListChains(args)) => {
+                        utils::list_chains(global_args, args)
+                    }
+                    ClientUtils::ListProfiles(ListProfiles(args)) => {
+                        utils::list_profiles(global_args, args)
+                    }
+                    ClientUtils::ListDevices(ListDevices(args)) => {
+                        utils::list_devices(args)
+                    }
+                    ClientUtils::SelfTest(SelfTest(args)) => {
+                        utils::self_test(args).await
+                    }
+                    ClientUtils::ExplainError(ExplainError(args)) => {
+                        utils::explain_error(args)
+                    }
                     ClientUtils::EpochSleep(EpochSleep(args)) => {
                         let mut ctx = cli::Context::new::<IO>(global_args)
                             .expect("expected to construct a context");
@@ -851,16 +894,49 @@ impl CliApi {
                         let namada = ctx.to_sdk(client, io);
                         rpc::epoch_sleep(&namada, args).await;
                     }
+                    ClientUtils::GenBondsTemplate(GenBondsTemplate(args)) => {
+                        utils::gen_bonds_template(global_args, args)
+                    }
+                    ClientUtils::BondsTotal(BondsTotal(args)) => {
+                        utils::bonds_total(global_args, args)
+                    }
+                    ClientUtils::EstimateDeviceTime(EstimateDeviceTime(
+                        args,
+                    )) => utils::estimate_device_time(global_args, args).await,
                     ClientUtils::ValidateGenesisTemplates(
                         ValidateGenesisTemplates(args),
                     ) => utils::validate_genesis_templates(global_args, args),
+                    ClientUtils::CheckConsistency(CheckConsistency(args)) => {
+                        utils::check_consistency(global_args, args)
+                    }
                     ClientUtils::SignGenesisTxs(SignGenesisTxs(args)) => {
                         utils::sign_genesis_tx(global_args, args).await
                     }
+                    ClientUtils::VerifyGenesisTxs(VerifyGenesisTxs(args)) => {
+                        utils::verify_genesis_txs(global_args, args)
+                    }
+                    ClientUtils::Canonicalize(Canonicalize(args)) => {
+                        utils::canonicalize(global_args, args)
+                    }
+                    ClientUtils::MergeSigned(MergeSigned(args)) => {
+                        utils::merge_signed(global_args, args)
+                    }
                     ClientUtils::ByteGenesisTxs(ByteGenesisTxs(args)) => {
                         let signed = utils::byte_genesis_tx(global_args, args).await;
                         print!("{:?}", signed);
                     }
+                    ClientUtils::SubmitGenesisTxs(SubmitGenesisTxs(args)) => {
+                        utils::submit_genesis_txs(global_args, args).await
+                    }
+                    ClientUtils::AttachSignatures(AttachSignatures(args)) => {
+                        utils::attach_signatures(global_args, args)
+                    }
+                    ClientUtils::SignersManifest(SignersManifest(args)) => {
+                        utils::signers_manifest(global_args, args)
+                    }
+                    ClientUtils::DumpCli(DumpCli(args)) => {
+                        utils::dump_cli(args)
+                    }
                     ClientUtils::ParseMigrationJson(MigrationJson(args)) => {
                         #[cfg(feature = "migrations")]
                         {
@@ -161,15 +161,9 @@ impl Context {
     pub fn new<IO: Io>(global_args: args::Global) -> Result<Self> {
         let global_config = read_or_try_new_global_config(&global_args);
 
-        let env_var_chain_id = std::env::var(ENV_VAR_CHAIN_ID)
-            .ok()
-            .and_then(|chain_id| ChainId::from_str(&chain_id).ok());
-        let chain_id = env_var_chain_id
-            .as_ref()
-            .or(global_args.chain_id.as_ref())
-            .or(global_config.default_chain_id.as_ref());
+        let chain_id = resolve_chain_id(&global_args, &global_config);
 
-        let chain = match chain_id {
+        let chain = match chain_id.as_ref() {
             Some(chain_id) if !global_args.is_pre_genesis => {
                 let mut config =
                     Config::load(&global_args.base_dir, chain_id, None);
@@ -177,12 +171,20 @@ impl Context {
                 let native_token =
                     genesis::chain::Finalized::read_native_token(&chain_dir)
                         .expect("Missing genesis files");
-                let wallet = if wallet::exists(&chain_dir) {
-                    wallet::load(&chain_dir).unwrap()
+                let wallet_dir =
+                    wallet::chain_store_dir(&global_args.base_dir, chain_id);
+                let wallet_options = global_args.wallet_options();
+                let wallet = if wallet::exists(
+                    &wallet_dir,
+                    wallet_options.file_name.as_deref(),
+                ) {
+                    wallet::load_with_options(&wallet_dir, &wallet_options)
+                        .unwrap()
                 } else {
                     panic!(
                         "Could not find wallet at {}.",
-                        chain_dir.to_string_lossy()
+                        global_args
+                            .redact_base_dir(wallet_dir.to_string_lossy())
                     );
                 };
 
@@ -316,6 +318,57 @@ impl ChainContext {
     }
 }
 
+/// Resolve the chain ID to use, which is taken from the env var, else the
+/// global args, else the default chain ID of the global config.
+fn resolve_chain_id(
+    global_args: &args::Global,
+    global_config: &GlobalConfig,
+) -> Option<ChainId> {
+    std::env::var(ENV_VAR_CHAIN_ID)
+        .ok()
+        .and_then(|chain_id| ChainId::from_str(&chain_id).ok())
+        .or_else(|| global_args.chain_id.clone())
+        .or_else(|| global_config.default_chain_id.clone())
+}
+
+/// Get the dir of the wallet store that a [`Context`] constructed from the
+/// given global args would use, without loading anything from it. Returns
+/// `None` if no chain is configured outside of pre-genesis.
+pub fn wallet_store_dir(global_args: &args::Global) -> Option<PathBuf> {
+    let chain_id = if global_args.is_pre_genesis {
+        None
+    } else {
+        let global_config = read_or_try_new_global_config(global_args);
+        resolve_chain_id(global_args, &global_config)
+    };
+    wallet::store_dir(
+        &global_args.base_dir,
+        chain_id.as_ref(),
+        global_args.is_pre_genesis,
+    )
+}
+
+/// Get the dir that a [`ChainContext`] constructed from the given global args
+/// would read WASMs from. Without a configured chain, this is only the WASM
+/// dir given in the args or env, if any.
+pub fn chain_wasm_dir(global_args: &args::Global) -> Option<PathBuf> {
+    let wasm_dir = wasm_dir_from_env_or_args(global_args);
+    let chain_id = if global_args.is_pre_genesis {
+        None
+    } else {
+        let global_config = read_or_try_new_global_config(global_args);
+        resolve_chain_id(global_args, &global_config)
+    };
+    match chain_id {
+        Some(chain_id) => {
+            let config = Config::load(&global_args.base_dir, &chain_id, None);
+            let wasm_dir = wasm_dir.unwrap_or(config.wasm_dir);
+            Some(config.ledger.chain_dir().join(wasm_dir))
+        }
+        None => wasm_dir,
+    }
+}
+
 /// Load global config from expected path in the `base_dir` or try to generate a
 /// new one without a chain if it doesn't exist.
 pub fn read_or_try_new_global_config(
@@ -1,11 +1,13 @@
 //! Command line interface utilities
 use std::fmt::Debug;
-use std::io::Write;
+use std::io::{IsTerminal, Write};
 use std::marker::PhantomData;
+use std::path::Path;
 use std::str::FromStr;
 use std::sync::Arc;
 
-use clap::{ArgAction, ArgMatches};
+use clap::parser::ValueSource;
+use clap::{ArgAction, ArgMatches, ColorChoice};
 use color_eyre::eyre::Result;
 use data_encoding::HEXLOWER_PERMISSIVE;
 use namada_sdk::eth_bridge::ethers::core::k256::elliptic_curve::SecretKey as Secp256k1Sk;
@@ -82,6 +84,9 @@ pub struct ArgOpt<T> {
 pub struct ArgDefault<T> {
     pub name: &'static str,
     pub default: DefaultFn<T>,
+    /// The default as it's written on the command line, if it doesn't
+    /// depend on the environment
+    pub shown_default: Option<&'static str>,
     pub r#type: PhantomData<T>,
 }
 
@@ -128,6 +133,7 @@ pub const fn arg_default<T>(
     ArgDefault {
         name,
         default,
+        shown_default: None,
         r#type: PhantomData,
     }
 }
@@ -173,6 +179,7 @@ impl<T> Arg<T> {
         ArgDefault {
             name: self.name,
             default,
+            shown_default: None,
             r#type: PhantomData,
         }
     }
@@ -249,14 +256,46 @@ where
     <T as FromStr>::Err: Debug,
 {
     pub fn def(&self) -> ClapArg {
-        ClapArg::new(self.name).long(self.name).num_args(1)
+        let arg = ClapArg::new(self.name).long(self.name).num_args(1);
+        match self.shown_default {
+            // The help texts already describe the defaults, this is only for
+            // the description of the CLI from `dump-cli`
+            Some(default) => {
+                arg.default_value(default).hide_default_value(true)
+            }
+            None => arg,
+        }
     }
 
     pub fn parse(&self, matches: &ArgMatches) -> T {
-        parse_opt(matches, self.name).unwrap_or_else(|| {
-            let DefaultFn(default) = self.default;
-            default()
-        })
+        let given =
+            matches.value_source(self.name) != Some(ValueSource::DefaultValue);
+        given
+            .then(|| parse_opt(matches, self.name))
+            .flatten()
+            .unwrap_or_else(|| {
+                let DefaultFn(default) = self.default;
+                default()
+            })
+    }
+}
+
+impl<T> ArgDefault<T> {
+    /// Set the default as it's written on the command line. It must parse to
+    /// the value of the `DefaultFn`.
+    pub const fn show_default(self, shown_default: &'static str) -> Self {
+        let ArgDefault {
+            name,
+            default,
+            shown_default: _,
+            r#type,
+        } = self;
+        ArgDefault {
+            name,
+            default,
+            shown_default: Some(shown_default),
+            r#type,
+        }
     }
 }
 
@@ -420,6 +459,92 @@ where
     })
 }
 
+/// Environment variable that disables colored output when set to a non-empty
+/// value, following <https://no-color.org>.
+pub const NO_COLOR_ENV_VAR: &str = "NO_COLOR";
+
+/// Check if the `NO_COLOR` environment variable is set to a non-empty value.
+pub fn no_color_env() -> bool {
+    std::env::var_os(NO_COLOR_ENV_VAR).is_some_and(|val| !val.is_empty())
+}
+
+/// Environment variable that enables logging the APDU exchanges with
+/// hardware wallets when set to a non-empty value other than `0`.
+pub const DEVICE_DEBUG_ENV_VAR: &str = "NAMADA_DEVICE_DEBUG";
+
+/// Check if the [`DEVICE_DEBUG_ENV_VAR`] env var is set to log the APDU
+/// exchanges with hardware wallets.
+pub fn debug_apdu_env() -> bool {
+    std::env::var_os(DEVICE_DEBUG_ENV_VAR)
+        .is_some_and(|val| !val.is_empty() && val != "0")
+}
+
+/// Environment variable that makes prompts interactive as if there were a
+/// terminal when set to a non-empty value other than `0`.
+pub const ASSUME_TTY_ENV_VAR: &str = "NAMADA_ASSUME_TTY";
+
+/// Check if the user can be prompted for input. This is detected from stdin,
+/// unless overridden with the given override of `--assume-tty` or
+/// `--no-tty`, or with the [`ASSUME_TTY_ENV_VAR`] env var, in this order of
+/// precedence.
+pub fn tty_enabled(tty_override: Option<bool>) -> bool {
+    if let Some(is_tty) = tty_override {
+        return is_tty;
+    }
+    assume_tty_env() || std::io::stdin().is_terminal()
+}
+
+/// Check if the [`ASSUME_TTY_ENV_VAR`] env var is set to make prompts
+/// interactive.
+pub fn assume_tty_env() -> bool {
+    std::env::var_os(ASSUME_TTY_ENV_VAR)
+        .is_some_and(|val| !val.is_empty() && val != "0")
+}
+
+/// The placeholder that replaces the base directory in output redacted with
+/// `--redact`.
+pub const REDACTED_BASE_DIR: &str = "<base-dir>";
+
+/// The renderings of the given base directory to hide from output redacted
+/// with `--redact`.
+pub fn redacted_base_dirs(base_dir: &Path) -> Vec<String> {
+    let mut dirs = vec![base_dir.to_string_lossy().into_owned()];
+    if let Ok(canonical) = base_dir.canonicalize() {
+        dirs.push(canonical.to_string_lossy().into_owned());
+    }
+    // A base dir of only dots, such as ".", would redact every dot
+    dirs.retain(|dir| !dir.chars().all(|c| c == '.'));
+    // Replace the longest renderings first, as one may contain another
+    dirs.sort_by_key(|dir| std::cmp::Reverse(dir.len()));
+    dirs.dedup();
+    dirs
+}
+
+/// Replace the given renderings of the base directory in the given text with
+/// [`REDACTED_BASE_DIR`]. Only meant for display, as the redacted paths no
+/// longer point at anything.
+pub fn redact_base_dir(
+    redacted_base_dirs: &[String],
+    text: impl std::fmt::Display,
+) -> String {
+    redacted_base_dirs
+        .iter()
+        .fold(text.to_string(), |text, dir| {
+            text.replace(dir, REDACTED_BASE_DIR)
+        })
+}
+
+/// The color choice of the CLI apps. This is needed before the args are
+/// parsed, so the `--no-color` flag is looked up directly.
+pub fn color_choice() -> ColorChoice {
+    let no_color_flag = std::env::args_os().any(|arg| arg == "--no-color");
+    if no_color_flag || no_color_env() {
+        ColorChoice::Never
+    } else {
+        ColorChoice::Auto
+    }
+}
+
 #[cfg(not(feature = "testing"))]
 /// A helper to exit after flushing output, borrowed from `clap::util` module.
 pub fn safe_exit(code: i32) -> ! {
@@ -1,33 +1,47 @@
 //! Namada Wallet CLI.
 
+use std::collections::BTreeSet;
 use std::fs::File;
-use std::io::{self, Write};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use borsh::BorshDeserialize;
 use borsh_ext::BorshSerializeExt;
 use color_eyre::eyre::Result;
-use itertools::sorted;
+use data_encoding::HEXLOWER;
 use ledger_namada_rs::{BIP44Path, NamadaApp};
 use namada_core::chain::BlockHeight;
 use namada_core::masp::{ExtendedSpendingKey, MaspValue, PaymentAddress};
+use namada_core::time::DateTimeUtc;
 use namada_sdk::address::{Address, DecodeError};
-use namada_sdk::io::{display_line, edisplay_line, Io};
+use namada_sdk::bip39::{Language, Mnemonic, Seed};
+use namada_sdk::io::{display_line, edisplay_line, prompt, Io};
 use namada_sdk::key::*;
 use namada_sdk::masp::find_valid_diversifier;
+use namada_sdk::wallet::fs::{
+    restrict_file_permissions, write_file_atomically,
+};
+use namada_sdk::wallet::store::derive_hd_secret_key;
 use namada_sdk::wallet::{
-    DecryptionError, DerivationPath, DerivationPathError, FindKeyError, Wallet,
+    gen_secret_key, parse_public_key, DecryptionError, DerivationPath,
+    DerivationPathError, EncryptedKeypair, FindKeyError, Store, StoredKeypair,
+    Wallet, WalletIo,
 };
 use rand_core::OsRng;
+use serde::Deserialize;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
 use crate::cli;
 use crate::cli::api::CliApi;
 use crate::cli::args::CliToSdk;
 use crate::cli::{args, cmds, Context};
-use crate::client::utils::PRE_GENESIS_DIR;
+use crate::config::genesis::chain::DeriveEstablishedAddress;
+use crate::config::genesis::transactions::parse_unsigned;
 use crate::tendermint_node::validator_key_to_json;
 use crate::wallet::{
-    self, read_and_confirm_encryption_password, CliWalletUtils, WalletTransport,
+    self, gen_validator_keys, read_and_confirm_encryption_password, Alias,
+    CliWalletUtils, WalletTransport,
 };
 
 impl CliApi {
@@ -43,6 +57,12 @@ impl CliApi {
             cmds::NamadaWallet::KeyDerive(cmds::WalletDerive(args)) => {
                 key_derive(ctx, io, args).await
             }
+            cmds::NamadaWallet::KeyDeriveBatch(cmds::WalletDeriveBatch(
+                args,
+            )) => key_derive_batch(ctx, io, args),
+            cmds::NamadaWallet::KeyPreviewMnemonic(
+                cmds::WalletPreviewMnemonic(args),
+            ) => key_preview_mnemonic(io, &ctx.global_args, args),
             cmds::NamadaWallet::KeyAddrList(cmds::WalletListKeysAddresses(
                 args,
             )) => key_address_list(ctx, io, args),
@@ -58,12 +78,63 @@ impl CliApi {
             cmds::NamadaWallet::KeyImport(cmds::WalletImportKey(args)) => {
                 key_import(ctx, io, args)
             }
+            cmds::NamadaWallet::KeyImportMnemonicFile(
+                cmds::WalletImportMnemonicFile(args),
+            ) => key_import_mnemonic_file(ctx, io, args),
+            cmds::NamadaWallet::KeyValidateAliases(
+                cmds::WalletValidateAliases(args),
+            ) => key_validate_aliases(ctx, io, args),
             cmds::NamadaWallet::KeyAddrAdd(cmds::WalletAddKeyAddress(args)) => {
                 key_address_add(ctx, io, args)
             }
             cmds::NamadaWallet::KeyAddrRemove(
                 cmds::WalletRemoveKeyAddress(args),
             ) => key_address_remove(ctx, io, args),
+            cmds::NamadaWallet::KeySetPolicy(cmds::WalletSetKeyPolicy(
+                args,
+            )) => key_set_policy(ctx, io, args),
+            cmds::NamadaWallet::KeyAddrTag(cmds::WalletTagKeyAddress(args)) => {
+                key_address_tag(ctx, io, args)
+            }
+            cmds::NamadaWallet::AliasCaseSet(cmds::WalletSetAliasCase(
+                args,
+            )) => alias_case_set(ctx, io, args),
+            cmds::NamadaWallet::ValidatorKeyRotate(
+                cmds::WalletRotateValidatorKey(args),
+            ) => validator_key_rotate(ctx, io, args),
+            cmds::NamadaWallet::AddressBookEncrypt(
+                cmds::WalletEncryptAddressBook(args),
+            ) => address_book_encrypt(ctx, io, args),
+            cmds::NamadaWallet::KeyCheckPassword(
+                cmds::WalletCheckPasswordFile(args),
+            ) => key_check_password(ctx, io, args),
+            cmds::NamadaWallet::KeyAuditEncryption(
+                cmds::WalletAuditEncryption(args),
+            ) => key_audit_encryption(ctx, io, args),
+            cmds::NamadaWallet::KeyTestPassword(cmds::WalletTestPassword(
+                args::KeyTestPassword {},
+            )) => key_test_password(io, &ctx.global_args),
+            cmds::NamadaWallet::KeySignMessage(cmds::WalletSignMessage(
+                args,
+            )) => key_sign_message(ctx, io, args),
+            cmds::NamadaWallet::KeyVerifyMessage(
+                cmds::WalletVerifyMessage(args),
+            ) => key_verify_message(io, args),
+            cmds::NamadaWallet::Backup(cmds::WalletBackup(args)) => {
+                wallet_backup(io, &ctx.global_args, args)
+            }
+            cmds::NamadaWallet::Restore(cmds::WalletRestore(args)) => {
+                wallet_restore(io, &ctx.global_args, args)
+            }
+            cmds::NamadaWallet::Init(cmds::WalletInitWizard(args)) => {
+                wallet_init(ctx, io, args).await
+            }
+            cmds::NamadaWallet::StorePath(cmds::WalletStorePath(
+                args::WalletPath {},
+            )) => wallet_store_path(io, &ctx.global_args),
+            cmds::NamadaWallet::Compact(cmds::WalletCompact(
+                args::WalletCompact {},
+            )) => wallet_compact(ctx, io),
             cmds::NamadaWallet::PayAddrGen(cmds::WalletGenPaymentAddress(
                 args,
             )) => {
@@ -82,8 +153,11 @@ fn shielded_keys_list(
     decrypt: bool,
     unsafe_show_secret: bool,
     show_hint: bool,
+    sort: args::WalletListSort,
+    filter: ListFilter<'_>,
 ) {
-    let known_view_keys = wallet.get_viewing_keys();
+    let known_view_keys =
+        filter_listed(wallet, io, wallet.get_viewing_keys(), filter);
     let known_spend_keys = wallet.get_spending_keys();
     if known_view_keys.is_empty() {
         if show_hint {
@@ -97,6 +171,8 @@ fn shielded_keys_list(
     } else {
         let mut w_lock = io::stdout().lock();
         display_line!(io, &mut w_lock; "Known shielded keys:").unwrap();
+        let known_view_keys =
+            sort_listed(wallet, known_view_keys, sort, |key| key.to_string());
         for (alias, key) in known_view_keys {
             let spending_key_opt = known_spend_keys.get(&alias);
             // If this alias is associated with a spending key, indicate whether
@@ -106,13 +182,19 @@ fn shielded_keys_list(
                 Some(spend_key) if spend_key.is_encrypted() => "encrypted",
                 _ => "not encrypted",
             };
-            display_line!(io, &mut w_lock; "  Alias \"{}\" ({}):", alias, encrypted_status).unwrap();
+            display_line!(io, &mut w_lock; "  Alias \"{}\" ({}):", wallet.display_alias(&alias), encrypted_status).unwrap();
+            if let Some(created_at) = wallet.get_key_created_at(&alias) {
+                display_line!(io, &mut w_lock; "    Created: {}", created_at)
+                    .unwrap();
+            }
+            display_tags(wallet, io, &mut w_lock, &alias);
             // Always print the corresponding viewing key
             display_line!(io, &mut w_lock; "    Viewing Key: {}", key).unwrap();
             // A subset of viewing keys will have corresponding spending keys.
             // Print those too if they are available and requested.
             if let Some(spending_key) = spending_key_opt {
-                match spending_key.get::<CliWalletUtils>(
+                match spending_key.get(
+                    wallet.utils(),
                     decrypt,
                     None,
                     Some(&alias),
@@ -158,13 +240,131 @@ fn shielded_keys_list(
     }
 }
 
+/// The filters of the entries listed from the wallet
+#[derive(Clone, Copy, Debug, Default)]
+struct ListFilter<'a> {
+    /// Only list the entries with this tag
+    tag: Option<&'a str>,
+    /// Only list the entries created at or after this time
+    since: Option<DateTimeUtc>,
+    /// Only list the entries created at or before this time
+    until: Option<DateTimeUtc>,
+}
+
+/// Keep only the entries listed from the wallet that pass the given filter.
+/// When filtering by creation time, the entries without a recorded creation
+/// time are skipped with a note.
+fn filter_listed<T>(
+    wallet: &Wallet<CliWalletUtils>,
+    io: &impl Io,
+    entries: impl IntoIterator<Item = (String, T)>,
+    ListFilter { tag, since, until }: ListFilter<'_>,
+) -> Vec<(String, T)> {
+    let by_time = since.is_some() || until.is_some();
+    let mut num_untimed: usize = 0;
+    let entries = entries
+        .into_iter()
+        .filter(|(alias, _)| tag.map_or(true, |tag| wallet.has_tag(alias, tag)))
+        .filter(|(alias, _)| {
+            if !by_time {
+                return true;
+            }
+            match wallet.get_key_created_at(alias) {
+                Some(created_at) => {
+                    since.map_or(true, |since| created_at >= since)
+                        && until.map_or(true, |until| created_at <= until)
+                }
+                None => {
+                    num_untimed = num_untimed.saturating_add(1);
+                    false
+                }
+            }
+        })
+        .collect();
+    if num_untimed > 0 {
+        edisplay_line!(
+            io,
+            "Note: skipped {num_untimed} entries without a recorded creation \
+             time."
+        );
+    }
+    entries
+}
+
+/// Print the tags of a listed wallet entry, if it has any.
+fn display_tags(
+    wallet: &Wallet<CliWalletUtils>,
+    io: &impl Io,
+    w_lock: &mut io::StdoutLock<'_>,
+    alias: &str,
+) {
+    let tags = wallet.get_tags(alias);
+    if !tags.is_empty() {
+        display_line!(io, w_lock; "    Tags: {}", tags.join(", ")).unwrap();
+    }
+}
+
+/// Sort the entries listed from the wallet in the given order. Entries that
+/// compare equal are ordered by alias, making the listing deterministic.
+fn sort_listed<T>(
+    wallet: &Wallet<CliWalletUtils>,
+    entries: impl IntoIterator<Item = (String, T)>,
+    sort: args::WalletListSort,
+    address: impl Fn(&T) -> String,
+) -> Vec<(String, T)> {
+    let mut entries: Vec<_> = entries.into_iter().collect();
+    match sort {
+        args::WalletListSort::Alias => {
+            entries.sort_by(|(alias, _), (other, _)| alias.cmp(other))
+        }
+        args::WalletListSort::Address => {
+            entries.sort_by_cached_key(|(alias, value)| {
+                (address(value), alias.clone())
+            })
+        }
+        args::WalletListSort::Created => {
+            entries.sort_by_cached_key(|(alias, _)| {
+                (wallet.get_key_created_at(alias), alias.clone())
+            })
+        }
+    }
+    entries
+}
+
+/// Render a transparent address in the given encoding. Internal addresses
+/// have no raw hash, so they're always rendered in bech32m.
+fn encode_address(address: &Address, encoding: args::WalletEncoding) -> String {
+    match encoding {
+        args::WalletEncoding::Bech32m => address.to_pretty_string(),
+        args::WalletEncoding::Hex => address
+            .raw_hash()
+            .unwrap_or_else(|| address.to_pretty_string()),
+    }
+}
+
+/// Render a public key in the given encoding.
+fn encode_public_key(
+    public_key: &common::PublicKey,
+    encoding: args::WalletEncoding,
+) -> String {
+    match encoding {
+        args::WalletEncoding::Bech32m => public_key.to_string(),
+        args::WalletEncoding::Hex => {
+            HEXLOWER.encode(&public_key.serialize_to_vec())
+        }
+    }
+}
+
 /// List payment addresses.
 fn payment_addresses_list(
     wallet: &Wallet<CliWalletUtils>,
     io: &impl Io,
     show_hint: bool,
+    sort: args::WalletListSort,
+    filter: ListFilter<'_>,
 ) {
-    let known_addresses = wallet.get_payment_addrs();
+    let known_addresses =
+        filter_listed(wallet, io, wallet.get_payment_addrs(), filter);
     if known_addresses.is_empty() {
         if show_hint {
             display_line!(
@@ -176,8 +376,12 @@ fn payment_addresses_list(
     } else {
         let mut w_lock = io::stdout().lock();
         display_line!(io, &mut w_lock; "Known payment addresses:").unwrap();
-        for (alias, address) in sorted(known_addresses) {
-            display_line!(io, &mut w_lock; "  \"{}\": {}", alias, address)
+        let known_addresses =
+            sort_listed(wallet, known_addresses, sort, |address| {
+                address.to_string()
+            });
+        for (alias, address) in known_addresses {
+            display_line!(io, &mut w_lock; "  \"{}\": {}", wallet.display_alias(&alias), address)
                 .unwrap();
         }
     }
@@ -213,8 +417,10 @@ fn shielded_key_derive(
     }
     let alias = alias.to_lowercase();
     let alias = if !use_device {
-        let encryption_password =
-            read_and_confirm_encryption_password(unsafe_dont_encrypt);
+        let encryption_password = read_and_confirm_encryption_password(
+            wallet.utils(),
+            unsafe_dont_encrypt,
+        );
         wallet
             .derive_store_spending_key_from_mnemonic_code(
                 alias,
@@ -236,9 +442,9 @@ fn shielded_key_derive(
         display_line!(io, "No changes are persisted. Exiting.");
         cli::safe_exit(1)
     };
-    wallet
-        .save()
-        .unwrap_or_else(|err| edisplay_line!(io, "{}", err));
+    wallet.save().unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", wallet.utils().redact_base_dir(err))
+    });
     display_line!(
         io,
         "Successfully added a key and an address with alias: \"{}\"",
@@ -264,7 +470,10 @@ fn shielded_key_gen(
 ) {
     let mut wallet = load_wallet(ctx);
     let alias = alias.to_lowercase();
-    let password = read_and_confirm_encryption_password(unsafe_dont_encrypt);
+    let password = read_and_confirm_encryption_password(
+        wallet.utils(),
+        unsafe_dont_encrypt,
+    );
     let alias = if raw {
         wallet.gen_store_spending_key(
             alias,
@@ -287,11 +496,8 @@ fn shielded_key_gen(
             display_line!(io, "No changes are persisted. Exiting.");
             cli::safe_exit(1)
         }
-        let (_mnemonic, seed) = Wallet::<CliWalletUtils>::gen_hd_seed(
-            None,
-            &mut OsRng,
-            prompt_bip39_passphrase,
-        );
+        let (_mnemonic, seed) =
+            wallet.gen_hd_seed(None, &mut OsRng, prompt_bip39_passphrase);
         wallet.derive_store_hd_spending_key(
             alias,
             alias_force,
@@ -308,9 +514,9 @@ fn shielded_key_gen(
         cli::safe_exit(1);
     });
 
-    wallet
-        .save()
-        .unwrap_or_else(|err| edisplay_line!(io, "{}", err));
+    wallet.save().unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", wallet.utils().redact_base_dir(err))
+    });
     display_line!(
         io,
         "Successfully added a spending key with alias: \"{}\"",
@@ -343,7 +549,9 @@ fn payment_address_gen(
             edisplay_line!(io, "Payment address not added");
             cli::safe_exit(1);
         });
-    wallet.save().unwrap_or_else(|err| eprintln!("{}", err));
+    wallet.save().unwrap_or_else(|err| {
+        eprintln!("{}", wallet.utils().redact_base_dir(err))
+    });
     display_line!(
         io,
         "Successfully generated payment address {} with alias {}",
@@ -375,8 +583,10 @@ fn shielded_key_address_add(
             (alias, "viewing key")
         }
         MaspValue::ExtendedSpendingKey(spending_key) => {
-            let password =
-                read_and_confirm_encryption_password(unsafe_dont_encrypt);
+            let password = read_and_confirm_encryption_password(
+                wallet.utils(),
+                unsafe_dont_encrypt,
+            );
             let alias = wallet
                 .insert_spending_key(
                     alias,
@@ -402,7 +612,9 @@ fn shielded_key_address_add(
             (alias, "payment address")
         }
     };
-    wallet.save().unwrap_or_else(|err| eprintln!("{}", err));
+    wallet.save().unwrap_or_else(|err| {
+        eprintln!("{}", wallet.utils().redact_base_dir(err))
+    });
     display_line!(
         io,
         "Successfully added a {} with the following alias to wallet: {}",
@@ -444,6 +656,54 @@ pub fn decode_shielded_derivation_path(
     Ok(parsed_derivation_path)
 }
 
+/// Show the public key and implicit address derived from a mnemonic code,
+/// without storing the key.
+fn key_preview_mnemonic(
+    io: &impl Io,
+    global_args: &args::Global,
+    args::KeyPreviewMnemonic {
+        scheme,
+        derivation_path,
+        allow_non_compliant,
+        prompt_bip39_passphrase,
+    }: args::KeyPreviewMnemonic,
+) {
+    let derivation_path =
+        decode_transparent_derivation_path(scheme, derivation_path)
+            .unwrap_or_else(|err| {
+                edisplay_line!(io, "{}", err);
+                cli::safe_exit(1)
+            });
+    if !allow_non_compliant
+        && !derivation_path.is_namada_transparent_compliant(scheme)
+    {
+        edisplay_line!(io, "Path {} is not compliant.", derivation_path);
+        cli::safe_exit(1)
+    }
+    // The checksum of the mnemonic is checked as it is parsed
+    let utils = CliWalletUtils::without_store(global_args.wallet_options());
+    let mnemonic = utils.read_mnemonic_code().unwrap_or_else(|| {
+        edisplay_line!(io, "Invalid mnemonic code.");
+        cli::safe_exit(1)
+    });
+    let passphrase = if prompt_bip39_passphrase {
+        utils.read_mnemonic_passphrase(false)
+    } else {
+        Zeroizing::default()
+    };
+    let pk = {
+        let seed = Seed::new(&mnemonic, &passphrase);
+        derive_hd_secret_key(scheme, seed.as_bytes(), derivation_path.clone())
+            .ref_to()
+    };
+    drop(mnemonic);
+    drop(passphrase);
+
+    display_line!(io, "Derivation path: {}", derivation_path);
+    display_line!(io, "Public key: {}", pk);
+    display_line!(io, "Address: {}", Address::from(&pk));
+}
+
 /// Derives a keypair and an implicit address from the mnemonic code in the
 /// wallet.
 async fn transparent_key_and_address_derive(
@@ -462,6 +722,7 @@ async fn transparent_key_and_address_derive(
         ..
     }: args::KeyDerive,
 ) {
+    let debug_apdu = ctx.global_args.debug_apdu_enabled();
     let mut wallet = load_wallet(ctx);
     let derivation_path =
         decode_transparent_derivation_path(scheme, derivation_path)
@@ -479,8 +740,10 @@ async fn transparent_key_and_address_derive(
     }
     let alias = alias.to_lowercase();
     let alias = if !use_device {
-        let encryption_password =
-            read_and_confirm_encryption_password(unsafe_dont_encrypt);
+        let encryption_password = read_and_confirm_encryption_password(
+            wallet.utils(),
+            unsafe_dont_encrypt,
+        );
         wallet
             .derive_store_key_from_mnemonic_code(
                 scheme,
@@ -498,7 +761,7 @@ async fn transparent_key_and_address_derive(
             })
             .0
     } else {
-        let transport = WalletTransport::from_arg(device_transport);
+        let transport = WalletTransport::from_arg(device_transport, debug_apdu);
         let app = NamadaApp::new(transport);
         let response = app
             .get_address_and_pubkey(
@@ -536,9 +799,9 @@ async fn transparent_key_and_address_derive(
                 cli::safe_exit(1)
             })
     };
-    wallet
-        .save()
-        .unwrap_or_else(|err| edisplay_line!(io, "{}", err));
+    wallet.save().unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", wallet.utils().redact_base_dir(err))
+    });
     display_line!(
         io,
         "Successfully added a key and an address with alias: \"{}\"",
@@ -546,6 +809,107 @@ async fn transparent_key_and_address_derive(
     );
 }
 
+/// Parse the keys of a batch derivation, each given as `alias=path`. Fails on
+/// the first malformed or non-compliant path, or repeated alias.
+fn parse_batch_keys(
+    scheme: SchemeType,
+    keys: Vec<String>,
+    allow_non_compliant: bool,
+) -> Result<Vec<(String, DerivationPath)>, String> {
+    let mut aliases = BTreeSet::new();
+    keys.into_iter()
+        .map(|key| {
+            let (alias, path) = key
+                .split_once('=')
+                .map(|(alias, path)| (alias.trim().to_lowercase(), path.trim()))
+                .filter(|(alias, _)| !alias.is_empty())
+                .ok_or_else(|| {
+                    format!("The key \"{key}\" is not given as <alias>=<path>.")
+                })?;
+            if !aliases.insert(alias.clone()) {
+                return Err(format!("The alias \"{alias}\" is repeated."));
+            }
+            let path =
+                decode_transparent_derivation_path(scheme, path.to_string())
+                    .map_err(|err| {
+                        format!("Invalid path of the key \"{alias}\": {err}")
+                    })?;
+            if !allow_non_compliant
+                && !path.is_namada_transparent_compliant(scheme)
+            {
+                return Err(format!(
+                    "The path {path} of the key \"{alias}\" is not \
+                     compliant."
+                ));
+            }
+            Ok((alias, path))
+        })
+        .collect()
+}
+
+/// Derive keypairs and implicit addresses at several paths from one mnemonic
+/// code, storing all of them or none.
+fn key_derive_batch(
+    ctx: Context,
+    io: &impl Io,
+    args::KeyDeriveBatch {
+        scheme,
+        keys,
+        alias_force,
+        unsafe_dont_encrypt,
+        allow_non_compliant,
+        prompt_bip39_passphrase,
+    }: args::KeyDeriveBatch,
+) {
+    let keys = parse_batch_keys(scheme, keys, allow_non_compliant)
+        .unwrap_or_else(|err| {
+            edisplay_line!(io, "{err}");
+            display_line!(io, "No changes are persisted. Exiting.");
+            cli::safe_exit(1)
+        });
+    let mut wallet = load_wallet(ctx);
+    let mnemonic = wallet.utils().read_mnemonic_code().unwrap_or_else(|| {
+        edisplay_line!(io, "Invalid mnemonic code.");
+        cli::safe_exit(1)
+    });
+    let passphrase = if prompt_bip39_passphrase {
+        wallet.utils().read_mnemonic_passphrase(false)
+    } else {
+        Zeroizing::default()
+    };
+    let encryption_password = read_and_confirm_encryption_password(
+        wallet.utils(),
+        unsafe_dont_encrypt,
+    );
+    for (alias, derivation_path) in keys.iter().cloned() {
+        let (alias, sk) = wallet
+            .derive_store_key_from_mnemonic_code(
+                scheme,
+                Some(alias.clone()),
+                alias_force,
+                derivation_path.clone(),
+                Some((mnemonic.clone(), passphrase.clone())),
+                false,
+                encryption_password.clone(),
+            )
+            .unwrap_or_else(|| {
+                edisplay_line!(io, "Failed to derive the key \"{alias}\".");
+                display_line!(io, "No changes are persisted. Exiting.");
+                cli::safe_exit(1)
+            });
+        display_line!(
+            io,
+            "Derived the key \"{alias}\" at {derivation_path} with the \
+             address {}",
+            Address::from(&sk.ref_to())
+        );
+    }
+    wallet.save().unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", wallet.utils().redact_base_dir(err))
+    });
+    display_line!(io, "Successfully added {} keys and addresses.", keys.len());
+}
+
 /// Generate a new keypair and derive implicit address from it and store them in
 /// the wallet.
 fn transparent_key_and_address_gen(
@@ -565,8 +929,10 @@ fn transparent_key_and_address_gen(
 ) {
     let alias = alias.to_lowercase();
     let mut wallet = load_wallet(ctx);
-    let encryption_password =
-        read_and_confirm_encryption_password(unsafe_dont_encrypt);
+    let encryption_password = read_and_confirm_encryption_password(
+        wallet.utils(),
+        unsafe_dont_encrypt,
+    );
     let alias = if raw {
         wallet.gen_store_secret_key(
             scheme,
@@ -590,11 +956,8 @@ fn transparent_key_and_address_gen(
             display_line!(io, "No changes are persisted. Exiting.");
             cli::safe_exit(1)
         }
-        let (_mnemonic, seed) = Wallet::<CliWalletUtils>::gen_hd_seed(
-            None,
-            &mut OsRng,
-            prompt_bip39_passphrase,
-        );
+        let (_mnemonic, seed) =
+            wallet.gen_hd_seed(None, &mut OsRng, prompt_bip39_passphrase);
         wallet.derive_store_hd_secret_key(
             scheme,
             Some(alias),
@@ -610,9 +973,9 @@ fn transparent_key_and_address_gen(
         println!("No changes are persisted. Exiting.");
         cli::safe_exit(0);
     });
-    wallet
-        .save()
-        .unwrap_or_else(|err| edisplay_line!(io, "{}", err));
+    wallet.save().unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", wallet.utils().redact_base_dir(err))
+    });
     display_line!(
         io,
         "Successfully added a key and an address with alias: \"{}\"",
@@ -653,9 +1016,20 @@ fn key_address_list(
         keys_only,
         addresses_only,
         unsafe_show_secret,
+        sort,
+        encoding,
+        tag,
+        since,
+        until,
     }: args::KeyAddressList,
 ) {
     let wallet = load_wallet(ctx);
+    let now = CliWalletUtils::now();
+    let filter = ListFilter {
+        tag: tag.as_deref(),
+        since: since.map(|since| since.to_datetime(now)),
+        until: until.map(|until| until.to_datetime(now)),
+    };
     if !shielded_only {
         if !addresses_only {
             transparent_keys_list(
@@ -664,6 +1038,9 @@ fn key_address_list(
                 decrypt,
                 unsafe_show_secret,
                 transparent_only && keys_only,
+                sort,
+                encoding,
+                filter,
             )
         }
         if !keys_only {
@@ -671,6 +1048,9 @@ fn key_address_list(
                 &wallet,
                 io,
                 transparent_only && addresses_only,
+                sort,
+                encoding,
+                filter,
             )
         }
     }
@@ -683,12 +1063,40 @@ fn key_address_list(
                 decrypt,
                 unsafe_show_secret,
                 shielded_only && keys_only,
+                sort,
+                filter,
             )
         }
         if !keys_only {
-            payment_addresses_list(&wallet, io, shielded_only && addresses_only)
+            payment_addresses_list(
+                &wallet,
+                io,
+                shielded_only && addresses_only,
+                sort,
+                filter,
+            )
         }
     }
+
+    if !addresses_only {
+        key_policy_violations_warn(&wallet, io);
+    }
+}
+
+/// Warn about the stored keys that violate their encryption policy.
+fn key_policy_violations_warn(wallet: &Wallet<CliWalletUtils>, io: &impl Io) {
+    for (alias, policy) in wallet.store().key_policy_violations() {
+        edisplay_line!(
+            io,
+            "Warning: the key with alias \"{}\" violates its encryption \
+             policy \"{}\". Run `set-policy --alias {} --policy {}` to fix \
+             it.",
+            alias,
+            policy,
+            alias,
+            policy,
+        );
+    }
 }
 
 /// Find keys and addresses
@@ -705,6 +1113,7 @@ fn key_address_find(
         addresses_only,
         decrypt,
         unsafe_show_secret,
+        encoding,
     }: args::KeyAddressFind,
 ) {
     if let Some(alias) = alias {
@@ -718,6 +1127,7 @@ fn key_address_find(
             addresses_only,
             decrypt,
             unsafe_show_secret,
+            encoding,
         );
         let found_shielded = shielded_key_address_find_by_alias(
             &mut wallet,
@@ -733,7 +1143,7 @@ fn key_address_find(
         }
     } else if address.is_some() {
         // Search alias by address
-        transparent_address_or_alias_find(ctx, io, None, address)
+        transparent_address_or_alias_find(ctx, io, None, address, encoding)
     } else if public_key.is_some() || public_key_hash.is_some() {
         // Search transparent keypair by public key or public key hash
         transparent_key_find(
@@ -743,6 +1153,7 @@ fn key_address_find(
             public_key,
             public_key_hash,
             unsafe_show_secret,
+            encoding,
         )
     } else if payment_address.is_some() {
         // Search alias by MASP payment address
@@ -872,105 +1283,955 @@ fn key_address_remove(
     let alias = alias.to_lowercase();
     let mut wallet = load_wallet(ctx);
     wallet.remove_all_by_alias(alias.clone());
-    wallet
-        .save()
-        .unwrap_or_else(|err| edisplay_line!(io, "{}", err));
+    wallet.save().unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", wallet.utils().redact_base_dir(err))
+    });
     display_line!(io, "Successfully removed alias: \"{}\"", alias);
 }
 
-/// Find a keypair in the wallet store.
-fn transparent_key_find(
+/// Set the encryption policy of a key in the wallet store.
+fn key_set_policy(
     ctx: Context,
     io: &impl Io,
-    alias: Option<String>,
-    public_key: Option<common::PublicKey>,
-    public_key_hash: Option<String>,
-    unsafe_show_secret: bool,
+    args::KeySetPolicy { alias, policy }: args::KeySetPolicy,
 ) {
+    let alias = alias.to_lowercase();
     let mut wallet = load_wallet(ctx);
-    let found_keypair = match public_key {
-        Some(pk) => wallet.find_key_by_pk(&pk, None),
-        None => {
-            let alias = alias.map(|a| a.to_lowercase()).or(public_key_hash);
-            match alias {
-                None => {
-                    edisplay_line!(
-                        io,
-                        "An alias, public key or public key hash needs to be \
-                         supplied",
-                    );
-                    cli::safe_exit(1)
-                }
-                Some(alias) => wallet.find_secret_key(alias, None),
-            }
-        }
-    };
-    match found_keypair {
-        Ok(keypair) => {
-            let pkh: PublicKeyHash = (&keypair.ref_to()).into();
-            display_line!(io, "Public key hash: {}", pkh);
-            display_line!(io, "Public key: {}", keypair.ref_to());
-            if unsafe_show_secret {
-                display_line!(io, "Secret key: {}", keypair);
-            }
-        }
-        Err(err) => {
+    let converted = wallet
+        .set_key_policy(&alias, policy, None, None)
+        .unwrap_or_else(|err| {
             edisplay_line!(io, "{}", err);
-        }
+            display_line!(io, "No changes are persisted. Exiting.");
+            cli::safe_exit(1)
+        });
+    wallet.save().unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", wallet.utils().redact_base_dir(err))
+    });
+    if converted {
+        display_line!(
+            io,
+            "Successfully converted the key with alias \"{}\" to {}.",
+            alias,
+            policy
+        );
+    } else {
+        display_line!(
+            io,
+            "The key with alias \"{}\" already is {}. Its policy has been \
+             stored.",
+            alias,
+            policy
+        );
     }
 }
 
-/// Find address (alias) by its alias (address).
-fn transparent_address_or_alias_find(
+/// Regenerate one of the validator keys stored in the wallet, keeping the
+/// other one.
+fn validator_key_rotate(
     ctx: Context,
     io: &impl Io,
-    alias: Option<String>,
-    address: Option<Address>,
+    args::ValidatorKeyRotate { keep }: args::ValidatorKeyRotate,
 ) {
-    let wallet = load_wallet(ctx);
-    if address.is_some() && alias.is_some() {
-        panic!(
-            "This should not be happening: clap should emit its own error \
-             message."
-        );
-    } else if alias.is_some() {
-        let alias = alias.unwrap().to_lowercase();
-        if let Some(address) = wallet.find_address(&alias) {
-            display_line!(io, "Found address {}", address.to_pretty_string());
-        } else {
-            display_line!(
-                io,
-                "No address with alias {} found. Use the command `list \
-                 --addr` to see all the known transparent addresses.",
-                alias
-            );
+    let mut wallet = load_wallet(ctx);
+    let Some(validator_data) = wallet.get_validator_data() else {
+        edisplay_line!(io, "No validator keys found in the wallet.");
+        cli::safe_exit(1)
+    };
+    let address = validator_data.address.clone();
+    let protocol_pk = validator_data.keys.protocol_keypair.ref_to();
+    let eth_bridge_pk = validator_data.keys.eth_bridge_keypair.ref_to();
+    let (kept_protocol_pk, kept_eth_bridge_pk) = match keep {
+        args::ValidatorKeyKind::Protocol => (Some(protocol_pk.clone()), None),
+        args::ValidatorKeyKind::EthBridge => {
+            (None, Some(eth_bridge_pk.clone()))
         }
-    } else if address.is_some() {
-        if let Some(alias) = wallet.find_alias(address.as_ref().unwrap()) {
-            display_line!(io, "Found alias {}", alias);
-        } else {
-            display_line!(
-                io,
-                "No address with alias {} found. Use the command `list \
-                 --addr` to see all the known transparent addresses.",
-                address.unwrap()
-            );
+    };
+    let keys = gen_validator_keys(
+        &mut wallet,
+        kept_eth_bridge_pk,
+        kept_protocol_pk,
+        protocol_pk.scheme(),
+        &mut OsRng,
+    )
+    .unwrap_or_else(|err| {
+        edisplay_line!(
+            io,
+            "Unable to find the validator key to keep: {}. No changes were \
+             persisted.",
+            err
+        );
+        cli::safe_exit(1)
+    });
+    let (rotated, old_pk, new_pk) = match keep {
+        args::ValidatorKeyKind::Protocol => (
+            "Ethereum bridge",
+            eth_bridge_pk,
+            keys.eth_bridge_keypair.ref_to(),
+        ),
+        args::ValidatorKeyKind::EthBridge => {
+            ("protocol", protocol_pk, keys.protocol_keypair.ref_to())
         }
-    }
+    };
+    wallet.add_validator_data(address, keys);
+    wallet.save().unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", wallet.utils().redact_base_dir(err))
+    });
+    display_line!(
+        io,
+        "Rotated the {} key of the validator from {} to {}.",
+        rotated,
+        old_pk,
+        new_pk
+    );
 }
 
-/// Find payment address (alias) by its alias (payment address).
-fn payment_address_or_alias_find(
+/// Set whether the case of the aliases added to the wallet is preserved.
+fn alias_case_set(
     ctx: Context,
     io: &impl Io,
-    alias: Option<String>,
-    payment_address: Option<PaymentAddress>,
+    args::AliasCaseSet { preserve }: args::AliasCaseSet,
 ) {
-    let wallet = load_wallet(ctx);
-    if payment_address.is_some() && alias.is_some() {
-        panic!(
-            "This should not be happening: clap should emit its own error \
-             message."
+    let mut wallet = load_wallet(ctx);
+    wallet.set_preserve_alias_case(preserve);
+    wallet.save().unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", wallet.utils().redact_base_dir(err))
+    });
+    if preserve {
+        display_line!(
+            io,
+            "The case of the aliases added from now on will be preserved."
+        );
+    } else {
+        display_line!(io, "Aliases will be displayed in lower-case.");
+    }
+}
+
+/// Tag the wallet entries of an alias.
+fn key_address_tag(
+    ctx: Context,
+    io: &impl Io,
+    args::KeyAddressTag { alias, tags }: args::KeyAddressTag,
+) {
+    let alias = alias.to_lowercase();
+    let tags: Vec<String> = tags
+        .into_iter()
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect();
+    if tags.is_empty() {
+        edisplay_line!(io, "No tags given. Exiting.");
+        cli::safe_exit(1)
+    }
+    let mut wallet = load_wallet(ctx);
+    if !wallet.add_tags(&alias, tags) {
+        edisplay_line!(io, "No entry with alias \"{}\" found.", alias);
+        cli::safe_exit(1)
+    }
+    wallet.save().unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", wallet.utils().redact_base_dir(err))
+    });
+    display_line!(
+        io,
+        "Successfully tagged alias \"{}\": {}",
+        alias,
+        wallet.get_tags(&alias).join(", ")
+    );
+}
+
+/// Print the path of the wallet file resolved from the given global args.
+pub fn wallet_store_path(io: &impl Io, global_args: &args::Global) {
+    let path = wallet_file_or_exit(io, global_args);
+    display_line!(io, "{}", path.to_string_lossy())
+}
+
+/// Rewrite the wallet store without its dead data, reporting the size
+/// reduction.
+fn wallet_compact(ctx: Context, io: &impl Io) {
+    let wallet_file = wallet_file_or_exit(io, &ctx.global_args);
+    let redacted_base_dirs = ctx.global_args.redacted_base_dirs.clone();
+    let file_size = || {
+        std::fs::metadata(&wallet_file)
+            .map(|metadata| metadata.len())
+            .unwrap_or_else(|err| {
+                edisplay_line!(
+                    io,
+                    "Unable to read the wallet store {}: {err}",
+                    cli::redact_base_dir(
+                        &redacted_base_dirs,
+                        wallet_file.display()
+                    )
+                );
+                cli::safe_exit(1)
+            })
+    };
+    let size_before = file_size();
+    let mut wallet = load_wallet(ctx);
+    let num_removed = wallet.compact();
+    wallet.save_atomically().unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", wallet.utils().redact_base_dir(err));
+        cli::safe_exit(1)
+    });
+    let size_after = file_size();
+    display_line!(
+        io,
+        "Removed {num_removed} dead entries from the wallet store, which \
+         went from {size_before} to {size_after} bytes ({} bytes saved).",
+        size_before.saturating_sub(size_after)
+    );
+}
+
+/// Resolve the wallet store directory, exiting when no chain is configured.
+fn store_dir_or_exit(io: &impl Io, global_args: &args::Global) -> PathBuf {
+    cli::context::wallet_store_dir(global_args).unwrap_or_else(|| {
+        edisplay_line!(
+            io,
+            "No chain is configured. Pass --chain-id, set NAMADA_CHAIN_ID or \
+             use --pre-genesis."
+        );
+        cli::safe_exit(1)
+    })
+}
+
+/// The path of the wallet file in the store dir resolved from the given
+/// global args, with the file name given by `--wallet-file-name`, if any.
+fn wallet_file_or_exit(io: &impl Io, global_args: &args::Global) -> PathBuf {
+    wallet::wallet_file_with_name(
+        store_dir_or_exit(io, global_args),
+        global_args.wallet_file_name.as_deref(),
+    )
+}
+
+/// The name of the wallet store file inside a backup archive.
+const BACKUP_WALLET_FILE: &str = "wallet.toml";
+
+/// Bundle the wallet store into a tarball, encrypt it under a prompted
+/// password and write it out.
+fn wallet_backup(
+    io: &impl Io,
+    global_args: &args::Global,
+    args::WalletBackup { path }: args::WalletBackup,
+) {
+    let wallet_file = wallet_file_or_exit(io, global_args);
+    if !wallet_file.is_file() {
+        edisplay_line!(io, "No wallet found at {}.", wallet_file.display());
+        cli::safe_exit(1)
+    }
+    let tarball = archive_wallet_file(&wallet_file).unwrap_or_else(|err| {
+        edisplay_line!(
+            io,
+            "Failed to archive the wallet at {}: {err}",
+            wallet_file.display()
+        );
+        cli::safe_exit(1)
+    });
+    let utils = CliWalletUtils::without_store(global_args.wallet_options());
+    let password = utils.read_password(true, None);
+    let encrypted = EncryptedKeypair::new_with_kdf_params(
+        &tarball,
+        password,
+        utils.encryption_kdf_params(),
+    );
+    if let Err(err) = File::create(&path).and_then(|mut file| {
+        restrict_file_permissions(&file)?;
+        file.write_all(encrypted.to_string().as_bytes())
+    }) {
+        edisplay_line!(
+            io,
+            "Failed to write the backup to {}: {err}",
+            path.display()
+        );
+        cli::safe_exit(1)
+    }
+    display_line!(
+        io,
+        "Backed up the wallet at {} to {}.",
+        wallet_file.display(),
+        path.display()
+    );
+}
+
+/// Build an in-memory tarball holding the given wallet store file.
+fn archive_wallet_file(wallet_file: &Path) -> io::Result<Vec<u8>> {
+    let mut archive = tar::Builder::new(Vec::new());
+    archive.append_path_with_name(wallet_file, BACKUP_WALLET_FILE)?;
+    archive.into_inner()
+}
+
+/// Decrypt a backup written by [`wallet_backup`] and restore the wallet store
+/// it holds, refusing to replace an existing store unless forced.
+fn wallet_restore(
+    io: &impl Io,
+    global_args: &args::Global,
+    args::WalletRestore { path, force }: args::WalletRestore,
+) {
+    let store_dir = store_dir_or_exit(io, global_args);
+    let wallet_file = wallet::wallet_file_with_name(
+        &store_dir,
+        global_args.wallet_file_name.as_deref(),
+    );
+    if wallet_file.exists() && !force {
+        edisplay_line!(
+            io,
+            "A wallet already exists at {}. Pass --force to overwrite it.",
+            wallet_file.display()
+        );
+        cli::safe_exit(1)
+    }
+    let encrypted = std::fs::read_to_string(&path).unwrap_or_else(|err| {
+        edisplay_line!(
+            io,
+            "Failed to read the backup at {}: {err}",
+            path.display()
+        );
+        cli::safe_exit(1)
+    });
+    let encrypted = EncryptedKeypair::<Vec<u8>>::from_str(encrypted.trim())
+        .unwrap_or_else(|err| {
+            edisplay_line!(
+                io,
+                "{} is not a wallet backup: {err}",
+                path.display()
+            );
+            cli::safe_exit(1)
+        });
+    let password = CliWalletUtils::without_store(global_args.wallet_options())
+        .read_password(false, Some("the wallet backup"));
+    let tarball = encrypted.decrypt(password).unwrap_or_else(|err| {
+        edisplay_line!(io, "Failed to decrypt the backup: {err}");
+        cli::safe_exit(1)
+    });
+    let contents = match unpack_wallet_file(&tarball) {
+        Ok(Some(contents)) => contents,
+        Ok(None) => {
+            edisplay_line!(io, "The backup doesn't contain a wallet store.");
+            cli::safe_exit(1)
+        }
+        Err(err) => {
+            edisplay_line!(io, "Failed to unpack the backup: {err}");
+            cli::safe_exit(1)
+        }
+    };
+    if let Err(err) = Store::decode(contents.clone()) {
+        edisplay_line!(io, "The backup holds an invalid wallet store: {err}");
+        cli::safe_exit(1)
+    }
+    if let Err(err) = std::fs::create_dir_all(&store_dir)
+        .and_then(|()| write_file_atomically(&wallet_file, &contents))
+    {
+        edisplay_line!(
+            io,
+            "Failed to write the wallet to {}: {err}",
+            wallet_file.display()
+        );
+        cli::safe_exit(1)
+    }
+    display_line!(
+        io,
+        "Restored the wallet from {} to {}.",
+        path.display(),
+        wallet_file.display()
+    );
+}
+
+/// Read the wallet store file out of a backup tarball. Any other entries are
+/// ignored, so that a crafted archive can't write outside the store.
+fn unpack_wallet_file(tarball: &[u8]) -> io::Result<Option<Vec<u8>>> {
+    let mut archive = tar::Archive::new(tarball);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if entry.path()? == Path::new(BACKUP_WALLET_FILE) {
+            let mut contents = Vec::new();
+            entry.read_to_end(&mut contents)?;
+            return Ok(Some(contents));
+        }
+    }
+    Ok(None)
+}
+
+/// Check that the wallet password decrypts a stored key, without printing
+/// either of them.
+fn key_check_password(
+    ctx: Context,
+    io: &impl Io,
+    args::KeyCheckPassword { alias }: args::KeyCheckPassword,
+) {
+    let wallet = load_wallet(ctx);
+    let secret_keys = wallet.get_secret_keys();
+    let spending_keys = wallet.get_spending_keys();
+    let encrypted_aliases = secret_keys
+        .iter()
+        .filter(|(_, (key, _))| key.is_encrypted())
+        .map(|(alias, _)| alias)
+        .chain(
+            spending_keys
+                .iter()
+                .filter(|(_, key)| key.is_encrypted())
+                .map(|(alias, _)| alias),
+        );
+    let alias = match alias {
+        Some(alias) => resolve_key_alias(&wallet, io, &alias),
+        None => match encrypted_aliases.min() {
+            Some(alias) => alias.clone(),
+            None => {
+                edisplay_line!(io, "No encrypted keys found in the wallet.");
+                cli::safe_exit(1)
+            }
+        },
+    };
+    let is_encrypted =
+        match (secret_keys.get(&alias), spending_keys.get(&alias)) {
+            (Some((key, _)), _) => key.is_encrypted(),
+            (None, Some(key)) => key.is_encrypted(),
+            (None, None) => {
+                edisplay_line!(io, "No key with alias \"{}\" found.", alias);
+                cli::safe_exit(1)
+            }
+        };
+    if !is_encrypted {
+        edisplay_line!(
+            io,
+            "The key with alias \"{}\" is not encrypted.",
+            alias
+        );
+        cli::safe_exit(1)
+    }
+    let password = Some(wallet.utils().read_password(false, Some(&alias)));
+    let decrypted = match secret_keys.get(&alias) {
+        Some((key, _)) => key
+            .get(wallet.utils(), true, password, Some(&alias))
+            .map(drop),
+        None => spending_keys[&alias]
+            .get(wallet.utils(), true, password, Some(&alias))
+            .map(drop),
+    };
+    match decrypted {
+        Ok(()) => display_line!(
+            io,
+            "The password decrypts the key with alias \"{}\".",
+            alias
+        ),
+        Err(err) => {
+            edisplay_line!(
+                io,
+                "The password does not decrypt the key with alias \"{}\": \
+                 {}",
+                alias,
+                err
+            );
+            cli::safe_exit(1)
+        }
+    }
+}
+
+/// Walk through creating a key in the wallet, prompting for every choice
+/// that isn't given in the args unless running non-interactively.
+async fn wallet_init(
+    ctx: Context,
+    io: &impl Io,
+    args::WalletInit {
+        scheme,
+        alias,
+        restore,
+        unsafe_dont_encrypt,
+        genesis_txs_path,
+        non_interactive,
+    }: args::WalletInit,
+) {
+    let wallet_file =
+        cli::context::wallet_store_dir(&ctx.global_args).map(|store_dir| {
+            wallet::wallet_file_with_name(
+                store_dir,
+                ctx.global_args.wallet_file_name.as_deref(),
+            )
+        });
+    let mut wallet = load_wallet(ctx);
+
+    let scheme = match scheme {
+        Some(scheme) => scheme,
+        None if non_interactive => SchemeType::Ed25519,
+        None => loop {
+            let answer = prompt!(
+                io,
+                "Key scheme, \"ed25519\" or \"secp256k1\" [ed25519]: "
+            )
+            .await;
+            let answer = answer.trim();
+            if answer.is_empty() {
+                break SchemeType::Ed25519;
+            }
+            match SchemeType::from_str(answer) {
+                Ok(scheme) if scheme != SchemeType::Common => break scheme,
+                _ => edisplay_line!(io, "Invalid scheme \"{}\".", answer),
+            }
+        },
+    };
+    if scheme == SchemeType::Common {
+        edisplay_line!(
+            io,
+            "Keys can only be created with the ed25519 or secp256k1 scheme."
+        );
+        cli::safe_exit(1)
+    }
+    let alias = match alias {
+        Some(alias) => alias,
+        None => loop {
+            let alias = wallet.utils().read_alias("the new key");
+            if !alias.is_empty() {
+                break alias;
+            }
+        },
+    };
+    let restore = restore
+        || (!non_interactive
+            && prompt!(
+                io,
+                "Generate a new key or restore one from a mnemonic? [G/r]: "
+            )
+            .await
+            .trim()
+            .eq_ignore_ascii_case("r"));
+    let encryption_password = read_and_confirm_encryption_password(
+        wallet.utils(),
+        unsafe_dont_encrypt,
+    );
+    let encrypted = encryption_password.is_some();
+
+    let derivation_path =
+        DerivationPath::default_for_transparent_scheme(scheme);
+    let stored = if restore {
+        wallet.derive_store_key_from_mnemonic_code(
+            scheme,
+            Some(alias),
+            false,
+            derivation_path.clone(),
+            None,
+            false,
+            encryption_password,
+        )
+    } else {
+        let (_mnemonic, seed) = wallet.gen_hd_seed(None, &mut OsRng, false);
+        wallet.derive_store_hd_secret_key(
+            scheme,
+            Some(alias),
+            false,
+            seed,
+            derivation_path.clone(),
+            encryption_password,
+        )
+    };
+    let (alias, secret_key) = stored.unwrap_or_else(|| {
+        edisplay_line!(io, "Failed to create a keypair.");
+        display_line!(io, "No changes are persisted. Exiting.");
+        cli::safe_exit(1)
+    });
+    let public_key = secret_key.ref_to();
+
+    let genesis_txs_path = match genesis_txs_path {
+        Some(path) => Some(path),
+        None if non_interactive => None,
+        None => {
+            let answer = prompt!(
+                io,
+                "Path to a genesis txs file to add the established accounts \
+                 of the key from (empty to skip): "
+            )
+            .await;
+            let answer = answer.trim();
+            (!answer.is_empty()).then(|| answer.into())
+        }
+    };
+    let mut established = vec![];
+    if let Some(path) = genesis_txs_path {
+        let txs = std::fs::read(&path)
+            .map_err(|err| err.to_string())
+            .and_then(|contents| {
+                parse_unsigned(&contents).map_err(|err| err.to_string())
+            })
+            .unwrap_or_else(|err| {
+                edisplay_line!(
+                    io,
+                    "Unable to read the genesis txs from {}: {}",
+                    path.to_string_lossy(),
+                    err
+                );
+                display_line!(io, "No changes are persisted. Exiting.");
+                cli::safe_exit(1)
+            });
+        let own_accounts = txs
+            .established_account
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|tx| tx.public_keys.iter().any(|pk| pk.raw == public_key));
+        for (ix, tx) in own_accounts.enumerate() {
+            let account_alias = if ix == 0 {
+                format!("{alias}-established")
+            } else {
+                format!("{alias}-established-{ix}")
+            };
+            let address = tx.derive_address();
+            if let Some(account_alias) =
+                wallet.insert_address(account_alias, address.clone(), false)
+            {
+                established.push((account_alias, address));
+            }
+        }
+    }
+
+    wallet.save().unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", wallet.utils().redact_base_dir(err))
+    });
+    display_line!(
+        io,
+        "Created the {} key \"{}\" at derivation path {}, {}.",
+        scheme,
+        alias,
+        derivation_path,
+        if encrypted {
+            "encrypted"
+        } else {
+            "NOT encrypted"
+        }
+    );
+    display_line!(io, "  Public key: {}", public_key);
+    display_line!(io, "  Implicit address: {}", Address::from(&public_key));
+    for (account_alias, address) in established {
+        display_line!(
+            io,
+            "  Established account \"{}\": {}",
+            account_alias,
+            address
+        );
+    }
+    if let Some(wallet_file) = wallet_file {
+        display_line!(
+            io,
+            "Saved to {}",
+            wallet
+                .utils()
+                .redact_base_dir(wallet_file.to_string_lossy())
+        );
+    }
+}
+
+/// Encrypt a throwaway key with a candidate password, the same way the
+/// stored keys are, and check that it decrypts back to the same key. The
+/// wallet is not touched.
+fn key_test_password(io: &impl Io, global_args: &args::Global) {
+    let utils = CliWalletUtils::without_store(global_args.wallet_options());
+    let password = utils.read_password(true, None);
+    let key = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+    let (stored, key) = StoredKeypair::new_with_kdf_params(
+        key,
+        Some(password.clone()),
+        utils.encryption_kdf_params(),
+    );
+    let round_trip = serde_json::to_string(&stored)
+        .map_err(|err| err.to_string())
+        .and_then(|encoded| {
+            serde_json::from_str::<StoredKeypair<common::SecretKey>>(&encoded)
+                .map_err(|err| err.to_string())
+        })
+        .and_then(|stored| {
+            stored
+                .get(&utils, true, Some(password), None)
+                .map_err(|err| err.to_string())
+        });
+    match round_trip {
+        Ok(decrypted) if decrypted == key => {
+            display_line!(
+                io,
+                "The password encrypts and decrypts keys successfully."
+            );
+        }
+        Ok(_) => {
+            edisplay_line!(
+                io,
+                "The key decrypted with the password does not match the \
+                 encrypted one."
+            );
+            cli::safe_exit(1)
+        }
+        Err(err) => {
+            edisplay_line!(
+                io,
+                "The password failed to round-trip the encryption of a key: \
+                 {}",
+                err
+            );
+            cli::safe_exit(1)
+        }
+    }
+}
+
+/// The prefix of signed messages, which keeps their signatures apart from the
+/// ones over transactions
+const SIGNED_MESSAGE_PREFIX: &str = "Namada signed message:\n";
+
+/// The bytes that are signed over for a message.
+fn signed_message_bytes(message: &str) -> Vec<u8> {
+    [SIGNED_MESSAGE_PREFIX.as_bytes(), message.as_bytes()].concat()
+}
+
+/// Sign a message with a stored key, to prove control of it.
+fn key_sign_message(
+    ctx: Context,
+    io: &impl Io,
+    args::KeySignMessage { alias, message }: args::KeySignMessage,
+) {
+    let mut wallet = load_wallet(ctx);
+    let alias = resolve_key_alias(&wallet, io, &alias);
+    let secret_key =
+        wallet.find_secret_key(&alias, None).unwrap_or_else(|err| {
+            edisplay_line!(io, "{}", err);
+            cli::safe_exit(1)
+        });
+    let signature =
+        common::SigScheme::sign(&secret_key, signed_message_bytes(&message));
+    display_line!(io, "Public key: {}", secret_key.ref_to());
+    display_line!(io, "Signature: {}", signature);
+}
+
+/// Verify the signature over a message made with [`key_sign_message`].
+fn key_verify_message(
+    io: &impl Io,
+    args::KeyVerifyMessage {
+        public_key,
+        message,
+        signature,
+    }: args::KeyVerifyMessage,
+) {
+    match common::SigScheme::verify_signature(
+        &public_key,
+        &signed_message_bytes(&message),
+        &signature,
+    ) {
+        Ok(()) => display_line!(
+            io,
+            "The signature over the message is valid for public key {}.",
+            public_key
+        ),
+        Err(err) => {
+            edisplay_line!(
+                io,
+                "The signature over the message is not valid for public key \
+                 {}: {}",
+                public_key,
+                err
+            );
+            cli::safe_exit(1)
+        }
+    }
+}
+
+/// List whether each stored key is encrypted at rest, flagging the plaintext
+/// ones.
+fn key_audit_encryption(
+    ctx: Context,
+    io: &impl Io,
+    args::KeyAuditEncryption { fail_on_plaintext }: args::KeyAuditEncryption,
+) {
+    let wallet = load_wallet(ctx);
+    let aliases: BTreeSet<String> = wallet
+        .get_secret_keys()
+        .into_keys()
+        .chain(wallet.get_spending_keys().into_keys())
+        .collect();
+    if aliases.is_empty() {
+        display_line!(io, "No keys found in the wallet.");
+        return;
+    }
+    let mut plaintext = vec![];
+    display_line!(io, "Stored keys:");
+    for alias in aliases {
+        if wallet.is_encrypted(&alias) == Some(true) {
+            display_line!(io, "  \"{}\": encrypted", alias);
+        } else {
+            display_line!(io, "  \"{}\": NOT encrypted", alias);
+            plaintext.push(alias);
+        }
+    }
+    if plaintext.is_empty() {
+        display_line!(io, "All the stored keys are encrypted.");
+        return;
+    }
+    edisplay_line!(
+        io,
+        "Warning: {} key(s) are stored in plaintext: {}",
+        plaintext.len(),
+        plaintext.join(", ")
+    );
+    if fail_on_plaintext {
+        cli::safe_exit(1)
+    }
+}
+
+/// Encrypt the address books of the wallet store at rest, or decrypt them.
+fn address_book_encrypt(
+    ctx: Context,
+    io: &impl Io,
+    args::AddressBookEncrypt { decrypt }: args::AddressBookEncrypt,
+) {
+    let mut wallet = load_wallet(ctx);
+    if decrypt {
+        if !wallet.is_address_book_encrypted() {
+            display_line!(io, "The address book is not encrypted.");
+            return;
+        }
+        wallet.set_address_book_password(None);
+    } else {
+        let password =
+            read_and_confirm_encryption_password(wallet.utils(), false);
+        wallet.set_address_book_password(password);
+    }
+    wallet.save().unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", err);
+        cli::safe_exit(1)
+    });
+    if decrypt {
+        display_line!(io, "Successfully decrypted the address book.");
+    } else {
+        display_line!(io, "Successfully encrypted the address book.");
+    }
+}
+
+/// Resolve a key given on the command line to the alias to look it up by.
+/// Aliases of keys in the wallet are tried first. Otherwise the input is
+/// parsed as a raw public key and resolved to the alias of the matching key,
+/// or to its public key hash if it has none. The chosen interpretation is
+/// printed whenever the input is a valid public key.
+fn resolve_key_alias(
+    wallet: &Wallet<CliWalletUtils>,
+    io: &impl Io,
+    alias_or_pk: &str,
+) -> String {
+    let alias = alias_or_pk.to_lowercase();
+    let Some(pk) = parse_public_key(alias_or_pk) else {
+        return alias;
+    };
+    if wallet.get_secret_keys().contains_key(&alias)
+        || wallet.get_spending_keys().contains_key(&alias)
+    {
+        display_line!(io, "Interpreting \"{}\" as an alias.", alias);
+        return alias;
+    }
+    let pkh = PublicKeyHash::from(&pk);
+    display_line!(
+        io,
+        "Interpreting \"{}\" as a public key with hash {}.",
+        alias_or_pk,
+        pkh
+    );
+    wallet
+        .store()
+        .find_alias_by_pkh(&pkh)
+        .map(String::from)
+        .unwrap_or_else(|| pkh.to_string())
+}
+
+/// Find a keypair in the wallet store.
+fn transparent_key_find(
+    ctx: Context,
+    io: &impl Io,
+    alias: Option<String>,
+    public_key: Option<common::PublicKey>,
+    public_key_hash: Option<String>,
+    unsafe_show_secret: bool,
+    encoding: args::WalletEncoding,
+) {
+    let mut wallet = load_wallet(ctx);
+    let found_keypair = match public_key {
+        Some(pk) => wallet.find_key_by_pk(&pk, None),
+        None => {
+            let alias = alias.map(|a| a.to_lowercase()).or(public_key_hash);
+            match alias {
+                None => {
+                    edisplay_line!(
+                        io,
+                        "An alias, public key or public key hash needs to be \
+                         supplied",
+                    );
+                    cli::safe_exit(1)
+                }
+                Some(alias) => wallet.find_secret_key(alias, None),
+            }
+        }
+    };
+    match found_keypair {
+        Ok(keypair) => {
+            let pkh: PublicKeyHash = (&keypair.ref_to()).into();
+            display_line!(io, "Public key hash: {}", pkh);
+            display_line!(
+                io,
+                "Public key: {}",
+                encode_public_key(&keypair.ref_to(), encoding)
+            );
+            display_line!(io, "Scheme: {}", keypair.ref_to().scheme());
+            if unsafe_show_secret {
+                display_line!(io, "Secret key: {}", keypair);
+            }
+        }
+        Err(err) => {
+            edisplay_line!(io, "{}", err);
+        }
+    }
+}
+
+/// Find address (alias) by its alias (address).
+fn transparent_address_or_alias_find(
+    ctx: Context,
+    io: &impl Io,
+    alias: Option<String>,
+    address: Option<Address>,
+    encoding: args::WalletEncoding,
+) {
+    let wallet = load_wallet(ctx);
+    if address.is_some() && alias.is_some() {
+        panic!(
+            "This should not be happening: clap should emit its own error \
+             message."
+        );
+    } else if alias.is_some() {
+        let alias = alias.unwrap().to_lowercase();
+        if let Some(address) = wallet.find_address(&alias) {
+            display_line!(
+                io,
+                "Found address {}",
+                encode_address(&address, encoding)
+            );
+        } else {
+            display_line!(
+                io,
+                "No address with alias {} found. Use the command `list \
+                 --addr` to see all the known transparent addresses.",
+                alias
+            );
+        }
+    } else if address.is_some() {
+        if let Some(alias) = wallet.find_alias(address.as_ref().unwrap()) {
+            display_line!(io, "Found alias {}", alias);
+        } else {
+            display_line!(
+                io,
+                "No address with alias {} found. Use the command `list \
+                 --addr` to see all the known transparent addresses.",
+                address.unwrap()
+            );
+        }
+    }
+}
+
+/// Find payment address (alias) by its alias (payment address).
+fn payment_address_or_alias_find(
+    ctx: Context,
+    io: &impl Io,
+    alias: Option<String>,
+    payment_address: Option<PaymentAddress>,
+) {
+    let wallet = load_wallet(ctx);
+    if payment_address.is_some() && alias.is_some() {
+        panic!(
+            "This should not be happening: clap should emit its own error \
+             message."
         );
     } else if alias.is_some() {
         let alias = alias.unwrap().to_lowercase();
@@ -1010,6 +2271,7 @@ fn transparent_key_address_find_by_alias(
     addresses_only: bool,
     decrypt: bool,
     unsafe_show_secret: bool,
+    encoding: args::WalletEncoding,
 ) -> bool {
     let alias = alias.to_lowercase();
     let mut w_lock = io::stdout().lock();
@@ -1031,6 +2293,10 @@ fn transparent_key_address_find_by_alias(
                 "  Alias \"{}\" ({}):", alias, encrypted,
             )
             .unwrap();
+            if let Some(created_at) = wallet.get_key_created_at(&alias) {
+                display_line!(io, &mut w_lock; "    Created: {}", created_at)
+                    .unwrap();
+            }
             let pkh = PublicKeyHash::from(&public_key);
             // Always print the public key and hash
             display_line!(io, &mut w_lock; "    Public key hash: {}", pkh)
@@ -1039,9 +2305,11 @@ fn transparent_key_address_find_by_alias(
                 io,
                 &mut w_lock;
                 "    Public key: {}",
-                public_key
+                encode_public_key(&public_key, encoding)
             )
             .unwrap();
+            display_line!(io, &mut w_lock; "    Scheme: {}", public_key.scheme())
+                .unwrap();
             if decrypt {
                 // Check if alias is also a secret key. Decrypt and print it if
                 // requested.
@@ -1075,7 +2343,7 @@ fn transparent_key_address_find_by_alias(
                 .unwrap();
             display_line!(io,
                 &mut w_lock;
-                "  \"{}\": {}", alias, address.to_pretty_string(),
+                "  \"{}\": {}", alias, encode_address(&address, encoding),
             )
             .unwrap();
         }
@@ -1114,6 +2382,10 @@ fn shielded_key_address_find_by_alias(
                 "  Alias \"{}\" ({}):", alias, encrypted,
             )
             .unwrap();
+            if let Some(created_at) = wallet.get_key_created_at(&alias) {
+                display_line!(io, &mut w_lock; "    Created: {}", created_at)
+                    .unwrap();
+            }
             // Always print the viewing key
             display_line!(io, &mut w_lock; "    Viewing key: {}", viewing_key)
                 .unwrap();
@@ -1165,8 +2437,12 @@ fn transparent_keys_list(
     decrypt: bool,
     unsafe_show_secret: bool,
     show_hint: bool,
+    sort: args::WalletListSort,
+    encoding: args::WalletEncoding,
+    filter: ListFilter<'_>,
 ) {
-    let known_public_keys = wallet.get_public_keys();
+    let known_public_keys =
+        filter_listed(wallet, io, wallet.get_public_keys(), filter);
     if known_public_keys.is_empty() {
         if show_hint {
             display_line!(
@@ -1179,6 +2455,10 @@ fn transparent_keys_list(
         let mut w_lock = io::stdout().lock();
         display_line!(io, &mut w_lock; "Known transparent keys:").unwrap();
         let known_secret_keys = wallet.get_secret_keys();
+        let known_public_keys =
+            sort_listed(wallet, known_public_keys, sort, |public_key| {
+                Address::from(public_key).encode()
+            });
         for (alias, public_key) in known_public_keys {
             let stored_keypair = known_secret_keys.get(&alias);
             let encrypted = match stored_keypair {
@@ -1192,18 +2472,26 @@ fn transparent_keys_list(
             };
             display_line!(io,
                 &mut w_lock;
-                "  Alias \"{}\" ({}):", alias, encrypted,
+                "  Alias \"{}\" ({}):", wallet.display_alias(&alias), encrypted,
             )
             .unwrap();
+            if let Some(created_at) = wallet.get_key_created_at(&alias) {
+                display_line!(io, &mut w_lock; "    Created: {}", created_at)
+                    .unwrap();
+            }
+            display_tags(wallet, io, &mut w_lock, &alias);
             // Always print the corresponding public key and hash
             display_line!(io, &mut w_lock; "    Public key hash: {}", PublicKeyHash::from(&public_key))
                 .unwrap();
-            display_line!(io, &mut w_lock; "    Public key: {}", public_key)
+            display_line!(io, &mut w_lock; "    Public key: {}", encode_public_key(&public_key, encoding))
+                .unwrap();
+            display_line!(io, &mut w_lock; "    Scheme: {}", public_key.scheme())
                 .unwrap();
             // A subset of public keys will have corresponding secret keys.
             // Print those too if they are available and requested.
             if let Some((stored_keypair, _pkh)) = stored_keypair {
-                match stored_keypair.get::<CliWalletUtils>(
+                match stored_keypair.get(
+                    wallet.utils(),
                     decrypt,
                     None,
                     Some(&alias),
@@ -1246,8 +2534,8 @@ fn key_export(
     io: &impl Io,
     args::KeyExport { alias }: args::KeyExport,
 ) {
-    let alias = alias.to_lowercase();
     let mut wallet = load_wallet(ctx);
+    let alias = resolve_key_alias(&wallet, io, &alias);
     let key_to_export = wallet
         .find_secret_key(&alias, None)
         .map(|sk| Box::new(sk) as Box<dyn BorshSerializeExt>)
@@ -1274,8 +2562,8 @@ fn key_convert(
     io: &impl Io,
     args::KeyConvert { alias }: args::KeyConvert,
 ) {
-    let alias = alias.to_lowercase();
     let mut wallet = load_wallet(ctx);
+    let alias = resolve_key_alias(&wallet, io, &alias);
     let sk = wallet.find_secret_key(&alias, None);
     let key: serde_json::Value = validator_key_to_json(&sk.unwrap()).unwrap();
     let file_name = format!("priv_validator_key_{}.json", alias);
@@ -1332,13 +2620,265 @@ fn key_import(
     }
 }
 
+/// A file of mnemonic codes to derive keys from
+#[derive(Deserialize)]
+struct MnemonicFile {
+    key: Vec<MnemonicFileEntry>,
+}
+
+/// A key to derive from a mnemonic code. Not `Debug`, so that the mnemonic
+/// cannot end up in any output.
+#[derive(Deserialize, Zeroize, ZeroizeOnDrop)]
+struct MnemonicFileEntry {
+    alias: String,
+    mnemonic: String,
+    #[serde(default)]
+    passphrase: String,
+    derivation_path: Option<String>,
+    scheme: Option<String>,
+    #[serde(default)]
+    shielded: bool,
+}
+
+/// Derive and store the key of a mnemonic file entry. Returns the alias under
+/// which the key was stored.
+fn import_mnemonic_file_entry(
+    wallet: &mut Wallet<CliWalletUtils>,
+    entry: &MnemonicFileEntry,
+    alias_force: bool,
+    password: Option<Zeroizing<String>>,
+) -> Result<String, String> {
+    // The error is deliberately not displayed, as it may refer to the words
+    // of the mnemonic
+    let mnemonic = Mnemonic::from_phrase(&entry.mnemonic, Language::English)
+        .map_err(|_| "invalid mnemonic code".to_string())?;
+    let passphrase = Zeroizing::new(entry.passphrase.clone());
+    let derivation_path = entry
+        .derivation_path
+        .clone()
+        .unwrap_or_else(|| "default".to_string());
+    let alias = entry.alias.to_lowercase();
+    if entry.shielded {
+        let derivation_path = decode_shielded_derivation_path(derivation_path)
+            .map_err(|err| err.to_string())?;
+        wallet
+            .derive_store_spending_key_from_mnemonic_code(
+                alias,
+                alias_force,
+                None,
+                derivation_path,
+                Some((mnemonic, passphrase)),
+                false,
+                password,
+            )
+            .map(|(alias, _)| alias)
+    } else {
+        let scheme = entry
+            .scheme
+            .as_deref()
+            .map(SchemeType::from_str)
+            .transpose()
+            .map_err(|err| format!("invalid scheme: {err}"))?
+            .unwrap_or(SchemeType::Ed25519);
+        let derivation_path =
+            decode_transparent_derivation_path(scheme, derivation_path)
+                .map_err(|err| err.to_string())?;
+        wallet
+            .derive_store_key_from_mnemonic_code(
+                scheme,
+                Some(alias),
+                alias_force,
+                derivation_path,
+                Some((mnemonic, passphrase)),
+                false,
+                password,
+            )
+            .map(|(alias, _)| alias)
+    }
+    .ok_or_else(|| "the key was not stored".to_string())
+}
+
+/// Derive and import keys from the mnemonic codes in a file
+fn key_import_mnemonic_file(
+    ctx: Context,
+    io: &impl Io,
+    args::KeyImportMnemonicFile {
+        file_path,
+        alias_force,
+        unsafe_dont_encrypt,
+    }: args::KeyImportMnemonicFile,
+) {
+    let file_data = Zeroizing::new(
+        std::fs::read_to_string(&file_path).unwrap_or_else(|err| {
+            edisplay_line!(io, "{}", err);
+            display_line!(io, "No changes are persisted. Exiting.");
+            cli::safe_exit(1)
+        }),
+    );
+    // The error is not displayed, as it may quote the file's contents
+    let mnemonic_file: MnemonicFile = toml::from_str(&file_data)
+        .unwrap_or_else(|_| {
+            edisplay_line!(
+                io,
+                "Could not parse the mnemonics file {}. Every entry must be \
+                 a `[[key]]` table with an `alias` and a `mnemonic`.",
+                file_path
+            );
+            display_line!(io, "No changes are persisted. Exiting.");
+            cli::safe_exit(1)
+        });
+
+    let mut wallet = load_wallet(ctx);
+    let encryption_password = read_and_confirm_encryption_password(
+        wallet.utils(),
+        unsafe_dont_encrypt,
+    );
+    let mut failures = vec![];
+    for entry in &mnemonic_file.key {
+        match import_mnemonic_file_entry(
+            &mut wallet,
+            entry,
+            alias_force,
+            encryption_password.clone(),
+        ) {
+            Ok(alias) => display_line!(
+                io,
+                "Successfully added a key with alias: \"{}\"",
+                alias
+            ),
+            Err(err) => {
+                edisplay_line!(
+                    io,
+                    "Failed to import the key with alias \"{}\": {}",
+                    entry.alias,
+                    err
+                );
+                failures.push((entry.alias.clone(), err));
+            }
+        }
+    }
+    if failures.len() < mnemonic_file.key.len() {
+        wallet.save().unwrap_or_else(|err| {
+            edisplay_line!(io, "{}", wallet.utils().redact_base_dir(err))
+        });
+    }
+    if !failures.is_empty() {
+        edisplay_line!(
+            io,
+            "Failed to import {} out of {} keys:",
+            failures.len(),
+            mnemonic_file.key.len()
+        );
+        for (alias, err) in failures {
+            edisplay_line!(io, "  \"{}\": {}", alias, err);
+        }
+        cli::safe_exit(1)
+    }
+}
+
+/// The aliases of a file of keys to import. The other fields of its entries,
+/// such as mnemonic codes, are not read.
+#[derive(Deserialize)]
+struct AliasesFile {
+    key: Vec<AliasesFileEntry>,
+}
+
+#[derive(Deserialize)]
+struct AliasesFileEntry {
+    alias: String,
+}
+
+/// Report how the wallet would treat the aliases of a file of keys to import,
+/// without importing any of them.
+fn key_validate_aliases(
+    ctx: Context,
+    io: &impl Io,
+    args::KeyValidateAliases { file_path }: args::KeyValidateAliases,
+) {
+    // The file may also hold mnemonic codes
+    let file_data = Zeroizing::new(
+        std::fs::read_to_string(&file_path).unwrap_or_else(|err| {
+            edisplay_line!(io, "{}", err);
+            cli::safe_exit(1)
+        }),
+    );
+    let aliases_file: AliasesFile =
+        toml::from_str(&file_data).unwrap_or_else(|_| {
+            edisplay_line!(
+                io,
+                "Could not parse the file {}. Every entry must be a \
+                 `[[key]]` table with an `alias`.",
+                file_path
+            );
+            cli::safe_exit(1)
+        });
+
+    let wallet = load_wallet(ctx);
+    let mut seen = BTreeSet::new();
+    let mut num_invalid: usize = 0;
+    for AliasesFileEntry { alias: given } in &aliases_file.key {
+        let alias = Alias::from(given);
+        let normalized = alias.normalize();
+        let mut problems = vec![];
+        if given.is_empty() {
+            problems.push("empty, so it would default to the key's hash");
+        }
+        if Alias::is_reserved(&alias).is_some() {
+            problems.push("reserved for an internal address");
+        }
+        if !seen.insert(normalized.clone()) {
+            problems.push("collides with an earlier entry of the file");
+        }
+        if wallet.store().contains_alias(&alias) {
+            problems.push("collides with an alias of the wallet");
+        }
+        let changed = if normalized != *given {
+            "changed by normalization"
+        } else {
+            "unchanged"
+        };
+        if problems.is_empty() {
+            display_line!(
+                io,
+                "\"{}\" -> \"{}\": {}",
+                given,
+                normalized,
+                changed
+            );
+        } else {
+            num_invalid = num_invalid.saturating_add(1);
+            display_line!(
+                io,
+                "\"{}\" -> \"{}\": {}, {}",
+                given,
+                normalized,
+                changed,
+                problems.join(", ")
+            );
+        }
+    }
+    if num_invalid > 0 {
+        edisplay_line!(
+            io,
+            "{} out of {} aliases would not be imported as given.",
+            num_invalid,
+            aliases_file.key.len()
+        );
+        cli::safe_exit(1)
+    }
+}
+
 /// List all known transparent addresses.
 fn transparent_addresses_list(
     wallet: &Wallet<CliWalletUtils>,
     io: &impl Io,
     show_hint: bool,
+    sort: args::WalletListSort,
+    encoding: args::WalletEncoding,
+    filter: ListFilter<'_>,
 ) {
-    let known_addresses = wallet.get_addresses();
+    let known_addresses =
+        filter_listed(wallet, io, wallet.get_addresses(), filter);
     if known_addresses.is_empty() {
         if show_hint {
             display_line!(
@@ -1350,10 +2890,18 @@ fn transparent_addresses_list(
     } else {
         let mut w_lock = io::stdout().lock();
         display_line!(io, &mut w_lock; "Known transparent addresses:").unwrap();
-        for (alias, address) in sorted(known_addresses) {
+        let known_addresses =
+            sort_listed(wallet, known_addresses, sort, |address| {
+                address.encode()
+            });
+        for (alias, address) in known_addresses {
+            let scheme = wallet.store().find_scheme_by_address(&address);
             display_line!(io,
                 &mut w_lock;
-                "  \"{}\": {}", alias, address.to_pretty_string(),
+                "  \"{}\": {} (scheme: {})",
+                wallet.display_alias(&alias),
+                encode_address(&address, encoding),
+                scheme.map_or_else(|| "n/a".to_string(), |s| s.to_string()),
             )
             .unwrap();
         }
@@ -1370,8 +2918,10 @@ fn transparent_secret_key_add(
     unsafe_dont_encrypt: bool,
 ) {
     let mut wallet = load_wallet(ctx);
-    let encryption_password =
-        read_and_confirm_encryption_password(unsafe_dont_encrypt);
+    let encryption_password = read_and_confirm_encryption_password(
+        wallet.utils(),
+        unsafe_dont_encrypt,
+    );
     let alias = wallet
         .insert_keypair(alias, alias_force, sk, encryption_password, None, None)
         .unwrap_or_else(|| {
@@ -1379,13 +2929,13 @@ fn transparent_secret_key_add(
             display_line!(io, "No changes are persisted. Exiting.");
             cli::safe_exit(1);
         });
-    wallet
-        .save()
-        .unwrap_or_else(|err| edisplay_line!(io, "{}", err));
+    wallet.save().unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", wallet.utils().redact_base_dir(err))
+    });
     display_line!(
         io,
         "Successfully added a key and an address with alias: \"{}\"",
-        alias
+        wallet.display_alias(&alias)
     );
 }
 
@@ -1397,7 +2947,6 @@ fn transparent_public_key_add(
     alias_force: bool,
     pubkey: common::PublicKey,
 ) {
-    let alias = alias.to_lowercase();
     let mut wallet = load_wallet(ctx);
     if wallet
         .insert_public_key(alias.clone(), pubkey, None, None, alias_force)
@@ -1406,13 +2955,13 @@ fn transparent_public_key_add(
         edisplay_line!(io, "Public key not added");
         cli::safe_exit(1);
     }
-    wallet
-        .save()
-        .unwrap_or_else(|err| edisplay_line!(io, "{}", err));
+    wallet.save().unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", wallet.utils().redact_base_dir(err))
+    });
     display_line!(
         io,
         "Successfully added a public key with alias: \"{}\"",
-        alias
+        wallet.display_alias(&alias)
     );
 }
 
@@ -1424,7 +2973,6 @@ fn transparent_address_add(
     alias_force: bool,
     address: Address,
 ) {
-    let alias = alias.to_lowercase();
     let mut wallet = load_wallet(ctx);
     if wallet
         .insert_address(&alias, address, alias_force)
@@ -1433,13 +2981,13 @@ fn transparent_address_add(
         edisplay_line!(io, "Address not added");
         cli::safe_exit(1);
     }
-    wallet
-        .save()
-        .unwrap_or_else(|err| edisplay_line!(io, "{}", err));
+    wallet.save().unwrap_or_else(|err| {
+        edisplay_line!(io, "{}", wallet.utils().redact_base_dir(err))
+    });
     display_line!(
         io,
         "Successfully added an address with alias: \"{}\"",
-        alias
+        wallet.display_alias(&alias)
     );
 }
 
@@ -1447,9 +2995,55 @@ fn transparent_address_add(
 /// `ctx.global_args.is_pre_genesis`.
 fn load_wallet(ctx: Context) -> Wallet<CliWalletUtils> {
     if ctx.global_args.is_pre_genesis {
-        let wallet_path = ctx.global_args.base_dir.join(PRE_GENESIS_DIR);
-        wallet::load_or_new(&wallet_path)
+        let wallet_path =
+            wallet::pre_genesis_store_dir(&ctx.global_args.base_dir);
+        wallet::load_or_new_with_options(
+            &wallet_path,
+            &ctx.global_args.wallet_options(),
+        )
     } else {
         ctx.take_chain_or_exit().wallet
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::test_utils::wallet_with_keys;
+
+    #[test]
+    fn test_sort_listed() {
+        // Only "carol" has a key, and so a creation time
+        let wallet = wallet_with_keys(&["carol"]);
+        let entries = || {
+            [
+                ("carol", "b"),
+                ("bertha", "b"),
+                ("albert", "c"),
+                ("dave", "a"),
+            ]
+            .map(|(alias, address)| (alias.to_string(), address.to_string()))
+        };
+        let sorted = |sort| {
+            sort_listed(&wallet, entries(), sort, Clone::clone)
+                .into_iter()
+                .map(|(alias, _)| alias)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            sorted(args::WalletListSort::Alias),
+            ["albert", "bertha", "carol", "dave"]
+        );
+        // "bertha" and "carol" share an address, so they're ordered by alias
+        assert_eq!(
+            sorted(args::WalletListSort::Address),
+            ["dave", "bertha", "carol", "albert"]
+        );
+        // The entries of unknown creation time come first, by alias
+        assert_eq!(
+            sorted(args::WalletListSort::Created),
+            ["albert", "bertha", "dave", "carol"]
+        );
+    }
+}
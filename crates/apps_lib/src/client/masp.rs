@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use color_eyre::owo_colors::OwoColorize;
+use color_eyre::owo_colors::{OwoColorize, Style};
 use namada_sdk::args::ShieldedSync;
 use namada_sdk::control_flow::install_shutdown_signal;
 use namada_sdk::error::Error;
@@ -22,6 +22,7 @@ pub async fn syncing<
     client: C,
     args: ShieldedSync,
     io: &IO,
+    heading_style: Style,
 ) -> Result<ShieldedContext<U>, Error> {
     let (fetched_bar, scanned_bar, applied_bar) = {
         #[cfg(any(test, feature = "testing"))]
@@ -110,7 +111,8 @@ pub async fn syncing<
         display_line!(
             io,
             "{}\n",
-            "==== Shielded sync started using indexer client ====".bold()
+            "==== Shielded sync started using indexer client ===="
+                .style(heading_style)
         );
 
         let client = reqwest::Client::builder()
@@ -135,7 +137,8 @@ pub async fn syncing<
         display_line!(
             io,
             "{}\n",
-            "==== Shielded sync started using ledger client ====".bold()
+            "==== Shielded sync started using ledger client ===="
+                .style(heading_style)
         );
 
         dispatch_client!(LedgerMaspClient::new(
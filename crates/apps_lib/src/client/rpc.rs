@@ -48,6 +48,7 @@ use namada_sdk::storage::BlockResults;
 use namada_sdk::tendermint_rpc::endpoint::status;
 use namada_sdk::token::{DenominatedAmount, MaspDigitPos};
 use namada_sdk::tx::display_batch_resp;
+use namada_sdk::wallet::alias::validator_consensus_key;
 use namada_sdk::wallet::AddressVpType;
 use namada_sdk::{error, state as storage, token, Namada};
 
@@ -1829,6 +1830,90 @@ pub async fn query_find_validator<N: Namada>(
     }
 }
 
+/// Compare the consensus and protocol keys of a validator on chain with the
+/// keys of the wallet under the aliases that `become-validator` gives them,
+/// reporting each match and mismatch. Exits with an error on any mismatch.
+pub async fn query_validator_keys<N: Namada>(
+    context: &N,
+    args::QueryValidatorKeys {
+        query: _,
+        validator,
+        alias,
+    }: args::QueryValidatorKeys,
+) {
+    if !is_validator(context.client(), &validator).await {
+        edisplay_line!(
+            context.io(),
+            "The address {validator} is not a validator."
+        );
+        cli::safe_exit(1)
+    }
+    let consensus_key = unwrap_client_response::<N::Client, _>(
+        RPC.vp()
+            .pos()
+            .consensus_key(context.client(), &validator)
+            .await,
+    );
+    let protocol_key: Option<common::PublicKey> = query_storage_value(
+        context.client(),
+        &namada_sdk::account::protocol_pk_key(&validator),
+    )
+    .await
+    .ok();
+    let keys = [
+        (
+            "consensus",
+            validator_consensus_key(&alias.clone().into()).to_string(),
+            consensus_key,
+        ),
+        ("protocol", format!("{alias}-protocol-key"), protocol_key),
+    ];
+
+    let wallet = context.wallet().await;
+    let mut num_mismatches: usize = 0;
+    for (kind, key_alias, on_chain) in keys {
+        let local = wallet.find_public_key(&key_alias).ok();
+        match (local, on_chain) {
+            (Some(local), Some(on_chain)) if local == on_chain => {
+                display_line!(
+                    context.io(),
+                    "Match: the {kind} key \"{key_alias}\" is the one of the \
+                     validator on chain, {on_chain}."
+                );
+                continue;
+            }
+            (Some(local), Some(on_chain)) => edisplay_line!(
+                context.io(),
+                "Mismatch: the {kind} key \"{key_alias}\" is {local}, but the \
+                 one of the validator on chain is {on_chain}."
+            ),
+            (None, Some(on_chain)) => edisplay_line!(
+                context.io(),
+                "Mismatch: the wallet has no {kind} key \"{key_alias}\", but \
+                 the validator has {on_chain} on chain."
+            ),
+            (_, None) => edisplay_line!(
+                context.io(),
+                "Mismatch: the {kind} key of the validator {validator} could \
+                 not be found on chain."
+            ),
+        }
+        num_mismatches = num_mismatches.saturating_add(1);
+    }
+    if num_mismatches > 0 {
+        edisplay_line!(
+            context.io(),
+            "{num_mismatches} of the keys of the validator don't match those \
+             of the wallet. Fix the wallet before starting the node."
+        );
+        cli::safe_exit(1)
+    }
+    display_line!(
+        context.io(),
+        "All the keys of the validator match those of the wallet."
+    );
+}
+
 /// Get account's public key stored in its storage sub-space
 pub async fn get_public_key<C: Client + Sync>(
     client: &C,
@@ -2113,7 +2198,10 @@ pub async fn query_result(context: &impl Namada, args: args::QueryResult) {
 pub async fn epoch_sleep(context: &impl Namada, _args: args::Query) {
     let start_epoch = query_and_print_epoch(context).await;
     loop {
-        tokio::time::sleep(core::time::Duration::from_secs(1)).await;
+        namada_sdk::control_flow::time::sleep(core::time::Duration::from_secs(
+            1,
+        ))
+        .await;
         let current_epoch = query_epoch(context.client()).await.unwrap();
         if current_epoch > start_epoch {
             display_line!(context.io(), "Reached epoch {}", current_epoch);
@@ -170,7 +170,8 @@ pub async fn sign<N: Namada>(
 ) -> Result<(), error::Error> {
     // Setup a reusable context for signing transactions using the Ledger
     if args.use_device {
-        let transport = WalletTransport::from_arg(args.device_transport);
+        let transport =
+            WalletTransport::from_arg(args.device_transport, args.debug_apdu);
         let app = NamadaApp::new(transport);
         let with_hw_data = (context.wallet_lock(), &app);
         // Finally, begin the signing with the Ledger as backup
@@ -383,8 +384,10 @@ pub async fn submit_change_consensus_key(
         })
         .unwrap_or_else(|| {
             display_line!(namada.io(), "Generating new consensus key...");
-            let password =
-                read_and_confirm_encryption_password(args.unsafe_dont_encrypt);
+            let password = read_and_confirm_encryption_password(
+                wallet.utils(),
+                args.unsafe_dont_encrypt,
+            );
             wallet
                 .gen_store_secret_key(
                     // Note that TM only allows ed25519 for consensus key
@@ -483,8 +486,10 @@ pub async fn submit_become_validator(
         })
         .unwrap_or_else(|| {
             display_line!(namada.io(), "Generating consensus key...");
-            let password =
-                read_and_confirm_encryption_password(args.unsafe_dont_encrypt);
+            let password = read_and_confirm_encryption_password(
+                wallet.utils(),
+                args.unsafe_dont_encrypt,
+            );
             wallet
                 .gen_store_secret_key(
                     // Note that TM only allows ed25519 for consensus key
@@ -514,8 +519,10 @@ pub async fn submit_become_validator(
         })
         .unwrap_or_else(|| {
             display_line!(namada.io(), "Generating Eth cold key...");
-            let password =
-                read_and_confirm_encryption_password(args.unsafe_dont_encrypt);
+            let password = read_and_confirm_encryption_password(
+                wallet.utils(),
+                args.unsafe_dont_encrypt,
+            );
             wallet
                 .gen_store_secret_key(
                     // Note that ETH only allows secp256k1
@@ -545,8 +552,10 @@ pub async fn submit_become_validator(
         })
         .unwrap_or_else(|| {
             display_line!(namada.io(), "Generating Eth hot key...");
-            let password =
-                read_and_confirm_encryption_password(args.unsafe_dont_encrypt);
+            let password = read_and_confirm_encryption_password(
+                wallet.utils(),
+                args.unsafe_dont_encrypt,
+            );
             wallet
                 .gen_store_secret_key(
                     // Note that ETH only allows secp256k1
@@ -573,6 +582,7 @@ pub async fn submit_become_validator(
         Some(eth_hot_pk.clone()),
         args.protocol_key.clone(),
         args.scheme,
+        &mut OsRng,
     )
     .unwrap();
     let protocol_sk = validator_keys.get_protocol_keypair();
@@ -589,8 +599,10 @@ pub async fn submit_become_validator(
     // Store the protocol key in the wallet so that we can sign the tx with it
     // to verify ownership
     display_line!(namada.io(), "Storing protocol key in the wallet...");
-    let password =
-        read_and_confirm_encryption_password(args.unsafe_dont_encrypt);
+    let password = read_and_confirm_encryption_password(
+        namada.wallet().await.utils(),
+        args.unsafe_dont_encrypt,
+    );
     namada
         .wallet_mut()
         .await
@@ -1,25 +1,36 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::env;
 use std::fs::{self, File, OpenOptions};
-use std::io::Write;
+use std::io::{BufRead, BufReader, IsTerminal, Read, Write};
 use std::path::{Path, PathBuf};
 
+use borsh::BorshDeserialize;
 use borsh_ext::BorshSerializeExt;
-use color_eyre::owo_colors::OwoColorize;
+use color_eyre::owo_colors::{OwoColorize, Style};
 use flate2::read::GzDecoder;
 use flate2::write::GzEncoder;
 use flate2::Compression;
 use itertools::Either;
+use ledger_namada_rs::{BIP44Path, NamadaApp};
 use namada_sdk::account::AccountPublicKeysMap;
-use namada_sdk::address::Address;
+use namada_sdk::address::{Address, EstablishedAddress, ImplicitAddress};
 use namada_sdk::args::DeviceTransport;
 use namada_sdk::chain::ChainId;
+use namada_sdk::collections::HashSet;
 use namada_sdk::dec::Dec;
 use namada_sdk::key::*;
+use namada_sdk::signing::Signable;
 use namada_sdk::string_encoding::StringEncoded;
+use namada_sdk::time::DateTimeUtc;
 use namada_sdk::token;
 use namada_sdk::tx::Tx;
 use namada_sdk::uint::Uint;
-use namada_sdk::wallet::{alias, LoadStoreError, Wallet};
+use namada_sdk::wallet::fs::restrict_file_permissions;
+use namada_sdk::wallet::{
+    alias, parse_public_key, DerivationPath, LoadStoreError, Store,
+    StoredKeypair, Wallet, WalletIo,
+};
+#[cfg(not(target_family = "wasm"))]
 use namada_vm::validate_untrusted_wasm;
 use prost::bytes::Bytes;
 use serde::Serialize;
@@ -27,18 +38,29 @@ use serde_json::json;
 use sha2::{Digest, Sha256};
 use tokio::sync::RwLock;
 use wasm_bindgen::prelude::*;
+use zeroize::Zeroizing;
 
-use crate::cli::args;
-use crate::cli::context::wasm_dir_from_env_or;
+use crate::cli::context::{
+    chain_wasm_dir, read_or_try_new_global_config, wallet_store_dir,
+    wasm_dir_from_env_or, ENV_VAR_CHAIN_ID, ENV_VAR_WASM_DIR,
+};
+use crate::cli::{args, assume_tty_env, namada_client_app, redact_base_dir};
+use crate::client::tx::with_hardware_wallet;
 use crate::config::genesis::chain::DeriveEstablishedAddress;
 use crate::config::genesis::transactions::{
-    sign_delegation_bond_tx, sign_validator_account_tx, Transactions, UnsignedTransactions
+    sign_delegation_bond_tx, sign_validator_account_tx, SignatureEncoding,
+    SignerBackend, Transactions, UnsignedTransactions, UnsignedTx,
+    UnsignedTxReader,
 };
+use crate::config::genesis::utils::TomlKeyOrder;
 use crate::config::genesis::{AddrOrPk, GenesisAddress};
 use crate::config::global::GlobalConfig;
 use crate::config::{self, genesis, get_default_namada_folder, TendermintMode};
 use crate::tendermint::node::Id as TendermintNodeId;
-use crate::wallet::{pre_genesis, CliWalletUtils};
+use crate::wallet::{
+    list_hid_devices, pre_genesis, CliWalletUtils, HidDevice, Pkcs11Config,
+    Pkcs11Signer, TransportTcp, WalletOptions, WalletTransport,
+};
 use crate::{tendermint_node, wasm_loader};
 
 pub const NET_ACCOUNTS_DIR: &str = "setup";
@@ -52,6 +74,9 @@ const DEFAULT_NETWORK_CONFIGS_SERVER: &str =
 
 /// We do pre-genesis validator set up in this directory
 pub const PRE_GENESIS_DIR: &str = "pre-genesis";
+/// Env var to set the name of the pre-genesis directory instead of
+/// [`PRE_GENESIS_DIR`]
+pub const ENV_VAR_PRE_GENESIS_DIR: &str = "NAMADA_PRE_GENESIS_DIR";
 
 /// Configure Namada to join an existing network. The chain must be released in
 /// the <https://github.com/heliaxdev/anoma-network-config> repository.
@@ -63,18 +88,26 @@ pub async fn join_network(
         pre_genesis_path,
         allow_duplicate_ip,
         add_persistent_peers,
+        download_timeout,
+        download_retries,
+        download_retry_delay,
+        alias_prefix,
+        include_aliases,
+        exclude_aliases,
+        dry_run,
     }: args::JoinNetwork,
 ) {
-    use tokio::fs;
+    use crate::async_fs as fs;
 
-    let base_dir = global_args.base_dir;
+    let wallet_options = global_args.wallet_options();
+    let base_dir = global_args.base_dir.clone();
 
     // If the base-dir doesn't exist yet, create it
     if let Err(err) = fs::canonicalize(&base_dir).await {
-        if err.kind() == std::io::ErrorKind::NotFound {
+        if err.kind() == std::io::ErrorKind::NotFound && !dry_run {
             fs::create_dir_all(&base_dir).await.unwrap();
         }
-    } else {
+    } else if !dry_run {
         // If the base-dir exists, check if it's already got this chain ID
         if fs::canonicalize(base_dir.join(chain_id.as_str()))
             .await
@@ -84,7 +117,12 @@ pub async fn join_network(
             safe_exit(1);
         }
     }
-    let base_dir_full = fs::canonicalize(&base_dir).await.unwrap();
+    // A dry-run doesn't create the base-dir, so it may not exist yet
+    let base_dir_full = if dry_run && !base_dir.exists() {
+        base_dir.clone()
+    } else {
+        fs::canonicalize(&base_dir).await.unwrap()
+    };
     let chain_dir = base_dir_full.join(chain_id.as_str());
 
     let validator_alias_and_dir = pre_genesis_path
@@ -118,50 +156,129 @@ pub async fn join_network(
         .map(|(validator_alias, pre_genesis_dir)| {
             (
                 alias::Alias::from(validator_alias),
-                pre_genesis::load(pre_genesis_dir).unwrap_or_else(|err| {
-                    eprintln!(
-                        "Error loading validator pre-genesis wallet {err}",
-                    );
-                    safe_exit(1)
-                }),
+                pre_genesis::load(pre_genesis_dir, &wallet_options)
+                    .unwrap_or_else(|err| {
+                        eprintln!(
+                            "Error loading validator pre-genesis wallet {err}",
+                        );
+                        safe_exit(1)
+                    }),
             )
         });
 
-    let release_filename = format!("{}.tar.gz", chain_id);
-    let net_config = if let Some(configs_dir) = network_configs_dir() {
-        fs::read(PathBuf::from(&configs_dir).join(release_filename))
+    let release_filenames: Vec<String> = ArchiveFormat::ALL
+        .iter()
+        .map(|format| format!("{}.{}", chain_id, format.extension()))
+        .collect();
+    let (release_filename, net_config) = if let Some(configs_dir) =
+        network_configs_dir()
+    {
+        let configs_dir = PathBuf::from(configs_dir);
+        let release_filename = release_filenames
+            .iter()
+            .find(|filename| configs_dir.join(filename).is_file())
+            .unwrap_or(&release_filenames[0]);
+        let net_config = fs::read(configs_dir.join(release_filename))
             .await
             .unwrap_or_else(|err| {
                 panic!(
                     "Network config not found or couldn't be read from dir \
-                     \"{configs_dir}\" set by an env var \
-                     {ENV_VAR_NETWORK_CONFIGS_DIR}. Error: {err}."
+                     \"{}\" set by an env var \
+                     {ENV_VAR_NETWORK_CONFIGS_DIR}. Error: {err}.",
+                    configs_dir.to_string_lossy()
                 )
-            })
+            });
+        (release_filename.clone(), net_config)
     } else {
-        let release_url = format!(
-            "{}/{}",
-            network_configs_url_prefix(&chain_id),
-            release_filename
-        );
+        let url_prefix = network_configs_url_prefix(&chain_id);
+        let mut release = None;
+        for release_filename in &release_filenames {
+            let release_url = format!("{}/{}", url_prefix, release_filename);
 
-        // Read or download the release archive
-        println!("Downloading config release from {} ...", release_url);
-        let release: Bytes = match download_file(release_url).await {
-            Ok(contents) => contents,
-            Err(error) => {
-                eprintln!("Error downloading release: {}", error);
-                safe_exit(1);
+            // Read or download the release archive
+            println!("Downloading config release from {} ...", release_url);
+            #[allow(clippy::disallowed_methods)]
+            let download_start = std::time::Instant::now();
+            match download_file_with_retry(
+                &release_url,
+                download_timeout.0,
+                download_retries,
+                download_retry_delay.0,
+            )
+            .await
+            {
+                Ok(contents) => {
+                    release = Some((release_filename.clone(), contents));
+                    break;
+                }
+                Err(error)
+                    if error.status()
+                        == Some(reqwest::StatusCode::NOT_FOUND) =>
+                {
+                    println!("No release found at {}.", release_url);
+                }
+                Err(error) if error.is_timeout() => {
+                    eprintln!(
+                        "Timed out downloading release from {} after {:.1}s. \
+                         The timeout can be raised with \
+                         `--download-timeout`.",
+                        release_url,
+                        download_start.elapsed().as_secs_f64()
+                    );
+                    safe_exit(1);
+                }
+                Err(error) => {
+                    eprintln!("Error downloading release: {}", error);
+                    safe_exit(1);
+                }
             }
-        };
-        release.to_vec()
+        }
+        let (release_filename, release): (String, Bytes) = release
+            .unwrap_or_else(|| {
+                eprintln!(
+                    "No release of the chain {chain_id} was found at {} in \
+                     any of the {} formats.",
+                    url_prefix,
+                    ArchiveFormat::ALL
+                        .iter()
+                        .map(|format| format!(".{}", format.extension()))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                safe_exit(1)
+            });
+        (release_filename, release.to_vec())
     };
 
     // Decode and unpack the archive
-    let decoder = GzDecoder::new(&net_config[..]);
-    let mut archive = tar::Archive::new(decoder);
-    archive.unpack(&base_dir_full).unwrap();
-    _ = archive;
+    let format = ArchiveFormat::detect(&net_config).unwrap_or_else(|| {
+        eprintln!(
+            "The network config archive {release_filename} is not in a \
+             supported format. Expected a tar archive compressed with one of \
+             gzip, xz or zstd."
+        );
+        safe_exit(1)
+    });
+    if dry_run {
+        print_archive_targets(
+            &global_args,
+            format,
+            &net_config,
+            &release_filename,
+            &base_dir_full,
+        );
+        return;
+    }
+    format
+        .unpack(&net_config, &base_dir_full)
+        .unwrap_or_else(|err| {
+            eprintln!(
+                "Failed to unpack the network config archive \
+                 {release_filename} as a {} archive: {err}",
+                format.extension()
+            );
+            safe_exit(1)
+        });
 
     // Read the genesis files
     let genesis = genesis::chain::Finalized::read_toml_files(&chain_dir)
@@ -199,21 +316,37 @@ pub async fn join_network(
     );
 
     // Try to load pre-genesis wallet, if any
-    let pre_genesis_wallet_path = base_dir.join(PRE_GENESIS_DIR);
-    let pre_genesis_wallet =
-        if let Ok(wallet) = crate::wallet::load(&pre_genesis_wallet_path) {
-            Some(wallet)
-        } else {
-            validator_alias_and_dir
-                .as_ref()
-                .and_then(|(_, path)| crate::wallet::load(path).ok())
-        };
+    let pre_genesis_wallet_path =
+        crate::wallet::pre_genesis_store_dir(&base_dir);
+    let pre_genesis_wallet = if let Ok(wallet) =
+        crate::wallet::load_with_options(
+            &pre_genesis_wallet_path,
+            &wallet_options,
+        ) {
+        Some(wallet)
+    } else {
+        validator_alias_and_dir.as_ref().and_then(|(_, path)| {
+            crate::wallet::load_with_options(path, &wallet_options).ok()
+        })
+    };
 
     // Derive wallet from genesis
     let wallet = genesis.derive_wallet(
         &chain_dir,
+        &wallet_options,
         pre_genesis_wallet,
         validator_alias_and_pre_genesis_wallet,
+        alias_prefix.as_deref(),
+        &genesis::chain::GenesisAliasFilter {
+            include: include_aliases
+                .into_iter()
+                .map(alias::Alias::from)
+                .collect(),
+            exclude: exclude_aliases
+                .into_iter()
+                .map(alias::Alias::from)
+                .collect(),
+        },
     );
 
     // Setup the node for a genesis validator, if used
@@ -244,7 +377,7 @@ pub async fn join_network(
     {
         let wasm_dir_full = chain_dir.join(wasm_dir);
 
-        tokio::fs::rename(
+        fs::rename(
             base_dir_full
                 .join(chain_id.as_str())
                 .join(config::DEFAULT_WASM_DIR),
@@ -272,6 +405,42 @@ async fn validate_wasm_artifacts_aux(chain_id: &ChainId, wasm_dir: &Path) {
     wasm_loader::validate_wasm_artifacts(wasm_dir).await;
 }
 
+/// Check that the WASM dir in use contains the WASMs of all the txs that the
+/// client can build, listing the missing ones.
+pub fn check_wasm(global_args: args::Global, _args: args::CheckWasm) {
+    let wasm_dir = chain_wasm_dir(&global_args).unwrap_or_else(|| {
+        eprintln!(
+            "No WASM dir to check. Set one with `--wasm-dir` or \
+             {ENV_VAR_WASM_DIR}, or configure a chain."
+        );
+        safe_exit(1)
+    });
+    let missing = wasm_loader::missing_wasms(&wasm_dir, args::CLIENT_WASMS)
+        .unwrap_or_else(|err| {
+            eprintln!("Unable to check the WASM dir: {err}");
+            safe_exit(1)
+        });
+    if missing.is_empty() {
+        println!(
+            "All the {} transaction WASMs are present in {}.",
+            args::CLIENT_WASMS.len(),
+            wasm_dir.to_string_lossy()
+        );
+    } else {
+        eprintln!(
+            "{} of the {} transaction WASMs are missing from {}:",
+            missing.len(),
+            args::CLIENT_WASMS.len(),
+            wasm_dir.to_string_lossy()
+        );
+        for name in missing {
+            eprintln!("  {name}");
+        }
+        safe_exit(1)
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
 pub fn validate_wasm(args::ValidateWasm { code_path }: args::ValidateWasm) {
     let code = std::fs::read(code_path).unwrap();
     match validate_untrusted_wasm(code) {
@@ -283,6 +452,13 @@ pub fn validate_wasm(args::ValidateWasm { code_path }: args::ValidateWasm) {
     }
 }
 
+/// Validating wasm code needs the VM, which is not built for wasm32.
+#[cfg(target_family = "wasm")]
+pub fn validate_wasm(_args: args::ValidateWasm) {
+    eprintln!("Wasm code can't be validated on wasm32");
+    safe_exit(1)
+}
+
 /// Length of a Tendermint Node ID in bytes
 const TENDERMINT_NODE_ID_LENGTH: usize = 20;
 
@@ -335,10 +511,14 @@ pub fn init_network(
 
     // Also check that at least one validator account has positive voting power.
     let tm_votes_per_token = templates.parameters.pos_params.tm_votes_per_token;
-    if !templates
+    let has_voting_power = templates
         .transactions
         .has_validator_with_positive_voting_power(tm_votes_per_token)
-    {
+        .unwrap_or_else(|err| {
+            eprintln!("{err}, aborting.");
+            safe_exit(1)
+        });
+    if !has_voting_power {
         let min_stake = token::Amount::from_uint(
             if tm_votes_per_token > Dec::from(1) {
                 Uint::one()
@@ -453,6 +633,59 @@ pub fn pk_to_tm_address(
     println!("{tm_addr}");
 }
 
+pub fn recover_address(
+    _global_args: args::Global,
+    args::RecoverAddress { public_key, scheme }: args::RecoverAddress,
+) {
+    let public_key = decode_public_key_of_scheme(&public_key, scheme)
+        .unwrap_or_else(|err| {
+            eprintln!("{err}");
+            safe_exit(1)
+        });
+    println!("{}", Address::from(&public_key));
+}
+
+/// Decode a public key that is either bech32m encoded or hex encoded, and
+/// check that it is a key of the given scheme. Hex encoded keys may be the
+/// borsh serialization of a key, which is tagged with its scheme, or the bare
+/// key bytes, which are decoded according to the given scheme.
+fn decode_public_key_of_scheme(
+    raw: &str,
+    scheme: SchemeType,
+) -> Result<common::PublicKey, String> {
+    let hex_bytes = data_encoding::HEXLOWER_PERMISSIVE
+        .decode(raw.as_bytes())
+        .ok();
+    let tagged = raw.parse::<common::PublicKey>().ok().or_else(|| {
+        common::PublicKey::try_from_slice(hex_bytes.as_ref()?).ok()
+    });
+    if let Some(public_key) = tagged {
+        let key_scheme = public_key.scheme();
+        if scheme != SchemeType::Common && scheme != key_scheme {
+            return Err(format!(
+                "The public key is a {key_scheme} key, but the {scheme} \
+                 scheme was given."
+            ));
+        }
+        return Ok(public_key);
+    }
+    let bytes = hex_bytes.ok_or_else(|| {
+        "The public key is neither bech32m nor hex encoded.".to_string()
+    })?;
+    match scheme {
+        SchemeType::Ed25519 => ed25519::PublicKey::try_from_slice(&bytes)
+            .map(common::PublicKey::Ed25519)
+            .map_err(|err| format!("Invalid ed25519 public key: {err}")),
+        SchemeType::Secp256k1 => secp256k1::PublicKey::try_from_slice(&bytes)
+            .map(common::PublicKey::Secp256k1)
+            .map_err(|err| format!("Invalid secp256k1 public key: {err}")),
+        SchemeType::Common => Err("The scheme of bare key bytes cannot be \
+                                   inferred, please give ed25519 or \
+                                   secp256k1."
+            .to_string()),
+    }
+}
+
 pub fn default_base_dir(
     _global_args: args::Global,
     _args: args::DefaultBaseDir,
@@ -465,16 +698,339 @@ pub fn default_base_dir(
     );
 }
 
+/// Print the resolved global args, each with the source of its value, in the
+/// order of precedence that the commands resolve them in.
+pub fn show_config(
+    global_args: args::Global,
+    args::ShowConfig { given_args }: args::ShowConfig,
+) {
+    let given = |name: &str| given_args.iter().any(|given| *given == name);
+    let from_env = |var: &str| env::var(var).is_ok();
+
+    let base_dir_source = if given(args::BASE_DIR.name) {
+        "--base-dir"
+    } else if from_env(args::BASE_DIR_ENV_VAR) {
+        args::BASE_DIR_ENV_VAR
+    } else {
+        "default"
+    };
+    println!(
+        "base_dir: {} ({base_dir_source})",
+        global_args.base_dir.display()
+    );
+
+    match &global_args.profile {
+        Some(profile) => {
+            let source = if given(args::PROFILE.name) {
+                "--profile"
+            } else {
+                args::PROFILE_ENV_VAR
+            };
+            println!("profile: {profile} ({source})")
+        }
+        None => println!("profile: not set"),
+    }
+
+    let chain_id_from_env = env::var(ENV_VAR_CHAIN_ID)
+        .ok()
+        .and_then(|chain_id| chain_id.parse::<ChainId>().ok());
+    let chain_id = match (chain_id_from_env, &global_args.chain_id) {
+        (Some(chain_id), _) => Some((chain_id, ENV_VAR_CHAIN_ID)),
+        (None, Some(chain_id)) => Some((chain_id.clone(), "--chain-id")),
+        (None, None) => read_or_try_new_global_config(&global_args)
+            .default_chain_id
+            .map(|chain_id| (chain_id, "global config")),
+    };
+    match chain_id {
+        Some((chain_id, source)) => {
+            println!("chain_id: {} ({source})", chain_id.as_str())
+        }
+        None => println!("chain_id: not set"),
+    }
+
+    match wasm_dir_from_env_or(global_args.wasm_dir.as_ref()) {
+        Some(wasm_dir) => {
+            let source = if given(args::WASM_DIR.name) {
+                "--wasm-dir"
+            } else {
+                ENV_VAR_WASM_DIR
+            };
+            println!("wasm_dir: {} ({source})", wasm_dir.display())
+        }
+        None => println!("wasm_dir: not set (the chain's config is used)"),
+    }
+
+    let pre_genesis_source = if given(args::PRE_GENESIS.name) {
+        "--pre-genesis"
+    } else {
+        "default"
+    };
+    println!(
+        "is_pre_genesis: {} ({pre_genesis_source})",
+        global_args.is_pre_genesis
+    );
+
+    let tty_source = if global_args.assume_tty {
+        "--assume-tty"
+    } else if global_args.no_tty {
+        "--no-tty"
+    } else if assume_tty_env() {
+        "NAMADA_ASSUME_TTY"
+    } else {
+        "detected"
+    };
+    println!("tty: {} ({tty_source})", global_args.tty_enabled());
+}
+
+/// List the chain directories found in the base dir, along with whether
+/// each of them has a wallet and a config.
+pub fn list_chains(global_args: args::Global, _args: args::ListChains) {
+    let base_dir = &global_args.base_dir;
+    let dir_entries = fs::read_dir(base_dir).unwrap_or_else(|err| {
+        eprintln!(
+            "Couldn't read the base directory {}: {err}",
+            global_args.redact_base_dir(base_dir.display())
+        );
+        safe_exit(1)
+    });
+    let mut chain_ids: Vec<ChainId> = dir_entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            if !entry.file_type().ok()?.is_dir() {
+                return None;
+            }
+            entry.file_name().to_str()?.parse().ok()
+        })
+        .collect();
+    if chain_ids.is_empty() {
+        println!(
+            "No chains found in {}.",
+            global_args.redact_base_dir(base_dir.display())
+        );
+        return;
+    }
+    chain_ids.sort();
+    let default_chain_id = GlobalConfig::read(base_dir)
+        .ok()
+        .and_then(|global_config| global_config.default_chain_id);
+    let yes_no = |exists: bool| if exists { "yes" } else { "no" };
+    for chain_id in chain_ids {
+        let chain_dir = base_dir.join(chain_id.as_str());
+        let has_wallet = crate::wallet::wallet_file(&chain_dir).is_file();
+        let has_config =
+            config::Config::file_path(base_dir, &chain_id).is_file();
+        let default = if default_chain_id.as_ref() == Some(&chain_id) {
+            " (default)"
+        } else {
+            ""
+        };
+        println!(
+            "{chain_id}{default}: wallet: {}, config: {}",
+            yes_no(has_wallet),
+            yes_no(has_config)
+        );
+    }
+}
+
+/// List the profiles found in the base dir, marking the selected one.
+pub fn list_profiles(global_args: args::Global, _args: args::ListProfiles) {
+    let base_dir = match &global_args.profile {
+        // The base dir is then the dir of the selected profile
+        Some(_) => global_args.base_dir.ancestors().nth(2),
+        None => Some(global_args.base_dir.as_path()),
+    }
+    .unwrap_or(&global_args.base_dir);
+    let profiles_dir = base_dir.join(config::PROFILES_DIR);
+    let mut profiles: Vec<String> = match fs::read_dir(&profiles_dir) {
+        Ok(dir_entries) => dir_entries
+            .filter_map(|entry| {
+                let entry = entry.ok()?;
+                if !entry.file_type().ok()?.is_dir() {
+                    return None;
+                }
+                entry.file_name().into_string().ok()
+            })
+            .collect(),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => vec![],
+        Err(err) => {
+            eprintln!(
+                "Couldn't read the profiles directory {}: {err}",
+                global_args.redact_base_dir(profiles_dir.display())
+            );
+            safe_exit(1)
+        }
+    };
+    if profiles.is_empty() {
+        println!(
+            "No profiles found in {}.",
+            global_args.redact_base_dir(profiles_dir.display())
+        );
+        return;
+    }
+    profiles.sort();
+    for profile in profiles {
+        let selected = if global_args.profile.as_ref() == Some(&profile) {
+            " (selected)"
+        } else {
+            ""
+        };
+        println!("{profile}{selected}");
+    }
+}
+
+/// Print the tree of the client commands as JSON. It's generated from the
+/// definitions of the commands, so that tools built over the CLI can't drift
+/// from it.
+pub fn dump_cli(_args: args::DumpCli) {
+    let tree = command_tree(&namada_client_app());
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&tree)
+            .expect("Serializing the command tree shouldn't fail")
+    );
+}
+
+/// The JSON description of the given command, its arguments and its
+/// subcommands. Hidden arguments and subcommands are left out.
+fn command_tree(app: &clap::Command) -> serde_json::Value {
+    let args: Vec<_> = app
+        .get_arguments()
+        .filter(|arg| !arg.is_hide_set())
+        .map(|arg| {
+            json!({
+                "name": arg.get_id().as_str(),
+                "long": arg.get_long(),
+                "short": arg.get_short(),
+                "help": arg.get_help().map(ToString::to_string),
+                "required": arg.is_required_set(),
+                "global": arg.is_global_set(),
+                "takes_value": arg.get_action().takes_values(),
+                "defaults": arg
+                    .get_default_values()
+                    .iter()
+                    .map(|value| value.to_string_lossy())
+                    .collect::<Vec<_>>(),
+                "possible_values": arg
+                    .get_possible_values()
+                    .iter()
+                    .map(|value| value.get_name().to_string())
+                    .collect::<Vec<_>>(),
+            })
+        })
+        .collect();
+    let subcommands: Vec<_> = app
+        .get_subcommands()
+        .filter(|sub| !sub.is_hide_set())
+        .map(command_tree)
+        .collect();
+    json!({
+        "name": app.get_name(),
+        "about": app.get_about().map(ToString::to_string),
+        "args": args,
+        "subcommands": subcommands,
+    })
+}
+
+/// List the hardware wallets that can be reached with the given transport.
+pub fn list_devices(args::ListDevices { device_transport }: args::ListDevices) {
+    match device_transport {
+        DeviceTransport::Hid => {
+            let devices = list_hid_devices().unwrap_or_else(|err| {
+                eprintln!("Unable to enumerate the HID devices: {err}");
+                safe_exit(1)
+            });
+            if devices.is_empty() {
+                println!("No Ledger devices were found over HID.");
+                return;
+            }
+            for HidDevice { product, path } in devices {
+                println!(
+                    "{}: {path}",
+                    product.as_deref().unwrap_or("Unknown Ledger device")
+                );
+            }
+        }
+        DeviceTransport::Tcp => {
+            let address = TransportTcp::proxy_address();
+            match connect_device_emulator(address) {
+                Ok(()) => {
+                    println!("A device emulator is listening at {address}.")
+                }
+                Err(err) => {
+                    eprintln!(
+                        "Unable to reach a device emulator at {address}: \
+                         {err}"
+                    );
+                    safe_exit(1)
+                }
+            }
+        }
+    }
+}
+
+/// Try to open a connection to the device emulator listening at the given
+/// address.
+fn connect_device_emulator(
+    address: std::net::SocketAddr,
+) -> std::io::Result<()> {
+    std::net::TcpStream::connect_timeout(
+        &address,
+        std::time::Duration::from_secs(5),
+    )
+    .map(drop)
+}
+
+/// Check that a hardware wallet can be reached with the given transport
+/// before signing with it, and exit with guidance if it can't.
+fn ensure_device_reachable(device_transport: DeviceTransport) {
+    match device_transport {
+        DeviceTransport::Hid => match list_hid_devices() {
+            Ok(devices) if !devices.is_empty() => {}
+            Ok(_) => {
+                eprintln!(
+                    "--use-device was passed, but no Ledger device was found \
+                     over HID. Connect and unlock the device, open the \
+                     Namada app on it and check that it is listed by \
+                     `namadac utils list-devices`."
+                );
+                safe_exit(1)
+            }
+            Err(err) => {
+                eprintln!(
+                    "--use-device was passed, but the HID devices couldn't \
+                     be enumerated: {err}. Check that this user is allowed \
+                     to access USB devices."
+                );
+                safe_exit(1)
+            }
+        },
+        DeviceTransport::Tcp => {
+            let address = TransportTcp::proxy_address();
+            if let Err(err) = connect_device_emulator(address) {
+                eprintln!(
+                    "--use-device was passed with the TCP transport, but no \
+                     device emulator could be reached at {address}: {err}. \
+                     Start the emulator, or point LEDGER_PROXY_ADDRESS and \
+                     LEDGER_PROXY_PORT at it."
+                );
+                safe_exit(1)
+            }
+        }
+    }
+}
+
 /// Derive and print all established addresses from the provided
 /// genesis txs toml file.
 pub fn derive_genesis_addresses(
     global_args: args::Global,
     args: args::DeriveGenesisAddresses,
 ) {
-    let maybe_pre_genesis_wallet =
-        try_load_pre_genesis_wallet(&global_args.base_dir)
-            .ok()
-            .map(|(wallet, _)| wallet);
+    let maybe_pre_genesis_wallet = try_load_pre_genesis_wallet(
+        &global_args.base_dir,
+        &global_args.wallet_options(),
+    )
+    .ok()
+    .map(|(wallet, _)| wallet);
     let contents =
         fs::read_to_string(&args.genesis_txs_path).unwrap_or_else(|err| {
             eprintln!(
@@ -512,16 +1068,27 @@ pub fn derive_genesis_addresses(
                 )
             });
 
-    println!("{}", "Established account txs:".underline().bold());
+    println!(
+        "{}",
+        "Established account txs:"
+            .style(global_args.output_style(Style::new().underline().bold()))
+    );
     for tx in &estbd_txs {
         println!();
         println!(
             "{} {}",
-            "Address:".bold().bright_green(),
+            "Address:".style(
+                global_args.output_style(Style::new().bold().bright_green())
+            ),
             tx.derive_address()
         );
 
-        println!("{}", "Public key(s):".bold().bright_green());
+        println!(
+            "{}",
+            "Public key(s):".style(
+                global_args.output_style(Style::new().bold().bright_green())
+            )
+        );
         for (ix, pk) in tx.public_keys.iter().enumerate() {
             println!("    {}. {}", ix, pk);
 
@@ -532,24 +1099,46 @@ pub fn derive_genesis_addresses(
                 });
 
             if let Some(alias) = maybe_alias {
-                println!("{} {alias}", "Wallet alias:".bold().bright_green());
+                println!(
+                    "{} {alias}",
+                    "Wallet alias:".style(
+                        global_args
+                            .output_style(Style::new().bold().bright_green())
+                    )
+                );
             }
         }
     }
     if estbd_txs.is_empty() {
         println!();
-        println!("{}", "<nil>".dimmed());
+        println!(
+            "{}",
+            "<nil>".style(global_args.output_style(Style::new().dimmed()))
+        );
     }
     println!();
 
-    println!("{}", "Validator account txs:".underline().bold());
+    println!(
+        "{}",
+        "Validator account txs:"
+            .style(global_args.output_style(Style::new().underline().bold()))
+    );
     for addr in &validator_addrs {
         println!();
-        println!("{} {}", "Address:".bold().bright_green(), addr.raw);
+        println!(
+            "{} {}",
+            "Address:".style(
+                global_args.output_style(Style::new().bold().bright_green())
+            ),
+            addr.raw
+        );
     }
     if validator_addrs.is_empty() {
         println!();
-        println!("{}", "<nil>".dimmed());
+        println!(
+            "{}",
+            "<nil>".style(global_args.output_style(Style::new().dimmed()))
+        );
     }
 }
 
@@ -559,8 +1148,10 @@ pub fn init_genesis_established_account(
     global_args: args::Global,
     args: args::InitGenesisEstablishedAccount,
 ) {
-    let (pre_genesis_wallet, _) =
-        load_pre_genesis_wallet_or_exit(&global_args.base_dir);
+    let (pre_genesis_wallet, _) = load_pre_genesis_wallet_or_exit(
+        &global_args.base_dir,
+        &global_args.wallet_options(),
+    );
 
     let public_keys: Vec<_> = args
         .wallet_aliases
@@ -585,7 +1176,8 @@ pub fn init_genesis_established_account(
         args.threshold,
     );
     let toml_path = args.output_path;
-    let toml_path_str = toml_path.to_string_lossy();
+    let toml_path_str =
+        global_args.redact_base_dir(toml_path.to_string_lossy());
 
     let genesis_part = toml::to_string(&txs).unwrap();
     fs::write(&toml_path, genesis_part).unwrap_or_else(|err| {
@@ -598,15 +1190,21 @@ pub fn init_genesis_established_account(
 
     println!(
         "{}: {}\n",
-        "Derived established account address".bold(),
-        address.green(),
+        "Derived established account address"
+            .style(global_args.output_style(Style::new().bold())),
+        address.style(global_args.output_style(Style::new().green())),
     );
     println!(
         "{}: keep a note of this address, especially if you plan to use it \
          for a validator account in the future!\n",
-        "IMPORTANT".bold().yellow()
+        "IMPORTANT"
+            .style(global_args.output_style(Style::new().bold().yellow()))
+    );
+    println!(
+        "{}: {toml_path_str}\n",
+        "Wrote genesis tx to"
+            .style(global_args.output_style(Style::new().bold()))
     );
-    println!("{}: {toml_path_str}\n", "Wrote genesis tx to".bold());
 }
 
 /// Bond to a validator at pre-genesis.
@@ -618,8 +1216,10 @@ pub fn genesis_bond(global_args: args::Global, args: args::GenesisBond) {
         output: toml_path,
     } = args;
 
-    let (wallet, _wallet_file) =
-        load_pre_genesis_wallet_or_exit(&global_args.base_dir);
+    let (wallet, _wallet_file) = load_pre_genesis_wallet_or_exit(
+        &global_args.base_dir,
+        &global_args.wallet_options(),
+    );
     let source = match source {
         AddrOrPk::Address(addr) => match &addr {
             Address::Established(established) => {
@@ -646,7 +1246,8 @@ pub fn genesis_bond(global_args: args::Global, args: args::GenesisBond) {
     };
     let txs = genesis::transactions::init_bond(source, validator, bond_amount);
 
-    let toml_path_str = toml_path.to_string_lossy();
+    let toml_path_str =
+        global_args.redact_base_dir(toml_path.to_string_lossy());
 
     let genesis_part = toml::to_string(&txs).unwrap();
     fs::write(&toml_path, genesis_part).unwrap_or_else(|err| {
@@ -657,42 +1258,273 @@ pub fn genesis_bond(global_args: args::Global, args: args::GenesisBond) {
         safe_exit(1)
     });
 
-    println!("{}: {toml_path_str}", "Wrote genesis tx to".bold());
+    println!(
+        "{}: {toml_path_str}",
+        "Wrote genesis tx to"
+            .style(global_args.output_style(Style::new().bold()))
+    );
 }
 
-/// Initialize genesis validator's address, consensus key and validator account
-/// key into a special "pre-genesis" wallet.
-pub fn init_genesis_validator(
+/// Generate a template of genesis bonds to be filled in and signed.
+pub fn gen_bonds_template(
     global_args: args::Global,
-    args::InitGenesisValidator {
-        alias,
-        commission_rate,
-        max_commission_rate_change,
-        net_address,
-        unsafe_dont_encrypt,
-        key_scheme,
-        self_bond_amount,
-        email,
-        description,
-        website,
-        discord_handle,
-        avatar,
-        name,
-        tx_path,
-        address,
-    }: args::InitGenesisValidator,
+    args::GenBondsTemplate {
+        templates_path,
+        count,
+        output,
+    }: args::GenBondsTemplate,
 ) {
-    let contents = fs::read_to_string(&tx_path).unwrap_or_else(|err| {
+    let validators: Vec<Option<EstablishedAddress>> =
+        match (templates_path, count) {
+            (Some(templates_path), _) => {
+                let txs_path = templates_path
+                    .join(genesis::templates::TRANSACTIONS_FILE_NAME);
+                let txs = genesis::templates::read_transactions(&txs_path)
+                    .unwrap_or_else(|err| {
+                        eprintln!(
+                            "Unable to read the genesis transactions from {}. \
+                             Failed with: {err}",
+                            txs_path.to_string_lossy()
+                        );
+                        safe_exit(1)
+                    });
+                let validators: Vec<_> = txs
+                    .validator_account
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|tx| Some(tx.data.address.raw))
+                    .collect();
+                if validators.is_empty() {
+                    eprintln!(
+                        "No validator accounts found in {}.",
+                        txs_path.to_string_lossy()
+                    );
+                    safe_exit(1)
+                }
+                validators
+            }
+            (None, Some(count)) => vec![None; count],
+            (None, None) => {
+                eprintln!(
+                    "Either a templates path or a number of bonds must be \
+                     given."
+                );
+                safe_exit(1)
+            }
+        };
+    let template = genesis::transactions::bonds_template(&validators);
+
+    match output {
+        Some(toml_path) => {
+            let toml_path_str =
+                global_args.redact_base_dir(toml_path.to_string_lossy());
+            fs::write(&toml_path, template).unwrap_or_else(|err| {
+                eprintln!(
+                    "Couldn't write the bonds template to {toml_path_str}. \
+                     Failed with: {err}",
+                );
+                safe_exit(1)
+            });
+            println!(
+                "{}: {toml_path_str}",
+                "Wrote bonds template to"
+                    .style(global_args.output_style(Style::new().bold()))
+            );
+        }
+        None => print!("{template}"),
+    }
+}
+
+/// Print the total stake of the bonds in a genesis txs file, the subtotal of
+/// every validator and the number of distinct bond sources.
+pub fn bonds_total(
+    global_args: args::Global,
+    args::BondsTotal { path }: args::BondsTotal,
+) {
+    let contents = std::fs::read(&path).unwrap_or_else(|err| {
         eprintln!(
-            "Unable to read from file {}. Failed with error {err}.",
-            tx_path.to_string_lossy()
+            "Unable to read the bonds file {}. Failed with: {err}",
+            path.to_string_lossy()
         );
         safe_exit(1)
     });
-    let prev_txs: UnsignedTransactions = toml::from_str(&contents).unwrap();
-    if prev_txs
-        .established_account
-        .as_ref()
+    let unsigned = genesis::transactions::parse_unsigned(&contents)
+        .unwrap_or_else(|err| {
+            eprintln!(
+                "Unable to parse the bonds file {}. Failed with: {err}",
+                path.to_string_lossy()
+            );
+            safe_exit(1)
+        });
+    let bonds: Vec<_> = unsigned
+        .bond
+        .unwrap_or_default()
+        .into_iter()
+        .enumerate()
+        .map(|(index, bond)| {
+            bond.denominate().unwrap_or_else(|err| {
+                eprintln!(
+                    "The amount of the bond #{} is invalid: {err}",
+                    index + 1
+                );
+                safe_exit(1)
+            })
+        })
+        .collect();
+    let sources: HashSet<_> = bonds.iter().map(|bond| &bond.source).collect();
+
+    let subtotals = genesis::transactions::validator_stakes(&bonds)
+        .unwrap_or_else(|err| {
+            eprintln!("{err}.");
+            safe_exit(1)
+        });
+    let total = genesis::transactions::total_stake(subtotals.values().copied())
+        .unwrap_or_else(|err| {
+            eprintln!("{err}.");
+            safe_exit(1)
+        });
+
+    println!(
+        "{}: {}",
+        "Total stake".style(global_args.output_style(Style::new().bold())),
+        total.to_string_native()
+    );
+    println!("Distinct sources: {}", sources.len());
+    for (validator, subtotal) in subtotals {
+        println!("  {validator}: {}", subtotal.to_string_native());
+    }
+}
+
+/// The number of round trips to the device that the per-signature latency is
+/// calibrated with
+const DEVICE_CALIBRATION_ROUND_TRIPS: u32 = 5;
+
+/// Estimate how long signing the given number of txs with a hardware wallet
+/// will take, calibrating the per-signature latency with the device unless
+/// it's given.
+pub async fn estimate_device_time(
+    global_args: args::Global,
+    args::EstimateDeviceTime {
+        path,
+        count,
+        per_sig_ms,
+        device_transport,
+    }: args::EstimateDeviceTime,
+) {
+    let count = match (path, count) {
+        (Some(path), _) => {
+            let contents = std::fs::read(&path).unwrap_or_else(|err| {
+                eprintln!(
+                    "Unable to read {}. Failed with: {err}",
+                    path.to_string_lossy()
+                );
+                safe_exit(1)
+            });
+            genesis::transactions::parse_unsigned(&contents)
+                .unwrap_or_else(|err| {
+                    eprintln!(
+                        "Unable to parse the unsigned txs. Failed with: {err}"
+                    );
+                    safe_exit(1)
+                })
+                .num_txs()
+        }
+        (None, Some(count)) => count,
+        (None, None) => {
+            eprintln!("Either a path or a number of txs must be given.");
+            safe_exit(1)
+        }
+    };
+    let per_sig = match per_sig_ms {
+        Some(ms) => std::time::Duration::from_millis(ms),
+        None => {
+            let per_sig =
+                calibrate_device_latency(&global_args, device_transport)
+                    .await
+                    .unwrap_or_else(|err| {
+                        eprintln!(
+                            "Unable to calibrate with the device: {err}\nPass \
+                         `--per-sig-ms` to estimate without it."
+                        );
+                        safe_exit(1)
+                    });
+            println!(
+                "Measured a device round trip of {} ms. The time to approve \
+                 each signature on the device comes on top of it.",
+                per_sig.as_millis()
+            );
+            per_sig
+        }
+    };
+    let total =
+        per_sig.saturating_mul(u32::try_from(count).unwrap_or(u32::MAX));
+    println!(
+        "{}: {:.1}s for {count} signatures at {} ms each",
+        "Estimated device signing time"
+            .style(global_args.output_style(Style::new().bold())),
+        total.as_secs_f64(),
+        per_sig.as_millis()
+    );
+}
+
+/// Measure the average latency of a round trip to the connected device.
+async fn calibrate_device_latency(
+    global_args: &args::Global,
+    device_transport: DeviceTransport,
+) -> Result<std::time::Duration, String> {
+    let app = NamadaApp::new(WalletTransport::from_arg(
+        device_transport,
+        global_args.debug_apdu_enabled(),
+    ));
+    let path = BIP44Path {
+        path: DerivationPath::default_for_transparent_scheme(
+            SchemeType::Ed25519,
+        )
+        .to_string(),
+    };
+    #[allow(clippy::disallowed_methods)]
+    let start = std::time::Instant::now();
+    for _ in 0..DEVICE_CALIBRATION_ROUND_TRIPS {
+        app.get_address_and_pubkey(&path, false)
+            .await
+            .map_err(|err| err.to_string())?;
+    }
+    Ok(start.elapsed() / DEVICE_CALIBRATION_ROUND_TRIPS)
+}
+
+/// Initialize genesis validator's address, consensus key and validator account
+/// key into a special "pre-genesis" wallet.
+pub fn init_genesis_validator(
+    global_args: args::Global,
+    args::InitGenesisValidator {
+        alias,
+        commission_rate,
+        max_commission_rate_change,
+        net_address,
+        unsafe_dont_encrypt,
+        key_scheme,
+        self_bond_amount,
+        email,
+        description,
+        website,
+        discord_handle,
+        avatar,
+        name,
+        tx_path,
+        address,
+    }: args::InitGenesisValidator,
+) {
+    let contents = fs::read_to_string(&tx_path).unwrap_or_else(|err| {
+        eprintln!(
+            "Unable to read from file {}. Failed with error {err}.",
+            global_args.redact_base_dir(tx_path.to_string_lossy())
+        );
+        safe_exit(1)
+    });
+    let prev_txs: UnsignedTransactions = toml::from_str(&contents).unwrap();
+    if prev_txs
+        .established_account
+        .as_ref()
         .and_then(|accts| {
             accts
                 .iter()
@@ -732,6 +1564,7 @@ pub fn init_genesis_validator(
         key_scheme,
         unsafe_dont_encrypt,
         &pre_genesis_dir,
+        &global_args.wallet_options(),
     )
     .unwrap_or_else(|err| {
         eprintln!(
@@ -742,7 +1575,10 @@ pub fn init_genesis_validator(
     });
     println!(
         "The validator's keys were stored in the wallet at {}",
-        pre_genesis::validator_file_name(&pre_genesis_dir).to_string_lossy()
+        global_args.redact_base_dir(
+            pre_genesis::validator_file_name(&pre_genesis_dir)
+                .to_string_lossy()
+        )
     );
 
     let (address, mut transactions) = genesis::transactions::init_validator(
@@ -762,7 +1598,8 @@ pub fn init_genesis_validator(
         &validator_wallet,
     );
     let toml_path = tx_path;
-    let toml_path_str = toml_path.to_string_lossy();
+    let toml_path_str =
+        global_args.redact_base_dir(toml_path.to_string_lossy());
     // append new transactions to the previous txs from the provided file.
     transactions.established_account = prev_txs.established_account;
     transactions
@@ -787,43 +1624,536 @@ pub fn init_genesis_validator(
 
     println!(
         "{}: {}",
-        "Validator account address".bold(),
-        address.green()
+        "Validator account address"
+            .style(global_args.output_style(Style::new().bold())),
+        address.style(global_args.output_style(Style::new().green()))
+    );
+    println!(
+        "{}: {toml_path_str}",
+        "Wrote genesis tx to"
+            .style(global_args.output_style(Style::new().bold()))
     );
-    println!("{}: {toml_path_str}", "Wrote genesis tx to".bold());
 }
 
-/// Try to load a pre-genesis wallet or return nothing,
-/// if it cannot be found.
+/// Try to load a pre-genesis wallet with the given options, or return
+/// nothing, if it cannot be found.
 pub fn try_load_pre_genesis_wallet(
     base_dir: &Path,
+    options: &WalletOptions,
 ) -> Result<(Wallet<CliWalletUtils>, PathBuf), LoadStoreError> {
-    let pre_genesis_dir = base_dir.join(PRE_GENESIS_DIR);
+    let pre_genesis_dir = crate::wallet::pre_genesis_store_dir(base_dir);
 
-    crate::wallet::load(&pre_genesis_dir).map(|wallet| {
-        let wallet_file = crate::wallet::wallet_file(&pre_genesis_dir);
+    crate::wallet::load_with_options(&pre_genesis_dir, options).map(|wallet| {
+        let wallet_file = crate::wallet::wallet_file_with_name(
+            &pre_genesis_dir,
+            options.file_name.as_deref(),
+        );
         (wallet, wallet_file)
     })
 }
 
-/// Try to load a pre-genesis wallet or terminate if it cannot be found.
+/// Try to load a pre-genesis wallet with the given options, or terminate if
+/// it cannot be found.
 pub fn load_pre_genesis_wallet_or_exit(
     base_dir: &Path,
+    options: &WalletOptions,
 ) -> (Wallet<CliWalletUtils>, PathBuf) {
-    match try_load_pre_genesis_wallet(base_dir) {
+    match try_load_pre_genesis_wallet(base_dir, options) {
         Ok(wallet) => wallet,
         Err(e) => {
-            eprintln!("Error loading the wallet: {e}");
+            eprintln!(
+                "Error loading the wallet: {}",
+                redact_base_dir(&options.redacted_base_dirs, e)
+            );
+            safe_exit(1)
+        }
+    }
+}
+
+/// Load the wallet to sign genesis txs with. Given a key file, this is a
+/// wallet that only holds its key in memory, such that no wallet store needs
+/// to exist. Otherwise, it is the pre-genesis wallet. The wallets of the extra
+/// dirs are merged into it.
+fn load_genesis_signing_wallet(
+    global_args: &args::Global,
+    key_file: Option<&Path>,
+    extra_wallet_dirs: &[PathBuf],
+) -> Wallet<CliWalletUtils> {
+    let mut wallet = match key_file {
+        Some(key_file) => load_key_file_wallet(global_args, key_file),
+        None => {
+            load_pre_genesis_wallet_or_exit(
+                &global_args.base_dir,
+                &global_args.wallet_options(),
+            )
+            .0
+        }
+    };
+    merge_extra_signing_wallets(
+        &mut wallet,
+        extra_wallet_dirs,
+        &global_args.wallet_options(),
+    );
+    wallet
+}
+
+/// Merge the wallets of the given dirs into the wallet to sign genesis txs
+/// with, in order. The extra wallets are loaded with the given options and
+/// are never saved. When an alias is already used for a key by the wallet or
+/// by an earlier dir, the first-listed key is kept and a conflict with a
+/// different key is reported.
+fn merge_extra_signing_wallets(
+    wallet: &mut Wallet<CliWalletUtils>,
+    extra_wallet_dirs: &[PathBuf],
+    options: &WalletOptions,
+) {
+    for dir in extra_wallet_dirs {
+        let dir_str =
+            redact_base_dir(&options.redacted_base_dirs, dir.to_string_lossy());
+        let mut extra = crate::wallet::load_with_options(dir, options)
+            .unwrap_or_else(|err| {
+                eprintln!(
+                    "Unable to load the wallet in {dir_str}: {}",
+                    redact_base_dir(&options.redacted_base_dirs, err)
+                );
+                safe_exit(1)
+            });
+        let known_aliases: BTreeSet<String> = wallet
+            .get_secret_keys()
+            .into_keys()
+            .chain(wallet.get_public_keys().into_keys())
+            .collect();
+        let extra_aliases: BTreeSet<String> = extra
+            .get_secret_keys()
+            .into_keys()
+            .chain(extra.get_public_keys().into_keys())
+            .collect();
+        for alias in extra_aliases.intersection(&known_aliases) {
+            if wallet.find_public_key(alias).ok()
+                != extra.find_public_key(alias).ok()
+            {
+                eprintln!(
+                    "Warning: the key \"{alias}\" of the wallet in {dir_str} \
+                     differs from the key of the same alias in an earlier \
+                     wallet, which is used instead."
+                );
+            }
+            extra.remove_all_by_alias(alias.clone());
+        }
+        wallet.extend(extra);
+    }
+}
+
+/// Load a wallet that only holds the secret key of the given file in memory.
+fn load_key_file_wallet(
+    global_args: &args::Global,
+    key_file: &Path,
+) -> Wallet<CliWalletUtils> {
+    let key_file_str = global_args.redact_base_dir(key_file.to_string_lossy());
+    let file_data = Zeroizing::new(fs::read(key_file).unwrap_or_else(|err| {
+        eprintln!(
+            "Unable to read the key file {key_file_str}. Failed with: \
+                 {err}"
+        );
+        safe_exit(1)
+    }));
+    // Either a key as stored in a wallet, which may be encrypted, or a raw
+    // key as exported by `namadaw export`
+    let stored = std::str::from_utf8(&file_data).ok().and_then(|data| {
+        let data: serde::de::value::StrDeserializer<
+            '_,
+            serde::de::value::Error,
+        > = serde::de::IntoDeserializer::into_deserializer(data.trim());
+        <StoredKeypair<common::SecretKey> as serde::Deserialize>::deserialize(
+            data,
+        )
+        .ok()
+    });
+    let utils = CliWalletUtils::without_store(global_args.wallet_options());
+    let sk = match stored {
+        Some(stored) => stored
+            .get(&utils, true, None, Some(&key_file_str))
+            .unwrap_or_else(|err| {
+                eprintln!("Unable to decrypt the key file: {err}");
+                safe_exit(1)
+            }),
+        None => {
+            common::SecretKey::try_from_slice(&file_data).unwrap_or_else(|_| {
+                eprintln!(
+                    "The file {key_file_str} does not hold a secret key."
+                );
+                safe_exit(1)
+            })
+        }
+    };
+    let mut wallet = CliWalletUtils::new_with_options(
+        PathBuf::new(),
+        global_args.wallet_options(),
+    );
+    wallet
+        .insert_keypair("key-file".to_string(), true, sk, None, None, None)
+        .unwrap_or_else(|| {
+            eprintln!("Unable to use the key of {key_file_str} for signing.");
+            safe_exit(1)
+        });
+    wallet
+}
+
+/// A failure of the client, explained by `utils explain-error`
+struct ErrorExplanation {
+    /// The exit code or name of the failure
+    code: &'static str,
+    /// What the failure means
+    explanation: &'static str,
+    /// How to remedy the failure
+    remediation: &'static str,
+}
+
+/// The failures that `utils explain-error` knows of. The exit codes come
+/// first, followed by the common failures that exit with code 1.
+const ERROR_EXPLANATIONS: &[ErrorExplanation] = &[
+    ErrorExplanation {
+        code: "1",
+        explanation: "The command failed after its arguments were parsed. \
+                      The error printed to stderr right before exiting says \
+                      what failed.",
+        remediation: "Read the last error printed by the command. If it \
+                      names one of the common failures listed by \
+                      `explain-error`, look that one up instead.",
+    },
+    ErrorExplanation {
+        code: "2",
+        explanation: "The command line could not be parsed into a command, \
+                      for instance because a subcommand is missing, so the \
+                      usage was printed instead.",
+        remediation: "Check the printed usage, or pass `--help` to the \
+                      subcommand, and fix the command line.",
+    },
+    ErrorExplanation {
+        code: "no-matching-keys",
+        explanation: "None of the keys available for signing match the \
+                      signers that the genesis transactions expect, so \
+                      nothing could be signed.",
+        remediation: "Check that the pre-genesis wallet, or the file given \
+                      with `--key-file`, holds the keys of the signers. \
+                      `utils check-consistency` reports the mismatches \
+                      between the pre-genesis wallets and the genesis \
+                      transactions.",
+    },
+    ErrorExplanation {
+        code: "device-rejected",
+        explanation: "The hardware wallet refused to sign, either because \
+                      the signature was rejected on the device or because \
+                      the device was locked or not running the Namada app.",
+        remediation: "Unlock the device, open the Namada app and approve \
+                      the signature on the device. `utils list-devices` \
+                      shows whether the device is reachable at all.",
+    },
+    ErrorExplanation {
+        code: "wrong-password",
+        explanation: "A stored key could not be decrypted with the given \
+                      password.",
+        remediation: "Check the password with `namadaw check-password-file`. \
+                      When it's read from NAMADA_WALLET_PASSWORD_FILE or \
+                      NAMADA_WALLET_PASSWORD, check that these point to the \
+                      password of this wallet.",
+    },
+    ErrorExplanation {
+        code: "download-failed",
+        explanation: "The network configs could not be downloaded when \
+                      joining a network, even after retrying.",
+        remediation: "Check the connection and the chain ID. Raise \
+                      `--download-retries` or `--download-timeout` for a \
+                      flaky connection, or point \
+                      NAMADA_NETWORK_CONFIGS_SERVER to a reachable server.",
+    },
+    ErrorExplanation {
+        code: "invalid-signature",
+        explanation: "The signatures of a genesis transaction don't verify \
+                      against the keys and threshold of its source account.",
+        remediation: "Sign the transaction again with the keys of its \
+                      source account, then check it with `utils \
+                      verify-genesis-txs`.",
+    },
+];
+
+/// Explain a failure of the client and how to remedy it. Exits with an error
+/// listing the known failures if the code is unknown.
+pub fn explain_error(args::ExplainError { code }: args::ExplainError) {
+    let code = code.trim().to_lowercase();
+    match ERROR_EXPLANATIONS.iter().find(|error| error.code == code) {
+        Some(error) => {
+            println!("{}", error.explanation);
+            println!();
+            println!("Remediation: {}", error.remediation);
+        }
+        None => {
+            eprintln!("Unknown error code \"{code}\". The known codes are:");
+            for error in ERROR_EXPLANATIONS {
+                eprintln!("  {}", error.code);
+            }
             safe_exit(1)
         }
     }
 }
-async fn download_file(url: impl AsRef<str>) -> reqwest::Result<Bytes> {
+
+/// Sign a bond tx with a throwaway key of every scheme and verify its
+/// signature, without touching any on-disk state. Exits with an error if any
+/// of the checks fails.
+pub async fn self_test(_args: args::SelfTest) {
+    let mut failed = false;
+    for scheme in [SchemeType::Ed25519, SchemeType::Secp256k1] {
+        match self_test_scheme(scheme).await {
+            Ok(()) => println!("{scheme}: pass"),
+            Err(err) => {
+                println!("{scheme}: FAIL ({err})");
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        eprintln!("The self-test failed.");
+        safe_exit(1)
+    }
+    println!("The self-test passed.");
+}
+
+/// Sign a bond tx from a throwaway key of the given scheme, then check that
+/// its signature verifies and that it no longer does once the tx is altered.
+async fn self_test_scheme(scheme: SchemeType) -> Result<(), String> {
+    let sk = namada_sdk::wallet::gen_secret_key(scheme, &mut rand_core::OsRng);
+    let pk = sk.ref_to();
+    let mut wallet = CliWalletUtils::new(PathBuf::new());
+    wallet
+        .insert_keypair("self-test".to_string(), true, sk, None, None, None)
+        .ok_or("the throwaway key couldn't be added to an in-memory wallet")?;
+    let validator = genesis::transactions::EstablishedAccountTx {
+        vp: "vp_user".to_string(),
+        threshold: 1,
+        public_keys: vec![StringEncoded::new(pk.clone())],
+    }
+    .derive_address();
+    let bond = genesis::transactions::BondTx::<genesis::templates::Unvalidated> {
+        source: GenesisAddress::PublicKey(StringEncoded::new(pk.clone())),
+        validator,
+        amount: token::DenominatedAmount::native(token::Amount::native_whole(
+            1,
+        )),
+    };
+
+    let signed = sign_delegation_bond_tx(
+        bond.into(),
+        &RwLock::new(wallet),
+        &None,
+        &SignerBackend::Wallet,
+    )
+    .await
+    .map_err(|err| format!("signing failed: {err}"))?;
+    if !signed.signatures.contains_key(&StringEncoded::new(pk)) {
+        return Err("the tx wasn't signed with the throwaway key".to_string());
+    }
+    signed
+        .verify_sig(1)
+        .map_err(|err| format!("the signature didn't verify: {err}"))?;
+
+    let mut altered = signed;
+    altered.data.amount =
+        token::DenominatedAmount::native(token::Amount::native_whole(2));
+    if altered.verify_sig(1).is_ok() {
+        return Err("the signature verified for an altered tx".to_string());
+    }
+    Ok(())
+}
+
+/// Download a file, giving up once the given timeout elapses. When stdout is
+/// a terminal, the number of downloaded bytes is reported as it progresses.
+async fn download_file(
+    url: impl AsRef<str>,
+    timeout: std::time::Duration,
+) -> reqwest::Result<Bytes> {
     let url = url.as_ref();
-    let response = reqwest::get(url).await?;
+    let client = reqwest::Client::builder().timeout(timeout).build()?;
+    let mut response = client.get(url).send().await?;
     response.error_for_status_ref()?;
-    let contents = response.bytes().await?;
-    Ok(contents)
+    if !std::io::stdout().is_terminal() {
+        return response.bytes().await;
+    }
+    let total = response.content_length();
+    let mut contents = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        contents.extend_from_slice(&chunk);
+        match total {
+            Some(total) => {
+                print!("\rDownloaded {} / {} bytes", contents.len(), total)
+            }
+            None => print!("\rDownloaded {} bytes", contents.len()),
+        }
+        std::io::stdout().flush().unwrap();
+    }
+    println!();
+    Ok(Bytes::from(contents))
+}
+
+/// Download the file at the given URL like [`download_file`], retrying with
+/// an exponential backoff from `base_delay` up to `retries` times when the
+/// download fails on a transient error. Client errors, such as a missing file,
+/// are returned right away.
+async fn download_file_with_retry(
+    url: impl AsRef<str>,
+    timeout: std::time::Duration,
+    retries: u32,
+    base_delay: std::time::Duration,
+) -> reqwest::Result<Bytes> {
+    let url = url.as_ref();
+    let mut delay = base_delay;
+    let mut attempt: u32 = 0;
+    loop {
+        match download_file(url, timeout).await {
+            Err(error) if attempt < retries && is_transient(&error) => {
+                attempt = attempt.saturating_add(1);
+                eprintln!(
+                    "Downloading from {} failed: {}. Retrying in {:.1}s \
+                     (attempt {} of {}) ...",
+                    url,
+                    error,
+                    delay.as_secs_f64(),
+                    attempt,
+                    retries
+                );
+                namada_sdk::control_flow::time::sleep(delay).await;
+                delay = delay.saturating_mul(2);
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Whether the download error is worth retrying: timeouts, connection
+/// failures and server errors are, other errors, such as a 404, are not.
+fn is_transient(error: &reqwest::Error) -> bool {
+    match error.status() {
+        Some(status) => status.is_server_error(),
+        None => error.is_timeout() || error.is_connect() || error.is_body(),
+    }
+}
+
+/// The compression formats of the network config archives that can be
+/// unpacked when joining a network
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ArchiveFormat {
+    Gzip,
+    Xz,
+    Zstd,
+}
+
+impl ArchiveFormat {
+    /// All the formats, in the order their releases are looked for
+    const ALL: [Self; 3] = [Self::Gzip, Self::Zstd, Self::Xz];
+
+    /// The extension of the release archives in this format
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Gzip => "tar.gz",
+            Self::Xz => "tar.xz",
+            Self::Zstd => "tar.zst",
+        }
+    }
+
+    /// Detect the format of an archive from its magic bytes
+    fn detect(archive: &[u8]) -> Option<Self> {
+        if archive.starts_with(&[0x1f, 0x8b]) {
+            Some(Self::Gzip)
+        } else if archive.starts_with(&[0xfd, b'7', b'z', b'X', b'Z', 0x00]) {
+            Some(Self::Xz)
+        } else if archive.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+            Some(Self::Zstd)
+        } else {
+            None
+        }
+    }
+
+    /// Get a reader of the decompressed tar archive. Only gzip archives can
+    /// be decompressed on wasm32, as the xz and zstd decoders are C libraries.
+    fn decoder<'a>(
+        self,
+        archive: &'a [u8],
+    ) -> std::io::Result<Box<dyn std::io::Read + 'a>> {
+        Ok(match self {
+            Self::Gzip => Box::new(GzDecoder::new(archive)),
+            #[cfg(not(target_family = "wasm"))]
+            Self::Xz => Box::new(xz2::read::XzDecoder::new(archive)),
+            #[cfg(not(target_family = "wasm"))]
+            Self::Zstd => Box::new(zstd::stream::read::Decoder::new(archive)?),
+            #[cfg(target_family = "wasm")]
+            Self::Xz | Self::Zstd => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    format!(
+                        "{} archives can't be decompressed on wasm32",
+                        self.extension()
+                    ),
+                ));
+            }
+        })
+    }
+
+    /// Decompress the archive and unpack its contents into the given dir
+    fn unpack(self, archive: &[u8], dest: &Path) -> std::io::Result<()> {
+        tar::Archive::new(self.decoder(archive)?).unpack(dest)
+    }
+
+    /// Decompress the archive and list the paths of its entries, without
+    /// unpacking them
+    fn entries(self, archive: &[u8]) -> std::io::Result<Vec<PathBuf>> {
+        tar::Archive::new(self.decoder(archive)?)
+            .entries()?
+            .map(|entry| Ok(entry?.path()?.into_owned()))
+            .collect()
+    }
+}
+
+/// Print the paths that unpacking the network config archive would write
+/// into the base dir, flagging the ones that already exist.
+fn print_archive_targets(
+    global_args: &args::Global,
+    format: ArchiveFormat,
+    archive: &[u8],
+    release_filename: &str,
+    base_dir: &Path,
+) {
+    let entries = format.entries(archive).unwrap_or_else(|err| {
+        eprintln!(
+            "Failed to read the network config archive {release_filename} as \
+             a {} archive: {err}",
+            format.extension()
+        );
+        safe_exit(1)
+    });
+    println!(
+        "Dry run: unpacking {release_filename} would write the following \
+         paths:"
+    );
+    let mut existing = 0_usize;
+    for entry in entries {
+        let target = base_dir.join(entry);
+        if target.exists() && !target.is_dir() {
+            existing += 1;
+            println!(
+                "  {} (already exists)",
+                global_args.redact_base_dir(target.display())
+            );
+        } else {
+            println!("  {}", global_args.redact_base_dir(target.display()));
+        }
+    }
+    if existing == 0 {
+        println!("None of these paths exist yet. Nothing was written.");
+    } else {
+        println!(
+            "{existing} of these paths already exist and would be \
+             overwritten. Nothing was written."
+        );
+    }
 }
 
 fn network_configs_url_prefix(chain_id: &ChainId) -> String {
@@ -836,6 +2166,15 @@ fn network_configs_dir() -> Option<String> {
     std::env::var(ENV_VAR_NETWORK_CONFIGS_DIR).ok()
 }
 
+/// The name of the pre-genesis directory under the base dir, which is
+/// [`PRE_GENESIS_DIR`] unless overridden with [`ENV_VAR_PRE_GENESIS_DIR`].
+pub fn pre_genesis_dir_name() -> String {
+    std::env::var(ENV_VAR_PRE_GENESIS_DIR)
+        .ok()
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| PRE_GENESIS_DIR.to_string())
+}
+
 /// Write the node key into tendermint config dir.
 pub fn write_tendermint_node_key(
     tm_home_dir: &Path,
@@ -884,7 +2223,7 @@ pub fn validator_pre_genesis_txs_file(pre_genesis_path: &Path) -> PathBuf {
 
 /// The default validator pre-genesis directory
 pub fn validator_pre_genesis_dir(base_dir: &Path, alias: &str) -> PathBuf {
-    base_dir.join(PRE_GENESIS_DIR).join(alias)
+    base_dir.join(pre_genesis_dir_name()).join(alias)
 }
 
 /// Validate genesis templates. Exits process if invalid.
@@ -897,113 +2236,2106 @@ pub fn validate_genesis_templates(
     }
 }
 
-async fn append_signature_to_signed_toml(
-    input_txs: &Path,
-    wallet: &RwLock<Wallet<CliWalletUtils>>,
-    use_device: bool,
-    device_transport: DeviceTransport,
-) -> genesis::transactions::Transactions<genesis::templates::Unvalidated> {
-    // Parse signed txs toml to append new signatures to
-    let mut genesis_txs = genesis::templates::read_transactions(input_txs)
-        .unwrap_or_else(|_| {
+/// Check that the pre-genesis wallet and validator wallets of the base dir
+/// agree with the genesis txs of the given templates. Exits with an error if
+/// they don't.
+pub fn check_consistency(
+    global_args: args::Global,
+    args::CheckConsistency { path }: args::CheckConsistency,
+) {
+    let txs_path = if path.is_dir() {
+        path.join(genesis::templates::TRANSACTIONS_FILE_NAME)
+    } else {
+        path
+    };
+    let txs = genesis::templates::read_transactions(&txs_path).unwrap_or_else(
+        |err| {
             eprintln!(
-                "Unable to parse the TOML from path: {}",
-                input_txs.to_string_lossy()
+                "Unable to read the genesis txs from {}. Failed with: {err}",
+                global_args.redact_base_dir(txs_path.to_string_lossy())
             );
             safe_exit(1)
-        });
-    // Sign bond txs and append signatures to toml file
-    if let Some(txs) = genesis_txs.bond {
-        let mut bonds = vec![];
-        for tx in txs {
-            bonds.push(
-                sign_delegation_bond_tx(
-                    tx,
-                    wallet,
-                    &genesis_txs.established_account,
-                    use_device,
-                    device_transport,
-                )
-                .await,
-            );
+        },
+    );
+    let wallet = try_load_pre_genesis_wallet(
+        &global_args.base_dir,
+        &global_args.wallet_options(),
+    )
+    .ok()
+    .map(|(wallet, _)| wallet);
+    if wallet.is_none() {
+        println!(
+            "No pre-genesis wallet was found, only checking the validator \
+             wallets."
+        );
+    }
+    let genesis_validators: Vec<(Address, common::PublicKey)> = txs
+        .validator_account
+        .iter()
+        .flatten()
+        .map(|tx| {
+            (
+                Address::Established(tx.data.address.raw.clone()),
+                tx.data.protocol_key.pk.raw.clone(),
+            )
+        })
+        .collect();
+    let genesis_accounts: BTreeSet<Address> = txs
+        .established_account
+        .iter()
+        .flatten()
+        .map(|account| account.derive_address())
+        .collect();
+    let mut problems = vec![];
+
+    // The validators of the base dir must be genesis validators, matched by
+    // their protocol key which is stored unencrypted
+    let mut local_validators = BTreeSet::new();
+    for (alias, store) in read_validator_stores(&global_args) {
+        let protocol_pk = store.validator_keys.protocol_keypair.ref_to();
+        let Some((address, _)) =
+            genesis_validators.iter().find(|(_, pk)| *pk == protocol_pk)
+        else {
+            problems.push(format!(
+                "The validator \"{alias}\" of the base dir has no validator \
+                 account tx in the genesis config."
+            ));
+            continue;
+        };
+        local_validators.insert(address.clone());
+        let wallet_address = wallet
+            .as_ref()
+            .and_then(|wallet| wallet.find_address(&alias));
+        if let Some(wallet_address) = wallet_address {
+            if *wallet_address != *address {
+                problems.push(format!(
+                    "The alias \"{alias}\" is {wallet_address} in the \
+                     wallet, but the validator \"{alias}\" is {address} in \
+                     the genesis config."
+                ));
+            }
         }
-        genesis_txs.bond = Some(bonds);
     }
-    // Sign validator txs and append signatures to toml file
-    if let Some(txs) = genesis_txs.validator_account {
-        let mut validator_accounts = vec![];
-        for tx in txs {
-            validator_accounts.push(
-                sign_validator_account_tx(
+
+    if let Some(wallet) = &wallet {
+        for (address, _) in &genesis_validators {
+            if let Some(alias) = wallet.find_alias(address) {
+                if !local_validators.contains(address) {
+                    problems.push(format!(
+                        "The genesis validator {address}, \"{alias}\" in the \
+                         wallet, has no validator wallet in the base dir."
+                    ));
+                }
+            }
+        }
+
+        let wallet_pks: BTreeSet<common::PublicKey> =
+            wallet.get_public_keys().into_values().collect();
+        for account in txs.established_account.iter().flatten() {
+            let address = account.derive_address();
+            let is_controlled = account
+                .public_keys
+                .iter()
+                .any(|pk| wallet_pks.contains(&pk.raw));
+            if is_controlled && wallet.find_alias(&address).is_none() {
+                problems.push(format!(
+                    "The genesis established account {address} is controlled \
+                     by keys of the wallet, but has no alias in it."
+                ));
+            }
+        }
+
+        let wallet_addresses: BTreeMap<String, Address> =
+            wallet.get_addresses().into_iter().collect();
+        for (alias, address) in wallet_addresses {
+            if matches!(address, Address::Established(_))
+                && !genesis_accounts.contains(&address)
+            {
+                problems.push(format!(
+                    "The address \"{alias}\" ({address}) of the wallet is not \
+                     an established account of the genesis config."
+                ));
+            }
+        }
+    }
+
+    if problems.is_empty() {
+        println!(
+            "The pre-genesis wallets of the base dir are consistent with the \
+             genesis config."
+        );
+    } else {
+        for problem in &problems {
+            eprintln!("{problem}");
+        }
+        eprintln!(
+            "Found {} inconsistencies between the pre-genesis wallets of the \
+             base dir and the genesis config.",
+            problems.len()
+        );
+        safe_exit(1)
+    }
+}
+
+/// Read the stores of the validator pre-genesis wallets of the base dir,
+/// keyed by the validator alias, without decrypting their keys.
+fn read_validator_stores(
+    global_args: &args::Global,
+) -> BTreeMap<String, namada_sdk::wallet::pre_genesis::ValidatorStore> {
+    let Ok(entries) =
+        fs::read_dir(global_args.base_dir.join(pre_genesis_dir_name()))
+    else {
+        return BTreeMap::new();
+    };
+    let mut stores = BTreeMap::new();
+    for entry in entries.flatten() {
+        let wallet_file = pre_genesis::validator_file_name(entry.path());
+        if !wallet_file.is_file() {
+            continue;
+        }
+        let alias = entry.file_name().to_string_lossy().into_owned();
+        let store = fs::read(&wallet_file)
+            .map_err(|err| err.to_string())
+            .and_then(|data| {
+                namada_sdk::wallet::pre_genesis::ValidatorStore::decode(data)
+                    .map_err(|err| err.to_string())
+            });
+        match store {
+            Ok(store) => {
+                stores.insert(alias, store);
+            }
+            Err(err) => eprintln!(
+                "Warning: skipping the validator wallet at {}, which \
+                 couldn't be read: {err}",
+                global_args.redact_base_dir(wallet_file.to_string_lossy())
+            ),
+        }
+    }
+    stores
+}
+
+/// Verify the signatures of the genesis txs of a file, spreading the
+/// verification over a number of worker threads.
+pub fn verify_genesis_txs(
+    global_args: args::Global,
+    args::VerifyGenesisTxs { path, jobs }: args::VerifyGenesisTxs,
+) {
+    let txs =
+        genesis::templates::read_transactions(&path).unwrap_or_else(|err| {
+            eprintln!(
+                "Unable to read the genesis txs from {}. Failed with: {err}",
+                global_args.redact_base_dir(path.to_string_lossy())
+            );
+            safe_exit(1)
+        });
+    let checks = genesis::transactions::verify_signatures(&txs, jobs)
+        .unwrap_or_else(|err| {
+            eprintln!("{err}");
+            safe_exit(1)
+        });
+    if checks.is_empty() {
+        println!("No signed transactions were found.");
+        return;
+    }
+    let mut num_invalid = 0_usize;
+    for genesis::transactions::SignatureCheck { tx, result } in &checks {
+        match result {
+            Ok(()) => println!("{tx}: valid"),
+            Err(err) => {
+                num_invalid = num_invalid.saturating_add(1);
+                println!("{tx}: INVALID: {err}");
+            }
+        }
+    }
+    println!(
+        "{} of {} transactions have valid signatures.",
+        checks.len().saturating_sub(num_invalid),
+        checks.len()
+    );
+    if num_invalid > 0 {
+        safe_exit(1)
+    }
+}
+
+async fn append_signature_to_signed_toml(
+    global_args: &args::Global,
+    input_txs: &Path,
+    wallet: &RwLock<Wallet<CliWalletUtils>>,
+    backend: &SignerBackend,
+) -> genesis::transactions::Transactions<genesis::templates::Unvalidated> {
+    // Parse signed txs toml to append new signatures to
+    let mut genesis_txs = genesis::templates::read_transactions(input_txs)
+        .unwrap_or_else(|_| {
+            eprintln!(
+                "Unable to parse the TOML from path: {}",
+                global_args.redact_base_dir(input_txs.to_string_lossy())
+            );
+            safe_exit(1)
+        });
+    // Sign bond txs and append signatures to toml file
+    if let Some(txs) = genesis_txs.bond {
+        let mut bonds = vec![];
+        for tx in txs {
+            bonds.push(
+                sign_delegation_bond_tx(
+                    tx,
+                    wallet,
+                    &genesis_txs.established_account,
+                    backend,
+                )
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("Failed to sign a bond tx: {err}");
+                    safe_exit(1)
+                }),
+            );
+        }
+        genesis_txs.bond = Some(bonds);
+    }
+    // Sign validator txs and append signatures to toml file
+    if let Some(txs) = genesis_txs.validator_account {
+        let mut validator_accounts = vec![];
+        for tx in txs {
+            validator_accounts.push(
+                sign_validator_account_tx(
                     Either::Right(tx),
                     wallet,
                     genesis_txs.established_account.as_ref().expect(
                         "Established account txs required when signing \
                          validator account txs",
                     ),
-                    use_device,
-                    device_transport,
+                    backend,
+                )
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("Failed to sign a validator account tx: {err}");
+                    safe_exit(1)
+                }),
+            );
+        }
+        genesis_txs.validator_account = Some(validator_accounts);
+    }
+    genesis_txs
+}
+
+/// The backend to sign genesis txs with, logging in to the PKCS#11 token of
+/// the given config if any.
+fn genesis_signer_backend(
+    global_args: &args::Global,
+    use_device: bool,
+    device_transport: DeviceTransport,
+    retry_rejections: bool,
+    pkcs11: Option<&Pkcs11Config>,
+) -> SignerBackend {
+    match pkcs11 {
+        Some(config) => SignerBackend::Pkcs11(
+            Pkcs11Signer::open(config, global_args.tty_enabled())
+                .unwrap_or_else(|err| {
+                    eprintln!("{err}");
+                    safe_exit(1)
+                }),
+        ),
+        None => SignerBackend::from_device_args(
+            use_device,
+            device_transport,
+            global_args.debug_apdu_enabled(),
+            retry_rejections,
+            global_args.tty_enabled(),
+        ),
+    }
+}
+
+/// Resolve the source of a bond given as an alias of the wallet to its public
+/// key or established address. Anything else must be a raw public key or
+/// established address.
+fn resolve_bond_source(
+    wallet: &Wallet<CliWalletUtils>,
+    raw: &str,
+) -> Result<GenesisAddress, String> {
+    let raw = raw.trim();
+    if let Ok(pk) = wallet.find_public_key(raw) {
+        return Ok(GenesisAddress::PublicKey(StringEncoded::new(pk)));
+    }
+    match wallet.find_address(raw).map(|address| address.into_owned()) {
+        Some(Address::Established(address)) => {
+            Ok(GenesisAddress::EstablishedAddress(address))
+        }
+        Some(Address::Implicit(ImplicitAddress(pkh))) => wallet
+            .find_public_key_by_pkh(&pkh)
+            .map(|pk| GenesisAddress::PublicKey(StringEncoded::new(pk)))
+            .map_err(|_| {
+                format!(
+                    "The bond source alias \"{raw}\" is of an implicit \
+                     address whose public key isn't in the wallet."
+                )
+            }),
+        Some(address) => Err(format!(
+            "The bond source alias \"{raw}\" is of the address {address}, \
+             which can't be the source of a genesis bond."
+        )),
+        None => raw.parse().map_err(|err| {
+            format!(
+                "The bond source \"{raw}\" is neither an alias of the \
+                 wallet, nor a public key or an established address: {err}"
+            )
+        }),
+    }
+}
+
+/// Resolve the validator of a bond given as an alias of the wallet to its
+/// address. Anything else must be a raw address.
+fn resolve_bond_validator(
+    wallet: &Wallet<CliWalletUtils>,
+    raw: &str,
+) -> Result<Address, String> {
+    let raw = raw.trim();
+    match wallet.find_address(raw) {
+        Some(address) => Ok(address.into_owned()),
+        None => Address::decode(raw).map_err(|err| {
+            format!(
+                "The bond validator \"{raw}\" is neither an alias of the \
+                 wallet nor an address: {err}"
+            )
+        }),
+    }
+}
+
+/// Sign genesis transactions.
+pub async fn sign_genesis_tx(
+    global_args: args::Global,
+    args: args::SignGenesisTxs,
+) {
+    if args.use_device {
+        ensure_device_reachable(args.device_transport);
+        if !args.skip_version_check {
+            genesis::transactions::check_device_app_version(
+                args.device_transport,
+                global_args.debug_apdu_enabled(),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!(
+                    "{err}\nNo signatures were made. Pass \
+                     `--skip-version-check` to sign anyway."
+                );
+                safe_exit(1)
+            });
+        }
+    }
+    if args.stream {
+        return sign_genesis_tx_streamed(global_args, args).await;
+    }
+    if args.fill_missing {
+        return sign_genesis_tx_missing(global_args, args).await;
+    }
+    let args::SignGenesisTxs {
+        source,
+        validator,
+        amount,
+        validator_alias,
+        use_device,
+        device_transport,
+        expected_signers,
+        skip_device_verify,
+        skip_version_check: _,
+        max_txs,
+        detached,
+        only_validator,
+        yes,
+        source_sha256,
+        download_timeout,
+        toml_key_order,
+        on_error,
+        stream: _,
+        fill_missing: _,
+        key_file,
+        extra_wallet_dirs,
+        print_unsigned,
+        output,
+        machine_summary,
+        assumed_amount_unit,
+        lock_keys_in_memory,
+        pkcs11,
+        include_signer_address,
+        interactive_select,
+        audit_log,
+        signature_encoding,
+        hide_amounts,
+        no_retry,
+    } = args;
+    let wallet = load_genesis_signing_wallet(
+        &global_args,
+        key_file.as_deref(),
+        &extra_wallet_dirs,
+    );
+    let wallet_lock = RwLock::new(wallet);
+    let backend = genesis_signer_backend(
+        &global_args,
+        use_device,
+        device_transport,
+        !no_retry,
+        pkcs11.as_ref(),
+    );
+    let maybe_pre_genesis_wallet = validator_alias.and_then(|alias| {
+        let pre_genesis_dir =
+            validator_pre_genesis_dir(&global_args.base_dir, &alias);
+        pre_genesis::load(&pre_genesis_dir, &global_args.wallet_options()).ok()
+    });
+    let contents = if is_url(&source) {
+        fetch_unsigned_txs(&source, source_sha256.as_deref(), download_timeout)
+            .await
+    } else {
+        let amount =
+            genesis::utils::parse_native_amount(&amount, assumed_amount_unit)
+                .unwrap_or_else(|err| {
+                    eprintln!("{err}");
+                    safe_exit(1)
+                });
+        let resolved = {
+            let wallet = wallet_lock.read().await;
+            resolve_bond_source(&wallet, &source).and_then(|source| {
+                resolve_bond_validator(&wallet, &validator)
+                    .map(|validator| (source, validator))
+            })
+        };
+        let (source, validator) = resolved.unwrap_or_else(|err| {
+            eprintln!("{err}");
+            safe_exit(1)
+        });
+        let bond = Bond {
+            source: source.to_string(),
+            validator: validator.encode(),
+            amount: token::DenominatedAmount::native(amount).to_string(),
+        };
+
+        // Create the bond list
+        let bond_list = BondList { bond: vec![bond] };
+
+        // Serialize the bond list to a TOML string
+        let toml_content = toml::to_string(&bond_list).unwrap_or_else(|err| {
+            eprintln!("Unable to serialize to TOML. Failed with {err}.");
+            safe_exit(1)
+        });
+        toml_content.into_bytes()
+    };
+    let contents = decrypt_txs_if_encrypted(&global_args, contents, &source);
+    // Sign a subset of the input txs (the ones whose keys we own)
+    let mut unsigned = genesis::transactions::parse_unsigned(&contents)
+        .unwrap_or_else(|err| {
+            eprintln!("Unable to parse the unsigned txs. Failed with: {err}");
+            safe_exit(1)
+        });
+
+    if let Some(only_validator) = &only_validator {
+        if let Some(bonds) = unsigned.bond.as_mut() {
+            bonds.retain(|bond| &bond.validator == only_validator);
+        }
+        if unsigned.bond.as_ref().map_or(true, Vec::is_empty) {
+            eprintln!(
+                "Warning: there are no bonds to the validator \
+                 {only_validator} to sign."
+            );
+            return;
+        }
+    }
+
+    let num_txs = unsigned.num_txs();
+    if num_txs > max_txs {
+        eprintln!(
+            "Refusing to sign {num_txs} transactions, which is more than the \
+             maximum of {max_txs}. Pass `--max-txs {num_txs}` to sign them \
+             anyway."
+        );
+        safe_exit(1)
+    }
+
+    if print_unsigned {
+        print_unsigned_txs(&unsigned, hide_amounts);
+    }
+    if interactive_select {
+        select_unsigned_txs(&global_args, &mut unsigned, hide_amounts)
+            .unwrap_or_else(|err| {
+                eprintln!("{err}\nNo signatures were made.");
+                safe_exit(1)
+            });
+    }
+    let num_signable = unsigned
+        .validator_account
+        .as_ref()
+        .map_or(0, Vec::len)
+        .saturating_add(unsigned.bond.as_ref().map_or(0, Vec::len));
+
+    if use_device && !skip_device_verify {
+        verify_device_signers(
+            &global_args,
+            &unsigned,
+            &*wallet_lock.read().await,
+            device_transport,
+        )
+        .await
+        .unwrap_or_else(|err| {
+            eprintln!(
+                "Device verification failed: {err}\nNo signatures were made. \
+                 Pass `--skip-device-verify` to sign anyway."
+            );
+            safe_exit(1)
+        });
+    }
+
+    if use_device && !yes {
+        confirm_device_addresses(
+            &global_args,
+            &unsigned,
+            &*wallet_lock.read().await,
+            device_transport,
+        )
+        .await
+        .unwrap_or_else(|err| {
+            eprintln!("{err}\nNo signatures were made.");
+            safe_exit(1)
+        });
+    }
+
+    let (signed, failures) = genesis::transactions::sign_txs_with(
+        unsigned,
+        &wallet_lock,
+        maybe_pre_genesis_wallet.as_ref(),
+        &backend,
+        on_error,
+        lock_keys_in_memory,
+    )
+    .await
+    .unwrap_or_else(|err| {
+        eprintln!("Failed to sign the genesis txs: {err}");
+        safe_exit(1)
+    });
+
+    if let Some(expected_signers) = expected_signers {
+        check_expected_signers(
+            &expected_signers,
+            &signed,
+            &*wallet_lock.read().await,
+            maybe_pre_genesis_wallet.as_ref(),
+        );
+    }
+
+    let transactions = if detached {
+        let signatures = genesis::transactions::detach_signatures(&signed)
+            .unwrap_or_else(|err| {
+                eprintln!("{err}");
+                safe_exit(1)
+            });
+        genesis::utils::to_toml_string(&signatures, toml_key_order)
+            .unwrap_or_else(|err| {
+                eprintln!("Unable to serialize the signed txs to TOML: {err}");
+                safe_exit(1)
+            })
+    } else {
+        signed_txs_to_toml(
+            &signed,
+            include_signer_address,
+            signature_encoding,
+            toml_key_order,
+        )
+    };
+    write_signed_txs(&global_args, &transactions, output.as_deref());
+
+    if let Some(audit_log) = audit_log {
+        append_audit_log(
+            &global_args,
+            &audit_log,
+            &SigningAuditRecord::new(
+                &contents,
+                signer_pks(&signed),
+                num_signable,
+                count_signed(&signed),
+                failures.len(),
+                use_device,
+            ),
+        );
+    }
+    if machine_summary {
+        print_machine_summary(num_signable, count_signed(&signed), use_device);
+    }
+
+    if !failures.is_empty() {
+        eprintln!(
+            "{} transaction(s) failed to be signed and were left out:",
+            failures.len()
+        );
+        for genesis::transactions::SignFailure { tx, reason } in failures {
+            eprintln!("  {tx}: {reason}");
+        }
+        safe_exit(1)
+    }
+}
+
+/// Sign the unsigned genesis txs of the given source one at a time, printing
+/// each as soon as it is signed. The established account txs are kept, as
+/// signing the txs that follow them may require them.
+async fn sign_genesis_tx_streamed(
+    global_args: args::Global,
+    args::SignGenesisTxs {
+        source,
+        validator_alias,
+        use_device,
+        device_transport,
+        skip_device_verify,
+        max_txs,
+        detached,
+        only_validator,
+        yes,
+        source_sha256,
+        download_timeout,
+        toml_key_order,
+        on_error,
+        key_file,
+        extra_wallet_dirs,
+        print_unsigned,
+        lock_keys_in_memory,
+        pkcs11,
+        include_signer_address,
+        signature_encoding,
+        hide_amounts,
+        no_retry,
+        ..
+    }: args::SignGenesisTxs,
+) {
+    let wallet = load_genesis_signing_wallet(
+        &global_args,
+        key_file.as_deref(),
+        &extra_wallet_dirs,
+    );
+    let wallet_lock = RwLock::new(wallet);
+    let backend = genesis_signer_backend(
+        &global_args,
+        use_device,
+        device_transport,
+        !no_retry,
+        pkcs11.as_ref(),
+    );
+    let maybe_pre_genesis_wallet = validator_alias.and_then(|alias| {
+        let pre_genesis_dir =
+            validator_pre_genesis_dir(&global_args.base_dir, &alias);
+        pre_genesis::load(&pre_genesis_dir, &global_args.wallet_options()).ok()
+    });
+    let downloaded = if is_url(&source) {
+        let contents = fetch_unsigned_txs(
+            &source,
+            source_sha256.as_deref(),
+            download_timeout,
+        )
+        .await;
+        Some(decrypt_txs_if_encrypted(&global_args, contents, &source))
+    } else {
+        read_encrypted_txs(&global_args, &source)
+    };
+    let num_txs = UnsignedTxReader::new(open_unsigned_txs(
+        &global_args,
+        &source,
+        downloaded.as_deref(),
+    ))
+    .count_txs()
+    .unwrap_or_else(|err| {
+        eprintln!("Unable to parse the unsigned txs. Failed with: {err}");
+        safe_exit(1)
+    });
+    if num_txs > max_txs {
+        eprintln!(
+            "Refusing to sign {num_txs} transactions, which is more than the \
+             maximum of {max_txs}. Pass `--max-txs {num_txs}` to sign them \
+             anyway."
+        );
+        safe_exit(1)
+    }
+
+    let mut established_accounts = vec![];
+    let mut num_bonds: usize = 0;
+    let mut failures = vec![];
+    for tx in UnsignedTxReader::new(open_unsigned_txs(
+        &global_args,
+        &source,
+        downloaded.as_deref(),
+    )) {
+        let tx = tx.unwrap_or_else(|err| {
+            eprintln!("Unable to parse the unsigned txs. Failed with: {err}");
+            safe_exit(1)
+        });
+        let tx = match tx {
+            UnsignedTx::EstablishedAccount(account) => {
+                // Established accounts are output as they are
+                let established =
+                    Transactions::<genesis::templates::Unvalidated> {
+                        established_account: Some(vec![account]),
+                        ..Default::default()
+                    };
+                if print_unsigned {
+                    print_unsigned_txs(
+                        &UnsignedTransactions {
+                            established_account: established
+                                .established_account
+                                .clone(),
+                            ..Default::default()
+                        },
+                        hide_amounts,
+                    );
+                }
+                if !detached {
+                    print_toml_chunk(&established, toml_key_order);
+                }
+                established_accounts.extend(
+                    established.established_account.into_iter().flatten(),
+                );
+                continue;
+            }
+            UnsignedTx::Bond(bond)
+                if only_validator
+                    .as_ref()
+                    .is_some_and(|validator| &bond.validator != validator) =>
+            {
+                continue;
+            }
+            UnsignedTx::Bond(bond) => {
+                num_bonds = num_bonds.saturating_add(1);
+                UnsignedTx::Bond(bond)
+            }
+            tx => tx,
+        };
+        let mut unsigned = UnsignedTransactions::from(tx);
+        if print_unsigned {
+            print_unsigned_txs(&unsigned, hide_amounts);
+        }
+        unsigned.established_account =
+            Some(std::mem::take(&mut established_accounts));
+
+        if use_device && !skip_device_verify {
+            verify_device_signers(
+                &global_args,
+                &unsigned,
+                &*wallet_lock.read().await,
+                device_transport,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!(
+                    "Device verification failed: {err}\nPass \
+                     `--skip-device-verify` to sign anyway."
+                );
+                safe_exit(1)
+            });
+        }
+        if use_device && !yes {
+            confirm_device_addresses(
+                &global_args,
+                &unsigned,
+                &*wallet_lock.read().await,
+                device_transport,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("{err}");
+                safe_exit(1)
+            });
+        }
+
+        let (mut signed, mut tx_failures) =
+            genesis::transactions::sign_txs_with(
+                unsigned,
+                &wallet_lock,
+                maybe_pre_genesis_wallet.as_ref(),
+                &backend,
+                on_error,
+                lock_keys_in_memory,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("Failed to sign the genesis txs: {err}");
+                safe_exit(1)
+            });
+        failures.append(&mut tx_failures);
+        // Take back the established accounts, which were already output
+        established_accounts =
+            signed.established_account.take().unwrap_or_default();
+
+        if detached {
+            let signatures = genesis::transactions::detach_signatures(&signed)
+                .unwrap_or_else(|err| {
+                    eprintln!("{err}");
+                    safe_exit(1)
+                });
+            if !signatures.signature.is_empty() {
+                print_toml_chunk(&signatures, toml_key_order);
+            }
+        } else if signed.validator_account.iter().flatten().next().is_some()
+            || signed.bond.iter().flatten().next().is_some()
+        {
+            println!(
+                "{}",
+                signed_txs_to_toml(
+                    &signed,
+                    include_signer_address,
+                    signature_encoding,
+                    toml_key_order
                 )
-                .await,
             );
         }
-        genesis_txs.validator_account = Some(validator_accounts);
     }
-    genesis_txs
+
+    if let Some(only_validator) = &only_validator {
+        if num_bonds == 0 {
+            eprintln!(
+                "Warning: there are no bonds to the validator \
+                 {only_validator} to sign."
+            );
+        }
+    }
+    if !failures.is_empty() {
+        eprintln!(
+            "{} transaction(s) failed to be signed and were left out:",
+            failures.len()
+        );
+        for genesis::transactions::SignFailure { tx, reason } in failures {
+            eprintln!("  {tx}: {reason}");
+        }
+        safe_exit(1)
+    }
+}
+
+/// Sign the txs of the signed genesis txs of the given source that lack a
+/// signature of this wallet's keys, printing them all with the new
+/// signatures merged in.
+async fn sign_genesis_tx_missing(
+    global_args: args::Global,
+    args::SignGenesisTxs {
+        source,
+        use_device,
+        device_transport,
+        expected_signers,
+        skip_device_verify,
+        max_txs,
+        yes,
+        validator_alias,
+        source_sha256,
+        download_timeout,
+        toml_key_order,
+        key_file,
+        extra_wallet_dirs,
+        output,
+        machine_summary,
+        lock_keys_in_memory,
+        pkcs11,
+        include_signer_address,
+        audit_log,
+        signature_encoding,
+        no_retry,
+        ..
+    }: args::SignGenesisTxs,
+) {
+    let wallet = load_genesis_signing_wallet(
+        &global_args,
+        key_file.as_deref(),
+        &extra_wallet_dirs,
+    );
+    let wallet_lock = RwLock::new(wallet);
+    let backend = genesis_signer_backend(
+        &global_args,
+        use_device,
+        device_transport,
+        !no_retry,
+        pkcs11.as_ref(),
+    );
+    let contents = if is_url(&source) {
+        fetch_unsigned_txs(&source, source_sha256.as_deref(), download_timeout)
+            .await
+    } else {
+        fs::read(&source).unwrap_or_else(|err| {
+            eprintln!(
+                "Unable to read the signed txs from {}. Failed with: {err}",
+                global_args.redact_base_dir(&source)
+            );
+            safe_exit(1)
+        })
+    };
+    let contents = decrypt_txs_if_encrypted(&global_args, contents, &source);
+    let signed =
+        genesis::transactions::parse_signed(&contents).unwrap_or_else(|err| {
+            eprintln!("Unable to parse the signed txs. Failed with: {err}");
+            safe_exit(1)
+        });
+    let prior_signers = signer_pks(&signed);
+
+    let num_txs = signed
+        .validator_account
+        .as_ref()
+        .map_or(0, Vec::len)
+        .saturating_add(signed.bond.as_ref().map_or(0, Vec::len));
+    if num_txs > max_txs {
+        eprintln!(
+            "Refusing to sign {num_txs} transactions, which is more than the \
+             maximum of {max_txs}. Pass `--max-txs {num_txs}` to sign them \
+             anyway."
+        );
+        safe_exit(1)
+    }
+
+    if use_device && (!skip_device_verify || !yes) {
+        // The device checks are made on the txs without their signatures
+        let unsigned = UnsignedTransactions {
+            established_account: signed.established_account.clone(),
+            validator_account: signed
+                .validator_account
+                .as_ref()
+                .map(|txs| txs.iter().map(|tx| (&tx.data).into()).collect()),
+            bond: signed
+                .bond
+                .as_ref()
+                .map(|txs| txs.iter().map(|tx| tx.data.clone()).collect()),
+        };
+        if !skip_device_verify {
+            verify_device_signers(
+                &global_args,
+                &unsigned,
+                &*wallet_lock.read().await,
+                device_transport,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!(
+                    "Device verification failed: {err}\nNo signatures were \
+                     made. Pass `--skip-device-verify` to sign anyway."
+                );
+                safe_exit(1)
+            });
+        }
+        if !yes {
+            confirm_device_addresses(
+                &global_args,
+                &unsigned,
+                &*wallet_lock.read().await,
+                device_transport,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("{err}\nNo signatures were made.");
+                safe_exit(1)
+            });
+        }
+    }
+
+    let (signed, num_signed) = genesis::transactions::sign_missing(
+        signed,
+        &wallet_lock,
+        &backend,
+        lock_keys_in_memory,
+    )
+    .await
+    .unwrap_or_else(|err| {
+        eprintln!("{err}");
+        safe_exit(1)
+    });
+    eprintln!(
+        "Signed {num_signed} transaction(s) that lacked a signature of this \
+         wallet's keys."
+    );
+
+    if let Some(expected_signers) = expected_signers {
+        let maybe_pre_genesis_wallet = validator_alias.and_then(|alias| {
+            let pre_genesis_dir =
+                validator_pre_genesis_dir(&global_args.base_dir, &alias);
+            pre_genesis::load(&pre_genesis_dir, &global_args.wallet_options())
+                .ok()
+        });
+        check_expected_signers(
+            &expected_signers,
+            &signed,
+            &*wallet_lock.read().await,
+            maybe_pre_genesis_wallet.as_ref(),
+        );
+    }
+    let transactions = signed_txs_to_toml(
+        &signed,
+        include_signer_address,
+        signature_encoding,
+        toml_key_order,
+    );
+    write_signed_txs(&global_args, &transactions, output.as_deref());
+
+    if let Some(audit_log) = audit_log {
+        let new_signers = signer_pks(&signed)
+            .difference(&prior_signers)
+            .cloned()
+            .collect();
+        append_audit_log(
+            &global_args,
+            &audit_log,
+            &SigningAuditRecord::new(
+                &contents,
+                new_signers,
+                num_txs,
+                num_signed,
+                0,
+                use_device,
+            ),
+        );
+    }
+    if machine_summary {
+        print_machine_summary(num_txs, num_signed, use_device);
+    }
+}
+
+/// A record of a signing run, appended to the `--audit-log` file. It only
+/// holds public data, so that no secret key, password or PIN ever ends up
+/// in the log.
+#[derive(Serialize)]
+struct SigningAuditRecord {
+    /// When the run ended, in RFC 3339 format
+    timestamp: String,
+    /// Hex-encoded SHA-256 digest of the input txs
+    source_sha256: String,
+    /// The public keys that made the new signatures
+    signers: BTreeSet<String>,
+    /// The number of validator account and bond txs to sign
+    txs: usize,
+    /// The number of those txs that were signed
+    signed: usize,
+    /// The number of those txs that failed to be signed
+    failed: usize,
+    /// Whether a hardware wallet was signed with
+    device: bool,
+}
+
+impl SigningAuditRecord {
+    fn new(
+        source: &[u8],
+        signers: BTreeSet<String>,
+        txs: usize,
+        signed: usize,
+        failed: usize,
+        device: bool,
+    ) -> Self {
+        #[allow(clippy::disallowed_methods)]
+        let timestamp = DateTimeUtc::now().to_rfc3339();
+        Self {
+            timestamp,
+            source_sha256: data_encoding::HEXLOWER
+                .encode(&Sha256::digest(source)),
+            signers,
+            txs,
+            signed,
+            failed,
+            device,
+        }
+    }
+}
+
+/// The public keys of all the signatures of the given txs
+fn signer_pks(
+    signed: &Transactions<genesis::templates::Unvalidated>,
+) -> BTreeSet<String> {
+    let validator_signatures = signed
+        .validator_account
+        .iter()
+        .flatten()
+        .map(|tx| &tx.signatures);
+    let bond_signatures = signed.bond.iter().flatten().map(|tx| &tx.signatures);
+    validator_signatures
+        .chain(bond_signatures)
+        .flat_map(|signatures| signatures.keys().map(ToString::to_string))
+        .collect()
+}
+
+/// Append the given record of a signing run to the audit log at the given
+/// path, as a line of JSON.
+fn append_audit_log(
+    global_args: &args::Global,
+    path: &Path,
+    record: &SigningAuditRecord,
+) {
+    let line = serde_json::to_string(record)
+        .expect("Serializing an audit record shouldn't fail");
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| writeln!(file, "{line}"))
+        .unwrap_or_else(|err| {
+            eprintln!(
+                "Unable to append to the audit log {}. Failed with: {err}",
+                global_args.redact_base_dir(path.to_string_lossy())
+            );
+            safe_exit(1)
+        });
+}
+
+/// Write the signed txs TOML to the given file, if any, or else print it.
+fn write_signed_txs(
+    global_args: &args::Global,
+    transactions: &str,
+    output: Option<&Path>,
+) {
+    match output {
+        Some(toml_path) => {
+            let toml_path_str =
+                global_args.redact_base_dir(toml_path.to_string_lossy());
+            File::create(toml_path)
+                .and_then(|mut file| {
+                    restrict_file_permissions(&file)?;
+                    file.write_all(transactions.as_bytes())
+                })
+                .unwrap_or_else(|err| {
+                    eprintln!(
+                        "Couldn't write the signed txs to {toml_path_str}. \
+                         Failed with: {err}",
+                    );
+                    safe_exit(1)
+                });
+            eprintln!(
+                "{}: {toml_path_str}",
+                "Wrote signed txs to"
+                    .style(global_args.output_style(Style::new().bold()))
+            );
+        }
+        None => println!("{transactions}"),
+    }
+}
+
+/// The number of bond and validator account txs that carry at least one
+/// signature.
+fn count_signed(
+    signed: &Transactions<genesis::templates::Unvalidated>,
+) -> usize {
+    let num_validator_accounts = signed
+        .validator_account
+        .iter()
+        .flatten()
+        .filter(|tx| !tx.signatures.is_empty())
+        .count();
+    let num_bonds = signed
+        .bond
+        .iter()
+        .flatten()
+        .filter(|tx| !tx.signatures.is_empty())
+        .count();
+    num_validator_accounts.saturating_add(num_bonds)
+}
+
+/// Print the summary line of a signing run, in a stable format for scripts.
+fn print_machine_summary(num_txs: usize, num_signed: usize, use_device: bool) {
+    println!(
+        "SUMMARY txs={num_txs} signed={num_signed} skipped={} device={}",
+        num_txs.saturating_sub(num_signed),
+        use_device
+    );
+}
+
+/// Decrypt the given txs document with a password prompted for if it is
+/// encrypted, exiting on failure. Plaintext documents are returned as they
+/// are.
+fn decrypt_txs_if_encrypted(
+    global_args: &args::Global,
+    contents: Vec<u8>,
+    source: &str,
+) -> Vec<u8> {
+    if !genesis::transactions::is_encrypted_txs(&contents) {
+        return contents;
+    }
+    let target = format!("the txs of {}", global_args.redact_base_dir(source));
+    let password = CliWalletUtils::without_store(global_args.wallet_options())
+        .read_password(false, Some(&target));
+    genesis::transactions::decrypt_txs(&contents, password).unwrap_or_else(
+        |err| {
+            eprintln!("{err}");
+            safe_exit(1)
+        },
+    )
+}
+
+/// Read and decrypt the txs of the file at the given path if it is
+/// encrypted. Plaintext files are left to be streamed from, and so are files
+/// that can't be read, for their error to be reported when opening them.
+fn read_encrypted_txs(
+    global_args: &args::Global,
+    path: &str,
+) -> Option<Vec<u8>> {
+    // Enough to find the prefix of encrypted docs after any whitespace
+    const SNIFF_LEN: u64 = 1024;
+    let mut head = vec![];
+    File::open(path)
+        .and_then(|file| file.take(SNIFF_LEN).read_to_end(&mut head))
+        .ok()?;
+    if !genesis::transactions::is_encrypted_txs(&head) {
+        return None;
+    }
+    let contents = fs::read(path).unwrap_or_else(|err| {
+        eprintln!(
+            "Unable to read the unsigned txs from {}. Failed with: {err}",
+            global_args.redact_base_dir(path)
+        );
+        safe_exit(1)
+    });
+    Some(decrypt_txs_if_encrypted(global_args, contents, path))
+}
+
+/// Open the unsigned txs that were downloaded or decrypted, if any, or else
+/// those from the file at the given path.
+fn open_unsigned_txs<'a>(
+    global_args: &args::Global,
+    path: &str,
+    downloaded: Option<&'a [u8]>,
+) -> Box<dyn BufRead + 'a> {
+    match downloaded {
+        Some(contents) => Box::new(contents),
+        None => match File::open(path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(err) => {
+                eprintln!(
+                    "Unable to read the unsigned txs from {}. Failed with: \
+                     {err}",
+                    global_args.redact_base_dir(path)
+                );
+                safe_exit(1)
+            }
+        },
+    }
+}
+
+/// Serialize signed genesis txs to TOML, annotated with the addresses of
+/// their signers if requested and with their signatures in the given
+/// encoding, exiting on failure.
+fn signed_txs_to_toml(
+    signed: &Transactions<genesis::templates::Unvalidated>,
+    include_signer_address: bool,
+    signature_encoding: SignatureEncoding,
+    order: TomlKeyOrder,
+) -> String {
+    if signature_encoding != SignatureEncoding::Default {
+        if include_signer_address {
+            genesis::transactions::with_signer_addresses(signed)
+        } else {
+            toml::Value::try_from(signed)
+        }
+        .and_then(|mut value| {
+            genesis::transactions::encode_signatures(
+                &mut value,
+                signature_encoding,
+            );
+            genesis::utils::to_toml_string(&value, order)
+        })
+    } else if include_signer_address {
+        genesis::transactions::with_signer_addresses(signed).and_then(
+            |annotated| genesis::utils::to_toml_string(&annotated, order),
+        )
+    } else {
+        genesis::utils::to_toml_string(signed, order)
+    }
+    .unwrap_or_else(|err| {
+        eprintln!("Unable to serialize the signed txs to TOML: {err}");
+        safe_exit(1)
+    })
+}
+
+/// Print a chunk of genesis txs or signatures as TOML, exiting on failure.
+fn print_toml_chunk<T: Serialize>(data: &T, order: TomlKeyOrder) {
+    let toml =
+        genesis::utils::to_toml_string(data, order).unwrap_or_else(|err| {
+            eprintln!("Unable to serialize the signed txs to TOML: {err}");
+            safe_exit(1)
+        });
+    println!("{toml}");
+}
+
+/// Print a human-readable rendering of the given unsigned txs to stderr,
+/// keeping stdout for the signed txs. The amounts are redacted if
+/// `hide_amounts` is set.
+fn print_unsigned_txs(unsigned: &UnsignedTransactions, hide_amounts: bool) {
+    for account in unsigned.established_account.iter().flatten() {
+        eprintln!(
+            "Established account {} with the VP \"{}\" and a threshold of \
+             {} out of the public keys:",
+            account.derive_address(),
+            account.vp,
+            account.threshold,
+        );
+        for pk in &account.public_keys {
+            eprintln!("  {pk}");
+        }
+    }
+    for validator in unsigned.validator_account.iter().flatten() {
+        eprintln!(
+            "Validator account {} at {} with a commission rate of {} (max \
+             change of {} per epoch)",
+            Address::Established(validator.address.raw.clone()),
+            validator.net_address,
+            validator.commission_rate,
+            validator.max_commission_rate_change,
+        );
+        eprintln!("  Consensus key: {}", validator.consensus_key);
+        eprintln!("  Protocol key: {}", validator.protocol_key);
+        eprintln!("  CometBFT node key: {}", validator.tendermint_node_key);
+        eprintln!("  Ethereum hot key: {}", validator.eth_hot_key);
+        eprintln!("  Ethereum cold key: {}", validator.eth_cold_key);
+    }
+    for bond in unsigned.bond.iter().flatten() {
+        eprintln!(
+            "Bond of {} from {} to the validator {}",
+            display_amount(&bond.amount, hide_amounts),
+            bond.source,
+            bond.validator
+        );
+    }
+}
+
+/// Render an amount for the human-readable output, or `***` in its place if
+/// it is hidden.
+fn display_amount(amount: &token::DenominatedAmount, hide: bool) -> String {
+    if hide {
+        "***".to_string()
+    } else {
+        amount.to_string()
+    }
+}
+
+/// Attach detached signatures to the unsigned genesis txs that they sign.
+pub fn attach_signatures(
+    global_args: args::Global,
+    args::AttachSignatures {
+        path,
+        signatures,
+        output,
+    }: args::AttachSignatures,
+) {
+    let unsigned = fs::read(&path)
+        .map_err(|err| err.to_string())
+        .and_then(|bytes| {
+            genesis::transactions::parse_unsigned(&bytes)
+                .map_err(|err| err.to_string())
+        })
+        .unwrap_or_else(|err| {
+            eprintln!(
+                "Unable to read the unsigned txs from {}. Failed with: {err}",
+                global_args.redact_base_dir(path.to_string_lossy())
+            );
+            safe_exit(1)
+        });
+    let mut detached = genesis::transactions::DetachedSignatures::default();
+    for signatures_path in signatures {
+        let mut signatures: genesis::transactions::DetachedSignatures =
+            fs::read(&signatures_path)
+                .map_err(|err| err.to_string())
+                .and_then(|bytes| {
+                    toml::from_slice(&bytes).map_err(|err| err.to_string())
+                })
+                .unwrap_or_else(|err| {
+                    eprintln!(
+                        "Unable to read the detached signatures from {}. \
+                         Failed with: {err}",
+                        global_args
+                            .redact_base_dir(signatures_path.to_string_lossy())
+                    );
+                    safe_exit(1)
+                });
+        detached.signature.append(&mut signatures.signature);
+    }
+    let signed = genesis::transactions::attach_signatures(unsigned, &detached)
+        .unwrap_or_else(|err| {
+            eprintln!("{err}");
+            safe_exit(1)
+        });
+    let transactions = toml::to_string(&signed).unwrap();
+
+    match output {
+        Some(toml_path) => {
+            let toml_path_str =
+                global_args.redact_base_dir(toml_path.to_string_lossy());
+            File::create(&toml_path)
+                .and_then(|mut file| {
+                    restrict_file_permissions(&file)?;
+                    file.write_all(transactions.as_bytes())
+                })
+                .unwrap_or_else(|err| {
+                    eprintln!(
+                        "Couldn't write the signed txs to {toml_path_str}. \
+                         Failed with: {err}",
+                    );
+                    safe_exit(1)
+                });
+            println!(
+                "{}: {toml_path_str}",
+                "Wrote signed transactions to"
+                    .style(global_args.output_style(Style::new().bold()))
+            );
+        }
+        None => println!("{transactions}"),
+    }
+}
+
+/// Write a manifest of the keys that signed the given signed genesis txs,
+/// resolving their aliases with the pre-genesis wallet, if any.
+pub fn signers_manifest(
+    global_args: args::Global,
+    args::SignersManifest { path, output }: args::SignersManifest,
+) {
+    let signed =
+        genesis::templates::read_transactions(&path).unwrap_or_else(|err| {
+            eprintln!(
+                "Unable to read the signed txs from {}. Failed with: {err}",
+                global_args.redact_base_dir(path.to_string_lossy())
+            );
+            safe_exit(1)
+        });
+    let wallet = try_load_pre_genesis_wallet(
+        &global_args.base_dir,
+        &global_args.wallet_options(),
+    )
+    .ok()
+    .map(|(wallet, _)| wallet);
+    let manifest =
+        genesis::transactions::ExpectedSigners::from_signed(&signed, |pk| {
+            let wallet = wallet.as_ref()?;
+            wallet
+                .find_alias(&Address::from(pk))
+                .map(|alias| alias.to_string())
+        });
+    let manifest =
+        genesis::utils::to_toml_string(&manifest, TomlKeyOrder::Sorted)
+            .unwrap_or_else(|err| {
+                eprintln!("Unable to serialize the manifest to TOML: {err}");
+                safe_exit(1)
+            });
+
+    match output {
+        Some(toml_path) => {
+            let toml_path_str =
+                global_args.redact_base_dir(toml_path.to_string_lossy());
+            fs::write(&toml_path, manifest).unwrap_or_else(|err| {
+                eprintln!(
+                    "Couldn't write the signers manifest to {toml_path_str}. \
+                     Failed with: {err}",
+                );
+                safe_exit(1)
+            });
+            println!(
+                "{}: {toml_path_str}",
+                "Wrote signers manifest to"
+                    .style(global_args.output_style(Style::new().bold()))
+            );
+        }
+        None => print!("{manifest}"),
+    }
 }
 
-/// Sign genesis transactions.
-pub async fn sign_genesis_tx(
+/// Whether the given source of unsigned txs is an HTTP(S) URL, rather than a
+/// local path.
+fn is_url(source: &str) -> bool {
+    source.starts_with("http://") || source.starts_with("https://")
+}
+
+/// Re-emit a signed or unsigned genesis txs TOML file in its canonical form
+/// and print the SHA-256 digest of it.
+pub fn canonicalize(
     global_args: args::Global,
-    args::SignGenesisTxs {
-        source,
-        validator,
-        amount,
-        validator_alias,
-        use_device,
-        device_transport,
-    }: args::SignGenesisTxs,
+    args::Canonicalize { path, output }: args::Canonicalize,
 ) {
-    let (wallet, _wallet_file) =
-        load_pre_genesis_wallet_or_exit(&global_args.base_dir);
-    let wallet_lock = RwLock::new(wallet);
-    let maybe_pre_genesis_wallet = validator_alias.and_then(|alias| {
-        let pre_genesis_dir =
-            validator_pre_genesis_dir(&global_args.base_dir, &alias);
-        pre_genesis::load(&pre_genesis_dir).ok()
+    let contents = fs::read(&path).unwrap_or_else(|err| {
+        eprintln!(
+            "Unable to read {}: {err}",
+            global_args.redact_base_dir(path.to_string_lossy())
+        );
+        safe_exit(1)
     });
-    let bond = Bond {
-        source,
-        validator,
-        amount,
-    };
+    let canonical = genesis::transactions::canonicalize(&contents)
+        .unwrap_or_else(|err| {
+            eprintln!("{err}");
+            safe_exit(1)
+        });
+    let digest = data_encoding::HEXLOWER.encode(&Sha256::digest(&canonical));
+    match output {
+        Some(output) => {
+            if let Err(err) = fs::write(&output, &canonical) {
+                eprintln!(
+                    "Unable to write {}: {err}",
+                    global_args.redact_base_dir(output.to_string_lossy())
+                );
+                safe_exit(1)
+            }
+        }
+        None => print!("{canonical}"),
+    }
+    eprintln!("SHA-256: {digest}");
+}
 
-    // Create the bond list
-    let bond_list = BondList {
-        bond: vec![bond],
-    };
+/// Merge signed genesis txs files, taking the union of the signatures of
+/// each tx.
+pub fn merge_signed(
+    global_args: args::Global,
+    args::MergeSigned { paths, output }: args::MergeSigned,
+) {
+    if paths.is_empty() {
+        eprintln!("No signed txs files to merge were given.");
+        safe_exit(1)
+    }
+    let docs = paths.iter().map(|path| {
+        fs::read(path)
+            .map_err(|err| err.to_string())
+            .and_then(|bytes| {
+                genesis::transactions::parse_signed(&bytes)
+                    .map_err(|err| err.to_string())
+            })
+            .unwrap_or_else(|err| {
+                eprintln!(
+                    "Unable to read the signed txs from {}. Failed with: \
+                     {err}",
+                    global_args.redact_base_dir(path.to_string_lossy())
+                );
+                safe_exit(1)
+            })
+    });
+    let merged =
+        genesis::transactions::merge_signed(docs).unwrap_or_else(|err| {
+            eprintln!("{err}");
+            safe_exit(1)
+        });
+    let transactions =
+        genesis::utils::to_toml_string(&merged, TomlKeyOrder::Sorted)
+            .unwrap_or_else(|err| {
+                eprintln!("Unable to serialize the signed txs to TOML: {err}");
+                safe_exit(1)
+            });
+    write_signed_txs(&global_args, &transactions, output.as_deref());
+}
 
-    // Serialize the bond list to a TOML string
-    let toml_content = toml::to_string(&bond_list).unwrap_or_else(|err| {
-        eprintln!("Unable to serialize to TOML. Failed with {err}.");
+/// Fetch the unsigned txs published at the given URL, checking them against
+/// the expected SHA-256 digest, if any.
+async fn fetch_unsigned_txs(
+    url: &str,
+    expected_sha256: Option<&str>,
+    timeout: args::Duration,
+) -> Vec<u8> {
+    let contents = download_file(url, timeout.0).await.unwrap_or_else(|err| {
+        eprintln!("Unable to fetch the unsigned txs from {url}: {err}");
         safe_exit(1)
     });
-    let contents = toml_content.into_bytes();
-    // Sign a subset of the input txs (the ones whose keys we own)
-    let unsigned = 
-        genesis::transactions::parse_unsigned(&contents).unwrap();
+    if let Some(expected) = expected_sha256 {
+        let digest = data_encoding::HEXLOWER.encode(&Sha256::digest(&contents));
+        if !digest.eq_ignore_ascii_case(expected.trim()) {
+            eprintln!(
+                "The unsigned txs fetched from {url} have the SHA-256 digest \
+                 {digest}, which doesn't match the expected {expected}."
+            );
+            safe_exit(1)
+        }
+    }
+    contents.to_vec()
+}
 
+/// Sign the txs of the given unsigned txs TOML with the keys of the given
+/// wallet store, returning the signed txs TOML. Unlike [`sign_genesis_tx`],
+/// nothing is read from the filesystem and the password of encrypted keys is
+/// taken as an argument instead of being prompted for, so all the encrypted
+/// keys of the store must be encrypted under it. Validator account txs are
+/// not supported, as signing them requires a validator wallet.
+pub async fn sign_genesis_tx_inner(
+    unsigned: &[u8],
+    wallet_store: &[u8],
+    password: Option<Zeroizing<String>>,
+) -> Result<String, String> {
+    let unsigned = genesis::transactions::parse_unsigned(unsigned)
+        .map_err(|err| format!("Unable to parse the unsigned txs: {err}"))?;
+    if unsigned.validator_account.is_some() {
+        return Err("Signing validator account txs requires a validator \
+                    wallet, which is not supported here."
+            .to_string());
+    }
+    let store = decode_store(wallet_store, password.as_ref())?;
+    let mut wallet = CliWalletUtils::new(PathBuf::new());
+    *wallet.store_mut() = store;
+    // Cache the decrypted keys, so that signing never prompts for a
+    // password, which couldn't be done on wasm32
+    let encrypted: Vec<String> = wallet
+        .get_secret_keys()
+        .into_iter()
+        .filter_map(|(alias, (key, _))| key.is_encrypted().then_some(alias))
+        .collect();
+    for alias in encrypted {
+        let password = password.clone().ok_or_else(|| {
+            format!("A password is required to decrypt the key {alias}.")
+        })?;
+        wallet
+            .find_secret_key(&alias, Some(password))
+            .map_err(|err| {
+                format!("Unable to decrypt the key {alias}: {err}")
+            })?;
+    }
     let signed = genesis::transactions::sign_txs(
         unsigned,
-        &wallet_lock,
-        maybe_pre_genesis_wallet.as_ref(),
-        use_device,
-        device_transport,
+        &RwLock::new(wallet),
+        None,
+        &SignerBackend::Wallet,
     )
-    .await;
+    .await
+    .map_err(|err| format!("Failed to sign the genesis txs: {err}"))?;
+    toml::to_string(&signed).map_err(|err| err.to_string())
+}
 
-    let transactions = toml::to_string(&signed).unwrap();
-    println!("{transactions}");
+/// List the public keys and addresses of the given wallet store as a JSON
+/// object.
+pub fn wallet_list_inner(
+    wallet_store: &[u8],
+    password: Option<Zeroizing<String>>,
+) -> Result<String, String> {
+    let store = decode_store(wallet_store, password.as_ref())?;
+    let public_keys: BTreeMap<_, _> = store
+        .get_public_keys()
+        .iter()
+        .map(|(alias, pk)| (alias.to_string(), pk.to_string()))
+        .collect();
+    let addresses: BTreeMap<_, _> = store
+        .get_addresses()
+        .iter()
+        .map(|(alias, address)| (alias.to_string(), address.encode()))
+        .collect();
+    let schemes: BTreeMap<_, _> =
+        store
+            .get_addresses()
+            .iter()
+            .map(|(alias, address)| {
+                let scheme = store.find_scheme_by_address(address);
+                (
+                    alias.to_string(),
+                    scheme.map_or_else(|| "n/a".to_string(), |s| s.to_string()),
+                )
+            })
+            .chain(store.get_public_keys().iter().map(|(alias, pk)| {
+                (alias.to_string(), pk.scheme().to_string())
+            }))
+            .collect();
+    let payment_addresses: BTreeMap<_, _> = store
+        .get_payment_addrs()
+        .iter()
+        .map(|(alias, address)| (alias.to_string(), address.to_string()))
+        .collect();
+    Ok(json!({
+        "public_keys": public_keys,
+        "addresses": addresses,
+        "payment_addresses": payment_addresses,
+        "schemes": schemes,
+    })
+    .to_string())
+}
+
+/// Decode a wallet store from its bytes, unlocking its address book with the
+/// given password if it is encrypted.
+fn decode_store(
+    wallet_store: &[u8],
+    password: Option<&Zeroizing<String>>,
+) -> Result<Store, String> {
+    let mut store = Store::decode(wallet_store.to_vec())
+        .map_err(|err| format!("Unable to decode the wallet store: {err}"))?;
+    if store.is_address_book_locked() {
+        let password = password.ok_or_else(|| {
+            "A password is required to unlock the address book.".to_string()
+        })?;
+        store.unlock_address_book(password.clone()).map_err(|err| {
+            format!("Unable to unlock the address book: {err}")
+        })?;
+    }
+    Ok(store)
+}
+
+/// JS binding of [`sign_genesis_tx_inner`].
+#[wasm_bindgen(js_name = signGenesisTxs)]
+pub fn sign_genesis_txs_js(
+    unsigned: Vec<u8>,
+    wallet_store: Vec<u8>,
+    password: Option<String>,
+) -> Result<String, JsValue> {
+    // Signing with the keys of a store never waits on any IO, so the future
+    // completes when first polled
+    futures::executor::block_on(sign_genesis_tx_inner(
+        &unsigned,
+        &wallet_store,
+        password.map(Zeroizing::new),
+    ))
+    .map_err(|err| JsValue::from_str(&err))
+}
+
+/// JS binding of [`wallet_list_inner`].
+#[wasm_bindgen(js_name = listWallet)]
+pub fn wallet_list_js(
+    wallet_store: Vec<u8>,
+    password: Option<String>,
+) -> Result<String, JsValue> {
+    wallet_list_inner(&wallet_store, password.map(Zeroizing::new))
+        .map_err(|err| JsValue::from_str(&err))
+}
+
+/// Check that the connected hardware wallet holds the public keys of the
+/// signers of every validator account and bond tx, at the derivation paths
+/// stored for them in the wallet. The keys of a validator or of an
+/// established account source are those of the established account of its
+/// address among the given txs, of which at least one must have a
+/// derivation path.
+async fn verify_device_signers(
+    global_args: &args::Global,
+    unsigned: &UnsignedTransactions,
+    wallet: &Wallet<CliWalletUtils>,
+    device_transport: DeviceTransport,
+) -> Result<(), String> {
+    let app = NamadaApp::new(WalletTransport::from_arg(
+        device_transport,
+        global_args.debug_apdu_enabled(),
+    ));
+    let established_accounts =
+        unsigned.established_account.as_deref().unwrap_or_default();
+    let account_pks = |address: &EstablishedAddress, signer: &str| {
+        established_accounts
+            .iter()
+            .find(|account| &account.derive_established_address() == address)
+            .map(|account| {
+                account
+                    .public_keys
+                    .iter()
+                    .map(|pk| pk.raw.clone())
+                    .collect::<Vec<_>>()
+            })
+            .ok_or_else(|| {
+                format!(
+                    "the {signer} is not the address of any established \
+                     account of the txs"
+                )
+            })
+    };
+    let mut signers = vec![];
+    for tx in unsigned.validator_account.iter().flatten() {
+        let signer = format!(
+            "validator {}",
+            Address::Established(tx.address.raw.clone())
+        );
+        let pks = account_pks(&tx.address.raw, &signer)?;
+        signers.push((signer, pks));
+    }
+    for bond in unsigned.bond.iter().flatten() {
+        let signer = format!("bond source {}", bond.source.address());
+        let pks = match &bond.source {
+            GenesisAddress::PublicKey(pk) => vec![pk.raw.clone()],
+            GenesisAddress::EstablishedAddress(address) => {
+                account_pks(address, &signer)?
+            }
+        };
+        signers.push((signer, pks));
+    }
+    for (signer, pks) in signers {
+        let paths: Vec<_> = pks
+            .iter()
+            .filter_map(|pk| {
+                let path = wallet.find_path_by_pkh(&pk.into()).ok()?;
+                Some((pk, path))
+            })
+            .collect();
+        if paths.is_empty() {
+            return Err(format!(
+                "no derivation path found in the wallet for the keys of the \
+                 {signer}"
+            ));
+        }
+        for (expected, path) in paths {
+            verify_device_key(&app, expected, &path, &signer).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Check that the connected hardware wallet holds the given public key of a
+/// signer at the given derivation path.
+async fn verify_device_key(
+    app: &NamadaApp<WalletTransport>,
+    expected: &common::PublicKey,
+    path: &DerivationPath,
+    signer: &str,
+) -> Result<(), String> {
+    let response = app
+        .get_address_and_pubkey(
+            &BIP44Path {
+                path: path.to_string(),
+            },
+            false,
+        )
+        .await
+        .map_err(|err| {
+            format!(
+                "unable to query the public key at {path} from the device: \
+                 {err}"
+            )
+        })?;
+    let device_pk = common::PublicKey::try_from_slice(&response.public_key)
+        .map_err(|err| {
+            format!("unable to decode the public key from the device: {err}")
+        })?;
+    if &device_pk != expected {
+        return Err(format!(
+            "the device holds the public key {device_pk} at {path}, but the \
+             {signer} expects {expected}. Check that the device is loaded \
+             with the right seed."
+        ));
+    }
+    Ok(())
+}
+
+/// Display the addresses that the connected hardware wallet derives for the
+/// bond sources of the given txs, and ask the operator to type "yes" to sign
+/// with them. Fails without prompting if stdin is not a terminal.
+async fn confirm_device_addresses(
+    global_args: &args::Global,
+    unsigned: &UnsignedTransactions,
+    wallet: &Wallet<CliWalletUtils>,
+    device_transport: DeviceTransport,
+) -> Result<(), String> {
+    let app = NamadaApp::new(WalletTransport::from_arg(
+        device_transport,
+        global_args.debug_apdu_enabled(),
+    ));
+    let mut paths = BTreeMap::new();
+    for bond in unsigned.bond.iter().flatten() {
+        if let GenesisAddress::PublicKey(pk) = &bond.source {
+            if let Ok(path) = wallet.find_path_by_pkh(&(&pk.raw).into()) {
+                paths.insert(path.to_string(), Address::from(&pk.raw));
+            }
+        }
+    }
+    if paths.is_empty() {
+        return Ok(());
+    }
+    println!("The hardware wallet will sign with:");
+    for (path, source) in paths {
+        let response = app
+            .get_address_and_pubkey(&BIP44Path { path: path.clone() }, true)
+            .await
+            .map_err(|err| {
+                format!(
+                    "Unable to query the address at {path} from the device: \
+                     {err}"
+                )
+            })?;
+        println!(
+            "  {} at {path} (bond source {source})",
+            response
+                .address_str
+                .style(global_args.output_style(Style::new().bold()))
+        );
+    }
+    if !global_args.tty_enabled() {
+        return Err("Cannot ask to confirm the device addresses without a \
+                    terminal. Pass `--yes` to sign without confirming."
+            .to_string());
+    }
+    print!("Type \"yes\" to sign with these addresses: ");
+    std::io::stdout().flush().map_err(|err| err.to_string())?;
+    let mut answer = String::new();
+    std::io::stdin()
+        .read_line(&mut answer)
+        .map_err(|err| format!("Unable to read the confirmation: {err}"))?;
+    if answer.trim() != "yes" {
+        return Err("The device addresses were not confirmed.".to_string());
+    }
+    Ok(())
+}
+
+/// List the validator account and bond txs of the given unsigned txs and ask
+/// the operator which of them to sign, leaving out the others. The
+/// established account txs are kept, as signing the others may require them.
+/// Fails without prompting if stdin is not a terminal. The amounts are
+/// redacted if `hide_amounts` is set.
+fn select_unsigned_txs(
+    global_args: &args::Global,
+    unsigned: &mut UnsignedTransactions,
+    hide_amounts: bool,
+) -> Result<(), String> {
+    if !global_args.tty_enabled() {
+        return Err("Cannot select the transactions to sign without a \
+                    terminal. Pass `--assume-tty` if there is one."
+            .to_string());
+    }
+    let validator_accounts =
+        unsigned.validator_account.take().unwrap_or_default();
+    let bonds = unsigned.bond.take().unwrap_or_default();
+    let num_validator_accounts = validator_accounts.len();
+    let num_txs = num_validator_accounts.saturating_add(bonds.len());
+    if num_txs == 0 {
+        return Err("There are no transactions to select.".to_string());
+    }
+    eprintln!("The transactions to select from:");
+    let numbers = 1..;
+    for (number, validator) in numbers.clone().zip(&validator_accounts) {
+        eprintln!(
+            "  {number}. Validator account {} at {}",
+            Address::Established(validator.address.raw.clone()),
+            validator.net_address,
+        );
+    }
+    for (number, bond) in numbers.skip(num_validator_accounts).zip(&bonds) {
+        eprintln!(
+            "  {number}. Bond of {} from {} to the validator {}",
+            display_amount(&bond.amount, hide_amounts),
+            bond.source,
+            bond.validator
+        );
+    }
+    eprint!(
+        "Enter the numbers of the transactions to sign, e.g. \"1,3-5\", or \
+         \"all\": "
+    );
+    std::io::stderr().flush().map_err(|err| err.to_string())?;
+    let mut answer = String::new();
+    std::io::stdin()
+        .read_line(&mut answer)
+        .map_err(|err| format!("Unable to read the selection: {err}"))?;
+    let selected = genesis::utils::parse_tx_selection(&answer, num_txs)?;
+
+    /// The txs whose index, offset by the given number, is selected
+    fn keep_selected<T>(
+        txs: Vec<T>,
+        offset: usize,
+        selected: &BTreeSet<usize>,
+    ) -> Option<Vec<T>> {
+        let txs: Vec<T> = txs
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| {
+                selected.contains(&index.saturating_add(offset))
+            })
+            .map(|(_, tx)| tx)
+            .collect();
+        (!txs.is_empty()).then_some(txs)
+    }
+    unsigned.validator_account =
+        keep_selected(validator_accounts, 0, &selected);
+    unsigned.bond = keep_selected(bonds, num_validator_accounts, &selected);
+    Ok(())
+}
+
+/// Warn about the keys of the given wallets that signed the genesis txs
+/// differently from the manifest of expected signers.
+fn check_expected_signers(
+    manifest_path: &Path,
+    signed: &Transactions<config::genesis::templates::Unvalidated>,
+    wallet: &Wallet<CliWalletUtils>,
+    validator_wallet: Option<&pre_genesis::ValidatorWallet>,
+) {
+    let manifest_path_str = wallet
+        .utils()
+        .redact_base_dir(manifest_path.to_string_lossy());
+    let expected: genesis::transactions::ExpectedSigners =
+        fs::read(manifest_path)
+            .map_err(|err| err.to_string())
+            .and_then(|bytes| {
+                toml::from_slice(&bytes).map_err(|err| err.to_string())
+            })
+            .unwrap_or_else(|err| {
+                eprintln!(
+                    "Unable to read the expected signers manifest from \
+                     {manifest_path_str}. Failed with: {err}"
+                );
+                safe_exit(1)
+            });
+    let validator_keys: Vec<common::PublicKey> = validator_wallet
+        .map(|validator_wallet| {
+            vec![
+                validator_wallet.consensus_key.ref_to(),
+                validator_wallet.eth_cold_key.ref_to(),
+                validator_wallet.eth_hot_key.ref_to(),
+                validator_wallet.tendermint_node_key.ref_to(),
+                validator_wallet
+                    .store
+                    .validator_keys
+                    .protocol_keypair
+                    .ref_to(),
+            ]
+        })
+        .unwrap_or_default();
+    let mismatches = genesis::transactions::check_expected_signers(
+        &expected,
+        signed,
+        |pk| {
+            validator_keys.contains(pk)
+                || wallet.find_public_key_by_pkh(&pk.into()).is_ok()
+        },
+    );
+    for mismatch in &mismatches {
+        eprintln!("Warning: {mismatch}");
+    }
+    if mismatches.is_empty() {
+        eprintln!(
+            "The signatures match the expected signers in \
+             {manifest_path_str}."
+        );
+    }
+}
+
+/// Submit signed genesis transactions to a coordinator endpoint.
+pub async fn submit_genesis_txs(
+    global_args: args::Global,
+    args::SubmitGenesisTxs {
+        path,
+        coordinator_url,
+        token,
+        dry_run,
+        toml_key_order,
+    }: args::SubmitGenesisTxs,
+) {
+    let signed =
+        genesis::templates::read_transactions(&path).unwrap_or_else(|_| {
+            eprintln!(
+                "Unable to parse the TOML from path: {}",
+                global_args.redact_base_dir(path.to_string_lossy())
+            );
+            safe_exit(1)
+        });
+    // Serialize the txs the same way as `sign-genesis-txs` outputs them
+    let body = genesis::utils::to_toml_string(&signed, toml_key_order)
+        .unwrap_or_else(|err| {
+            eprintln!("Unable to serialize the signed txs: {err}");
+            safe_exit(1)
+        });
+
+    if dry_run {
+        println!("POST {coordinator_url}");
+        println!("Content-Type: application/toml");
+        if token.is_some() {
+            println!("Authorization: Bearer <token>");
+        }
+        println!();
+        println!("{body}");
+        return;
+    }
+
+    let mut request = reqwest::Client::new()
+        .post(&coordinator_url)
+        .header(reqwest::header::CONTENT_TYPE, "application/toml")
+        .body(body);
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    let response = request.send().await.unwrap_or_else(|err| {
+        eprintln!(
+            "Unable to submit the genesis txs to {coordinator_url}. Failed \
+             with: {err}"
+        );
+        safe_exit(1)
+    });
+    let status = response.status();
+    let acknowledgment =
+        response.text().await.unwrap_or_else(|_| String::new());
+    if !status.is_success() {
+        eprintln!(
+            "The coordinator rejected the genesis txs with status {status}: \
+             {acknowledgment}"
+        );
+        safe_exit(1)
+    }
+    println!("The coordinator accepted the genesis txs with status {status}.");
+    if !acknowledgment.is_empty() {
+        println!("{acknowledgment}");
+    }
 }
 
 #[derive(Serialize)]
@@ -1031,13 +4363,15 @@ pub async fn byte_genesis_tx(
     }: args::ByteGenesisTxs,
 ) -> std::result::Result<Transactions<config::genesis::templates::Unvalidated>, Box<dyn std::error::Error>> {
     // Create the bond entry
-    let (wallet, _wallet_file) =
-    load_pre_genesis_wallet_or_exit(&global_args.base_dir);
+    let (wallet, _wallet_file) = load_pre_genesis_wallet_or_exit(
+        &global_args.base_dir,
+        &global_args.wallet_options(),
+    );
     let wallet_lock = RwLock::new(wallet);
     let maybe_pre_genesis_wallet = validator_alias.and_then(|alias| {
-    let pre_genesis_dir =
-        validator_pre_genesis_dir(&global_args.base_dir, &alias);
-    pre_genesis::load(&pre_genesis_dir).ok()
+        let pre_genesis_dir =
+            validator_pre_genesis_dir(&global_args.base_dir, &alias);
+        pre_genesis::load(&pre_genesis_dir, &global_args.wallet_options()).ok()
     });
     let bond = Bond {
         source,
@@ -1063,10 +4397,16 @@ pub async fn byte_genesis_tx(
             unsigned,
             &wallet_lock,
             maybe_pre_genesis_wallet.as_ref(),
-            use_device,
-            device_transport,
+            &SignerBackend::from_device_args(
+                use_device,
+                device_transport,
+                global_args.debug_apdu_enabled(),
+                true,
+                global_args.tty_enabled(),
+            ),
         )
-        .await;
+        .await
+        .map_err(|err| err.to_string())?;
         
         // Step 3: Return the signed transactions
         Ok(signed)
@@ -1133,6 +4473,125 @@ pub async fn sign_offline(
     }
 }
 
+/// Sign a serialized tx offline with a key of the wallet resolved from the
+/// global args, or with the hardware wallet, and write out the signed tx.
+pub async fn sign_offline_tx(
+    global_args: args::Global,
+    args::SignOfflineTx {
+        tx_path,
+        signer,
+        use_device,
+        device_transport,
+        output,
+    }: args::SignOfflineTx,
+) {
+    let tx_data = fs::read(&tx_path).unwrap_or_else(|err| {
+        eprintln!(
+            "Couldn't read the transaction at {}. Failed with: {err}",
+            tx_path.display()
+        );
+        safe_exit(1)
+    });
+    let mut tx = Tx::deserialize(&tx_data).unwrap_or_else(|_| {
+        eprintln!("Couldn't decode the transaction.");
+        safe_exit(1)
+    });
+
+    let store_dir = wallet_store_dir(&global_args).unwrap_or_else(|| {
+        eprintln!(
+            "No chain is configured to load the wallet of. Pass --chain-id, \
+             set NAMADA_CHAIN_ID or use --pre-genesis."
+        );
+        safe_exit(1)
+    });
+    let mut wallet = crate::wallet::load_with_options(
+        &store_dir,
+        &global_args.wallet_options(),
+    )
+    .unwrap_or_else(|err| {
+        eprintln!("Error loading the wallet: {err}");
+        safe_exit(1)
+    });
+    let public_key = wallet
+        .find_public_key(signer.to_lowercase())
+        .ok()
+        .or_else(|| parse_public_key(&signer))
+        .unwrap_or_else(|| {
+            eprintln!(
+                "The signer \"{signer}\" is neither the alias of a key in the \
+                 wallet nor a public key."
+            );
+            safe_exit(1)
+        });
+    // Only the fee payer can sign the wrapper
+    let signs_wrapper = tx
+        .header()
+        .wrapper()
+        .is_some_and(|wrapper| wrapper.pk == public_key);
+
+    if use_device {
+        let wallet_lock = RwLock::new(wallet);
+        let transport = WalletTransport::from_arg(
+            device_transport,
+            global_args.debug_apdu_enabled(),
+        );
+        let app = NamadaApp::new(transport);
+        let mut parts = HashSet::from([Signable::RawHeader]);
+        if signs_wrapper {
+            parts.insert(Signable::FeeHeader);
+        }
+        tx = with_hardware_wallet(tx, public_key, parts, (&wallet_lock, &app))
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("Failed to sign with the hardware wallet: {err}");
+                safe_exit(1)
+            });
+    } else {
+        let secret_key = wallet
+            .find_key_by_pk(&public_key, None)
+            .unwrap_or_else(|err| {
+                eprintln!("{err}");
+                safe_exit(1)
+            });
+        tx.sign_raw(
+            vec![secret_key.clone()],
+            AccountPublicKeysMap::from_iter([public_key]),
+            None,
+        );
+        if signs_wrapper {
+            tx.sign_wrapper(secret_key);
+        }
+    }
+    if !signs_wrapper {
+        println!(
+            "The signer is not the fee payer of the transaction, so only the \
+             inner transaction was signed."
+        );
+    }
+
+    let signed = serde_json::to_string_pretty(&tx)
+        .expect("Should be able to json encode the tx.");
+    match output {
+        Some(path) => {
+            fs::write(&path, signed).unwrap_or_else(|err| {
+                eprintln!(
+                    "Couldn't write the signed transaction to {}. Failed \
+                     with: {err}",
+                    path.display()
+                );
+                safe_exit(1)
+            });
+            println!(
+                "{}: {}",
+                "Wrote the signed transaction to"
+                    .style(global_args.output_style(Style::new().bold())),
+                path.display()
+            );
+        }
+        None => println!("{signed}"),
+    }
+}
+
 /// Add a spinning wheel to a message for long running commands.
 /// Can be turned off for E2E tests by setting the `REDUCED_CLI_PRINTING`
 /// environment variable.
@@ -1166,3 +4625,52 @@ fn safe_exit(code: i32) -> ! {
 fn safe_exit(code: i32) -> ! {
     panic!("Process exited unsuccessfully with error code: {}", code);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Find the defaults of the args with the given name in the tree
+    fn find_defaults(tree: &serde_json::Value, name: &str) -> Vec<String> {
+        let mut found = vec![];
+        for arg in tree["args"].as_array().unwrap() {
+            if arg["name"] == name {
+                found.extend(
+                    arg["defaults"]
+                        .as_array()
+                        .unwrap()
+                        .iter()
+                        .map(|default| default.as_str().unwrap().to_string()),
+                );
+            }
+        }
+        for sub in tree["subcommands"].as_array().unwrap() {
+            found.extend(find_defaults(sub, name));
+        }
+        found
+    }
+
+    #[test]
+    fn test_command_tree_defaults() {
+        let tree = command_tree(&namada_client_app());
+
+        let max_txs = find_defaults(&tree, args::MAX_TXS.name);
+        assert!(!max_txs.is_empty());
+        assert!(max_txs.iter().all(|default| default == "100"));
+        let key_order = find_defaults(&tree, args::TOML_KEY_ORDER.name);
+        assert!(!key_order.is_empty());
+        assert!(key_order.iter().all(|default| default == "sorted"));
+    }
+
+    #[test]
+    fn test_shown_default_parse() {
+        let app = clap::Command::new("test").arg(args::MAX_TXS.def());
+
+        let matches = app.clone().try_get_matches_from(["test"]).unwrap();
+        assert_eq!(args::MAX_TXS.parse(&matches), 100);
+        let matches = app
+            .try_get_matches_from(["test", "--max-txs", "5"])
+            .unwrap();
+        assert_eq!(args::MAX_TXS.parse(&matches), 5);
+    }
+}
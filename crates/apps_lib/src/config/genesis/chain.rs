@@ -20,6 +20,7 @@ use namada_sdk::parameters::EpochDuration;
 use namada_sdk::time::{DateTimeUtc, DurationNanos, Rfc3339String};
 use namada_sdk::token::Amount;
 use namada_sdk::wallet::store::AddressVpType;
+use namada_sdk::wallet::alias::with_prefix;
 use namada_sdk::wallet::{pre_genesis, Wallet};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
@@ -32,7 +33,7 @@ use crate::config::{Config, TendermintMode};
 use crate::tendermint::node::Id as TendermintNodeId;
 use crate::tendermint_config::net::Address as TendermintAddress;
 use crate::tendermint_node::id_from_pk;
-use crate::wallet::{Alias, CliWalletUtils};
+use crate::wallet::{Alias, CliWalletUtils, WalletOptions};
 use crate::wasm_loader;
 
 pub const METADATA_FILE_NAME: &str = "chain.toml";
@@ -142,15 +143,39 @@ impl Finalized {
             .address
     }
 
-    /// Derive Namada wallet from genesis
+    /// Derive Namada wallet from genesis, loaded or created with the given
+    /// options. The aliases of the genesis tokens are namespaced under the
+    /// given prefix, if any, and only those allowed by the filter are added.
     pub fn derive_wallet(
         &self,
         base_dir: &Path,
+        wallet_options: &WalletOptions,
         pre_genesis_wallet: Option<Wallet<CliWalletUtils>>,
         validator: Option<(Alias, pre_genesis::ValidatorWallet)>,
+        alias_prefix: Option<&str>,
+        alias_filter: &GenesisAliasFilter,
     ) -> Wallet<CliWalletUtils> {
-        let mut wallet = crate::wallet::load_or_new(base_dir);
+        let mut wallet =
+            crate::wallet::load_or_new_with_options(base_dir, wallet_options);
+        let mut num_filtered: usize = 0;
         for (alias, config) in &self.tokens.token {
+            if !alias_filter.allows(alias) {
+                num_filtered = num_filtered.saturating_add(1);
+                continue;
+            }
+            let alias = match alias_prefix {
+                Some(prefix) => with_prefix(prefix, alias),
+                None => alias.clone(),
+            };
+            if let Some(existing) = wallet.find_address(alias.normalize()) {
+                if *existing != config.address {
+                    eprintln!(
+                        "Warning: the alias \"{alias}\" of the genesis token \
+                         {} is already used for {} in the wallet.",
+                        config.address, existing
+                    );
+                }
+            }
             wallet.insert_address(
                 alias.normalize(),
                 config.address.clone(),
@@ -161,6 +186,14 @@ impl Finalized {
                 config.address.clone(),
             );
         }
+        if !alias_filter.is_empty() {
+            println!(
+                "Added {} of the genesis token aliases to the wallet, {} were \
+                 filtered out.",
+                self.tokens.token.len().saturating_sub(num_filtered),
+                num_filtered
+            );
+        }
         if let Some(pre_genesis_wallet) = pre_genesis_wallet {
             wallet.extend(pre_genesis_wallet);
         }
@@ -857,6 +890,30 @@ pub struct Metadata<ID> {
     pub address_gen: Option<EstablishedAddressGen>,
 }
 
+/// Which of the aliases of the genesis tokens to add to a wallet derived from
+/// genesis
+#[derive(Clone, Debug, Default)]
+pub struct GenesisAliasFilter {
+    /// When not empty, only these aliases are added
+    pub include: Vec<Alias>,
+    /// These aliases are never added
+    pub exclude: Vec<Alias>,
+}
+
+impl GenesisAliasFilter {
+    /// Whether the filter lets every alias through
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Whether the given genesis alias, before any prefix is added to it, is
+    /// to be added to the wallet
+    pub fn allows(&self, alias: &Alias) -> bool {
+        (self.include.is_empty() || self.include.contains(alias))
+            && !self.exclude.contains(alias)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::path::PathBuf;
@@ -901,4 +958,30 @@ mod test {
 
         pretty_assertions::assert_eq!(finalized_0, finalized_1);
     }
+
+    #[test]
+    fn test_genesis_alias_filter() {
+        let nam = Alias::from("nam");
+        let btc = Alias::from("btc");
+        let eth = Alias::from("eth");
+
+        let filter = GenesisAliasFilter::default();
+        assert!(filter.is_empty());
+        assert!(filter.allows(&nam));
+
+        let filter = GenesisAliasFilter {
+            include: vec![Alias::from("NAM"), btc.clone()],
+            exclude: vec![btc.clone()],
+        };
+        assert!(filter.allows(&nam));
+        assert!(!filter.allows(&btc));
+        assert!(!filter.allows(&eth));
+
+        let filter = GenesisAliasFilter {
+            include: vec![],
+            exclude: vec![eth.clone()],
+        };
+        assert!(filter.allows(&nam));
+        assert!(!filter.allows(&eth));
+    }
 }
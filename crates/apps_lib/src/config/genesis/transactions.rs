@@ -2,12 +2,13 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Debug;
+use std::io::Write;
 use std::net::SocketAddr;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use borsh_ext::BorshSerializeExt;
 use itertools::{Either, Itertools};
-use ledger_namada_rs::NamadaApp;
+use ledger_namada_rs::{BIP44Path, NamadaApp};
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
@@ -18,7 +19,9 @@ use namada_sdk::chain::ChainId;
 use namada_sdk::collections::HashSet;
 use namada_sdk::dec::Dec;
 use namada_sdk::key::common::PublicKey;
-use namada_sdk::key::{common, ed25519, RefTo, SerializeWithBorsh, SigScheme};
+use namada_sdk::key::{
+    common, ed25519, RefTo, SchemeType, SerializeWithBorsh, SigScheme,
+};
 use namada_sdk::proof_of_stake::parameters::MAX_VALIDATOR_METADATA_LEN;
 use namada_sdk::proof_of_stake::types::ValidatorMetaData;
 use namada_sdk::signing::{sign_tx, SigningTxData};
@@ -33,17 +36,23 @@ use namada_sdk::tx::{
 };
 use namada_sdk::wallet::alias::Alias;
 use namada_sdk::wallet::pre_genesis::ValidatorWallet;
-use namada_sdk::wallet::Wallet;
+use namada_sdk::wallet::{DerivationPath, EncryptedKeypair, Wallet};
+#[cfg(not(target_family = "wasm"))]
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
+use zeroize::Zeroizing;
 
 use super::templates::{DenominatedBalances, Parameters, ValidityPredicates};
 use crate::config::genesis::chain::DeriveEstablishedAddress;
 use crate::config::genesis::templates::{
     TemplateValidation, Unvalidated, Validated,
 };
+use crate::config::genesis::utils::TomlKeyOrder;
 use crate::config::genesis::{utils, GenesisAddress};
-use crate::wallet::{CliWalletUtils, WalletTransport};
+use crate::wallet::{
+    CliWalletUtils, LockedKeys, Pkcs11Signer, TransportTcp, WalletTransport,
+};
 
 /// Dummy chain id used to sign [`Tx`] objects at pre-genesis.
 const NAMADA_GENESIS_TX_CHAIN_ID: &str = "namada-genesis";
@@ -155,37 +164,345 @@ pub struct GenesisValidatorData {
     pub name: Option<String>,
 }
 
+/// Keys of a genesis validator that must authorize its validator account tx.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ValidatorKeyKind {
+    /// The consensus key
+    Consensus,
+    /// The key used to sign protocol txs
+    Protocol,
+    /// The CometBFT node key
+    TendermintNode,
+    /// The Ethereum hot key, i.e. the validator's Ethereum bridge keypair
+    EthHot,
+    /// The Ethereum cold key
+    EthCold,
+}
+
+impl ValidatorKeyKind {
+    /// The signature scheme that is required for keys of this kind, if any.
+    pub fn required_scheme(&self) -> Option<SchemeType> {
+        match self {
+            Self::EthHot | Self::EthCold => Some(SchemeType::Secp256k1),
+            Self::Consensus | Self::Protocol | Self::TendermintNode => None,
+        }
+    }
+
+    /// Select the secret key of this kind from a validator pre-genesis wallet.
+    pub fn select_key<'wallet>(
+        &self,
+        validator_wallet: &'wallet ValidatorWallet,
+    ) -> &'wallet common::SecretKey {
+        match self {
+            Self::Consensus => &validator_wallet.consensus_key,
+            Self::Protocol => {
+                &validator_wallet.store.validator_keys.protocol_keypair
+            }
+            Self::TendermintNode => &validator_wallet.tendermint_node_key,
+            Self::EthHot => &validator_wallet.eth_hot_key,
+            Self::EthCold => &validator_wallet.eth_cold_key,
+        }
+    }
+}
+
+impl std::fmt::Display for ValidatorKeyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Consensus => write!(f, "consensus key"),
+            Self::Protocol => write!(f, "protocol key"),
+            Self::TendermintNode => write!(f, "tendermint node key"),
+            Self::EthHot => write!(f, "Ethereum hot key"),
+            Self::EthCold => write!(f, "Ethereum cold key"),
+        }
+    }
+}
+
+/// Select the secret key from the validator pre-genesis wallet that is
+/// expected to authorize the given public key of a validator account tx.
+///
+/// Returns an error if the public key is not of the scheme required by the
+/// key kind, or if the validator wallet doesn't hold the matching secret key,
+/// so that a tx is never signed with the wrong key.
+pub fn select_validator_key<'wallet>(
+    kind: ValidatorKeyKind,
+    expected_pk: &common::PublicKey,
+    validator_wallet: &'wallet ValidatorWallet,
+) -> eyre::Result<&'wallet common::SecretKey> {
+    if let Some(SchemeType::Secp256k1) = kind.required_scheme() {
+        if !matches!(expected_pk, common::PublicKey::Secp256k1(_)) {
+            return Err(eyre::eyre!(
+                "The {kind} {expected_pk} of the validator account tx must be \
+                 a secp256k1 key"
+            ));
+        }
+    }
+    let sk = kind.select_key(validator_wallet);
+    if sk.ref_to() != *expected_pk {
+        return Err(eyre::eyre!(
+            "The {kind} {expected_pk} of the validator account tx doesn't \
+             match the {kind} {} found in the validator pre-genesis wallet",
+            sk.ref_to()
+        ));
+    }
+    Ok(sk)
+}
+
+/// What genesis txs are signed with, besides the keys of the software wallet
+pub enum SignerBackend {
+    /// Only the software wallet
+    Wallet,
+    /// A hardware wallet
+    Device {
+        /// The transport that the hardware wallet is connected over
+        transport: DeviceTransport,
+        /// Whether the APDU exchanges with the hardware wallet are logged
+        debug_apdu: bool,
+        /// Whether the operator is offered to retry signing a tx that they
+        /// rejected on the device
+        retry_rejections: bool,
+        /// Whether there is a terminal to ask to retry signing in
+        is_tty: bool,
+    },
+    /// A key pair held in a PKCS#11 token
+    Pkcs11(Pkcs11Signer),
+}
+
+impl SignerBackend {
+    /// The backend of the `--use-device` and `--device-transport` args
+    pub fn from_device_args(
+        use_device: bool,
+        device_transport: DeviceTransport,
+        debug_apdu: bool,
+        retry_rejections: bool,
+        is_tty: bool,
+    ) -> Self {
+        if use_device {
+            Self::Device {
+                transport: device_transport,
+                debug_apdu,
+                retry_rejections,
+                is_tty,
+            }
+        } else {
+            Self::Wallet
+        }
+    }
+
+    /// Whether genesis txs are signed with a hardware wallet
+    pub fn is_device(&self) -> bool {
+        matches!(self, Self::Device { .. })
+    }
+}
+
+/// The number of times that signing a tx with the hardware wallet is
+/// attempted when the operator keeps rejecting it
+const MAX_DEVICE_SIGN_ATTEMPTS: usize = 4;
+
+/// The status word that the device answers with when the operator rejects a
+/// tx, `APDU_CODE_COMMAND_NOT_ALLOWED`
+const DEVICE_REJECTION_RETCODE: u16 = 0x6986;
+
+/// Whether signing with the hardware wallet failed on the operator rejecting
+/// the tx on the device, going by the status word of the last answer of the
+/// device. A failure after the device last answered successfully, or before
+/// it answered at all, is never a rejection.
+fn is_device_rejection(last_retcode: Option<u16>) -> bool {
+    last_retcode == Some(DEVICE_REJECTION_RETCODE)
+}
+
+/// Ask the operator whether to retry signing a tx that they rejected on the
+/// hardware wallet, after the given number of attempts. Never retries when
+/// retrying is disabled, the attempts are used up or there's no terminal to
+/// answer in.
+fn confirm_device_retry(
+    attempts: usize,
+    retry_rejections: bool,
+    is_tty: bool,
+) -> bool {
+    if !retry_rejections || attempts >= MAX_DEVICE_SIGN_ATTEMPTS || !is_tty {
+        return false;
+    }
+    eprint!(
+        "The transaction was rejected on the device. Retry signing it ({} \
+         retries left)? [y/N]: ",
+        MAX_DEVICE_SIGN_ATTEMPTS.saturating_sub(attempts)
+    );
+    let mut answer = String::new();
+    std::io::stderr().flush().is_ok()
+        && std::io::stdin().read_line(&mut answer).is_ok()
+        && matches!(answer.trim().to_ascii_lowercase().as_str(), "y" | "yes")
+}
+
+/// The [`SignerBackend`]s that can be selected with `--signer-backend`. A
+/// hardware wallet is selected with `--use-device` instead.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SignerBackendKind {
+    /// The software wallet
+    #[default]
+    Wallet,
+    /// A PKCS#11 token
+    Pkcs11,
+}
+
+impl std::str::FromStr for SignerBackendKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "wallet" => Ok(Self::Wallet),
+            "pkcs11" => Ok(Self::Pkcs11),
+            raw => Err(format!(
+                "Unexpected signer backend \"{raw}\". Valid options are \
+                 \"wallet\" or \"pkcs11\"."
+            )),
+        }
+    }
+}
+
 /// Sign all genesis transactions.
 ///
 /// Panics if the given `txs.validator_accounts` is non-empty and
 /// `validator_wallet` is `None`.
+///
+/// Validator account txs are authorized with the matching keys of the
+/// `validator_wallet` - in particular, their Ethereum hot key is authorized
+/// with the validator's Ethereum bridge keypair. An error is returned if any
+/// of the keys don't match.
 pub async fn sign_txs(
     txs: UnsignedTransactions,
     wallet: &RwLock<Wallet<CliWalletUtils>>,
     validator_wallet: Option<&ValidatorWallet>,
-    use_device: bool,
-    device_transport: DeviceTransport,
-) -> Transactions<Unvalidated> {
+    backend: &SignerBackend,
+) -> eyre::Result<Transactions<Unvalidated>> {
+    let (signed, _failures) = sign_txs_with(
+        txs,
+        wallet,
+        validator_wallet,
+        backend,
+        SignErrorMode::FailFast,
+        false,
+    )
+    .await?;
+    Ok(signed)
+}
+
+/// How to proceed when a genesis tx fails to be signed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SignErrorMode {
+    /// Abort signing on the first failure
+    #[default]
+    FailFast,
+    /// Leave out the txs that fail to be signed and report them once all
+    /// the others are signed
+    ContinueOnError,
+}
+
+impl SignErrorMode {
+    /// Return the error of a tx that failed to be signed when failing fast,
+    /// otherwise record it with the other failures.
+    fn handle(
+        self,
+        tx: String,
+        err: eyre::Report,
+        failures: &mut Vec<SignFailure>,
+    ) -> eyre::Result<()> {
+        match self {
+            Self::FailFast => {
+                Err(eyre::eyre!("Failed to sign the {tx}: {err}"))
+            }
+            Self::ContinueOnError => {
+                failures.push(SignFailure {
+                    tx,
+                    reason: err.to_string(),
+                });
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A genesis tx that failed to be signed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignFailure {
+    /// Description of the tx
+    pub tx: String,
+    /// Why it couldn't be signed
+    pub reason: String,
+}
+
+/// Sign the given txs, proceeding on failures as given by `on_error`. Along
+/// with the signed txs, returns the txs that were left out after failing to
+/// be signed, which is only ever non-empty when continuing on errors. The
+/// decrypted keys are locked in memory while signing if `lock_keys` is set.
+pub async fn sign_txs_with(
+    txs: UnsignedTransactions,
+    wallet: &RwLock<Wallet<CliWalletUtils>>,
+    validator_wallet: Option<&ValidatorWallet>,
+    backend: &SignerBackend,
+    on_error: SignErrorMode,
+    lock_keys: bool,
+) -> eyre::Result<(Transactions<Unvalidated>, Vec<SignFailure>)> {
     let UnsignedTransactions {
         established_account,
         validator_account,
         bond,
     } = txs;
+    let mut failures = vec![];
+    let signers: Vec<_> = {
+        let accounts = established_account.as_deref().unwrap_or(&[]);
+        let bond_signers = bond
+            .iter()
+            .flatten()
+            .map(|tx| (describe_bond_tx(tx), tx.get_pks(accounts).0));
+        let validator_signers = validator_account.iter().flatten().map(|tx| {
+            let pks = accounts
+                .iter()
+                .filter(|account| {
+                    account.derive_established_address() == tx.address.raw
+                })
+                .flat_map(|account| {
+                    account.public_keys.iter().map(|pk| pk.raw.clone())
+                })
+                .collect();
+            (describe_validator_account_tx(&tx.address.raw), pks)
+        });
+        bond_signers.chain(validator_signers).collect()
+    };
+    if let SignerBackend::Device {
+        transport,
+        debug_apdu,
+        ..
+    } = backend
+    {
+        if matches!(transport, DeviceTransport::Tcp) {
+            probe_device_emulator(*debug_apdu).await?;
+        }
+        check_device_schemes(&signers, wallet, *transport, *debug_apdu).await?;
+    }
+    let locked_keys = lock_signing_keys(
+        wallet,
+        signers.into_iter().flat_map(|(_tx, pks)| pks),
+        lock_keys,
+        backend.is_device(),
+    )
+    .await;
 
     // Sign bond txs
     let bond = if let Some(txs) = bond {
         let mut bonds = vec![];
         for tx in txs {
-            bonds.push(
-                sign_delegation_bond_tx(
-                    tx.into(),
-                    wallet,
-                    &established_account,
-                    use_device,
-                    device_transport,
-                )
-                .await,
-            );
+            let description = describe_bond_tx(&tx);
+            match sign_delegation_bond_tx(
+                tx.into(),
+                wallet,
+                &established_account,
+                backend,
+            )
+            .await
+            {
+                Ok(signed) => bonds.push(signed),
+                Err(err) => on_error.handle(description, err, &mut failures)?,
+            }
         }
         Some(bonds)
     } else {
@@ -200,40 +517,1233 @@ pub async fn sign_txs(
         let mut filtered_txs = vec![];
         for tx in txs {
             if tx.tendermint_node_key.raw == tnk {
-                filtered_txs.push(
-                    sign_validator_account_tx(
-                        Either::Left((tx, validator_wallet)),
-                        wallet,
-                        established_account.as_ref().expect(
-                            "Established account txs required when signing \
-                             validator account txs",
-                        ),
-                        use_device,
-                        device_transport,
-                    )
-                    .await,
-                );
+                let description =
+                    describe_validator_account_tx(&tx.address.raw);
+                match sign_validator_account_tx(
+                    Either::Left((tx, validator_wallet)),
+                    wallet,
+                    established_account.as_ref().expect(
+                        "Established account txs required when signing \
+                         validator account txs",
+                    ),
+                    backend,
+                )
+                .await
+                {
+                    Ok(signed) => filtered_txs.push(signed),
+                    Err(err) => {
+                        on_error.handle(description, err, &mut failures)?
+                    }
+                }
             }
         }
         Some(filtered_txs)
     } else {
         None
     };
+    drop(locked_keys);
 
-    Transactions {
-        established_account,
-        validator_account,
-        bond,
+    Ok((
+        Transactions {
+            established_account,
+            validator_account,
+            bond,
+        },
+        failures,
+    ))
+}
+
+/// Describe a bond tx in messages about signing it.
+fn describe_bond_tx<T: TemplateValidation>(tx: &BondTx<T>) -> String {
+    format!("bond tx from {} to {}", tx.source, tx.validator)
+}
+
+/// Describe the validator account tx of the given address in messages about
+/// signing it.
+fn describe_validator_account_tx(address: &EstablishedAddress) -> String {
+    format!(
+        "validator account tx of {}",
+        Address::Established(address.clone())
+    )
+}
+
+/// The oldest version of the Namada app of a hardware wallet, as `(major,
+/// minor, patch)`, whose signatures of genesis txs are compatible with those
+/// of this version of Namada
+pub const MIN_DEVICE_APP_VERSION: (u32, u32, u32) = (0, 0, 24);
+
+/// Check that the Namada app of the connected hardware wallet is at least
+/// [`MIN_DEVICE_APP_VERSION`] before signing with it, as older ones sign
+/// genesis txs incompatibly.
+pub async fn check_device_app_version(
+    device_transport: DeviceTransport,
+    debug_apdu: bool,
+) -> eyre::Result<()> {
+    let app =
+        NamadaApp::new(WalletTransport::from_arg(device_transport, debug_apdu));
+    let version = app.version().await.map_err(|err| {
+        eyre::eyre!(
+            "Unable to query the version of the Namada app on the device: \
+             {err}"
+        )
+    })?;
+    ensure_min_device_app_version((
+        u32::from(version.major),
+        u32::from(version.minor),
+        u32::from(version.patch),
+    ))
+}
+
+/// How long the device emulator of the TCP transport has to answer the
+/// liveness probe made before signing
+const DEVICE_EMULATOR_PROBE_TIMEOUT: std::time::Duration =
+    std::time::Duration::from_secs(5);
+
+/// Check that the device emulator of the TCP transport answers a trivial
+/// query, so that signing fails up front on a dead endpoint rather than
+/// partway through the txs.
+async fn probe_device_emulator(debug_apdu: bool) -> eyre::Result<()> {
+    let address = TransportTcp::proxy_address();
+    let app = NamadaApp::new(WalletTransport::from_arg(
+        DeviceTransport::Tcp,
+        debug_apdu,
+    ));
+    // Timed out with the sleep of the SDK, which unlike tokio's also works on
+    // wasm32
+    let version = std::pin::pin!(app.version());
+    let timeout = std::pin::pin!(namada_sdk::control_flow::time::sleep(
+        DEVICE_EMULATOR_PROBE_TIMEOUT
+    ));
+    match futures::future::select(version, timeout).await {
+        futures::future::Either::Left((Ok(_version), _)) => Ok(()),
+        futures::future::Either::Left((Err(err), _)) => Err(eyre::eyre!(
+            "The device emulator at {address} is not responding: {err}"
+        )),
+        futures::future::Either::Right(((), _)) => Err(eyre::eyre!(
+            "The device emulator at {address} is not responding: no answer \
+             within {} seconds",
+            DEVICE_EMULATOR_PROBE_TIMEOUT.as_secs()
+        )),
+    }
+}
+
+fn ensure_min_device_app_version(
+    version @ (major, minor, patch): (u32, u32, u32),
+) -> eyre::Result<()> {
+    if version < MIN_DEVICE_APP_VERSION {
+        let (min_major, min_minor, min_patch) = MIN_DEVICE_APP_VERSION;
+        return Err(eyre::eyre!(
+            "The Namada app on the device is at version \
+             {major}.{minor}.{patch}, but signing genesis txs requires at \
+             least version {min_major}.{min_minor}.{min_patch}, as older \
+             ones produce incompatible signatures. Upgrade the app on the \
+             device, e.g. with Ledger Live."
+        ));
+    }
+    Ok(())
+}
+
+/// Query the signature schemes that the connected hardware wallet signs
+/// with. The device derives all of its transparent keys with one scheme,
+/// which is read off the public key that it derives at the default path.
+async fn query_device_schemes(
+    device_transport: DeviceTransport,
+    debug_apdu: bool,
+) -> eyre::Result<Vec<SchemeType>> {
+    let app =
+        NamadaApp::new(WalletTransport::from_arg(device_transport, debug_apdu));
+    let path = BIP44Path {
+        path: DerivationPath::default_for_transparent_scheme(
+            SchemeType::Ed25519,
+        )
+        .to_string(),
+    };
+    let response = app
+        .get_address_and_pubkey(&path, false)
+        .await
+        .map_err(|err| {
+            eyre::eyre!(
+                "Unable to query the device for the schemes it signs with: \
+                 {err}"
+            )
+        })?;
+    let pk = common::PublicKey::try_from_slice(&response.public_key).map_err(
+        |err| {
+            eyre::eyre!(
+                "Unable to decode the public key from the device: {err}"
+            )
+        },
+    )?;
+    Ok(vec![pk.scheme()])
+}
+
+/// Check ahead of signing with the hardware wallet that it supports the
+/// scheme of every key of the wallet that the given txs are to be signed
+/// with, so that an unsupported key fails before any signature is attempted.
+/// The device is only queried once for all the txs.
+async fn check_device_schemes(
+    signers: &[(String, Vec<common::PublicKey>)],
+    wallet: &RwLock<Wallet<CliWalletUtils>>,
+    device_transport: DeviceTransport,
+    debug_apdu: bool,
+) -> eyre::Result<()> {
+    let supported = query_device_schemes(device_transport, debug_apdu).await?;
+    let wallet = wallet.read().await;
+    for (tx, pks) in signers {
+        let unsupported = pks.iter().find(|pk| {
+            !supported.contains(&pk.scheme())
+                && wallet.find_public_key_by_pkh(&(*pk).into()).is_ok()
+        });
+        if let Some(pk) = unsupported {
+            return Err(eyre::eyre!(
+                "The {tx} is to be signed with the {} key {pk}, but the \
+                 device only signs with {} keys.",
+                pk.scheme(),
+                supported.iter().join(", ")
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Decrypt the keys of the wallet among the given signers ahead of signing
+/// and lock them in memory, if `lock_keys` is set. The keys of a hardware
+/// wallet never enter memory and unencrypted keys are already in plaintext in
+/// the wallet file, so neither of them is locked.
+async fn lock_signing_keys<'a>(
+    wallet: &'a RwLock<Wallet<CliWalletUtils>>,
+    signers: impl IntoIterator<Item = common::PublicKey>,
+    lock_keys: bool,
+    use_device: bool,
+) -> SigningKeysLock<'a> {
+    if use_device || !lock_keys {
+        return SigningKeysLock {
+            wallet,
+            locked_keys: None,
+        };
+    }
+    let locked_keys = {
+        let mut wallet = wallet.write().await;
+        for pk in signers.into_iter().collect::<BTreeSet<_>>() {
+            // A signer missing from the wallet is left for signing to report
+            let _ = wallet.find_key_by_pk(&pk, None);
+        }
+        LockedKeys::lock(wallet.decrypted_secret_keys())
+    };
+    SigningKeysLock {
+        wallet,
+        locked_keys: Some(locked_keys),
+    }
+}
+
+/// The keys decrypted for signing, as locked in memory by
+/// [`lock_signing_keys`]. However signing ends, the keys are zeroized on drop
+/// before their memory is unlocked.
+struct SigningKeysLock<'a> {
+    wallet: &'a RwLock<Wallet<CliWalletUtils>>,
+    locked_keys: Option<LockedKeys>,
+}
+
+impl Drop for SigningKeysLock<'_> {
+    fn drop(&mut self) {
+        let Some(locked_keys) = self.locked_keys.take() else {
+            return;
+        };
+        match self.wallet.try_write() {
+            Ok(mut wallet) => {
+                // Zeroize the keys while their memory is still locked
+                wallet.clear_decrypted_key_cache();
+                drop(locked_keys);
+            }
+            // The keys can't be zeroized while the wallet is borrowed, so
+            // their memory stays locked until the process exits rather than
+            // being unlocked with the keys in it
+            Err(_) => std::mem::forget(locked_keys),
+        }
+    }
+}
+
+/// Sign the given signed txs that lack a signature of every key of this
+/// wallet that may sign them, leaving those that any of its keys already
+/// signed untouched. Returns the txs along with the number of txs that were
+/// signed. The decrypted keys are locked in memory while signing if
+/// `lock_keys` is set.
+pub async fn sign_missing(
+    mut txs: Transactions<Unvalidated>,
+    wallet: &RwLock<Wallet<CliWalletUtils>>,
+    backend: &SignerBackend,
+    lock_keys: bool,
+) -> eyre::Result<(Transactions<Unvalidated>, usize)> {
+    let established_accounts =
+        txs.established_account.as_deref().unwrap_or(&[]);
+    let mut num_signed: usize = 0;
+    let validator_signers = txs.validator_account.iter().flatten().map(|tx| {
+        (
+            describe_validator_account_tx(&tx.data.address.raw),
+            tx.data.get_pks(established_accounts).0,
+        )
+    });
+    let bond_signers = txs.bond.iter().flatten().map(|tx| {
+        (
+            describe_bond_tx(&tx.data),
+            tx.data.get_pks(established_accounts).0,
+        )
+    });
+    let signers: Vec<_> = validator_signers.chain(bond_signers).collect();
+    if let SignerBackend::Device {
+        transport,
+        debug_apdu,
+        ..
+    } = backend
+    {
+        if matches!(transport, DeviceTransport::Tcp) {
+            probe_device_emulator(*debug_apdu).await?;
+        }
+        check_device_schemes(&signers, wallet, *transport, *debug_apdu).await?;
     }
+    let locked_keys = lock_signing_keys(
+        wallet,
+        signers.into_iter().flat_map(|(_tx, pks)| pks),
+        lock_keys,
+        backend.is_device(),
+    )
+    .await;
+
+    for tx in txs.validator_account.iter_mut().flatten() {
+        let lacks_signature = {
+            let wallet = wallet.read().await;
+            lacks_own_signature(tx, established_accounts, |pk| {
+                wallet.find_public_key_by_pkh(&pk.into()).is_ok()
+            })
+        };
+        if lacks_signature {
+            tx.sign(established_accounts, wallet, backend)
+                .await
+                .map_err(|err| {
+                    eyre::eyre!(
+                        "Failed to sign the validator account tx of {}: {err}",
+                        Address::Established(tx.data.address.raw.clone())
+                    )
+                })?;
+            num_signed = num_signed.saturating_add(1);
+        }
+    }
+    for tx in txs.bond.iter_mut().flatten() {
+        let lacks_signature = {
+            let wallet = wallet.read().await;
+            lacks_own_signature(tx, established_accounts, |pk| {
+                wallet.find_public_key_by_pkh(&pk.into()).is_ok()
+            })
+        };
+        if lacks_signature {
+            tx.sign(established_accounts, wallet, backend)
+                .await
+                .map_err(|err| {
+                    eyre::eyre!(
+                        "Failed to sign the bond tx from {} to {}: {err}",
+                        tx.data.source,
+                        tx.data.validator
+                    )
+                })?;
+            num_signed = num_signed.saturating_add(1);
+        }
+    }
+    drop(locked_keys);
+    Ok((txs, num_signed))
 }
 
-/// Parse [`UnsignedTransactions`] from bytes.
+/// Whether some of the given own keys may sign the tx, while none of them
+/// has signed it yet.
+fn lacks_own_signature<T: TxToSign>(
+    tx: &Signed<T>,
+    established_accounts: &[EstablishedAccountTx],
+    is_own_key: impl Fn(&common::PublicKey) -> bool,
+) -> bool {
+    let (pks, _threshold) = tx.data.get_pks(established_accounts);
+    let mut own_keys = pks.into_iter().filter(|pk| is_own_key(pk)).peekable();
+    own_keys.peek().is_some()
+        && own_keys
+            .all(|pk| !tx.signatures.contains_key(&StringEncoded::new(pk)))
+}
+
+/// The maximum nesting depth of arrays and tables in unsigned txs. The TOML
+/// parser recurses into nested values, so deeper documents could overflow
+/// the stack.
+const MAX_UNSIGNED_NESTING_DEPTH: usize = 32;
+
+/// Parse [`UnsignedTransactions`] from bytes. Returns an error rather than
+/// panicking on any malformed input.
 pub fn parse_unsigned(
     bytes: &[u8],
 ) -> Result<UnsignedTransactions, toml::de::Error> {
+    parse_bounded(bytes)
+}
+
+/// Parse signed [`Transactions`] from bytes. Returns an error rather than
+/// panicking on any malformed input. The signatures may be in any
+/// [`SignatureEncoding`].
+pub fn parse_signed(
+    bytes: &[u8],
+) -> Result<Transactions<Unvalidated>, toml::de::Error> {
+    let mut value: toml::Value = parse_bounded(bytes)?;
+    for signature in signature_values_mut(&mut value) {
+        if let Some(raw) = signature.as_str() {
+            let decoded = SignatureEncoding::decode_any(raw)
+                .map_err(serde::de::Error::custom)?;
+            *signature = toml::Value::String(decoded);
+        }
+    }
+    value.try_into()
+}
+
+/// Re-emit a signed or unsigned txs TOML document in a canonical form, with
+/// sorted keys and normalized whitespace. Documents that hold the same txs
+/// thus give the same bytes, whichever tool wrote them.
+pub fn canonicalize(bytes: &[u8]) -> Result<String, String> {
+    let canonical = match parse_signed(bytes) {
+        Ok(signed) => utils::to_toml_string(&signed, TomlKeyOrder::Sorted),
+        Err(signed_err) => {
+            let unsigned = parse_unsigned(bytes).map_err(|unsigned_err| {
+                format!(
+                    "The document holds neither signed txs ({signed_err}) \
+                     nor unsigned txs ({unsigned_err})."
+                )
+            })?;
+            utils::to_toml_string(&unsigned, TomlKeyOrder::Sorted)
+        }
+    };
+    canonical.map_err(|err| format!("Unable to serialize the txs: {err}"))
+}
+
+/// The start of a txs document encrypted with a password, which is followed
+/// by the document encrypted as keys are in the wallet
+pub const ENCRYPTED_TXS_PREFIX: &str = "namada-encrypted-txs:";
+
+/// Whether the given txs document is encrypted, as told by its start
+pub fn is_encrypted_txs(bytes: &[u8]) -> bool {
+    bytes
+        .trim_ascii_start()
+        .starts_with(ENCRYPTED_TXS_PREFIX.as_bytes())
+}
+
+/// Encrypt a txs document with the given password.
+pub fn encrypt_txs(bytes: &[u8], password: Zeroizing<String>) -> String {
+    let encrypted = EncryptedKeypair::new(&bytes.to_vec(), password);
+    format!("{ENCRYPTED_TXS_PREFIX}{encrypted}\n")
+}
+
+/// Decrypt a txs document encrypted with [`encrypt_txs`] with the given
+/// password.
+pub fn decrypt_txs(
+    bytes: &[u8],
+    password: Zeroizing<String>,
+) -> Result<Vec<u8>, String> {
+    let encrypted = std::str::from_utf8(bytes)
+        .ok()
+        .and_then(|encrypted| {
+            encrypted.trim().strip_prefix(ENCRYPTED_TXS_PREFIX)
+        })
+        .ok_or_else(|| "The txs document isn't encrypted.".to_string())?
+        .parse::<EncryptedKeypair<Vec<u8>>>()
+        .map_err(|err| format!("Invalid encrypted txs document: {err}"))?;
+    encrypted
+        .decrypt(password)
+        .map_err(|err| format!("Unable to decrypt the txs document: {err}"))
+}
+
+/// How the signatures of signed txs are encoded in their TOML documents
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SignatureEncoding {
+    /// Bech32m, as signatures are serialized
+    #[default]
+    Default,
+    /// Hex-encoded Borsh bytes
+    Hex,
+    /// Base64-encoded Borsh bytes
+    Base64,
+}
+
+impl std::str::FromStr for SignatureEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "default" => Ok(Self::Default),
+            "hex" => Ok(Self::Hex),
+            "base64" => Ok(Self::Base64),
+            raw => Err(format!(
+                "Unexpected signature encoding \"{raw}\". Valid options are \
+                 \"default\", \"hex\" or \"base64\"."
+            )),
+        }
+    }
+}
+
+impl SignatureEncoding {
+    /// Encode the given signature
+    pub fn encode(self, signature: &common::Signature) -> String {
+        match self {
+            Self::Default => StringEncoded::new(signature.clone()).to_string(),
+            Self::Hex => {
+                data_encoding::HEXLOWER.encode(&signature.serialize_to_vec())
+            }
+            Self::Base64 => {
+                data_encoding::BASE64.encode(&signature.serialize_to_vec())
+            }
+        }
+    }
+
+    /// Re-encode a signature in any of the encodings in the default one
+    fn decode_any(raw: &str) -> Result<String, String> {
+        if raw.parse::<StringEncoded<common::Signature>>().is_ok() {
+            return Ok(raw.to_string());
+        }
+        [data_encoding::HEXLOWER_PERMISSIVE, data_encoding::BASE64]
+            .iter()
+            .find_map(|encoding| {
+                let bytes = encoding.decode(raw.as_bytes()).ok()?;
+                common::Signature::try_from_slice(&bytes).ok()
+            })
+            .map(|signature| Self::Default.encode(&signature))
+            .ok_or_else(|| {
+                format!("\"{raw}\" is not a signature in any known encoding")
+            })
+    }
+}
+
+/// Encode the signatures of the given serialized signed txs with the given
+/// encoding instead of the default one.
+pub fn encode_signatures(value: &mut toml::Value, encoding: SignatureEncoding) {
+    for signature in signature_values_mut(value) {
+        let decoded = signature.as_str().and_then(|raw| {
+            raw.parse::<StringEncoded<common::Signature>>().ok()
+        });
+        if let Some(decoded) = decoded {
+            *signature = toml::Value::String(encoding.encode(&decoded.raw));
+        }
+    }
+}
+
+/// The signatures of the validator account and bond txs of the given
+/// serialized signed txs
+fn signature_values_mut(
+    value: &mut toml::Value,
+) -> impl Iterator<Item = &mut toml::Value> {
+    value
+        .as_table_mut()
+        .into_iter()
+        .flat_map(|txs| txs.iter_mut())
+        .filter(|(key, _)| {
+            ["validator_account", "bond"].contains(&key.as_str())
+        })
+        .filter_map(|(_, txs)| txs.as_array_mut())
+        .flatten()
+        .filter_map(|tx| tx.get_mut("signatures"))
+        .filter_map(|signatures| signatures.as_table_mut())
+        .flat_map(|signatures| signatures.values_mut())
+}
+
+/// The key of the table of the addresses of the signers of a tx, annotated by
+/// [`with_signer_addresses`]
+pub const SIGNER_ADDRESSES_KEY: &str = "signer_addresses";
+
+/// Annotate each signed tx with the implicit address of each of its signers,
+/// derived from their public key, in a table keyed by public key like the
+/// signatures. The annotations are ignored when the txs are parsed back.
+pub fn with_signer_addresses(
+    signed: &Transactions<Unvalidated>,
+) -> Result<toml::Value, toml::ser::Error> {
+    let mut value = toml::Value::try_from(signed)?;
+    annotate_signer_addresses(
+        &mut value,
+        "validator_account",
+        signed
+            .validator_account
+            .iter()
+            .flatten()
+            .map(|tx| &tx.signatures),
+    );
+    annotate_signer_addresses(
+        &mut value,
+        "bond",
+        signed.bond.iter().flatten().map(|tx| &tx.signatures),
+    );
+    Ok(value)
+}
+
+/// Add the addresses of the signers of each of the txs under the given key
+/// of the serialized txs, which are in the same order as the signatures.
+fn annotate_signer_addresses<'a>(
+    value: &mut toml::Value,
+    key: &str,
+    signatures: impl Iterator<
+        Item = &'a BTreeMap<
+            StringEncoded<common::PublicKey>,
+            StringEncoded<common::Signature>,
+        >,
+    >,
+) {
+    let Some(toml::Value::Array(txs)) = value.get_mut(key) else {
+        return;
+    };
+    for (tx, signatures) in txs.iter_mut().zip(signatures) {
+        if let toml::Value::Table(tx) = tx {
+            let addresses = signatures
+                .keys()
+                .map(|pk| {
+                    let address = Address::from(&pk.raw);
+                    (pk.to_string(), toml::Value::String(address.to_string()))
+                })
+                .collect();
+            tx.insert(
+                SIGNER_ADDRESSES_KEY.to_string(),
+                toml::Value::Table(addresses),
+            );
+        }
+    }
+}
+
+/// Merge signed txs documents, e.g. the ones that different signers returned
+/// for the same txs. Takes the union of the txs and of the signatures of each
+/// tx. Fails if two documents hold different signatures of a tx by the same
+/// signer.
+pub fn merge_signed(
+    docs: impl IntoIterator<Item = Transactions<Unvalidated>>,
+) -> Result<Transactions<Unvalidated>, String> {
+    let mut merged = Transactions::<Unvalidated>::default();
+    let mut validator_accounts = vec![];
+    let mut bonds = vec![];
+    for doc in docs {
+        let Transactions {
+            established_account,
+            validator_account,
+            bond,
+        } = doc;
+        merged.merge(Transactions {
+            established_account,
+            ..Default::default()
+        });
+        validator_accounts.extend(validator_account);
+        bonds.extend(bond);
+    }
+    merged.validator_account = merge_signatures(validator_accounts, |tx| {
+        describe_validator_account_tx(&tx.address.raw)
+    })?;
+    merged.bond = merge_signatures(bonds, describe_bond_tx)?;
+    Ok(merged)
+}
+
+/// Take the union of the signatures of each of the given txs, which is `None`
+/// if none of the documents held this kind of txs.
+fn merge_signatures<T: Ord>(
+    txs: Vec<Vec<Signed<T>>>,
+    describe: impl Fn(&T) -> String,
+) -> Result<Option<Vec<Signed<T>>>, String> {
+    if txs.is_empty() {
+        return Ok(None);
+    }
+    let mut merged: BTreeMap<T, BTreeMap<_, _>> = BTreeMap::new();
+    for Signed { data, signatures } in txs.into_iter().flatten() {
+        if let Some(known) = merged.get(&data) {
+            for (signer, signature) in &signatures {
+                if known.get(signer).is_some_and(|known| known != signature) {
+                    return Err(format!(
+                        "The {} has conflicting signatures by {signer}.",
+                        describe(&data)
+                    ));
+                }
+            }
+        }
+        merged.entry(data).or_default().extend(signatures);
+    }
+    Ok(Some(
+        merged
+            .into_iter()
+            .map(|(data, signatures)| Signed { data, signatures })
+            .collect(),
+    ))
+}
+
+/// Parse TOML with a bounded nesting depth.
+fn parse_bounded<T: serde::de::DeserializeOwned>(
+    bytes: &[u8],
+) -> Result<T, toml::de::Error> {
+    if nesting_depth(bytes) > MAX_UNSIGNED_NESTING_DEPTH {
+        return Err(serde::de::Error::custom(format!(
+            "arrays or tables are nested more than \
+             {MAX_UNSIGNED_NESTING_DEPTH} levels deep"
+        )));
+    }
     toml::from_slice(bytes)
 }
 
+/// An upper bound of the nesting depth of arrays and tables in a TOML
+/// document. Brackets within strings and comments are skipped.
+fn nesting_depth(bytes: &[u8]) -> usize {
+    let mut depth: usize = 0;
+    let mut max_depth = 0;
+    let mut bytes = bytes.iter();
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'[' | b'{' => {
+                depth = depth.saturating_add(1);
+                max_depth = max_depth.max(depth);
+            }
+            b']' | b'}' => depth = depth.saturating_sub(1),
+            b'"' => {
+                while let Some(byte) = bytes.next() {
+                    match byte {
+                        b'\\' => {
+                            bytes.next();
+                        }
+                        b'"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            b'\'' => {
+                bytes.by_ref().find(|byte| **byte == b'\'');
+            }
+            b'#' => {
+                bytes.by_ref().find(|byte| **byte == b'\n');
+            }
+            _ => {}
+        }
+    }
+    max_depth
+}
+
+/// The names of the arrays of tables that hold unsigned txs.
+const UNSIGNED_TX_TABLES: [&str; 3] =
+    ["established_account", "validator_account", "bond"];
+
+/// The maximum length in bytes of a single tx read by an
+/// [`UnsignedTxReader`].
+const MAX_UNSIGNED_TX_LEN: usize = 1 << 20;
+
+/// A single unsigned genesis tx.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UnsignedTx {
+    EstablishedAccount(EstablishedAccountTx),
+    ValidatorAccount(UnsignedValidatorAccountTx),
+    Bond(BondTx<Unvalidated>),
+}
+
+impl From<UnsignedTx> for UnsignedTransactions {
+    fn from(tx: UnsignedTx) -> Self {
+        match tx {
+            UnsignedTx::EstablishedAccount(tx) => Self {
+                established_account: Some(vec![tx]),
+                ..Default::default()
+            },
+            UnsignedTx::ValidatorAccount(tx) => Self {
+                validator_account: Some(vec![tx]),
+                ..Default::default()
+            },
+            UnsignedTx::Bond(tx) => Self {
+                bond: Some(vec![tx]),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Reads the unsigned txs of a TOML document one at a time, such that only
+/// a single tx is held in memory at once, unlike [`parse_unsigned`]. A tx
+/// spans from its `[[established_account]]`, `[[validator_account]]` or
+/// `[[bond]]` header up to the next one. Reading should stop at the first
+/// error.
+pub struct UnsignedTxReader<R> {
+    lines: std::io::Lines<R>,
+    /// The number of lines read so far
+    line_num: usize,
+    /// The header of the next tx and its line number, which is read while
+    /// looking for the end of the previous tx
+    next_header: Option<(usize, String)>,
+    /// The closing delimiter of the multi-line string that the last line
+    /// read ended in, if any
+    multiline: Option<&'static str>,
+}
+
+impl<R: std::io::BufRead> UnsignedTxReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: reader.lines(),
+            line_num: 0,
+            next_header: None,
+            multiline: None,
+        }
+    }
+
+    /// Count the txs left to read that are to be signed, i.e. the
+    /// validator accounts and bonds, without parsing them.
+    pub fn count_txs(mut self) -> eyre::Result<usize> {
+        let mut count: usize = 0;
+        while let Some(chunk) = self.next_chunk() {
+            let (_, table, _) = chunk?;
+            if table != "established_account" {
+                count = count.saturating_add(1);
+            }
+        }
+        Ok(count)
+    }
+
+    /// Read the TOML source of the next tx along with the number of the
+    /// line that it starts at and the name of its table.
+    fn next_chunk(
+        &mut self,
+    ) -> Option<eyre::Result<(usize, &'static str, String)>> {
+        let mut chunk = String::new();
+        let mut start = self.line_num.saturating_add(1);
+        let mut table = None;
+        if let Some((line_num, header)) = self.next_header.take() {
+            start = line_num;
+            table = unsigned_tx_table(&header);
+            chunk.push_str(&header);
+            chunk.push('\n');
+        }
+        for line in self.lines.by_ref() {
+            self.line_num = self.line_num.saturating_add(1);
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => {
+                    return Some(Err(eyre::eyre!(
+                        "Failed to read line {}: {err}",
+                        self.line_num
+                    )));
+                }
+            };
+            let starts_in_string = self.multiline.is_some();
+            self.multiline = multiline_after(&line, self.multiline);
+            let header_table = if starts_in_string {
+                None
+            } else {
+                unsigned_tx_table(&line)
+            };
+            if let Some(header_table) = header_table {
+                if let Some(table) = table {
+                    self.next_header = Some((self.line_num, line));
+                    return Some(Ok((start, table, chunk)));
+                }
+                start = self.line_num;
+                table = Some(header_table);
+            }
+            if chunk.len().saturating_add(line.len()) > MAX_UNSIGNED_TX_LEN {
+                return Some(Err(eyre::eyre!(
+                    "The tx at line {start} is longer than the maximum of \
+                     {MAX_UNSIGNED_TX_LEN} bytes"
+                )));
+            }
+            chunk.push_str(&line);
+            chunk.push('\n');
+        }
+        table.map(|table| Ok((start, table, chunk)))
+    }
+}
+
+impl<R: std::io::BufRead> Iterator for UnsignedTxReader<R> {
+    type Item = eyre::Result<UnsignedTx>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (line_num, _, chunk) = match self.next_chunk()? {
+            Ok(chunk) => chunk,
+            Err(err) => return Some(Err(err)),
+        };
+        let UnsignedTransactions {
+            established_account,
+            validator_account,
+            bond,
+        } = match parse_unsigned(chunk.as_bytes()) {
+            Ok(txs) => txs,
+            Err(err) => {
+                return Some(Err(eyre::eyre!(
+                    "Unable to parse the tx at line {line_num}: {err}"
+                )));
+            }
+        };
+        let mut txs = established_account
+            .into_iter()
+            .flatten()
+            .map(UnsignedTx::EstablishedAccount)
+            .chain(
+                validator_account
+                    .into_iter()
+                    .flatten()
+                    .map(UnsignedTx::ValidatorAccount),
+            )
+            .chain(bond.into_iter().flatten().map(UnsignedTx::Bond));
+        match (txs.next(), txs.next()) {
+            (Some(tx), None) => Some(Ok(tx)),
+            _ => Some(Err(eyre::eyre!(
+                "Expected a single tx at line {line_num}"
+            ))),
+        }
+    }
+}
+
+/// The table name of the given line, which doesn't start within a string,
+/// if it is the header of an unsigned tx, e.g. `bond` for `[[bond]]`.
+fn unsigned_tx_table(line: &str) -> Option<&'static str> {
+    let (name, rest) = line
+        .trim_start()
+        .strip_prefix("[[")
+        .and_then(|header| header.split_once("]]"))?;
+    let rest = rest.trim_start();
+    if !rest.is_empty() && !rest.starts_with('#') {
+        return None;
+    }
+    UNSIGNED_TX_TABLES
+        .into_iter()
+        .find(|table| *table == name.trim())
+}
+
+/// The closing delimiter of the multi-line string that the given line ends
+/// in, if any, given that of the string it starts in.
+fn multiline_after(
+    line: &str,
+    mut multiline: Option<&'static str>,
+) -> Option<&'static str> {
+    let bytes = line.as_bytes();
+    let mut ix = 0;
+    while ix < bytes.len() {
+        match multiline {
+            Some(delim) => {
+                if delim == "\"\"\"" && bytes[ix] == b'\\' {
+                    ix = ix.saturating_add(2);
+                } else if bytes[ix..].starts_with(delim.as_bytes()) {
+                    multiline = None;
+                    ix = ix.saturating_add(3);
+                } else {
+                    ix = ix.saturating_add(1);
+                }
+            }
+            None => match bytes[ix] {
+                b'#' => break,
+                quote @ (b'"' | b'\'') => {
+                    if bytes[ix..].starts_with(&[quote; 3]) {
+                        multiline =
+                            Some(if quote == b'"' { "\"\"\"" } else { "'''" });
+                        ix = ix.saturating_add(3);
+                        continue;
+                    }
+                    // Skip over a single-line string
+                    ix = ix.saturating_add(1);
+                    while ix < bytes.len() && bytes[ix] != quote {
+                        if quote == b'"' && bytes[ix] == b'\\' {
+                            ix = ix.saturating_add(1);
+                        }
+                        ix = ix.saturating_add(1);
+                    }
+                    ix = ix.saturating_add(1);
+                }
+                _ => ix = ix.saturating_add(1),
+            },
+        }
+    }
+    multiline
+}
+
+/// A coordinator's manifest of the public keys that are expected to sign
+/// genesis txs.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ExpectedSigners {
+    #[serde(default)]
+    pub validator_account: Vec<ExpectedValidatorAccountSigners>,
+    #[serde(default)]
+    pub bond: Vec<ExpectedBondSigners>,
+    /// Wallet aliases of the signers, for reference only
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub aliases: BTreeMap<StringEncoded<common::PublicKey>, String>,
+}
+
+impl ExpectedSigners {
+    /// Build the manifest of the keys that signed the given txs, such that
+    /// it can be checked against signatures of the same txs later on. The
+    /// aliases of the signers are looked up with `find_alias`.
+    pub fn from_signed(
+        signed: &Transactions<Unvalidated>,
+        find_alias: impl Fn(&common::PublicKey) -> Option<String>,
+    ) -> Self {
+        let signers_of =
+            |signatures: &BTreeMap<
+                StringEncoded<common::PublicKey>,
+                StringEncoded<common::Signature>,
+            >| signatures.keys().cloned().collect::<BTreeSet<_>>();
+        let validator_account = signed
+            .validator_account
+            .iter()
+            .flatten()
+            .map(|tx| ExpectedValidatorAccountSigners {
+                address: Address::Established(tx.data.address.raw.clone()),
+                signers: signers_of(&tx.signatures),
+            })
+            .collect();
+        let bond: Vec<_> = signed
+            .bond
+            .iter()
+            .flatten()
+            .map(|tx| ExpectedBondSigners {
+                source: tx.data.source.clone(),
+                validator: tx.data.validator.clone(),
+                signers: signers_of(&tx.signatures),
+            })
+            .collect();
+        let mut manifest = Self {
+            validator_account,
+            bond,
+            aliases: BTreeMap::new(),
+        };
+        let signers: BTreeSet<_> = manifest
+            .validator_account
+            .iter()
+            .flat_map(|tx| &tx.signers)
+            .chain(manifest.bond.iter().flat_map(|tx| &tx.signers))
+            .cloned()
+            .collect();
+        manifest.aliases = signers
+            .into_iter()
+            .filter_map(|signer| {
+                let alias = find_alias(&signer.raw)?;
+                Some((signer, alias))
+            })
+            .collect();
+        manifest
+    }
+}
+
+/// The keys expected to sign the account tx of a validator.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ExpectedValidatorAccountSigners {
+    pub address: Address,
+    pub signers: BTreeSet<StringEncoded<common::PublicKey>>,
+}
+
+/// The keys expected to sign a bond tx.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ExpectedBondSigners {
+    pub source: GenesisAddress,
+    pub validator: Address,
+    pub signers: BTreeSet<StringEncoded<common::PublicKey>>,
+}
+
+/// A discrepancy between the signatures of genesis txs and the
+/// [`ExpectedSigners`] manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignerMismatch {
+    /// The key signed a tx that it was not expected to sign
+    Unexpected {
+        tx: String,
+        signer: common::PublicKey,
+    },
+    /// The key was expected to sign a tx, but did not
+    Missing {
+        tx: String,
+        signer: common::PublicKey,
+    },
+}
+
+impl std::fmt::Display for SignerMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unexpected { tx, signer } => write!(
+                f,
+                "The key {signer} signed the {tx}, but it is not an expected \
+                 signer of it."
+            ),
+            Self::Missing { tx, signer } => write!(
+                f,
+                "The key {signer} is expected to sign the {tx}, but it did \
+                 not."
+            ),
+        }
+    }
+}
+
+/// Compare the signatures of the given txs with the manifest of expected
+/// signers. Only the keys for which `is_own_key` returns `true` are checked,
+/// such that signatures made by other parties are not reported.
+pub fn check_expected_signers(
+    expected: &ExpectedSigners,
+    signed: &Transactions<Unvalidated>,
+    is_own_key: impl Fn(&common::PublicKey) -> bool,
+) -> Vec<SignerMismatch> {
+    fn check_tx(
+        tx: String,
+        expected_signers: Option<&BTreeSet<StringEncoded<common::PublicKey>>>,
+        signatures: &BTreeMap<
+            StringEncoded<common::PublicKey>,
+            StringEncoded<common::Signature>,
+        >,
+        is_own_key: &impl Fn(&common::PublicKey) -> bool,
+        mismatches: &mut Vec<SignerMismatch>,
+    ) {
+        let no_signers = BTreeSet::new();
+        let expected_signers = expected_signers.unwrap_or(&no_signers);
+        for signer in signatures.keys() {
+            if is_own_key(&signer.raw) && !expected_signers.contains(signer) {
+                mismatches.push(SignerMismatch::Unexpected {
+                    tx: tx.clone(),
+                    signer: signer.raw.clone(),
+                });
+            }
+        }
+        for signer in expected_signers {
+            if is_own_key(&signer.raw) && !signatures.contains_key(signer) {
+                mismatches.push(SignerMismatch::Missing {
+                    tx: tx.clone(),
+                    signer: signer.raw.clone(),
+                });
+            }
+        }
+    }
+
+    let mut mismatches = vec![];
+    for tx in signed.validator_account.iter().flatten() {
+        let address = Address::Established(tx.data.address.raw.clone());
+        let expected_signers = expected
+            .validator_account
+            .iter()
+            .find(|expected| expected.address == address)
+            .map(|expected| &expected.signers);
+        check_tx(
+            format!("validator account tx of {address}"),
+            expected_signers,
+            &tx.signatures,
+            &is_own_key,
+            &mut mismatches,
+        );
+    }
+    for tx in signed.bond.iter().flatten() {
+        let expected_signers = expected
+            .bond
+            .iter()
+            .find(|expected| {
+                expected.source == tx.data.source
+                    && expected.validator == tx.data.validator
+            })
+            .map(|expected| &expected.signers);
+        check_tx(
+            format!("bond tx from {} to {}", tx.data.source, tx.data.validator),
+            expected_signers,
+            &tx.signatures,
+            &is_own_key,
+            &mut mismatches,
+        );
+    }
+    mismatches
+}
+
+/// Signatures of genesis txs, detached from the txs that they sign.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct DetachedSignatures {
+    #[serde(default)]
+    pub signature: Vec<DetachedSignature>,
+}
+
+/// A signature of a genesis tx, detached from the tx.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct DetachedSignature {
+    /// The hash of the raw header of the signed tx
+    pub tx_id: String,
+    pub signer: StringEncoded<common::PublicKey>,
+    pub signature: StringEncoded<common::Signature>,
+}
+
+/// The id by which a detached signature refers to the tx that it signs.
+fn detached_tx_id(tx: &impl TxToSign) -> String {
+    tx.tx_to_sign().raw_header_hash().to_string()
+}
+
+/// Detach the signatures of the given signed txs. Only bond txs may be
+/// detached, as validator account txs also carry signatures of their keys in
+/// the tx data.
+pub fn detach_signatures(
+    signed: &Transactions<Unvalidated>,
+) -> Result<DetachedSignatures, String> {
+    if signed.validator_account.iter().flatten().next().is_some() {
+        return Err("The signatures of validator account txs cannot be \
+                    detached."
+            .to_string());
+    }
+    let signature = signed
+        .bond
+        .iter()
+        .flatten()
+        .flat_map(|tx| {
+            let tx_id = detached_tx_id(&tx.data);
+            tx.signatures.iter().map(move |(signer, signature)| {
+                DetachedSignature {
+                    tx_id: tx_id.clone(),
+                    signer: signer.clone(),
+                    signature: signature.clone(),
+                }
+            })
+        })
+        .collect();
+    Ok(DetachedSignatures { signature })
+}
+
+/// Attach detached signatures to the unsigned txs that they sign, giving the
+/// same txs that signing them inline would. Identical bond txs share an id,
+/// so they all get its signatures. Fails if a signature refers to none of
+/// the bond txs.
+pub fn attach_signatures(
+    unsigned: UnsignedTransactions,
+    detached: &DetachedSignatures,
+) -> Result<Transactions<Unvalidated>, String> {
+    let UnsignedTransactions {
+        established_account,
+        validator_account,
+        bond,
+    } = unsigned;
+    if validator_account.iter().flatten().next().is_some() {
+        return Err("Detached signatures cannot be attached to validator \
+                    account txs."
+            .to_string());
+    }
+    let mut signatures_by_tx: BTreeMap<&str, Vec<&DetachedSignature>> =
+        BTreeMap::new();
+    for signature in &detached.signature {
+        signatures_by_tx
+            .entry(signature.tx_id.as_str())
+            .or_default()
+            .push(signature);
+    }
+    let mut attached_tx_ids = BTreeSet::new();
+    let bond = bond.map(|txs| {
+        txs.into_iter()
+            .map(|tx| {
+                let tx_id = detached_tx_id(&tx);
+                let mut signed = Signed::new(tx);
+                for signature in
+                    signatures_by_tx.get(tx_id.as_str()).into_iter().flatten()
+                {
+                    signed.signatures.insert(
+                        signature.signer.clone(),
+                        signature.signature.clone(),
+                    );
+                }
+                attached_tx_ids.insert(tx_id);
+                signed
+            })
+            .collect()
+    });
+    if let Some(tx_id) = signatures_by_tx
+        .keys()
+        .find(|tx_id| !attached_tx_ids.contains(**tx_id))
+    {
+        return Err(format!(
+            "A detached signature refers to the tx {tx_id}, which is not \
+             one of the unsigned bond txs."
+        ));
+    }
+    Ok(Transactions {
+        established_account,
+        validator_account: None,
+        bond,
+    })
+}
+
 /// Create signed [`Transactions`] for an established account.
 pub fn init_established_account(
     vp: String,
@@ -270,6 +1780,48 @@ pub fn init_bond(
     }
 }
 
+/// Placeholder for the bond source in a bonds template.
+pub const BOND_TEMPLATE_SOURCE: &str = "<SOURCE>";
+/// Placeholder for the validator in a bonds template.
+pub const BOND_TEMPLATE_VALIDATOR: &str = "<VALIDATOR>";
+/// Placeholder for the bond amount in a bonds template.
+pub const BOND_TEMPLATE_AMOUNT: &str = "<AMOUNT>";
+
+/// Generate a commented skeleton of `[[bond]]` entries, one per given
+/// validator. Validators that are not known are left as placeholders. Once
+/// every placeholder is filled in, the result can be parsed with
+/// [`parse_unsigned`].
+pub fn bonds_template(validators: &[Option<EstablishedAddress>]) -> String {
+    let mut template = String::from(
+        "# Genesis bonds template.\n\
+         #\n\
+         # Replace every placeholder below, remove any bonds you do not \
+         need and\n\
+         # sign the file with `namadac utils sign-genesis-txs`.\n",
+    );
+    for validator in validators {
+        let validator = validator
+            .as_ref()
+            .map(|addr| Address::Established(addr.clone()).to_string())
+            .unwrap_or_else(|| BOND_TEMPLATE_VALIDATOR.to_string());
+        template.push_str(&format!(
+            "\n[[bond]]\n\
+             # The source of the bond: an established account address or the \
+             public key\n\
+             # of an implicit account. For a self-bond, use the validator's \
+             address.\n\
+             source = \"{BOND_TEMPLATE_SOURCE}\"\n\
+             # The established address of the validator to bond to.\n\
+             validator = \"{validator}\"\n\
+             # The amount of native tokens to bond, with at most \
+             {NATIVE_MAX_DECIMAL_PLACES} decimal\n\
+             # places (e.g. \"1000.5\").\n\
+             amount = \"{BOND_TEMPLATE_AMOUNT}\"\n"
+        ));
+    }
+    template
+}
+
 /// Create [`UnsignedTransactions`] for a genesis validator.
 pub fn init_validator(
     GenesisValidatorData {
@@ -355,34 +1907,55 @@ pub async fn sign_validator_account_tx(
     >,
     wallet: &RwLock<Wallet<CliWalletUtils>>,
     established_accounts: &[EstablishedAccountTx],
-    use_device: bool,
-    device_transport: DeviceTransport,
-) -> SignedValidatorAccountTx {
+    backend: &SignerBackend,
+) -> eyre::Result<SignedValidatorAccountTx> {
     let mut to_sign = match to_sign {
         Either::Right(signed_tx) => signed_tx,
         Either::Left((unsigned_tx, validator_wallet)) => {
-            fn sign_key<T: BorshSerialize>(
-                tx_data: &T,
-                keypair: &common::SecretKey,
-            ) -> StringEncoded<common::Signature> {
-                StringEncoded::new(namada_sdk::tx::standalone_signature::<
-                    T,
+            fn sign_key(
+                kind: ValidatorKeyKind,
+                pk: &StringEncoded<common::PublicKey>,
+                tx_data: &UnsignedValidatorAccountTx,
+                validator_wallet: &ValidatorWallet,
+            ) -> eyre::Result<StringEncoded<common::Signature>> {
+                let keypair =
+                    select_validator_key(kind, &pk.raw, validator_wallet)?;
+                Ok(StringEncoded::new(namada_sdk::tx::standalone_signature::<
+                    UnsignedValidatorAccountTx,
                     SerializeWithBorsh,
-                >(keypair, tx_data))
+                >(keypair, tx_data)))
             }
             // Sign the tx with every validator key to authorize their usage
-            let consensus_key_sig =
-                sign_key(&unsigned_tx, &validator_wallet.consensus_key);
+            let consensus_key_sig = sign_key(
+                ValidatorKeyKind::Consensus,
+                &unsigned_tx.consensus_key,
+                &unsigned_tx,
+                validator_wallet,
+            )?;
             let protocol_key_sig = sign_key(
+                ValidatorKeyKind::Protocol,
+                &unsigned_tx.protocol_key,
                 &unsigned_tx,
-                &validator_wallet.store.validator_keys.protocol_keypair,
-            );
-            let eth_hot_key_sig =
-                sign_key(&unsigned_tx, &validator_wallet.eth_hot_key);
-            let eth_cold_key_sig =
-                sign_key(&unsigned_tx, &validator_wallet.eth_cold_key);
-            let tendermint_node_key_sig =
-                sign_key(&unsigned_tx, &validator_wallet.tendermint_node_key);
+                validator_wallet,
+            )?;
+            let eth_hot_key_sig = sign_key(
+                ValidatorKeyKind::EthHot,
+                &unsigned_tx.eth_hot_key,
+                &unsigned_tx,
+                validator_wallet,
+            )?;
+            let eth_cold_key_sig = sign_key(
+                ValidatorKeyKind::EthCold,
+                &unsigned_tx.eth_cold_key,
+                &unsigned_tx,
+                validator_wallet,
+            )?;
+            let tendermint_node_key_sig = sign_key(
+                ValidatorKeyKind::TendermintNode,
+                &unsigned_tx.tendermint_node_key,
+                &unsigned_tx,
+                validator_wallet,
+            )?;
 
             let ValidatorAccountTx {
                 address,
@@ -437,26 +2010,21 @@ pub async fn sign_validator_account_tx(
         }
     };
 
-    to_sign
-        .sign(established_accounts, wallet, use_device, device_transport)
-        .await;
-    to_sign
+    to_sign.sign(established_accounts, wallet, backend).await?;
+    Ok(to_sign)
 }
 
 pub async fn sign_delegation_bond_tx(
     mut to_sign: SignedBondTx<Unvalidated>,
     wallet: &RwLock<Wallet<CliWalletUtils>>,
     established_accounts: &Option<Vec<EstablishedAccountTx>>,
-    use_device: bool,
-    device_transport: DeviceTransport,
-) -> SignedBondTx<Unvalidated> {
+    backend: &SignerBackend,
+) -> eyre::Result<SignedBondTx<Unvalidated>> {
     let default = vec![];
     let established_accounts =
         established_accounts.as_ref().unwrap_or(&default);
-    to_sign
-        .sign(established_accounts, wallet, use_device, device_transport)
-        .await;
-    to_sign
+    to_sign.sign(established_accounts, wallet, backend).await?;
+    Ok(to_sign)
 }
 
 #[derive(
@@ -536,35 +2104,26 @@ impl Transactions<Validated> {
     /// Check if there is at least one validator with positive Tendermint voting
     /// power. The voting power is converted from `token::Amount` of the
     /// validator's stake using the `tm_votes_per_token` PoS parameter.
+    /// Returns an error if the stake of any validator overflows.
     pub fn has_validator_with_positive_voting_power(
         &self,
         votes_per_token: Dec,
-    ) -> bool {
-        self.bond
-            .as_ref()
-            .map(|txs| {
-                let mut stakes: BTreeMap<&Address, token::Amount> =
-                    BTreeMap::new();
-                for tx in txs {
-                    let entry = stakes.entry(&tx.validator).or_default();
-                    *entry = entry
-                        .checked_add(tx.amount.amount())
-                        .expect("Validator total stake must not overflow");
-                }
-
-                stakes.into_values().any(|stake| {
-                    let tendermint_voting_power =
-                        namada_sdk::proof_of_stake::types::into_tm_voting_power(
-                            votes_per_token,
-                            stake,
-                        );
-                    if tendermint_voting_power > 0 {
-                        return true;
-                    }
-                    false
-                })
-            })
-            .unwrap_or_default()
+    ) -> eyre::Result<bool> {
+        let Some(txs) = self.bond.as_ref() else {
+            return Ok(false);
+        };
+        let stakes = validator_stakes(txs)?;
+        Ok(stakes.into_values().any(|stake| {
+            let tendermint_voting_power =
+                namada_sdk::proof_of_stake::types::into_tm_voting_power(
+                    votes_per_token,
+                    stake,
+                );
+            if tendermint_voting_power > 0 {
+                return true;
+            }
+            false
+        }))
     }
 }
 
@@ -575,6 +2134,15 @@ pub struct UnsignedTransactions {
     pub bond: Option<Vec<BondTx<Unvalidated>>>,
 }
 
+impl UnsignedTransactions {
+    /// The total number of txs to be signed, i.e. the validator accounts
+    /// and bonds. Established accounts are not signed.
+    pub fn num_txs(&self) -> usize {
+        self.validator_account.as_ref().map_or(0, Vec::len)
+            + self.bond.as_ref().map_or(0, Vec::len)
+    }
+}
+
 pub type UnsignedValidatorAccountTx =
     ValidatorAccountTx<StringEncoded<common::PublicKey>>;
 
@@ -748,9 +2316,9 @@ impl<T> Signed<T> {
         &mut self,
         established_accounts: &[EstablishedAccountTx],
         wallet_lock: &RwLock<Wallet<CliWalletUtils>>,
-        use_device: bool,
-        device_transport: DeviceTransport,
-    ) where
+        backend: &SignerBackend,
+    ) -> eyre::Result<()>
+    where
         T: BorshSerialize + TxToSign,
     {
         let (pks, threshold) = self.data.get_pks(established_accounts);
@@ -763,48 +2331,90 @@ impl<T> Signed<T> {
             fee_payer: genesis_fee_payer_pk(),
         };
 
+        async fn software_wallet_sign(
+            tx: Tx,
+            pubkey: common::PublicKey,
+            _parts: HashSet<namada_sdk::signing::Signable>,
+            _user: (),
+        ) -> Result<Tx, namada_sdk::error::Error> {
+            if pubkey == genesis_fee_payer_pk() {
+                Ok(tx)
+            } else {
+                Err(namada_sdk::error::Error::Other(format!(
+                    "unable to sign transaction with {pubkey}",
+                )))
+            }
+        }
+
         let mut tx = self.data.tx_to_sign();
+        let tx_args = get_tx_args(backend.is_device());
 
-        if use_device {
-            let transport = WalletTransport::from_arg(device_transport);
-            let app = NamadaApp::new(transport);
-            sign_tx(
-                wallet_lock,
-                &get_tx_args(use_device),
-                &mut tx,
-                signing_data,
-                utils::with_hardware_wallet,
-                (wallet_lock, &app),
-            )
-            .await
-            .expect("Failed to sign pre-genesis transaction.")
-        } else {
-            async fn software_wallet_sign(
-                tx: Tx,
-                pubkey: common::PublicKey,
-                _parts: HashSet<namada_sdk::signing::Signable>,
-                _user: (),
-            ) -> Result<Tx, namada_sdk::error::Error> {
-                if pubkey == genesis_fee_payer_pk() {
-                    Ok(tx)
-                } else {
-                    Err(namada_sdk::error::Error::Other(format!(
-                        "unable to sign transaction with {pubkey}",
-                    )))
+        match backend {
+            SignerBackend::Device {
+                transport,
+                debug_apdu,
+                retry_rejections,
+                is_tty,
+            } => {
+                let transport =
+                    WalletTransport::from_arg(*transport, *debug_apdu);
+                let last_retcode = transport.last_retcode();
+                let app = NamadaApp::new(transport);
+                let mut attempts: usize = 0;
+                loop {
+                    // Every attempt starts over from the unsigned tx
+                    let mut attempt_tx = tx.clone();
+                    let result = sign_tx(
+                        wallet_lock,
+                        &tx_args,
+                        &mut attempt_tx,
+                        signing_data.clone(),
+                        utils::with_hardware_wallet,
+                        (wallet_lock, &app),
+                    )
+                    .await;
+                    attempts = attempts.saturating_add(1);
+                    match result {
+                        Err(_)
+                            if is_device_rejection(last_retcode.get())
+                                && confirm_device_retry(
+                                    attempts,
+                                    *retry_rejections,
+                                    *is_tty,
+                                ) => {}
+                        result => {
+                            tx = attempt_tx;
+                            break result;
+                        }
+                    }
                 }
             }
-
-            sign_tx(
-                wallet_lock,
-                &get_tx_args(use_device),
-                &mut tx,
-                signing_data,
-                software_wallet_sign,
-                (),
-            )
-            .await
-            .expect("Failed to sign pre-genesis transaction.");
+            SignerBackend::Pkcs11(token) => {
+                sign_tx(
+                    wallet_lock,
+                    &tx_args,
+                    &mut tx,
+                    signing_data,
+                    utils::with_pkcs11_token,
+                    token,
+                )
+                .await
+            }
+            SignerBackend::Wallet => {
+                sign_tx(
+                    wallet_lock,
+                    &tx_args,
+                    &mut tx,
+                    signing_data,
+                    software_wallet_sign,
+                    (),
+                )
+                .await
+            }
         }
+        .map_err(|err| {
+            eyre::eyre!("Failed to sign pre-genesis transaction: {err}")
+        })?;
 
         let raw_header_hash = tx.raw_header_hash();
         let sigs = tx
@@ -821,20 +2431,21 @@ impl<T> Signed<T> {
                     None
                 }
             })
-            .unwrap_or_else(|| {
-                panic!(
+            .ok_or_else(|| {
+                eyre::eyre!(
                     "No signature could be produced for a transaction of type \
                      {}. The most likely cause is a missing secret key, \
                      public key hash or alias in your pre-genesis wallet.",
                     std::any::type_name::<T>()
-                );
-            });
+                )
+            })?;
         for (ix, sig) in sigs.signatures.into_iter() {
             self.signatures.insert(
                 StringEncoded::new(pks[ix as usize].clone()),
                 StringEncoded::new(sig),
             );
         }
+        Ok(())
     }
 
     /// Verify the signatures of the inner data.
@@ -958,6 +2569,37 @@ impl BondTx<Unvalidated> {
     }
 }
 
+/// Sum up the stake of the given bonds by validator. Returns an error rather
+/// than wrapping around or panicking if the stake of any validator overflows.
+pub fn validator_stakes<'a>(
+    bonds: impl IntoIterator<Item = &'a BondTx<Validated>>,
+) -> eyre::Result<BTreeMap<&'a Address, token::Amount>> {
+    let mut stakes: BTreeMap<&Address, token::Amount> = BTreeMap::new();
+    for bond in bonds {
+        let stake = stakes.entry(&bond.validator).or_default();
+        *stake = stake.checked_add(bond.amount.amount()).ok_or_else(|| {
+            eyre::eyre!(
+                "The total stake of validator {} overflows",
+                bond.validator
+            )
+        })?;
+    }
+    Ok(stakes)
+}
+
+/// Sum up the given stakes. Returns an error rather than wrapping around or
+/// panicking on overflow.
+pub fn total_stake(
+    stakes: impl IntoIterator<Item = token::Amount>,
+) -> eyre::Result<token::Amount> {
+    stakes
+        .into_iter()
+        .try_fold(token::Amount::zero(), |total, stake| {
+            total.checked_add(stake)
+        })
+        .ok_or_else(|| eyre::eyre!("The total stake of the bonds overflows"))
+}
+
 /// Return the correctly denominated amount of bonded tokens
 fn denominate_amount<T>(
     amount: T::Amount,
@@ -1004,6 +2646,105 @@ pub struct SignedPk {
     pub authorization: StringEncoded<common::Signature>,
 }
 
+/// The outcome of verifying the signatures of a genesis tx
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignatureCheck {
+    /// A description of the tx
+    pub tx: String,
+    /// Why the signatures don't verify, if they don't
+    pub result: Result<(), String>,
+}
+
+/// A genesis tx whose signatures can be verified
+enum SignedGenesisTx<'a> {
+    ValidatorAccount(&'a SignedValidatorAccountTx),
+    Bond(&'a SignedBondTx<Unvalidated>),
+}
+
+/// Verify the signatures of the validator account and bond txs against the
+/// thresholds of their source accounts. The CPU-bound verification is spread
+/// over `jobs` worker threads, while the returned checks stay in the order of
+/// the txs, whatever the number of jobs. There are no threads on wasm32, where
+/// the txs are verified one after the other.
+pub fn verify_signatures(
+    transactions: &Transactions<Unvalidated>,
+    jobs: usize,
+) -> Result<Vec<SignatureCheck>, String> {
+    let thresholds: BTreeMap<Address, u8> = transactions
+        .established_account
+        .iter()
+        .flatten()
+        .map(|tx| (tx.derive_address(), tx.threshold))
+        .collect();
+    let established_threshold = |address: &EstablishedAddress| {
+        thresholds
+            .get(&Address::Established(address.clone()))
+            .copied()
+            .ok_or_else(|| {
+                format!(
+                    "The public keys of the source account \"{address}\" \
+                     cannot be found."
+                )
+            })
+    };
+    let txs: Vec<SignedGenesisTx<'_>> = transactions
+        .validator_account
+        .iter()
+        .flatten()
+        .map(SignedGenesisTx::ValidatorAccount)
+        .chain(
+            transactions
+                .bond
+                .iter()
+                .flatten()
+                .map(SignedGenesisTx::Bond),
+        )
+        .collect();
+    let check = |tx: &SignedGenesisTx<'_>| match tx {
+        SignedGenesisTx::ValidatorAccount(tx) => SignatureCheck {
+            tx: format!("validator account {}", tx.data.address),
+            result: established_threshold(&tx.data.address.raw)
+                .and_then(|threshold| tx.verify_sig(threshold)),
+        },
+        SignedGenesisTx::Bond(tx) => {
+            let BondTx {
+                source,
+                validator,
+                amount,
+            } = &tx.data;
+            let threshold = match source {
+                GenesisAddress::EstablishedAddress(address) => {
+                    established_threshold(address)
+                }
+                GenesisAddress::PublicKey(_) => Ok(1),
+            };
+            SignatureCheck {
+                tx: format!("bond of {amount} from {source} to {validator}"),
+                result: threshold
+                    .and_then(|threshold| tx.verify_sig(threshold)),
+            }
+        }
+    };
+    #[cfg(not(target_family = "wasm"))]
+    {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs)
+            .thread_name(|i| format!("genesis-verify-worker-{}", i))
+            .build()
+            .map_err(|err| {
+                format!("Failed to start the worker threads: {err}")
+            })?;
+        // Collecting an indexed parallel iterator keeps the order of its
+        // items
+        Ok(pool.install(|| txs.par_iter().map(check).collect()))
+    }
+    #[cfg(target_family = "wasm")]
+    {
+        let _ = jobs;
+        Ok(txs.iter().map(check).collect())
+    }
+}
+
 pub fn validate(
     transactions: Transactions<Unvalidated>,
     vps: Option<&ValidityPredicates>,
@@ -1614,3 +3355,781 @@ impl From<&ValidatorAccountTx<SignedPk>> for UnsignedValidatorAccountTx {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use namada_sdk::address::testing::{
+        established_address_1, established_address_2,
+    };
+    use namada_sdk::hash::Hash;
+    use namada_sdk::wallet::pre_genesis::ValidatorStore;
+    use namada_sdk::wallet::{gen_secret_key, StoredKeypair, ValidatorKeys};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    /// A validator pre-genesis wallet with freshly generated keys
+    fn validator_wallet() -> ValidatorWallet {
+        let consensus_key = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let eth_cold_key = gen_secret_key(SchemeType::Secp256k1, &mut OsRng);
+        let tendermint_node_key =
+            gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let validator_keys = ValidatorKeys {
+            protocol_keypair: gen_secret_key(SchemeType::Ed25519, &mut OsRng),
+            eth_bridge_keypair: gen_secret_key(
+                SchemeType::Secp256k1,
+                &mut OsRng,
+            ),
+        };
+        let eth_hot_key = validator_keys.eth_bridge_keypair.clone();
+        ValidatorWallet {
+            store: ValidatorStore {
+                consensus_key: StoredKeypair::new(consensus_key.clone(), None)
+                    .0,
+                eth_cold_key: StoredKeypair::new(eth_cold_key.clone(), None).0,
+                tendermint_node_key: StoredKeypair::new(
+                    tendermint_node_key.clone(),
+                    None,
+                )
+                .0,
+                validator_keys,
+            },
+            consensus_key,
+            eth_cold_key,
+            eth_hot_key,
+            tendermint_node_key,
+        }
+    }
+
+    /// An unsigned validator account tx, which registers the validator's
+    /// Ethereum bridge keys
+    fn eth_bridge_validator_account_tx(
+        validator_wallet: &ValidatorWallet,
+    ) -> UnsignedValidatorAccountTx {
+        let address = match established_address_1() {
+            Address::Established(address) => address,
+            _ => unreachable!(),
+        };
+        let (_address, txs) = init_validator(
+            GenesisValidatorData {
+                address,
+                commission_rate: Dec::new(5, 2).unwrap(),
+                max_commission_rate_change: Dec::new(1, 2).unwrap(),
+                net_address: SocketAddr::new(
+                    IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)),
+                    26656,
+                ),
+                self_bond_amount: DenominatedAmount::native(0.into()),
+                email: "null@null.net".to_string(),
+                description: None,
+                website: None,
+                discord_handle: None,
+                avatar: None,
+                name: None,
+            },
+            validator_wallet,
+        );
+        txs.validator_account
+            .and_then(|txs| txs.into_iter().next())
+            .unwrap()
+    }
+
+    /// Test that the Ethereum hot key of a validator account tx is authorized
+    /// with the validator's Ethereum bridge keypair.
+    #[test]
+    fn test_eth_hot_key_is_signed_with_eth_bridge_key() {
+        let validator_wallet = validator_wallet();
+        let tx = eth_bridge_validator_account_tx(&validator_wallet);
+
+        let sk = select_validator_key(
+            ValidatorKeyKind::EthHot,
+            &tx.eth_hot_key.raw,
+            &validator_wallet,
+        )
+        .unwrap();
+        assert_eq!(
+            sk.ref_to(),
+            validator_wallet
+                .store
+                .validator_keys
+                .eth_bridge_keypair
+                .ref_to()
+        );
+
+        let sig = namada_sdk::tx::standalone_signature::<
+            UnsignedValidatorAccountTx,
+            SerializeWithBorsh,
+        >(sk, &tx);
+        assert!(validate_signature(&tx, &tx.eth_hot_key.raw, &sig));
+
+        let sk = select_validator_key(
+            ValidatorKeyKind::EthCold,
+            &tx.eth_cold_key.raw,
+            &validator_wallet,
+        )
+        .unwrap();
+        assert_eq!(sk.ref_to(), validator_wallet.eth_cold_key.ref_to());
+    }
+
+    /// Test that Ethereum keys with a mismatching scheme or that are not
+    /// found in the validator wallet are rejected.
+    #[test]
+    fn test_mismatched_eth_keys_are_rejected() {
+        let validator_wallet = validator_wallet();
+        let tx = eth_bridge_validator_account_tx(&validator_wallet);
+
+        // Ethereum keys must be secp256k1 keys
+        let ed25519_pk = tx.consensus_key.raw.clone();
+        assert!(select_validator_key(
+            ValidatorKeyKind::EthHot,
+            &ed25519_pk,
+            &validator_wallet,
+        )
+        .is_err());
+
+        // The eth cold key must not be used in place of the eth hot key
+        assert!(select_validator_key(
+            ValidatorKeyKind::EthHot,
+            &tx.eth_cold_key.raw,
+            &validator_wallet,
+        )
+        .is_err());
+
+        // A secp256k1 key that is not in the validator wallet
+        let other_pk =
+            gen_secret_key(SchemeType::Secp256k1, &mut OsRng).ref_to();
+        assert!(select_validator_key(
+            ValidatorKeyKind::EthHot,
+            &other_pk,
+            &validator_wallet,
+        )
+        .is_err());
+    }
+
+    /// Test that a bonds template parses as unsigned transactions once its
+    /// placeholders are filled in.
+    #[test]
+    fn test_filled_bonds_template_parses() {
+        let validator = established_address_1();
+        let Address::Established(established) = validator.clone() else {
+            unreachable!()
+        };
+        let template = bonds_template(&[Some(established.clone()), None]);
+        let filled = template
+            .replace(BOND_TEMPLATE_SOURCE, &validator.to_string())
+            .replace(BOND_TEMPLATE_VALIDATOR, &validator.to_string())
+            .replace(BOND_TEMPLATE_AMOUNT, "1000.5");
+
+        let bonds = parse_unsigned(filled.as_bytes()).unwrap().bond.unwrap();
+        assert_eq!(bonds.len(), 2);
+        for bond in bonds {
+            assert_eq!(
+                bond.source,
+                GenesisAddress::EstablishedAddress(established.clone())
+            );
+            assert_eq!(bond.validator, validator);
+        }
+    }
+
+    /// Test that signatures made by this signer's keys are checked against
+    /// the manifest of expected signers, while others' are ignored.
+    #[test]
+    fn test_check_expected_signers() {
+        let own_key = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let other_key = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let validator = established_address_1();
+        let bond_from = |key: &common::SecretKey| BondTx::<Unvalidated> {
+            source: GenesisAddress::PublicKey(StringEncoded::new(key.ref_to())),
+            validator: validator.clone(),
+            amount: DenominatedAmount::native(token::Amount::native_whole(1)),
+        };
+        let sign = |tx: &mut SignedBondTx<Unvalidated>,
+                    key: &common::SecretKey| {
+            let sig = common::SigScheme::sign(key, [0; 32]);
+            tx.signatures.insert(
+                StringEncoded::new(key.ref_to()),
+                StringEncoded::new(sig),
+            );
+        };
+
+        // Our key signed a bond it was not supposed to, while the other
+        // key's signature is not our concern
+        let mut unexpected = Signed::new(bond_from(&other_key));
+        sign(&mut unexpected, &own_key);
+        sign(&mut unexpected, &other_key);
+        // Our key was supposed to sign this bond, but did not
+        let missing = Signed::new(bond_from(&own_key));
+        let signed = Transactions::<Unvalidated> {
+            bond: Some(vec![unexpected, missing]),
+            ..Default::default()
+        };
+        let expected = ExpectedSigners {
+            validator_account: vec![],
+            bond: vec![
+                ExpectedBondSigners {
+                    source: bond_from(&other_key).source,
+                    validator: validator.clone(),
+                    signers: [StringEncoded::new(other_key.ref_to())].into(),
+                },
+                ExpectedBondSigners {
+                    source: bond_from(&own_key).source,
+                    validator: validator.clone(),
+                    signers: [StringEncoded::new(own_key.ref_to())].into(),
+                },
+            ],
+            ..Default::default()
+        };
+
+        let mismatches = check_expected_signers(&expected, &signed, |pk| {
+            *pk == own_key.ref_to()
+        });
+        assert_eq!(mismatches.len(), 2);
+        assert!(matches!(
+            &mismatches[0],
+            SignerMismatch::Unexpected { signer, .. } if *signer == own_key.ref_to()
+        ));
+        assert!(matches!(
+            &mismatches[1],
+            SignerMismatch::Missing { signer, .. } if *signer == own_key.ref_to()
+        ));
+    }
+
+    /// Test that the manifest of the signers of signed txs has the signers
+    /// of every tx with their aliases, and that the signatures match it.
+    #[test]
+    fn test_signers_manifest() {
+        let key = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let other_key = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let mut bond = Signed::new(BondTx::<Unvalidated> {
+            source: GenesisAddress::PublicKey(StringEncoded::new(key.ref_to())),
+            validator: established_address_1(),
+            amount: DenominatedAmount::native(token::Amount::native_whole(1)),
+        });
+        for key in [&key, &other_key] {
+            let sig = common::SigScheme::sign(key, [0; 32]);
+            bond.signatures.insert(
+                StringEncoded::new(key.ref_to()),
+                StringEncoded::new(sig),
+            );
+        }
+        let signed = Transactions::<Unvalidated> {
+            bond: Some(vec![bond.clone()]),
+            ..Default::default()
+        };
+
+        let manifest = ExpectedSigners::from_signed(&signed, |pk| {
+            (*pk == key.ref_to()).then(|| "albert".to_string())
+        });
+        assert!(manifest.validator_account.is_empty());
+        assert_eq!(manifest.bond.len(), 1);
+        assert_eq!(manifest.bond[0].source, bond.data.source);
+        assert_eq!(
+            manifest.bond[0].signers,
+            [
+                StringEncoded::new(key.ref_to()),
+                StringEncoded::new(other_key.ref_to())
+            ]
+            .into()
+        );
+        assert_eq!(
+            manifest.aliases,
+            [(StringEncoded::new(key.ref_to()), "albert".to_string())].into()
+        );
+        assert!(check_expected_signers(&manifest, &signed, |_| true).is_empty());
+
+        // The manifest round-trips through TOML
+        let toml = toml::to_string(&manifest).unwrap();
+        let parsed: ExpectedSigners = toml::from_str(&toml).unwrap();
+        assert_eq!(parsed.bond[0].signers, manifest.bond[0].signers);
+        assert_eq!(parsed.aliases, manifest.aliases);
+    }
+
+    /// Test that txs read one at a time are the same as those parsed at
+    /// once, and that headers within multi-line strings don't split txs.
+    #[test]
+    fn test_unsigned_tx_reader() {
+        let toml = "# Unsigned txs\n\
+                    [[established_account]]\n\
+                    vp = \"\"\"\n\
+                    [[bond]]\n\
+                    \"\"\"\n\
+                    public_keys = []\n\
+                    \n\
+                    [[established_account]] # Another one\n\
+                    vp = '[[bond]]'\n\
+                    threshold = 2\n\
+                    public_keys = []\n";
+        let parsed = parse_unsigned(toml.as_bytes()).unwrap();
+        let read = UnsignedTxReader::new(toml.as_bytes())
+            .collect::<eyre::Result<Vec<_>>>()
+            .unwrap();
+        let expected: Vec<_> = parsed
+            .established_account
+            .unwrap()
+            .into_iter()
+            .map(UnsignedTx::EstablishedAccount)
+            .collect();
+        assert_eq!(read, expected);
+        // Established accounts are not signed, so they are not counted
+        assert_eq!(
+            UnsignedTxReader::new(toml.as_bytes()).count_txs().unwrap(),
+            0
+        );
+        let toml = "[[established_account]]\n\
+                    [[validator_account]]\n\
+                    [[bond]]\n\
+                    source = 1\n";
+        assert_eq!(
+            UnsignedTxReader::new(toml.as_bytes()).count_txs().unwrap(),
+            2
+        );
+
+        let malformed = "[[bond]]\nsource = 1\n";
+        let err = UnsignedTxReader::new(malformed.as_bytes())
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert!(err.to_string().contains("line 1"));
+    }
+
+    /// Test that only txs that may be signed by own keys, but aren't yet, are
+    /// found to lack their signature.
+    #[test]
+    fn test_lacks_own_signature() {
+        let own_key = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let other_key = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let is_own_key = |pk: &common::PublicKey| *pk == own_key.ref_to();
+        let bond_from = |key: &common::SecretKey| {
+            Signed::new(BondTx::<Unvalidated> {
+                source: GenesisAddress::PublicKey(StringEncoded::new(
+                    key.ref_to(),
+                )),
+                validator: established_address_1(),
+                amount: DenominatedAmount::native(token::Amount::native_whole(
+                    1,
+                )),
+            })
+        };
+
+        let mut own_bond = bond_from(&own_key);
+        assert!(lacks_own_signature(&own_bond, &[], is_own_key));
+        let sig = common::SigScheme::sign(&own_key, [0; 32]);
+        own_bond.signatures.insert(
+            StringEncoded::new(own_key.ref_to()),
+            StringEncoded::new(sig),
+        );
+        assert!(!lacks_own_signature(&own_bond, &[], is_own_key));
+
+        let other_bond = bond_from(&other_key);
+        assert!(!lacks_own_signature(&other_bond, &[], is_own_key));
+    }
+
+    /// Test that deeply nested input is rejected with an error instead of
+    /// overflowing the stack, while brackets in strings are not counted.
+    #[test]
+    fn test_parse_unsigned_deeply_nested() {
+        let nested = format!("bond = {}", "[".repeat(100_000));
+        assert!(parse_unsigned(nested.as_bytes()).is_err());
+
+        let bracketed = format!(
+            "# {}\n[[established_account]]\nvp = \"{}\"\nthreshold = 1\n",
+            "[".repeat(100),
+            "[".repeat(100),
+        );
+        assert_eq!(nesting_depth(bracketed.as_bytes()), 2);
+    }
+
+    /// Test that detached signatures attached back to the unsigned txs give
+    /// the txs signed inline.
+    #[test]
+    fn test_detached_signatures_roundtrip() {
+        let key = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let bond = BondTx::<Unvalidated> {
+            source: GenesisAddress::PublicKey(StringEncoded::new(key.ref_to())),
+            validator: established_address_1(),
+            amount: DenominatedAmount::native(token::Amount::native_whole(1)),
+        };
+        let mut signed_bond = Signed::new(bond.clone());
+        let sig = common::SigScheme::sign(
+            &key,
+            signed_bond.data.tx_to_sign().raw_header_hash(),
+        );
+        signed_bond
+            .signatures
+            .insert(StringEncoded::new(key.ref_to()), StringEncoded::new(sig));
+        let inline = Transactions::<Unvalidated> {
+            bond: Some(vec![signed_bond]),
+            ..Default::default()
+        };
+        let unsigned = UnsignedTransactions {
+            bond: Some(vec![bond]),
+            ..Default::default()
+        };
+
+        let detached = detach_signatures(&inline).unwrap();
+        assert_eq!(detached.signature.len(), 1);
+        let attached = attach_signatures(unsigned.clone(), &detached).unwrap();
+        assert_eq!(attached, inline);
+
+        // Identical txs all get the signatures of their id
+        let mut duplicated = unsigned.clone();
+        let bonds = duplicated.bond.as_mut().unwrap();
+        bonds.push(bonds[0].clone());
+        let attached = attach_signatures(duplicated, &detached).unwrap();
+        let mut inline_duplicated = inline;
+        let bonds = inline_duplicated.bond.as_mut().unwrap();
+        bonds.push(bonds[0].clone());
+        assert_eq!(attached, inline_duplicated);
+
+        // A signature of a tx missing from the unsigned txs is rejected
+        let mut unknown = detached;
+        unknown.signature[0].tx_id = "unknown".to_string();
+        assert!(attach_signatures(unsigned, &unknown).is_err());
+    }
+
+    /// Test that the signature checks come out in the order of the txs, the
+    /// same with any number of jobs.
+    #[test]
+    fn test_verify_signatures_is_deterministic() {
+        let key = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let bonds = (1..=20)
+            .map(|amount| {
+                let mut bond = Signed::new(BondTx::<Unvalidated> {
+                    source: GenesisAddress::PublicKey(StringEncoded::new(
+                        key.ref_to(),
+                    )),
+                    validator: established_address_1(),
+                    amount: DenominatedAmount::native(
+                        token::Amount::native_whole(amount),
+                    ),
+                });
+                // Leave every third bond with a signature over other data
+                let hash = if amount % 3 == 0 {
+                    Hash::zero()
+                } else {
+                    bond.data.tx_to_sign().raw_header_hash()
+                };
+                bond.signatures.insert(
+                    StringEncoded::new(key.ref_to()),
+                    StringEncoded::new(common::SigScheme::sign(&key, hash)),
+                );
+                bond
+            })
+            .collect();
+        let txs = Transactions::<Unvalidated> {
+            bond: Some(bonds),
+            ..Default::default()
+        };
+
+        let sequential = verify_signatures(&txs, 1).unwrap();
+        assert_eq!(sequential.len(), 20);
+        for (check, amount) in sequential.iter().zip(1..) {
+            let bonded =
+                DenominatedAmount::native(token::Amount::native_whole(amount));
+            assert!(check.tx.starts_with(&format!("bond of {bonded} from")));
+            assert_eq!(check.result.is_ok(), amount % 3 != 0);
+        }
+        assert_eq!(verify_signatures(&txs, 4).unwrap(), sequential);
+    }
+
+    /// Test that documents holding the same txs are canonicalized to the same
+    /// bytes, whatever their key order and whitespace.
+    #[test]
+    fn test_canonicalize() {
+        let key = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let mut bond = Signed::new(BondTx::<Unvalidated> {
+            source: GenesisAddress::PublicKey(StringEncoded::new(key.ref_to())),
+            validator: established_address_1(),
+            amount: DenominatedAmount::native(token::Amount::native_whole(5)),
+        });
+        let unsigned = UnsignedTransactions {
+            bond: Some(vec![bond.data.clone()]),
+            ..Default::default()
+        };
+        let hash = bond.data.tx_to_sign().raw_header_hash();
+        bond.signatures.insert(
+            StringEncoded::new(key.ref_to()),
+            StringEncoded::new(common::SigScheme::sign(&key, hash)),
+        );
+        let signed = Transactions::<Unvalidated> {
+            bond: Some(vec![bond]),
+            ..Default::default()
+        };
+
+        for declared in [
+            utils::to_toml_string(&signed, TomlKeyOrder::Declared).unwrap(),
+            utils::to_toml_string(&unsigned, TomlKeyOrder::Declared).unwrap(),
+        ] {
+            let reformatted = format!(
+                "# reformatted\n\n{}",
+                declared.replace(" = ", "  =   ")
+            );
+            let canonical = canonicalize(declared.as_bytes()).unwrap();
+            assert_eq!(
+                canonicalize(reformatted.as_bytes()).unwrap(),
+                canonical
+            );
+            assert_eq!(canonicalize(canonical.as_bytes()).unwrap(), canonical);
+        }
+        assert!(canonicalize(b"bond = 1").is_err());
+    }
+
+    /// Test that the signed txs are annotated with the address of each
+    /// signer, and parse back to the same txs.
+    #[test]
+    fn test_with_signer_addresses() {
+        let key = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let mut bond = Signed::new(BondTx::<Unvalidated> {
+            source: GenesisAddress::PublicKey(StringEncoded::new(key.ref_to())),
+            validator: established_address_1(),
+            amount: DenominatedAmount::native(token::Amount::native_whole(5)),
+        });
+        let hash = bond.data.tx_to_sign().raw_header_hash();
+        bond.signatures.insert(
+            StringEncoded::new(key.ref_to()),
+            StringEncoded::new(common::SigScheme::sign(&key, hash)),
+        );
+        let signed = Transactions::<Unvalidated> {
+            bond: Some(vec![bond]),
+            ..Default::default()
+        };
+
+        let annotated = with_signer_addresses(&signed).unwrap();
+        let address = &annotated["bond"][0][SIGNER_ADDRESSES_KEY]
+            [&StringEncoded::new(key.ref_to()).to_string()];
+        assert_eq!(
+            address.as_str(),
+            Some(Address::from(&key.ref_to()).to_string().as_str())
+        );
+
+        let toml =
+            utils::to_toml_string(&annotated, TomlKeyOrder::Sorted).unwrap();
+        assert_eq!(parse_signed(toml.as_bytes()).unwrap(), signed);
+    }
+
+    /// Test that signatures round-trip through every encoding, and that the
+    /// default encoding is left as it is.
+    #[test]
+    fn test_signature_encoding_roundtrip() {
+        let key = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let mut bond = Signed::new(BondTx::<Unvalidated> {
+            source: GenesisAddress::PublicKey(StringEncoded::new(key.ref_to())),
+            validator: established_address_1(),
+            amount: DenominatedAmount::native(token::Amount::native_whole(5)),
+        });
+        let hash = bond.data.tx_to_sign().raw_header_hash();
+        let signature = common::SigScheme::sign(&key, hash);
+        bond.signatures.insert(
+            StringEncoded::new(key.ref_to()),
+            StringEncoded::new(signature.clone()),
+        );
+        let signed = Transactions::<Unvalidated> {
+            bond: Some(vec![bond]),
+            ..Default::default()
+        };
+        let default =
+            utils::to_toml_string(&signed, TomlKeyOrder::Sorted).unwrap();
+
+        for encoding in [
+            SignatureEncoding::Default,
+            SignatureEncoding::Hex,
+            SignatureEncoding::Base64,
+        ] {
+            let mut value = toml::Value::try_from(&signed).unwrap();
+            encode_signatures(&mut value, encoding);
+            let encoded = value["bond"][0]["signatures"]
+                [&StringEncoded::new(key.ref_to()).to_string()]
+                .as_str()
+                .unwrap()
+                .to_string();
+            assert_eq!(encoded, encoding.encode(&signature));
+
+            let toml =
+                utils::to_toml_string(&value, TomlKeyOrder::Sorted).unwrap();
+            assert_eq!(encoding == SignatureEncoding::Default, toml == default);
+            assert_eq!(parse_signed(toml.as_bytes()).unwrap(), signed);
+        }
+
+        let garbled = default.replace(
+            &SignatureEncoding::Default.encode(&signature),
+            "not-a-signature",
+        );
+        assert!(parse_signed(garbled.as_bytes()).is_err());
+    }
+
+    /// Test that merging documents takes the union of the signatures of each
+    /// tx, and fails on different signatures by the same signer.
+    #[test]
+    fn test_merge_signed() {
+        let albert = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let bertha = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let bond = BondTx::<Unvalidated> {
+            source: GenesisAddress::PublicKey(StringEncoded::new(
+                albert.ref_to(),
+            )),
+            validator: established_address_1(),
+            amount: DenominatedAmount::native(token::Amount::native_whole(5)),
+        };
+        let hash = bond.tx_to_sign().raw_header_hash();
+        let signed_by = |key: &common::SecretKey, hash: Hash| {
+            let mut signed = Signed::new(bond.clone());
+            signed.signatures.insert(
+                StringEncoded::new(key.ref_to()),
+                StringEncoded::new(common::SigScheme::sign(key, hash)),
+            );
+            Transactions::<Unvalidated> {
+                bond: Some(vec![signed]),
+                ..Default::default()
+            }
+        };
+
+        let merged = merge_signed([
+            signed_by(&albert, hash),
+            signed_by(&bertha, hash),
+            signed_by(&albert, hash),
+        ])
+        .unwrap();
+        let bonds = merged.bond.unwrap();
+        assert_eq!(bonds.len(), 1);
+        assert_eq!(
+            bonds[0].signatures.keys().cloned().collect::<BTreeSet<_>>(),
+            BTreeSet::from([
+                StringEncoded::new(albert.ref_to()),
+                StringEncoded::new(bertha.ref_to()),
+            ])
+        );
+        assert!(merged.validator_account.is_none());
+
+        let err = merge_signed([
+            signed_by(&albert, hash),
+            signed_by(&albert, Hash::sha256(b"another tx")),
+        ])
+        .unwrap_err();
+        assert!(err.contains("conflicting signatures"), "{err}");
+    }
+
+    /// Test that failing fast returns the first signing error, while
+    /// continuing on errors records every failure with its reason.
+    #[test]
+    fn test_sign_error_mode() {
+        let mut failures = vec![];
+        let err = SignErrorMode::FailFast
+            .handle(
+                "bond tx".to_string(),
+                eyre::eyre!("rejected"),
+                &mut failures,
+            )
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Failed to sign the bond tx: rejected");
+        assert!(failures.is_empty());
+
+        for reason in ["rejected", "missing key"] {
+            SignErrorMode::ContinueOnError
+                .handle(
+                    "bond tx".to_string(),
+                    eyre::eyre!(reason),
+                    &mut failures,
+                )
+                .unwrap();
+        }
+        assert_eq!(
+            failures,
+            vec![
+                SignFailure {
+                    tx: "bond tx".to_string(),
+                    reason: "rejected".to_string(),
+                },
+                SignFailure {
+                    tx: "bond tx".to_string(),
+                    reason: "missing key".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_encrypted_txs() {
+        let plain = b"[[bond]]\nsource = \"tnam1\"\n";
+        assert!(!is_encrypted_txs(plain));
+
+        let password = || Zeroizing::new("password".to_string());
+        let encrypted = encrypt_txs(plain, password());
+        assert!(is_encrypted_txs(encrypted.as_bytes()));
+        assert_eq!(
+            decrypt_txs(encrypted.as_bytes(), password()).unwrap(),
+            plain
+        );
+        let err = decrypt_txs(
+            encrypted.as_bytes(),
+            Zeroizing::new("wrong".to_string()),
+        )
+        .unwrap_err();
+        assert!(err.contains("Is the password correct"), "{err}");
+    }
+
+    #[test]
+    fn test_is_device_rejection() {
+        assert!(is_device_rejection(Some(0x6986)));
+        assert!(!is_device_rejection(Some(0x9000)));
+        assert!(!is_device_rejection(Some(0x6984)));
+        assert!(!is_device_rejection(None));
+    }
+
+    #[test]
+    fn test_min_device_app_version() {
+        let (major, minor, patch) = MIN_DEVICE_APP_VERSION;
+        assert!(ensure_min_device_app_version((major, minor, patch)).is_ok());
+        assert!(
+            ensure_min_device_app_version((major.saturating_add(1), 0, 0))
+                .is_ok()
+        );
+        let err = ensure_min_device_app_version((0, 0, 0)).unwrap_err();
+        assert!(err.to_string().contains("Upgrade the app"), "{err}");
+    }
+
+    /// Test that summing up stakes near the maximum amount returns an error
+    /// instead of wrapping around.
+    #[test]
+    fn test_stakes_overflow() {
+        let key = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let bond = |validator: Address, amount: token::Amount| BondTx::<
+            Validated,
+        > {
+            source: GenesisAddress::PublicKey(StringEncoded::new(key.ref_to())),
+            validator,
+            amount: DenominatedAmount::native(amount),
+        };
+        let max = token::Amount::max();
+        let one = token::Amount::from_u64(1);
+        let validator_1 = established_address_1();
+        let validator_2 = established_address_2();
+
+        // The maximum stake of a single bond is fine
+        let bonds = [bond(validator_1.clone(), max)];
+        let stakes = validator_stakes(&bonds).unwrap();
+        assert_eq!(stakes[&validator_1], max);
+        assert_eq!(total_stake(stakes.into_values()).unwrap(), max);
+
+        // Another bond to the same validator overflows its stake
+        let bonds = [
+            bond(validator_1.clone(), max),
+            bond(validator_1.clone(), one),
+        ];
+        assert!(validator_stakes(&bonds).is_err());
+        let txs = Transactions::<Validated> {
+            bond: Some(bonds.to_vec()),
+            ..Default::default()
+        };
+        assert!(txs
+            .has_validator_with_positive_voting_power(Dec::one())
+            .is_err());
+
+        // Bonds to distinct validators only overflow the total stake
+        let bonds = [bond(validator_1, max), bond(validator_2.clone(), one)];
+        let stakes = validator_stakes(&bonds).unwrap();
+        assert_eq!(stakes[&validator_2], one);
+        assert!(total_stake(stakes.into_values()).is_err());
+    }
+}
@@ -1,17 +1,20 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::Path;
+use std::str::FromStr;
 
 use eyre::Context;
 use ledger_namada_rs::NamadaApp;
 use namada_sdk::collections::HashSet;
 use namada_sdk::key::common;
-use namada_sdk::tx::Tx;
+use namada_sdk::token;
+use namada_sdk::tx::{Authorization, Section, Signer, Tx};
 use namada_sdk::wallet::Wallet;
 use namada_sdk::{error, signing};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use tokio::sync::RwLock;
 
-use crate::wallet::CliWalletUtils;
+use crate::wallet::{CliWalletUtils, Pkcs11Signer};
 
 /// Validity predicaty assigned to established accounts.
 pub const VP_USER: &str = "vp_user";
@@ -49,6 +52,159 @@ pub fn write_toml<T: Serialize>(
     })
 }
 
+/// The order in which the keys of TOML tables are serialized
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TomlKeyOrder {
+    /// The order in which the fields and map entries are serialized
+    Declared,
+    /// Lexicographically sorted keys, such that the same data always gives
+    /// the same bytes regardless of the iteration order of the maps in it
+    #[default]
+    Sorted,
+}
+
+impl FromStr for TomlKeyOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "declared" => Ok(Self::Declared),
+            "sorted" => Ok(Self::Sorted),
+            raw => Err(format!(
+                "Unexpected TOML key order \"{raw}\". Valid options are \
+                 \"declared\" or \"sorted\"."
+            )),
+        }
+    }
+}
+
+/// Serialize data to a TOML string, with the keys of its tables in the
+/// given order.
+pub fn to_toml_string<T: Serialize>(
+    data: &T,
+    order: TomlKeyOrder,
+) -> Result<String, toml::ser::Error> {
+    match order {
+        TomlKeyOrder::Declared => toml::to_string(data),
+        TomlKeyOrder::Sorted => {
+            toml::to_string(&sort_toml_keys(toml::Value::try_from(data)?))
+        }
+    }
+}
+
+/// Recursively sort the keys of the tables in a TOML value
+fn sort_toml_keys(value: toml::Value) -> toml::Value {
+    match value {
+        toml::Value::Table(table) => {
+            let mut entries: Vec<_> = table.into_iter().collect();
+            entries.sort_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b));
+            toml::Value::Table(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, sort_toml_keys(value)))
+                    .collect(),
+            )
+        }
+        toml::Value::Array(array) => {
+            toml::Value::Array(array.into_iter().map(sort_toml_keys).collect())
+        }
+        value => value,
+    }
+}
+
+/// The unit suffix of amounts of the native token given in whole tokens
+const NATIVE_TOKEN_UNIT: &str = "NAM";
+
+/// The unit of an amount of the native token given as a bare number
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AmountUnit {
+    /// Whole tokens, e.g. "1.5" for 1.5 NAM
+    Display,
+    /// The smallest unit of the token, of which there are 10^6 per NAM
+    Base,
+}
+
+/// Parse an amount of the native token into base units. The amount is either
+/// given in whole tokens with a unit suffix, e.g. "100NAM", or as a bare
+/// number in the assumed unit. Bare numbers are rejected when no unit is
+/// assumed, as they are ambiguous.
+pub fn parse_native_amount(
+    raw: &str,
+    assumed_unit: Option<AmountUnit>,
+) -> Result<token::Amount, String> {
+    let raw = raw.trim();
+    let suffixed = raw
+        .len()
+        .checked_sub(NATIVE_TOKEN_UNIT.len())
+        .and_then(|split| Some((raw.get(..split)?, raw.get(split..)?)))
+        .filter(|(_, unit)| unit.eq_ignore_ascii_case(NATIVE_TOKEN_UNIT));
+    let (number, unit) = match (suffixed, assumed_unit) {
+        (Some((number, _)), _) => (number.trim_end(), AmountUnit::Display),
+        (None, Some(unit)) => (raw, unit),
+        (None, None) => {
+            return Err(format!(
+                "The amount \"{raw}\" has no unit. Either suffix it with \
+                 \"{NATIVE_TOKEN_UNIT}\" or pass `--assume-display-units` or \
+                 `--assume-base-units`."
+            ));
+        }
+    };
+    let denom = match unit {
+        AmountUnit::Display => token::NATIVE_MAX_DECIMAL_PLACES,
+        AmountUnit::Base => 0,
+    };
+    token::Amount::from_str(number, denom).map_err(|err| {
+        format!("Invalid amount \"{raw}\" in {unit:?} units: {err}")
+    })
+}
+
+/// Parse a selection of the txs of a list numbered from 1, e.g. "1,3-5" or
+/// "all", into the indices of the selected txs. Numbers and ranges may be
+/// separated by commas or whitespace.
+pub fn parse_tx_selection(
+    raw: &str,
+    num_txs: usize,
+) -> Result<BTreeSet<usize>, String> {
+    let raw = raw.trim();
+    if raw.eq_ignore_ascii_case("all") {
+        return Ok((0..num_txs).collect());
+    }
+    let parse_number = |number: &str| {
+        number
+            .trim()
+            .parse::<usize>()
+            .ok()
+            .filter(|number| (1..=num_txs).contains(number))
+            .ok_or_else(|| {
+                format!(
+                    "\"{number}\" is not the number of a transaction, from 1 \
+                     to {num_txs}."
+                )
+            })
+    };
+    let mut selected = BTreeSet::new();
+    for item in raw
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|item| !item.is_empty())
+    {
+        let (first, last) = match item.split_once('-') {
+            Some((first, last)) => (parse_number(first)?, parse_number(last)?),
+            None => {
+                let number = parse_number(item)?;
+                (number, number)
+            }
+        };
+        if first > last {
+            return Err(format!("The range \"{item}\" is empty."));
+        }
+        selected.extend((first..=last).map(|number| number.saturating_sub(1)));
+    }
+    if selected.is_empty() {
+        return Err("No transaction was selected.".to_string());
+    }
+    Ok(selected)
+}
+
 pub(super) async fn with_hardware_wallet<'a, T>(
     tx: Tx,
     pubkey: common::PublicKey,
@@ -71,3 +227,133 @@ where
         .await
     }
 }
+
+pub(super) async fn with_pkcs11_token(
+    mut tx: Tx,
+    pubkey: common::PublicKey,
+    parts: HashSet<signing::Signable>,
+    token: &Pkcs11Signer,
+) -> Result<Tx, error::Error> {
+    if parts.contains(&signing::Signable::FeeHeader) {
+        return Ok(tx);
+    }
+    if &pubkey != token.public_key() {
+        return Err(error::Error::Other(format!(
+            "The PKCS#11 token doesn't hold the key {pubkey}."
+        )));
+    }
+    if parts.contains(&signing::Signable::RawHeader) {
+        let unsigned = Authorization {
+            targets: vec![tx.raw_header_hash()],
+            signer: Signer::PubKeys(vec![pubkey]),
+            signatures: BTreeMap::new(),
+        };
+        let signature = token
+            .sign(&unsigned.get_raw_hash())
+            .map_err(error::Error::Other)?;
+        tx.add_section(Section::Authorization(Authorization {
+            signatures: [(0, signature)].into(),
+            ..unsigned
+        }));
+    }
+    Ok(tx)
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_sdk::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_native_amount() {
+        let nam = token::Amount::native_whole;
+
+        // Suffixed amounts are in whole tokens, whatever unit is assumed
+        assert_eq!(parse_native_amount("100NAM", None), Ok(nam(100)));
+        assert_eq!(parse_native_amount("100 nam", None), Ok(nam(100)));
+        assert_eq!(
+            parse_native_amount("1.5NAM", Some(AmountUnit::Base)),
+            Ok(token::Amount::from_u64(1_500_000))
+        );
+
+        // Bare numbers need an assumed unit
+        assert!(parse_native_amount("100", None).is_err());
+        assert_eq!(
+            parse_native_amount("100", Some(AmountUnit::Display)),
+            Ok(nam(100))
+        );
+        assert_eq!(
+            parse_native_amount("100", Some(AmountUnit::Base)),
+            Ok(token::Amount::from_u64(100))
+        );
+
+        // Base units can't be fractional, nor whole tokens finer than them
+        assert!(parse_native_amount("1.5", Some(AmountUnit::Base)).is_err());
+        assert!(parse_native_amount("0.0000001NAM", None).is_err());
+        assert!(parse_native_amount("NAM", None).is_err());
+    }
+
+    #[test]
+    fn test_parse_tx_selection() {
+        assert_eq!(parse_tx_selection("all", 3), Ok(BTreeSet::from([0, 1, 2])));
+        assert_eq!(
+            parse_tx_selection(" 1, 3-4 2\n", 5),
+            Ok(BTreeSet::from([0, 1, 2, 3]))
+        );
+        assert_eq!(parse_tx_selection("2-2", 2), Ok(BTreeSet::from([1])));
+
+        // Numbers must be those of listed txs, and ranges non-empty
+        assert!(parse_tx_selection("0", 3).is_err());
+        assert!(parse_tx_selection("4", 3).is_err());
+        assert!(parse_tx_selection("3-1", 3).is_err());
+        assert!(parse_tx_selection("first", 3).is_err());
+        assert!(parse_tx_selection(" ", 3).is_err());
+    }
+
+    /// Test that the same data serializes to the same bytes with sorted keys,
+    /// regardless of the order that its maps were filled in.
+    #[test]
+    fn test_sorted_toml_is_reproducible() {
+        #[derive(Serialize)]
+        struct Signed {
+            signatures: HashMap<String, String>,
+            tx: String,
+        }
+
+        let keys = ["tpknam1c", "tpknam1a", "tpknam1b"];
+        let signed = |keys: &[&str]| Signed {
+            signatures: keys
+                .iter()
+                .map(|key| (key.to_string(), format!("sig-{key}")))
+                .collect(),
+            tx: "bond".to_string(),
+        };
+        let mut reversed = keys;
+        reversed.reverse();
+
+        // Maps filled in different orders serialize differently as declared
+        let declared =
+            to_toml_string(&signed(&keys), TomlKeyOrder::Declared).unwrap();
+        assert_ne!(
+            declared,
+            to_toml_string(&signed(&reversed), TomlKeyOrder::Declared).unwrap()
+        );
+
+        // Sorted keys always give the same bytes
+        let sorted =
+            to_toml_string(&signed(&keys), TomlKeyOrder::Sorted).unwrap();
+        for _ in 0..10 {
+            assert_eq!(
+                to_toml_string(&signed(&reversed), TomlKeyOrder::Sorted)
+                    .unwrap(),
+                sorted
+            );
+        }
+        let a = sorted.find("tpknam1a").unwrap();
+        let b = sorted.find("tpknam1b").unwrap();
+        let c = sorted.find("tpknam1c").unwrap();
+        assert!(a < b && b < c);
+        assert!(toml::from_str::<toml::Value>(&sorted).is_ok());
+    }
+}
@@ -33,6 +33,9 @@ pub const FILENAME: &str = "config.toml";
 pub const COMETBFT_DIR: &str = "cometbft";
 /// Chain-specific Namada DB. Nested in chain dirs.
 pub const DB_DIR: &str = "db";
+/// Profiles, each with its own global config and chain directories. Nested
+/// in the base dir.
+pub const PROFILES_DIR: &str = "profiles";
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Config {
@@ -351,6 +354,26 @@ pub fn get_default_namada_folder() -> PathBuf {
     }
 }
 
+/// Get the dir of the given profile in the base dir, which then takes the
+/// place of the base dir. Fails if the name of the profile isn't a plain dir
+/// name.
+pub fn profile_base_dir(
+    base_dir: impl AsRef<Path>,
+    profile: &str,
+) -> Result<PathBuf, String> {
+    let is_plain_name = profile
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && !matches!(profile, "" | "." | "..");
+    if !is_plain_name {
+        return Err(format!(
+            "Invalid profile name \"{profile}\". Profile names may only \
+             contain ASCII letters, digits, '-', '_' and '.'."
+        ));
+    }
+    Ok(base_dir.as_ref().join(PROFILES_DIR).join(profile))
+}
+
 pub const VALUE_AFTER_TABLE_ERROR_MSG: &str = r#"
 Error while serializing to toml. It means that some nested structure is followed
  by simple fields.
@@ -861,4 +884,15 @@ mod tests {
     fn test_default_cometbft_config() {
         assert!(TendermintConfig::parse_toml(DEFAULT_COMETBFT_CONFIG).is_ok());
     }
+
+    #[test]
+    fn test_profile_base_dir() {
+        assert_eq!(
+            super::profile_base_dir("/base", "testnet").unwrap(),
+            std::path::Path::new("/base/profiles/testnet")
+        );
+        for invalid in ["", "..", "../mainnet", "main/net"] {
+            assert!(super::profile_base_dir("/base", invalid).is_err());
+        }
+    }
 }
@@ -14,6 +14,7 @@
     clippy::dbg_macro
 )]
 
+mod async_fs;
 pub mod cli;
 pub mod client;
 pub mod config;
@@ -26,6 +26,7 @@ mod dev {
     use namada_sdk::wallet::Wallet;
     use namada_sdk::{governance, proof_of_stake};
 
+    use crate::cli::args::WALLET_FILE_NAME_ENV_VAR;
     use crate::wallet::CliWalletUtils;
 
     /// Get protocol, eth_bridge, and dkg keys from the validator pre-genesis
@@ -227,7 +228,16 @@ mod dev {
                 root_dir.pop();
             }
             let path = derive_template_dir(&root_dir).join("src/pre-genesis");
-            crate::wallet::load(&path).unwrap()
+            // Use the wallet file name set by the env var if the templates
+            // hold such a file, otherwise the default one
+            let file_name = std::env::var(WALLET_FILE_NAME_ENV_VAR)
+                .ok()
+                .filter(|name| crate::wallet::exists(&path, Some(name.as_str())));
+            let options = crate::wallet::WalletOptions {
+                file_name,
+                ..Default::default()
+            };
+            crate::wallet::load_with_options(&path, &options).unwrap()
         };
 
         static ref VALIDATOR_WALLET: ValidatorWallet = {
@@ -241,7 +251,8 @@ mod dev {
             }
             let path =
                 derive_template_dir(&root_dir).join(VALIDATOR_0_PREGENESIS_DIR);
-            crate::wallet::pre_genesis::load(&path).unwrap()
+            crate::wallet::pre_genesis::load(&path, &Default::default())
+                .unwrap()
         };
     }
 }
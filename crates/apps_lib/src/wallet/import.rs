@@ -0,0 +1,151 @@
+//! Import of addresses listed in a file into the wallet
+
+use std::fs;
+use std::path::Path;
+
+use namada_sdk::address::Address;
+use namada_sdk::wallet::Wallet;
+use serde::Deserialize;
+
+use super::{Alias, CliWalletUtils};
+
+/// Chooses the alias of an address imported without one, so that callers can
+/// control the naming of ad-hoc imports.
+pub trait AliasGenerator {
+    /// Generate an alias for the given address, or return `None` if it
+    /// mustn't be imported without an alias.
+    fn generate(&mut self, address: &Address) -> Option<Alias>;
+}
+
+/// The default [`AliasGenerator`], which requires every imported address to
+/// come with its alias.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RequireAlias;
+
+impl AliasGenerator for RequireAlias {
+    fn generate(&mut self, _address: &Address) -> Option<Alias> {
+        None
+    }
+}
+
+impl<F> AliasGenerator for F
+where
+    F: FnMut(&Address) -> Option<Alias>,
+{
+    fn generate(&mut self, address: &Address) -> Option<Alias> {
+        self(address)
+    }
+}
+
+/// A TOML file of addresses to import, e.g.:
+///
+/// ```toml
+/// [[address]]
+/// address = "tnam1..."
+/// alias = "albert"
+///
+/// [[address]]
+/// address = "tnam1..."
+/// ```
+#[derive(Debug, Deserialize)]
+struct AddressesFile {
+    #[serde(default)]
+    address: Vec<AddressEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AddressEntry {
+    address: Address,
+    alias: Option<Alias>,
+}
+
+/// Add the addresses listed in the TOML file at the given path to the wallet.
+/// Addresses listed without an alias are named by the given generator. No
+/// address is added if any of them is left without an alias. Returns the
+/// aliases of the added addresses.
+pub fn add_addresses_from_file(
+    wallet: &mut Wallet<CliWalletUtils>,
+    path: &Path,
+    aliases: &mut impl AliasGenerator,
+    force_alias: bool,
+) -> Result<Vec<String>, String> {
+    let contents = fs::read_to_string(path).map_err(|err| {
+        format!(
+            "Failed to read the addresses file {}: {err}",
+            path.display()
+        )
+    })?;
+    let AddressesFile { address: entries } = toml::from_str(&contents)
+        .map_err(|err| {
+            format!("Invalid addresses file {}: {err}", path.display())
+        })?;
+    let entries = entries
+        .into_iter()
+        .map(|AddressEntry { address, alias }| {
+            match alias.or_else(|| aliases.generate(&address)) {
+                Some(alias) => Ok((alias, address)),
+                None => Err(format!("The address {address} has no alias.")),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(entries
+        .into_iter()
+        .filter_map(|(alias, address)| {
+            wallet.insert_address(alias.normalize(), address, force_alias)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_sdk::address::testing::{
+        established_address_1, established_address_2,
+    };
+
+    use super::*;
+
+    fn addresses_file(dir: &Path) -> std::path::PathBuf {
+        let path = dir.join("addresses.toml");
+        let contents = format!(
+            "[[address]]\naddress = \"{}\"\nalias = \"albert\"\n\n\
+             [[address]]\naddress = \"{}\"\n",
+            established_address_1(),
+            established_address_2()
+        );
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_add_addresses_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = addresses_file(dir.path());
+
+        // By default, every address must come with an alias
+        let mut wallet = CliWalletUtils::new(dir.path().to_path_buf());
+        let err = add_addresses_from_file(
+            &mut wallet,
+            &path,
+            &mut RequireAlias,
+            true,
+        )
+        .unwrap_err();
+        assert!(err.contains("has no alias"), "{err}");
+        assert!(wallet.find_address("albert").is_none());
+
+        // A generator names the addresses listed without an alias
+        let mut counter = 0_u64;
+        let mut generator = |_: &Address| {
+            counter = counter.saturating_add(1);
+            Some(Alias::from(format!("imported-{counter}")))
+        };
+        let added =
+            add_addresses_from_file(&mut wallet, &path, &mut generator, true)
+                .unwrap();
+        assert_eq!(added, vec!["albert", "imported-1"]);
+        assert_eq!(
+            wallet.find_address("imported-1").as_deref(),
+            Some(&established_address_2())
+        );
+    }
+}
@@ -0,0 +1,176 @@
+//! Locking of decrypted secret keys in memory, so that they can't be swapped
+//! out to disk while signing. Memory can't be locked on wasm32, where the
+//! keys are always left in ordinary memory.
+#[cfg(not(target_family = "wasm"))]
+use std::collections::BTreeMap;
+#[cfg(not(target_family = "wasm"))]
+use std::sync::{Mutex, PoisonError};
+
+use namada_sdk::key::common;
+#[cfg(not(target_family = "wasm"))]
+use namada_sdk::key::{ed25519, secp256k1};
+
+/// The number of locked keys on every page of memory that holds some, by the
+/// start address of the page. A page is only unlocked once none of the keys
+/// on it is locked anymore, as `munlock` unlocks whole pages regardless of
+/// how many times they were locked.
+#[cfg(not(target_family = "wasm"))]
+static LOCKED_PAGES: Mutex<BTreeMap<usize, usize>> =
+    Mutex::new(BTreeMap::new());
+
+/// The memory pages of secret keys locked with `mlock`, which are unlocked
+/// when this is dropped, unless other locked keys are still on them. The keys
+/// must be zeroized before this is dropped, as they may be swapped out after.
+#[derive(Debug, Default)]
+pub struct LockedKeys {
+    /// The start address of the pages of every locked key, once per key
+    #[cfg(not(target_family = "wasm"))]
+    pages: Vec<usize>,
+    /// The number of locked keys
+    num_keys: usize,
+}
+
+impl LockedKeys {
+    /// Lock the memory holding the secret material of the given keys. Where
+    /// locking isn't available, or the limit of locked memory is reached, the
+    /// keys are left in ordinary memory with a warning.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn lock<'a>(
+        keys: impl IntoIterator<Item = &'a common::SecretKey>,
+    ) -> Self {
+        let mut locked = Self::default();
+        let mut num_unlocked: usize = 0;
+        let mut last_err = None;
+        let mut locked_pages =
+            LOCKED_PAGES.lock().unwrap_or_else(PoisonError::into_inner);
+        for key in keys {
+            let mut key_locked = true;
+            for page in key_pages(key) {
+                if !locked_pages.contains_key(&page) {
+                    match region::lock(page as *const u8, region::page::size())
+                    {
+                        // The page is unlocked on drop of `Self` instead, as
+                        // the guard can't be held across the awaits of signing
+                        Ok(guard) => std::mem::forget(guard),
+                        Err(err) => {
+                            key_locked = false;
+                            last_err = Some(err);
+                            continue;
+                        }
+                    }
+                }
+                let count = locked_pages.entry(page).or_default();
+                *count = count.saturating_add(1);
+                locked.pages.push(page);
+            }
+            if key_locked {
+                locked.num_keys = locked.num_keys.saturating_add(1);
+            } else {
+                num_unlocked = num_unlocked.saturating_add(1);
+            }
+        }
+        if let Some(err) = last_err {
+            eprintln!(
+                "Warning: {num_unlocked} decrypted key(s) could not be locked \
+                 in memory and may be swapped out to disk: {err}"
+            );
+        }
+        locked
+    }
+
+    /// Memory can't be locked on wasm32, so the keys are left in ordinary
+    /// memory.
+    #[cfg(target_family = "wasm")]
+    pub fn lock<'a>(
+        _keys: impl IntoIterator<Item = &'a common::SecretKey>,
+    ) -> Self {
+        Self::default()
+    }
+
+    /// The number of keys locked in memory
+    pub fn len(&self) -> usize {
+        self.num_keys
+    }
+
+    /// Whether no key is locked in memory
+    pub fn is_empty(&self) -> bool {
+        self.num_keys == 0
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl Drop for LockedKeys {
+    fn drop(&mut self) {
+        let mut locked_pages =
+            LOCKED_PAGES.lock().unwrap_or_else(PoisonError::into_inner);
+        for page in self.pages.drain(..) {
+            let Some(count) = locked_pages.get_mut(&page) else {
+                continue;
+            };
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                locked_pages.remove(&page);
+                let _ = region::unlock(page as *const u8, region::page::size());
+            }
+        }
+    }
+}
+
+/// The start addresses of the memory pages holding the secret material of a
+/// key.
+#[cfg(not(target_family = "wasm"))]
+fn key_pages(key: &common::SecretKey) -> impl Iterator<Item = usize> {
+    let (address, len) = key_region(key);
+    let first = region::page::floor(address) as usize;
+    let end = region::page::ceil(address.wrapping_add(len)) as usize;
+    (first..end).step_by(region::page::size())
+}
+
+/// The heap region holding the secret material of a key. Both schemes box
+/// their keys, so the region stays put for as long as the key lives.
+#[cfg(not(target_family = "wasm"))]
+fn key_region(key: &common::SecretKey) -> (*const u8, usize) {
+    fn boxed_region<T>(boxed: &T) -> (*const u8, usize) {
+        ((boxed as *const T).cast(), std::mem::size_of_val(boxed))
+    }
+    match key {
+        common::SecretKey::Ed25519(ed25519::SecretKey(key)) => {
+            boxed_region(&**key)
+        }
+        common::SecretKey::Secp256k1(secp256k1::SecretKey(key)) => {
+            boxed_region(&**key)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_sdk::key::SchemeType;
+    use namada_sdk::wallet::gen_secret_key;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    /// Test that the pages of a key stay locked for as long as some locked
+    /// keys are on them.
+    #[test]
+    fn test_shared_pages_stay_locked() {
+        let key = gen_secret_key(SchemeType::Ed25519, &mut OsRng);
+        let first = LockedKeys::lock([&key]);
+        if first.is_empty() {
+            // Memory can't be locked on this machine
+            return;
+        }
+        let second = LockedKeys::lock([&key]);
+        let is_locked = |page: usize| {
+            LOCKED_PAGES
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .contains_key(&page)
+        };
+        drop(first);
+        assert!(key_pages(&key).all(is_locked));
+        drop(second);
+        assert!(!key_pages(&key).any(is_locked));
+    }
+}
@@ -1,6 +1,11 @@
 pub mod defaults;
+mod import;
+mod memory_lock;
+mod pkcs11;
 pub mod pre_genesis;
 mod store;
+#[cfg(test)]
+pub mod test_utils;
 mod transport;
 
 use std::borrow::Cow;
@@ -8,30 +13,89 @@ use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 use std::{env, fs};
 
+pub use import::{add_addresses_from_file, AliasGenerator, RequireAlias};
+pub use memory_lock::LockedKeys;
 use namada_sdk::bip39::{Language, Mnemonic};
+use namada_sdk::chain::ChainId;
 use namada_sdk::key::*;
 pub use namada_sdk::wallet::alias::Alias;
 use namada_sdk::wallet::store::Store;
 use namada_sdk::wallet::{
-    ConfirmationResponse, FindKeyError, LoadStoreError, Wallet, WalletIo,
+    ConfirmationResponse, FindKeyError, KdfParams, LoadStoreError, Wallet,
+    WalletIo,
 };
 pub use namada_sdk::wallet::{ValidatorData, ValidatorKeys};
-use namada_wallet::fs::FsWalletStorage;
-use rand_core::OsRng;
-pub use store::wallet_file;
-pub use transport::{TransportTcp, WalletTransport};
+use namada_wallet::fs::{FsWalletStorage, ADDRESS_BOOK_TARGET};
+pub use pkcs11::{Pkcs11Config, Pkcs11Signer};
+use rand::CryptoRng;
+use rand_core::{OsRng, RngCore};
+pub use store::{wallet_file, wallet_file_with_name};
+pub use transport::{
+    list_hid_devices, HidDevice, LastRetcode, TransportTcp, WalletTransport,
+};
 use zeroize::Zeroizing;
 
 use crate::cli;
+use crate::client::utils::pre_genesis_dir_name;
+
+/// The options of a CLI wallet, as set by the global args
+#[derive(Debug, Clone, Default)]
+pub struct WalletOptions {
+    /// The name of the wallet file, if not the default one
+    pub file_name: Option<String>,
+    /// Fail to load a wallet without an integrity MAC
+    pub require_integrity: bool,
+    /// Keep the changes made to the wallet in memory, without saving them
+    pub no_save: bool,
+    /// Log the source that supplied the wallet password
+    pub verbose: bool,
+    /// Whether there is a terminal to prompt on, if its detection is
+    /// overridden
+    pub tty_override: Option<bool>,
+    /// The renderings of the base dir to hide from errors, if redacted
+    pub redacted_base_dirs: Vec<String>,
+    /// The KDF parameters that keys are encrypted with, if not the default
+    /// ones
+    pub kdf_params: Option<KdfParams>,
+}
+
 #[derive(Debug, Clone)]
 pub struct CliWalletUtils {
     store_dir: PathBuf,
+    options: WalletOptions,
 }
 
 impl CliWalletUtils {
     /// Initialize a wallet at the given directory
     pub fn new(store_dir: PathBuf) -> Wallet<Self> {
-        Wallet::new(Self { store_dir }, Store::default())
+        Self::new_with_options(store_dir, WalletOptions::default())
+    }
+
+    /// Initialize a wallet at the given directory with the given options
+    pub fn new_with_options(
+        store_dir: PathBuf,
+        options: WalletOptions,
+    ) -> Wallet<Self> {
+        Wallet::new(Self { store_dir, options }, Store::default())
+    }
+
+    /// Initialize the utils with the given options to prompt with outside of
+    /// a wallet, which have no store dir
+    pub fn without_store(options: WalletOptions) -> Self {
+        Self {
+            store_dir: PathBuf::new(),
+            options,
+        }
+    }
+
+    /// Replace the base dir in the given text if redacted with `--redact`
+    pub fn redact_base_dir(&self, text: impl std::fmt::Display) -> String {
+        cli::redact_base_dir(&self.options.redacted_base_dirs, text)
+    }
+
+    /// Check if the user can be prompted for input
+    fn tty_enabled(&self) -> bool {
+        cli::tty_enabled(self.options.tty_override)
     }
 }
 
@@ -39,49 +103,78 @@ impl FsWalletStorage for CliWalletUtils {
     fn store_dir(&self) -> &PathBuf {
         &self.store_dir
     }
+
+    fn require_integrity(&self) -> bool {
+        self.options.require_integrity
+    }
+
+    fn save_disabled(&self) -> bool {
+        self.options.no_save
+    }
+
+    fn wallet_file(&self) -> PathBuf {
+        wallet_file_with_name(
+            &self.store_dir,
+            self.options.file_name.as_deref(),
+        )
+    }
+
+    fn address_book_password(&self) -> Option<Zeroizing<String>> {
+        Some(self.read_password(false, Some(ADDRESS_BOOK_TARGET)))
+    }
 }
 
 impl WalletIo for CliWalletUtils {
     type Rng = OsRng;
 
+    /// The password is read from the first of these sources that supplies
+    /// one, in order:
+    /// 1. the file at `NAMADA_WALLET_PASSWORD_FILE`, unless it is empty
+    /// 2. the `NAMADA_WALLET_PASSWORD` env var
+    /// 3. a TTY prompt
+    ///
+    /// With `--verbose`, the source that supplied the password is logged.
+    /// When there is no TTY to prompt on, this exits pointing to the other
+    /// sources.
     fn read_password(
+        &self,
         confirm: bool,
         target_key: Option<&str>,
     ) -> Zeroizing<String> {
-        let pwd = match env::var("NAMADA_WALLET_PASSWORD_FILE") {
-            Ok(path) => Zeroizing::new(
-                fs::read_to_string(path)
-                    .expect("Something went wrong reading the file"),
-            ),
-            Err(_) => match env::var("NAMADA_WALLET_PASSWORD") {
-                Ok(password) => Zeroizing::new(password),
-                Err(_) if confirm => {
-                    let prompt = "Enter your encryption password: ";
-                    read_and_confirm_passphrase_tty(prompt).unwrap_or_else(
-                        |e| {
-                            eprintln!("{e}");
-                            eprintln!(
-                                "Action cancelled, no changes persisted."
-                            );
-                            cli::safe_exit(1)
-                        },
-                    )
+        let pwd = match password_from_env_sources(self.options.verbose) {
+            Some((pwd, source)) => {
+                if self.options.verbose {
+                    eprintln!("Using the wallet password from {source}.");
                 }
-                Err(_) => {
-                    let prompt = match target_key {
-                        Some(target) => Cow::Owned(format!(
-                            "Enter your decryption password for {}: ",
-                            target
-                        )),
-                        None => {
-                            Cow::Borrowed("Enter your decryption password: ")
-                        }
-                    };
-                    rpassword::read_password_from_tty(Some(&prompt))
-                        .map(Zeroizing::new)
-                        .expect("Failed reading password from tty.")
+                pwd
+            }
+            None if confirm => {
+                let prompt = "Enter your encryption password: ";
+                match read_and_confirm_passphrase_tty(
+                    prompt,
+                    self.tty_enabled(),
+                ) {
+                    Ok(pwd) => pwd,
+                    // The passphrases didn't match
+                    Err(e) if e.kind() == io::ErrorKind::InvalidInput => {
+                        eprintln!("{e}");
+                        eprintln!("Action cancelled, no changes persisted.");
+                        cli::safe_exit(1)
+                    }
+                    Err(e) => exit_no_password_tty(e),
                 }
-            },
+            }
+            None => {
+                let prompt = match target_key {
+                    Some(target) => Cow::Owned(format!(
+                        "Enter your decryption password for {}: ",
+                        target
+                    )),
+                    None => Cow::Borrowed("Enter your decryption password: "),
+                };
+                read_password_tty(&prompt, self.tty_enabled())
+                    .unwrap_or_else(|e| exit_no_password_tty(e))
+            }
         };
         if confirm && pwd.as_str().is_empty() {
             eprintln!("Password cannot be empty");
@@ -91,7 +184,8 @@ impl WalletIo for CliWalletUtils {
         pwd
     }
 
-    fn read_alias(prompt_msg: &str) -> String {
+    fn read_alias(&self, prompt_msg: &str) -> String {
+        exit_if_no_tty("an alias", self.tty_enabled());
         print!("Choose an alias for {}: ", prompt_msg);
         io::stdout().flush().unwrap();
         let mut alias = String::new();
@@ -99,22 +193,23 @@ impl WalletIo for CliWalletUtils {
         alias.trim().to_owned()
     }
 
-    fn read_mnemonic_code() -> Option<Mnemonic> {
-        let phrase = get_secure_user_input("Input mnemonic code: ")
-            .unwrap_or_else(|e| {
-                eprintln!("{}", e);
-                eprintln!("Action cancelled, no changes persisted.");
-                cli::safe_exit(1)
-            });
+    fn read_mnemonic_code(&self) -> Option<Mnemonic> {
+        let phrase =
+            get_secure_user_input("Input mnemonic code: ", self.tty_enabled())
+                .unwrap_or_else(|e| {
+                    eprintln!("{}", e);
+                    eprintln!("Action cancelled, no changes persisted.");
+                    cli::safe_exit(1)
+                });
         Mnemonic::from_phrase(phrase.as_ref(), Language::English).ok()
     }
 
-    fn read_mnemonic_passphrase(confirm: bool) -> Zeroizing<String> {
+    fn read_mnemonic_passphrase(&self, confirm: bool) -> Zeroizing<String> {
         let prompt = "Enter BIP39 passphrase (empty for none): ";
         let result = if confirm {
-            read_and_confirm_passphrase_tty(prompt)
+            read_and_confirm_passphrase_tty(prompt, self.tty_enabled())
         } else {
-            rpassword::read_password_from_tty(Some(prompt)).map(Zeroizing::new)
+            read_password_tty(prompt, self.tty_enabled())
         };
         result.unwrap_or_else(|e| {
             eprintln!("{}", e);
@@ -126,14 +221,17 @@ impl WalletIo for CliWalletUtils {
     // the store. Offer the user to either replace existing mapping, alter the
     // chosen alias to a name of their choosing, or cancel the aliasing.
     fn show_overwrite_confirmation(
+        &self,
         alias: &Alias,
         alias_for: &str,
     ) -> ConfirmationResponse {
+        exit_if_no_tty("whether to replace the alias", self.tty_enabled());
         print!(
             "You're trying to create an alias \"{}\" that already exists for \
              {} in your store.\nWould you like to replace it? \
              s(k)ip/re(p)lace/re(s)elect: ",
-            alias, alias_for
+            alias.as_given(),
+            alias_for
         );
         io::stdout().flush().unwrap();
 
@@ -152,7 +250,7 @@ impl WalletIo for CliWalletUtils {
                         io::stdout().flush().unwrap();
                         if io::stdin().read_line(&mut buffer).is_ok() {
                             return ConfirmationResponse::Reselect(
-                                buffer.trim().into(),
+                                Alias::preserving_case(buffer.trim()),
                             );
                         }
                     }
@@ -165,14 +263,22 @@ impl WalletIo for CliWalletUtils {
         }
         // Input is senseless fall through to repeat prompt
         println!("Invalid option, try again.");
-        Self::show_overwrite_confirmation(alias, alias_for)
+        self.show_overwrite_confirmation(alias, alias_for)
+    }
+
+    fn encryption_kdf_params(&self) -> KdfParams {
+        self.options.kdf_params.unwrap_or_default()
     }
 }
 
-fn get_secure_user_input<S>(request: S) -> std::io::Result<Zeroizing<String>>
+fn get_secure_user_input<S>(
+    request: S,
+    is_tty: bool,
+) -> std::io::Result<Zeroizing<String>>
 where
     S: std::fmt::Display,
 {
+    ensure_tty(is_tty)?;
     print!("{} ", request);
     std::io::stdout().flush()?;
 
@@ -181,35 +287,51 @@ where
     Ok(response)
 }
 
+/// The number of times a passphrase can be entered on the TTY before giving
+/// up when its confirmation doesn't match
+const MAX_PASSPHRASE_ATTEMPTS: usize = 3;
+
+/// Read a passphrase from the TTY and ask to enter it again to confirm it. On
+/// a mismatch, both are prompted for again, up to [`MAX_PASSPHRASE_ATTEMPTS`]
+/// times.
 pub fn read_and_confirm_passphrase_tty(
     prompt: &str,
+    is_tty: bool,
 ) -> Result<Zeroizing<String>, std::io::Error> {
-    let passphrase =
-        rpassword::read_password_from_tty(Some(prompt)).map(Zeroizing::new)?;
-    if !passphrase.is_empty() {
-        let confirmed = rpassword::read_password_from_tty(Some(
-            "Enter same passphrase again: ",
-        ))
-        .map(Zeroizing::new)?;
-        if confirmed != passphrase {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "Passphrases did not match",
-            ));
+    for attempt in 1..=MAX_PASSPHRASE_ATTEMPTS {
+        let passphrase = read_password_tty(prompt, is_tty)?;
+        if passphrase.is_empty() {
+            return Ok(passphrase);
+        }
+        let confirmed =
+            read_password_tty("Enter same passphrase again: ", is_tty)?;
+        if confirmed == passphrase {
+            return Ok(passphrase);
+        }
+        if attempt < MAX_PASSPHRASE_ATTEMPTS {
+            eprintln!("Passphrases did not match, please try again.");
         }
     }
-    Ok(passphrase)
+    Err(std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        format!(
+            "Passphrases did not match after {MAX_PASSPHRASE_ATTEMPTS} \
+             attempts"
+        ),
+    ))
 }
 
 /// Generate keypair
 /// for signing protocol txs and for the DKG (which will also be stored)
 /// A protocol keypair may be optionally provided, indicating that
-/// we should reuse a keypair already in the wallet
+/// we should reuse a keypair already in the wallet. New keys are generated
+/// with the given RNG.
 pub fn gen_validator_keys<U: WalletIo>(
     wallet: &mut Wallet<U>,
     eth_bridge_pk: Option<common::PublicKey>,
     protocol_pk: Option<common::PublicKey>,
     protocol_key_scheme: SchemeType,
+    rng: &mut (impl CryptoRng + RngCore),
 ) -> Result<ValidatorKeys, FindKeyError> {
     let protocol_keypair = find_secret_key(wallet, protocol_pk, |data| {
         data.keys.protocol_keypair.clone()
@@ -221,6 +343,7 @@ pub fn gen_validator_keys<U: WalletIo>(
         eth_bridge_keypair,
         protocol_keypair,
         protocol_key_scheme,
+        rng,
     ))
 }
 
@@ -262,7 +385,18 @@ pub fn save(wallet: &Wallet<CliWalletUtils>) -> std::io::Result<()> {
 pub fn load(
     store_dir: &Path,
 ) -> Result<Wallet<CliWalletUtils>, LoadStoreError> {
-    let mut wallet = CliWalletUtils::new(store_dir.to_path_buf());
+    load_with_options(store_dir, &WalletOptions::default())
+}
+
+/// Load a wallet with the given options from the store file.
+pub fn load_with_options(
+    store_dir: &Path,
+    options: &WalletOptions,
+) -> Result<Wallet<CliWalletUtils>, LoadStoreError> {
+    let mut wallet = CliWalletUtils::new_with_options(
+        store_dir.to_path_buf(),
+        options.clone(),
+    );
     wallet.load()?;
     Ok(wallet)
 }
@@ -270,40 +404,180 @@ pub fn load(
 /// Load a wallet from the store file or create a new wallet without any
 /// keys or addresses.
 pub fn load_or_new(store_dir: &Path) -> Wallet<CliWalletUtils> {
-    let store = self::store::load_or_new(store_dir).unwrap_or_else(|err| {
-        eprintln!("Unable to load the wallet: {}", err);
-        cli::safe_exit(1)
-    });
-    let mut wallet = CliWalletUtils::new(store_dir.to_path_buf());
+    load_or_new_with_options(store_dir, &WalletOptions::default())
+}
+
+/// Load a wallet with the given options from the store file or create a new
+/// wallet without any keys or addresses.
+pub fn load_or_new_with_options(
+    store_dir: &Path,
+    options: &WalletOptions,
+) -> Wallet<CliWalletUtils> {
+    let store =
+        self::store::load_or_new(store_dir, options).unwrap_or_else(|err| {
+            eprintln!(
+                "Unable to load the wallet: {}",
+                cli::redact_base_dir(&options.redacted_base_dirs, err)
+            );
+            cli::safe_exit(1)
+        });
+    let mut wallet = CliWalletUtils::new_with_options(
+        store_dir.to_path_buf(),
+        options.clone(),
+    );
     *wallet.store_mut() = store;
     wallet
 }
 
-/// Check if a wallet exists in the given store dir.
-pub fn exists(store_dir: &Path) -> bool {
-    let file = wallet_file(store_dir);
+/// Resolve the store dir of the wallet under the given base dir.
+///
+/// When `is_pre_genesis` is set, this is the pre-genesis wallet dir, whether
+/// or not a chain ID is given. Otherwise, the wallet of a chain is stored in
+/// the chain's dir, next to the chain's state, and `None` is returned when no
+/// chain ID is given. A store dir passed explicitly to [`load`] or
+/// [`load_or_new`] is always used as is, bypassing this resolution.
+pub fn store_dir(
+    base_dir: &Path,
+    chain_id: Option<&ChainId>,
+    is_pre_genesis: bool,
+) -> Option<PathBuf> {
+    if is_pre_genesis {
+        Some(pre_genesis_store_dir(base_dir))
+    } else {
+        chain_id.map(|chain_id| chain_store_dir(base_dir, chain_id))
+    }
+}
+
+/// The store dir of the pre-genesis wallet under the given base dir.
+pub fn pre_genesis_store_dir(base_dir: &Path) -> PathBuf {
+    base_dir.join(pre_genesis_dir_name())
+}
+
+/// The store dir of the wallet of the given chain under the given base dir.
+pub fn chain_store_dir(base_dir: &Path, chain_id: &ChainId) -> PathBuf {
+    base_dir.join(chain_id.as_str())
+}
+
+/// Check if a wallet exists in the given store dir, in a file of the given
+/// name or of the default name if none is given.
+pub fn exists(store_dir: &Path, file_name: Option<&str>) -> bool {
+    let file = wallet_file_with_name(store_dir, file_name);
     file.exists()
 }
 
+/// Env var with the path of a file holding the wallet password
+pub const ENV_VAR_WALLET_PASSWORD_FILE: &str = "NAMADA_WALLET_PASSWORD_FILE";
+
+/// Env var holding the wallet password
+pub const ENV_VAR_WALLET_PASSWORD: &str = "NAMADA_WALLET_PASSWORD";
+
+/// Read the wallet password from the sources that don't prompt for it, in
+/// order of precedence: the file at [`ENV_VAR_WALLET_PASSWORD_FILE`], then
+/// [`ENV_VAR_WALLET_PASSWORD`]. An empty file falls through to the env var.
+/// Returns the password together with the name of the source that supplied
+/// it, if any did. When `verbose`, an empty file being ignored is logged.
+fn password_from_env_sources(
+    verbose: bool,
+) -> Option<(Zeroizing<String>, &'static str)> {
+    if let Ok(path) = env::var(ENV_VAR_WALLET_PASSWORD_FILE) {
+        let pwd = Zeroizing::new(
+            fs::read_to_string(path)
+                .expect("Something went wrong reading the file"),
+        );
+        if !pwd.is_empty() {
+            return Some((pwd, ENV_VAR_WALLET_PASSWORD_FILE));
+        }
+        if verbose {
+            eprintln!(
+                "The wallet password file at {ENV_VAR_WALLET_PASSWORD_FILE} \
+                 is empty, ignoring it."
+            );
+        }
+    }
+    env::var(ENV_VAR_WALLET_PASSWORD)
+        .ok()
+        .map(|pwd| (Zeroizing::new(pwd), ENV_VAR_WALLET_PASSWORD))
+}
+
+/// Exit when the wallet password can't be prompted for, e.g. because the
+/// process has no controlling TTY, pointing to the sources that don't prompt.
+/// Fail rather than prompt when there is no terminal, as detected or as
+/// overridden with `--assume-tty` or `--no-tty`, so that a prompt never
+/// hangs or reads from a pipe.
+fn ensure_tty(is_tty: bool) -> io::Result<()> {
+    if is_tty {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "stdin is not a terminal. Pass `--assume-tty` if it is one.",
+        ))
+    }
+}
+
+/// Read a password from the terminal without echoing it. There is no
+/// terminal to read it from when compiled to wasm32.
+fn read_password_tty(
+    prompt: &str,
+    is_tty: bool,
+) -> io::Result<Zeroizing<String>> {
+    ensure_tty(is_tty)?;
+    #[cfg(not(target_family = "wasm"))]
+    {
+        rpassword::read_password_from_tty(Some(prompt)).map(Zeroizing::new)
+    }
+    #[cfg(target_family = "wasm")]
+    {
+        let _ = prompt;
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Passwords can't be prompted for on wasm32.",
+        ))
+    }
+}
+
+/// Exit if there is no terminal to prompt for the given input on.
+fn exit_if_no_tty(input: &str, is_tty: bool) {
+    if let Err(err) = ensure_tty(is_tty) {
+        eprintln!("Unable to prompt for {input}: {err}");
+        eprintln!("Action cancelled, no changes persisted.");
+        cli::safe_exit(1)
+    }
+}
+
+fn exit_no_password_tty(err: io::Error) -> ! {
+    eprintln!(
+        "Unable to prompt for the wallet password, as no terminal could be \
+         accessed: {err}"
+    );
+    eprintln!(
+        "Without a terminal, supply the password in the file at \
+         {ENV_VAR_WALLET_PASSWORD_FILE} or in the {ENV_VAR_WALLET_PASSWORD} \
+         env var."
+    );
+    cli::safe_exit(1)
+}
+
 /// Read the password for encryption from the file/env/stdin, with
 /// confirmation if read from stdin.
-pub fn read_and_confirm_encryption_password(
+pub fn read_and_confirm_encryption_password<U: WalletIo>(
+    utils: &U,
     unsafe_dont_encrypt: bool,
 ) -> Option<Zeroizing<String>> {
     if unsafe_dont_encrypt {
         println!("Warning: The keypair will NOT be encrypted.");
         None
     } else {
-        Some(CliWalletUtils::read_password(true, None))
+        Some(utils.read_password(true, None))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use namada_sdk::bip39::MnemonicType;
-    use namada_sdk::wallet::WalletIo;
 
-    use super::CliWalletUtils;
+    use super::*;
+    use crate::client::utils::PRE_GENESIS_DIR;
 
     #[test]
     fn test_generate_mnemonic() {
@@ -316,4 +590,47 @@ mod tests {
             CliWalletUtils::generate_mnemonic_code(MNEMONIC_TYPE, &mut rng);
         assert_ne!(mnemonic1.into_phrase(), mnemonic2.into_phrase());
     }
+
+    #[test]
+    fn test_password_source_precedence() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        env::set_var(ENV_VAR_WALLET_PASSWORD_FILE, file.path());
+        env::set_var(ENV_VAR_WALLET_PASSWORD, "from env");
+
+        // An empty file falls through to the env var
+        let (pwd, source) = password_from_env_sources(false).unwrap();
+        assert_eq!(pwd.as_str(), "from env");
+        assert_eq!(source, ENV_VAR_WALLET_PASSWORD);
+
+        // A non-empty file takes precedence over the env var
+        fs::write(file.path(), "from file").unwrap();
+        let (pwd, source) = password_from_env_sources(false).unwrap();
+        assert_eq!(pwd.as_str(), "from file");
+        assert_eq!(source, ENV_VAR_WALLET_PASSWORD_FILE);
+
+        env::remove_var(ENV_VAR_WALLET_PASSWORD_FILE);
+        env::remove_var(ENV_VAR_WALLET_PASSWORD);
+        assert!(password_from_env_sources(false).is_none());
+    }
+
+    #[test]
+    fn test_store_dir_resolution() {
+        let base_dir = Path::new("/namada");
+        let chain_id = ChainId::default();
+
+        // The pre-genesis wallet takes precedence over a chain's wallet
+        assert_eq!(
+            store_dir(base_dir, Some(&chain_id), true),
+            Some(base_dir.join(PRE_GENESIS_DIR))
+        );
+        assert_eq!(
+            store_dir(base_dir, None, true),
+            Some(base_dir.join(PRE_GENESIS_DIR))
+        );
+        assert_eq!(
+            store_dir(base_dir, Some(&chain_id), false),
+            Some(base_dir.join(chain_id.as_str()))
+        );
+        assert_eq!(store_dir(base_dir, None, false), None);
+    }
 }
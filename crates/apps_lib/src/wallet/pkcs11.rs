@@ -0,0 +1,238 @@
+//! Signing with the ed25519 keys held in a PKCS#11 token, such as a network
+//! HSM. PKCS#11 modules can't be loaded on wasm32, where opening a token
+//! always fails.
+
+use std::fmt;
+use std::path::PathBuf;
+#[cfg(not(target_family = "wasm"))]
+use std::sync::{Mutex, PoisonError};
+
+use borsh::BorshDeserialize;
+#[cfg(not(target_family = "wasm"))]
+use cryptoki::context::{CInitializeArgs, Pkcs11};
+#[cfg(not(target_family = "wasm"))]
+use cryptoki::mechanism::Mechanism;
+#[cfg(not(target_family = "wasm"))]
+use cryptoki::object::{
+    Attribute, AttributeType, KeyType, ObjectClass, ObjectHandle,
+};
+#[cfg(not(target_family = "wasm"))]
+use cryptoki::session::{Session, UserType};
+#[cfg(not(target_family = "wasm"))]
+use cryptoki::types::AuthPin;
+use namada_sdk::hash::Hash;
+use namada_sdk::key::{common, ed25519};
+use zeroize::Zeroizing;
+
+/// How to reach the key of a PKCS#11 token to sign with
+#[derive(Clone)]
+pub struct Pkcs11Config {
+    /// Path to the PKCS#11 module of the token
+    pub module: PathBuf,
+    /// ID of the slot holding the token
+    pub slot: u64,
+    /// The user PIN of the token, which is prompted for if not given
+    pub pin: Option<Zeroizing<String>>,
+    /// Label of the key pair to sign with
+    pub key_label: String,
+}
+
+impl fmt::Debug for Pkcs11Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pkcs11Config")
+            .field("module", &self.module)
+            .field("slot", &self.slot)
+            .field("pin", &self.pin.as_ref().map(|_| "<redacted>"))
+            .field("key_label", &self.key_label)
+            .finish()
+    }
+}
+
+/// A session logged in to a PKCS#11 token to sign with one of its ed25519
+/// key pairs
+pub struct Pkcs11Signer {
+    #[cfg(not(target_family = "wasm"))]
+    session: Mutex<Session>,
+    #[cfg(not(target_family = "wasm"))]
+    key: ObjectHandle,
+    public_key: common::PublicKey,
+}
+
+impl Pkcs11Signer {
+    /// Log in to the token of the given config and find the key pair with the
+    /// configured label. Fails if the token has no ed25519 key pair with
+    /// this label. The PIN is only prompted for if there is a terminal, as
+    /// given.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn open(config: &Pkcs11Config, is_tty: bool) -> Result<Self, String> {
+        let Pkcs11Config {
+            module,
+            slot,
+            pin,
+            key_label,
+        } = config;
+        let pkcs11 = Pkcs11::new(module).map_err(|err| {
+            format!(
+                "Unable to load the PKCS#11 module {}: {err}",
+                module.display()
+            )
+        })?;
+        pkcs11
+            .initialize(CInitializeArgs::OsThreads)
+            .map_err(|err| {
+                format!("Unable to initialize the PKCS#11 module: {err}")
+            })?;
+        let token_slot = pkcs11
+            .get_slots_with_token()
+            .map_err(|err| format!("Unable to list the PKCS#11 slots: {err}"))?
+            .into_iter()
+            .find(|token_slot| token_slot.id() == *slot)
+            .ok_or_else(|| {
+                format!("There's no PKCS#11 token in slot {slot}.")
+            })?;
+        let session = pkcs11.open_ro_session(token_slot).map_err(|err| {
+            format!("Unable to open a session on the PKCS#11 token: {err}")
+        })?;
+        let pin = match pin {
+            Some(pin) => pin.clone(),
+            None => read_pin(*slot, is_tty)?,
+        };
+        session
+            .login(UserType::User, Some(&AuthPin::new(pin.to_string())))
+            .map_err(|err| {
+                format!("Unable to log in to the PKCS#11 token: {err}")
+            })?;
+
+        let find_key = |class: ObjectClass, kind: &str| {
+            session
+                .find_objects(&[
+                    Attribute::Class(class),
+                    Attribute::KeyType(KeyType::EC_EDWARDS),
+                    Attribute::Label(key_label.as_bytes().to_vec()),
+                ])
+                .map_err(|err| {
+                    format!("Unable to search the PKCS#11 token: {err}")
+                })?
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    format!(
+                        "The PKCS#11 token in slot {slot} has no ed25519 \
+                         {kind} key labelled \"{key_label}\"."
+                    )
+                })
+        };
+        let key = find_key(ObjectClass::PRIVATE_KEY, "private")?;
+        let public_handle = find_key(ObjectClass::PUBLIC_KEY, "public")?;
+        let public_key = session
+            .get_attributes(public_handle, &[AttributeType::EcPoint])
+            .map_err(|err| {
+                format!("Unable to read the key from the PKCS#11 token: {err}")
+            })?
+            .into_iter()
+            .find_map(|attribute| match attribute {
+                Attribute::EcPoint(point) => decode_ec_point(&point),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                format!(
+                    "Unable to decode the public key labelled \
+                     \"{key_label}\" from the PKCS#11 token."
+                )
+            })?;
+
+        Ok(Self {
+            session: Mutex::new(session),
+            key,
+            public_key,
+        })
+    }
+
+    /// Log in to the token of the given config, which is not possible on
+    /// wasm32.
+    #[cfg(target_family = "wasm")]
+    pub fn open(_config: &Pkcs11Config, _is_tty: bool) -> Result<Self, String> {
+        Err("PKCS#11 tokens can't be used on wasm32".to_string())
+    }
+
+    /// The public key of the key pair that this signer signs with
+    pub fn public_key(&self) -> &common::PublicKey {
+        &self.public_key
+    }
+
+    /// Sign the given hash with the key pair of the token
+    #[cfg(not(target_family = "wasm"))]
+    pub fn sign(&self, hash: &Hash) -> Result<common::Signature, String> {
+        let session =
+            self.session.lock().unwrap_or_else(PoisonError::into_inner);
+        let signature =
+            session.sign(&Mechanism::Eddsa, self.key, &hash.0).map_err(
+                |err| format!("The PKCS#11 token failed to sign: {err}"),
+            )?;
+        ed25519::Signature::try_from_slice(&signature)
+            .map(common::Signature::Ed25519)
+            .map_err(|err| {
+                format!(
+                    "Unable to decode the signature of the PKCS#11 token: \
+                     {err}"
+                )
+            })
+    }
+
+    /// Sign the given hash with the key pair of the token, which can't be
+    /// opened on wasm32.
+    #[cfg(target_family = "wasm")]
+    pub fn sign(&self, _hash: &Hash) -> Result<common::Signature, String> {
+        Err("PKCS#11 tokens can't be used on wasm32".to_string())
+    }
+}
+
+/// Prompt for the user PIN of the token in the given slot.
+#[cfg(not(target_family = "wasm"))]
+fn read_pin(slot: u64, is_tty: bool) -> Result<Zeroizing<String>, String> {
+    super::read_password_tty(
+        &format!("Enter the PIN of the PKCS#11 token in slot {slot}: "),
+        is_tty,
+    )
+    .map_err(|err| {
+        format!("Unable to read the PIN of the PKCS#11 token: {err}")
+    })
+}
+
+/// Decode an ed25519 public key from the `CKA_EC_POINT` attribute of a
+/// token. It is DER-encoded as an octet string as per the PKCS#11 spec, but
+/// some tokens return the raw key instead.
+fn decode_ec_point(point: &[u8]) -> Option<common::PublicKey> {
+    let raw = match point {
+        [0x04, 0x20, raw @ ..] if raw.len() == 32 => raw,
+        raw => raw,
+    };
+    ed25519::PublicKey::try_from_slice(raw)
+        .ok()
+        .map(common::PublicKey::Ed25519)
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_sdk::key::RefTo;
+
+    use super::*;
+    use crate::wallet::test_utils::secret_key;
+
+    #[test]
+    fn test_decode_ec_point() {
+        let pk = secret_key("hsm").ref_to();
+        let common::PublicKey::Ed25519(ed25519_pk) = &pk else {
+            panic!("The test key should be ed25519")
+        };
+        let raw = ed25519_pk.0.to_bytes();
+
+        // DER-encoded octet string, as per the spec
+        let der = [[0x04, 0x20].as_slice(), raw.as_slice()].concat();
+        assert_eq!(decode_ec_point(&der), Some(pk.clone()));
+        // Raw key
+        assert_eq!(decode_ec_point(&raw), Some(pk));
+        // Truncated key
+        assert_eq!(decode_ec_point(&der[..20]), None);
+    }
+}
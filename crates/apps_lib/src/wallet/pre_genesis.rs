@@ -4,15 +4,20 @@ use std::path::{Path, PathBuf};
 
 use fd_lock::RwLock;
 use namada_sdk::key::SchemeType;
+use namada_sdk::wallet::fs::restrict_file_permissions;
 use namada_sdk::wallet::pre_genesis::{
     ReadError, ValidatorStore, ValidatorWallet,
 };
-use namada_sdk::wallet::{gen_key_to_store, WalletIo};
+use namada_sdk::wallet::{gen_key_to_store, KdfParams, WalletIo};
 use rand::rngs::OsRng;
+use rand::CryptoRng;
+use rand_core::RngCore;
 use zeroize::Zeroizing;
 
 use crate::wallet::store::gen_validator_keys;
-use crate::wallet::{read_and_confirm_encryption_password, CliWalletUtils};
+use crate::wallet::{
+    read_and_confirm_encryption_password, CliWalletUtils, WalletOptions,
+};
 
 /// Validator pre-genesis wallet file name
 const VALIDATOR_FILE_NAME: &str = "validator-wallet.toml";
@@ -23,14 +28,18 @@ pub fn validator_file_name(store_dir: impl AsRef<Path>) -> PathBuf {
 }
 
 /// Generate a new [`ValidatorWallet`] with required pre-genesis keys and
-/// store it as TOML at the given path.
+/// store it as TOML at the given path, prompting with the given options.
 pub fn gen_and_store(
     scheme: SchemeType,
     unsafe_dont_encrypt: bool,
     store_dir: &Path,
+    wallet_options: &WalletOptions,
 ) -> std::io::Result<ValidatorWallet> {
-    let password = read_and_confirm_encryption_password(unsafe_dont_encrypt);
-    let validator = gen(scheme, password);
+    let utils = CliWalletUtils::without_store(wallet_options.clone());
+    let password =
+        read_and_confirm_encryption_password(&utils, unsafe_dont_encrypt);
+    let validator =
+        gen(scheme, password, utils.encryption_kdf_params(), &mut OsRng);
     let data = validator.store.encode();
     let wallet_path = validator_file_name(store_dir);
     // Make sure the dir exists
@@ -41,13 +50,17 @@ pub fn gen_and_store(
     options.create(true).write(true).truncate(true);
     let mut lock = RwLock::new(options.open(wallet_path)?);
     let mut guard = lock.write()?;
+    restrict_file_permissions(&*guard)?;
     guard.write_all(&data)?;
     Ok(validator)
 }
 
 /// Try to load and decrypt keys, if encrypted, in a [`ValidatorWallet`]
-/// from a TOML file.
-pub fn load(store_dir: &Path) -> Result<ValidatorWallet, ReadError> {
+/// from a TOML file, prompting with the given options.
+pub fn load(
+    store_dir: &Path,
+    wallet_options: &WalletOptions,
+) -> Result<ValidatorWallet, ReadError> {
     let wallet_file = validator_file_name(store_dir);
     let mut options = fs::OpenOptions::new();
     options.read(true).write(false);
@@ -72,24 +85,28 @@ pub fn load(store_dir: &Path) -> Result<ValidatorWallet, ReadError> {
     })?;
     let store = ValidatorStore::decode(store).map_err(ReadError::Decode)?;
 
+    let utils = CliWalletUtils::without_store(wallet_options.clone());
     let password = if store.consensus_key.is_encrypted() {
-        Some(CliWalletUtils::read_password(false, Some("consensus key")))
+        Some(utils.read_password(false, Some("consensus key")))
     } else {
         None
     };
 
-    let consensus_key = store.consensus_key.get::<CliWalletUtils>(
+    let consensus_key = store.consensus_key.get(
+        &utils,
         true,
         password.clone(),
         Some("consensus key"),
     )?;
-    let eth_cold_key = store.eth_cold_key.get::<CliWalletUtils>(
+    let eth_cold_key = store.eth_cold_key.get(
+        &utils,
         true,
         password.clone(),
         Some("eth cold key"),
     )?;
     let eth_hot_key = store.validator_keys.eth_bridge_keypair.clone();
-    let tendermint_node_key = store.tendermint_node_key.get::<CliWalletUtils>(
+    let tendermint_node_key = store.tendermint_node_key.get(
+        &utils,
         true,
         password,
         Some("tendermint node key"),
@@ -104,27 +121,36 @@ pub fn load(store_dir: &Path) -> Result<ValidatorWallet, ReadError> {
     })
 }
 
-/// Generate a new [`ValidatorWallet`] with required pre-genesis keys. Will
-/// prompt for password when `!unsafe_dont_encrypt`.
+/// Generate a new [`ValidatorWallet`] with required pre-genesis keys, using
+/// the given RNG. The keys are encrypted with the password, if any, and the
+/// given KDF parameters.
 fn gen(
     scheme: SchemeType,
     password: Option<Zeroizing<String>>,
+    kdf_params: KdfParams,
+    rng: &mut (impl CryptoRng + RngCore),
 ) -> ValidatorWallet {
     let (consensus_key, consensus_sk) = gen_key_to_store(
         // Note that TM only allows ed25519 for consensus key
         SchemeType::Ed25519,
         password.clone(),
-        &mut OsRng,
+        kdf_params,
+        rng,
+    );
+    let (eth_cold_key, eth_cold_sk) = gen_key_to_store(
+        SchemeType::Secp256k1,
+        password.clone(),
+        kdf_params,
+        rng,
     );
-    let (eth_cold_key, eth_cold_sk) =
-        gen_key_to_store(SchemeType::Secp256k1, password.clone(), &mut OsRng);
     let (tendermint_node_key, tendermint_node_sk) = gen_key_to_store(
         // Note that TM only allows ed25519 for node IDs
         SchemeType::Ed25519,
         password,
-        &mut OsRng,
+        kdf_params,
+        rng,
     );
-    let validator_keys = gen_validator_keys(None, None, scheme);
+    let validator_keys = gen_validator_keys(None, None, scheme, rng);
     let eth_hot_key = validator_keys.eth_bridge_keypair.clone();
     let store = ValidatorStore {
         consensus_key,
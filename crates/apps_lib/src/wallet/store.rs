@@ -4,24 +4,41 @@ use namada_sdk::key::*;
 use namada_sdk::wallet::{
     gen_secret_key, LoadStoreError, Store, ValidatorKeys,
 };
-use rand::rngs::OsRng;
+use rand::CryptoRng;
+use rand_core::RngCore;
 
-use crate::wallet::CliWalletUtils;
+use crate::wallet::{CliWalletUtils, WalletOptions};
 
 /// Wallet file name
 const FILE_NAME: &str = "wallet.toml";
 
 /// Get the path to the wallet store.
 pub fn wallet_file(store_dir: impl AsRef<Path>) -> PathBuf {
-    store_dir.as_ref().join(FILE_NAME)
+    wallet_file_with_name(store_dir, None)
 }
 
-/// Load the store file or create a new one without any keys or addresses.
-pub fn load_or_new(store_dir: &Path) -> Result<Store, LoadStoreError> {
-    load(store_dir).or_else(|err| {
+/// Get the path to the wallet store, with the given file name instead of the
+/// default one, if any, as overridden with `--wallet-file-name`.
+pub fn wallet_file_with_name(
+    store_dir: impl AsRef<Path>,
+    file_name: Option<&str>,
+) -> PathBuf {
+    store_dir.as_ref().join(file_name.unwrap_or(FILE_NAME))
+}
+
+/// Load the store file with the given options, or create a new one without
+/// any keys or addresses.
+pub fn load_or_new(
+    store_dir: &Path,
+    options: &WalletOptions,
+) -> Result<Store, LoadStoreError> {
+    load(store_dir, options).or_else(|err| {
         // Only create a new file if not found, otherwise propagate the err
         if let LoadStoreError::NotFound { .. } = &err {
-            let wallet = CliWalletUtils::new(store_dir.to_path_buf());
+            let wallet = CliWalletUtils::new_with_options(
+                store_dir.to_path_buf(),
+                options.clone(),
+            );
             wallet.save()?;
             Ok(wallet.into())
         } else {
@@ -30,21 +47,29 @@ pub fn load_or_new(store_dir: &Path) -> Result<Store, LoadStoreError> {
     })
 }
 
-/// Attempt to load the store file.
-pub fn load(store_dir: &Path) -> Result<Store, LoadStoreError> {
-    let mut wallet = CliWalletUtils::new(store_dir.to_path_buf());
+/// Attempt to load the store file with the given options.
+pub fn load(
+    store_dir: &Path,
+    options: &WalletOptions,
+) -> Result<Store, LoadStoreError> {
+    let mut wallet = CliWalletUtils::new_with_options(
+        store_dir.to_path_buf(),
+        options.clone(),
+    );
     wallet.load()?;
     Ok(wallet.into())
 }
 
 /// Generate keypair for signing protocol txs and for the DKG
-/// A protocol keypair may be optionally provided
+/// A protocol keypair may be optionally provided. The keys that are not
+/// provided are generated with the given RNG.
 ///
 /// Note that this removes the validator data.
 pub fn gen_validator_keys(
     eth_bridge_keypair: Option<common::SecretKey>,
     protocol_keypair: Option<common::SecretKey>,
     protocol_keypair_scheme: SchemeType,
+    rng: &mut (impl CryptoRng + RngCore),
 ) -> ValidatorKeys {
     let eth_bridge_keypair = eth_bridge_keypair
         .inspect(|k| {
@@ -52,9 +77,9 @@ pub fn gen_validator_keys(
                 panic!("Ethereum bridge keys can only be of kind Secp256k1");
             }
         })
-        .unwrap_or_else(|| gen_secret_key(SchemeType::Secp256k1, &mut OsRng));
+        .unwrap_or_else(|| gen_secret_key(SchemeType::Secp256k1, rng));
     let protocol_keypair = protocol_keypair
-        .unwrap_or_else(|| gen_secret_key(protocol_keypair_scheme, &mut OsRng));
+        .unwrap_or_else(|| gen_secret_key(protocol_keypair_scheme, rng));
     ValidatorKeys {
         protocol_keypair,
         eth_bridge_keypair,
@@ -64,6 +89,8 @@ pub fn gen_validator_keys(
 #[cfg(test)]
 mod test_wallet {
     use namada_sdk::address::Address;
+    use rand::rngs::{OsRng, StdRng};
+    use rand::SeedableRng;
 
     use super::*;
 
@@ -71,7 +98,7 @@ mod test_wallet {
     fn test_toml_roundtrip_ed25519() {
         let mut store = Store::default();
         let validator_keys =
-            gen_validator_keys(None, None, SchemeType::Ed25519);
+            gen_validator_keys(None, None, SchemeType::Ed25519, &mut OsRng);
         store.add_validator_data(
             Address::decode("tnam1q99c37u38grkdcc2qze0hz4zjjd8zr3yucd3mzgz")
                 .unwrap(),
@@ -85,7 +112,7 @@ mod test_wallet {
     fn test_toml_roundtrip_secp256k1() {
         let mut store = Store::default();
         let validator_keys =
-            gen_validator_keys(None, None, SchemeType::Secp256k1);
+            gen_validator_keys(None, None, SchemeType::Secp256k1, &mut OsRng);
         store.add_validator_data(
             Address::decode("tnam1q99c37u38grkdcc2qze0hz4zjjd8zr3yucd3mzgz")
                 .unwrap(),
@@ -94,4 +121,22 @@ mod test_wallet {
         let data = store.encode();
         let _ = Store::decode(data).expect("Test failed");
     }
+
+    #[test]
+    fn test_gen_validator_keys_with_seeded_rng() {
+        let gen = |seed| {
+            let keys = gen_validator_keys(
+                None,
+                None,
+                SchemeType::Ed25519,
+                &mut StdRng::seed_from_u64(seed),
+            );
+            (
+                keys.protocol_keypair.ref_to(),
+                keys.eth_bridge_keypair.ref_to(),
+            )
+        };
+        assert_eq!(gen(1), gen(1));
+        assert_ne!(gen(1), gen(2));
+    }
 }
@@ -0,0 +1,92 @@
+//! Deterministic wallet and genesis tx fixtures shared by the tests
+use std::path::PathBuf;
+
+use namada_sdk::address::Address;
+use namada_sdk::key::{common, RefTo, SchemeType};
+use namada_sdk::string_encoding::StringEncoded;
+use namada_sdk::token;
+use namada_sdk::wallet::{gen_secret_key, Wallet};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use sha2::{Digest, Sha256};
+
+use super::CliWalletUtils;
+use crate::config::genesis::templates::Unvalidated;
+use crate::config::genesis::transactions::{BondTx, UnsignedTransactions};
+use crate::config::genesis::GenesisAddress;
+
+/// The ed25519 secret key of the given alias. The same alias always gives the
+/// same key.
+pub fn secret_key(alias: &str) -> common::SecretKey {
+    let seed: [u8; 32] = Sha256::digest(alias.as_bytes()).into();
+    gen_secret_key(SchemeType::Ed25519, &mut StdRng::from_seed(seed))
+}
+
+/// An in-memory wallet holding the unencrypted [`secret_key`] of each of the
+/// given aliases. Nothing is read from or written to the filesystem, unless
+/// the wallet is saved.
+pub fn wallet_with_keys(aliases: &[&str]) -> Wallet<CliWalletUtils> {
+    let mut wallet = CliWalletUtils::new(PathBuf::new());
+    for alias in aliases {
+        wallet
+            .insert_keypair(
+                alias.to_string(),
+                true,
+                secret_key(alias),
+                None,
+                None,
+                None,
+            )
+            .expect("Inserting a test key shouldn't fail");
+    }
+    wallet
+}
+
+/// Unsigned genesis txs holding a single bond of the given whole amount of
+/// NAM from the public key of the given alias to the given validator.
+pub fn unsigned_bond_txs(
+    source_alias: &str,
+    validator: Address,
+    amount: u64,
+) -> UnsignedTransactions {
+    let source = secret_key(source_alias).ref_to();
+    UnsignedTransactions {
+        bond: Some(vec![BondTx::<Unvalidated> {
+            source: GenesisAddress::PublicKey(StringEncoded::new(source)),
+            validator,
+            amount: token::DenominatedAmount::native(
+                token::Amount::native_whole(amount),
+            ),
+        }]),
+        ..Default::default()
+    }
+}
+
+mod tests {
+    use namada_sdk::address::testing::established_address_1;
+
+    use super::*;
+
+    #[test]
+    fn test_fixtures_are_deterministic() {
+        assert_eq!(
+            secret_key("albert").ref_to(),
+            secret_key("albert").ref_to()
+        );
+        assert_ne!(
+            secret_key("albert").ref_to(),
+            secret_key("bertha").ref_to()
+        );
+
+        let wallet = wallet_with_keys(&["albert", "bertha"]);
+        assert_eq!(
+            wallet.find_public_key("albert").unwrap(),
+            secret_key("albert").ref_to()
+        );
+
+        assert_eq!(
+            unsigned_bond_txs("albert", established_address_1(), 5),
+            unsigned_bond_txs("albert", established_address_1(), 5)
+        );
+    }
+}
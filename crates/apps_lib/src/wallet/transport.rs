@@ -3,35 +3,83 @@
 use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
 use std::ops::Deref;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex, PoisonError};
 
 use ledger_lib::transport::TcpInfo;
 use ledger_lib::Transport;
 use ledger_transport::{APDUAnswer, APDUCommand};
+#[cfg(not(target_family = "wasm"))]
 use ledger_transport_hid::hidapi::HidApi;
+#[cfg(not(target_family = "wasm"))]
 use ledger_transport_hid::TransportNativeHID;
 use namada_sdk::args;
 
 /// Hardware wallet transport
-pub enum WalletTransport {
-    /// HID transport
-    HID(TransportNativeHID),
+pub struct WalletTransport {
+    connection: Connection,
+    /// Whether the APDU exchanges with the device are logged
+    debug_apdu: bool,
+    /// The status word of the last answer of the device
+    last_retcode: LastRetcode,
+}
+
+/// The status word of the last answer that a [`WalletTransport`] received
+/// from the device, which stays readable after the transport is moved into
+/// an app
+#[derive(Clone, Debug, Default)]
+pub struct LastRetcode(Arc<Mutex<Option<u16>>>);
+
+impl LastRetcode {
+    /// The status word of the last answer, or `None` if the last exchange
+    /// failed before the device answered
+    pub fn get(&self) -> Option<u16> {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn set(&self, retcode: Option<u16>) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) = retcode;
+    }
+}
+
+/// The connection of a [`WalletTransport`] to the device
+enum Connection {
+    /// HID transport, which is not available on wasm32
+    #[cfg(not(target_family = "wasm"))]
+    Hid(TransportNativeHID),
     /// TCP transport
-    TCP(TransportTcp),
+    Tcp(TransportTcp),
 }
 
 impl WalletTransport {
-    pub fn from_arg(arg: args::DeviceTransport) -> Self {
-        match arg {
+    /// Connect to the device over the given transport, logging the APDU
+    /// exchanges if `debug_apdu` is set.
+    pub fn from_arg(arg: args::DeviceTransport, debug_apdu: bool) -> Self {
+        let connection = match arg {
+            #[cfg(not(target_family = "wasm"))]
             args::DeviceTransport::Hid => {
                 let hidapi = HidApi::new()
                     .expect("Must be able to instantiate a hidapi context");
                 let transport = TransportNativeHID::new(&hidapi)
                     .expect("Must be able to connect to a HID wallet");
-                Self::HID(transport)
+                Connection::Hid(transport)
+            }
+            #[cfg(target_family = "wasm")]
+            args::DeviceTransport::Hid => {
+                panic!("HID wallets can't be connected to on wasm32")
             }
-            args::DeviceTransport::Tcp => Self::TCP(TransportTcp),
+            args::DeviceTransport::Tcp => Connection::Tcp(TransportTcp),
+        };
+        Self {
+            connection,
+            debug_apdu,
+            last_retcode: LastRetcode::default(),
         }
     }
+
+    /// A handle on the status word of the last answer of the device
+    pub fn last_retcode(&self) -> LastRetcode {
+        self.last_retcode.clone()
+    }
 }
 
 #[ledger_transport::async_trait]
@@ -46,22 +94,102 @@ impl ledger_transport::Exchange for WalletTransport {
     where
         I: Deref<Target = [u8]> + Send + Sync,
     {
-        match self {
-            WalletTransport::HID(transport) => transport
+        if self.debug_apdu {
+            log_apdu_command(command);
+        }
+        let answer = match &self.connection {
+            #[cfg(not(target_family = "wasm"))]
+            Connection::Hid(transport) => transport
                 .exchange(command)
                 .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e)),
-            WalletTransport::TCP(transport) => transport
+            Connection::Tcp(transport) => transport
                 .exchange(command)
                 .await
                 .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e)),
+        };
+        self.last_retcode
+            .set(answer.as_ref().ok().map(|answer| answer.retcode()));
+        if self.debug_apdu {
+            match &answer {
+                Ok(answer) => eprintln!(
+                    "APDU << retcode: {:#06x}, data: <{} bytes redacted>",
+                    answer.retcode(),
+                    answer.data().len()
+                ),
+                Err(err) => eprintln!("APDU << error: {err}"),
+            }
         }
+        answer
     }
 }
 
+/// Log the header of an APDU command. The payload is left out, as it may
+/// hold the data being signed or derivation paths.
+fn log_apdu_command<I>(command: &APDUCommand<I>)
+where
+    I: Deref<Target = [u8]>,
+{
+    eprintln!(
+        "APDU >> cla: {:#04x}, ins: {:#04x}, p1: {:#04x}, p2: {:#04x}, data: \
+         <{} bytes redacted>",
+        command.cla,
+        command.ins,
+        command.p1,
+        command.p2,
+        command.data.len()
+    );
+}
+
+/// A Ledger device connected over HID
+#[derive(Clone, Debug)]
+pub struct HidDevice {
+    /// The product name reported by the device, if any
+    pub product: Option<String>,
+    /// The platform-specific path of the device
+    pub path: String,
+}
+
+/// List the Ledger devices connected over HID.
+#[cfg(not(target_family = "wasm"))]
+pub fn list_hid_devices() -> std::io::Result<Vec<HidDevice>> {
+    let hidapi = HidApi::new()
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+    Ok(TransportNativeHID::list_ledgers(&hidapi)
+        .map(|info| HidDevice {
+            product: info.product_string().map(str::to_owned),
+            path: info.path().to_string_lossy().into_owned(),
+        })
+        .collect())
+}
+
+/// List the Ledger devices connected over HID, which are not available on
+/// wasm32.
+#[cfg(target_family = "wasm")]
+pub fn list_hid_devices() -> std::io::Result<Vec<HidDevice>> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "HID devices can't be listed on wasm32",
+    ))
+}
+
 /// Hardware wallet TCP transport
 #[derive(Default)]
 pub struct TransportTcp;
 
+impl TransportTcp {
+    /// The address of the device proxy, set by the `LEDGER_PROXY_ADDRESS`
+    /// and `LEDGER_PROXY_PORT` env vars. Defaults to `127.0.0.1:9999`.
+    pub fn proxy_address() -> SocketAddr {
+        let ip = std::env::var("LEDGER_PROXY_ADDRESS")
+            .map(|s| Ipv4Addr::from_str(&s).unwrap())
+            .unwrap_or(Ipv4Addr::LOCALHOST);
+        let port = std::env::var("LEDGER_PROXY_PORT")
+            .map(|s| u16::from_str(&s).unwrap())
+            .unwrap_or(9999);
+        SocketAddr::V4(SocketAddrV4::new(ip, port))
+    }
+}
+
 #[ledger_transport::async_trait]
 impl ledger_transport::Exchange for TransportTcp {
     type AnswerType = Vec<u8>;
@@ -76,15 +204,9 @@ impl ledger_transport::Exchange for TransportTcp {
     {
         use ledger_lib::Exchange;
         let mut transport = ledger_lib::transport::TcpTransport::default();
-        let ip = std::env::var("LEDGER_PROXY_ADDRESS")
-            .map(|s| Ipv4Addr::from_str(&s).unwrap())
-            .unwrap_or(Ipv4Addr::LOCALHOST);
-        let port = std::env::var("LEDGER_PROXY_PORT")
-            .map(|s| u16::from_str(&s).unwrap())
-            .unwrap_or(9999);
         let mut device = transport
             .connect(TcpInfo {
-                addr: SocketAddr::V4(SocketAddrV4::new(ip, port)),
+                addr: Self::proxy_address(),
             })
             .await?;
         let res = device
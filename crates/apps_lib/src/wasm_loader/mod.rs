@@ -9,8 +9,8 @@ use namada_sdk::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use thiserror::Error;
-use tokio::io::AsyncReadExt;
 
+use crate::async_fs;
 use crate::cli::safe_exit;
 use crate::config::DEFAULT_WASM_CHECKSUMS_FILE;
 
@@ -80,24 +80,19 @@ impl Checksums {
     ) -> Self {
         let checksums_path =
             wasm_directory.as_ref().join(DEFAULT_WASM_CHECKSUMS_FILE);
-        match tokio::fs::File::open(&checksums_path).await {
-            Ok(mut file) => {
-                let mut contents = vec![];
-                // Ignoring the result, next step will fail if not read
-                let _ = file.read_to_end(&mut contents).await;
-                match serde_json::from_slice(&contents[..]) {
-                    Ok(checksums) => checksums,
-                    Err(err) => {
-                        eprintln!(
-                            "Failed decoding WASM checksums from {}. Failed \
-                             with {}",
-                            checksums_path.to_string_lossy(),
-                            err
-                        );
-                        safe_exit(1);
-                    }
+        match async_fs::read(&checksums_path).await {
+            Ok(contents) => match serde_json::from_slice(&contents[..]) {
+                Ok(checksums) => checksums,
+                Err(err) => {
+                    eprintln!(
+                        "Failed decoding WASM checksums from {}. Failed \
+                         with {}",
+                        checksums_path.to_string_lossy(),
+                        err
+                    );
+                    safe_exit(1);
                 }
-            }
+            },
             Err(err) => {
                 eprintln!(
                     "Unable to read WASM checksums from {}. Failed with {}",
@@ -139,9 +134,9 @@ pub async fn validate_wasm_artifacts(wasm_directory: impl AsRef<Path>) {
         let wasm_directory = wasm_directory.as_ref().to_owned();
 
         // Async check and download (if needed) each file
-        tokio::spawn(async move {
+        let check = async move {
             let wasm_path = wasm_directory.join(&full_name);
-            match tokio::fs::read(&wasm_path).await {
+            match async_fs::read(&wasm_path).await {
                 // if the file exist, check the hash
                 Ok(bytes) => {
                     if let Err(derived_name) =
@@ -166,11 +161,39 @@ pub async fn validate_wasm_artifacts(wasm_directory: impl AsRef<Path>) {
                     safe_exit(1);
                 }
             }
-        })
+        };
+        // There are no tasks to spawn on wasm32, where the files are checked
+        // on the current task instead
+        #[cfg(not(target_family = "wasm"))]
+        {
+            tokio::spawn(check)
+        }
+        #[cfg(target_family = "wasm")]
+        {
+            check
+        }
     }))
     .await;
 }
 
+/// Find which of the given WASMs are missing from the given dir. A WASM is
+/// looked up by its full name from the checksums file when it's listed there,
+/// or by its plain name otherwise.
+pub fn missing_wasms<'a>(
+    wasm_directory: impl AsRef<Path>,
+    names: impl IntoIterator<Item = &'a str>,
+) -> eyre::Result<Vec<&'a str>> {
+    let checksums = Checksums::read_checksums(&wasm_directory)?;
+    Ok(names
+        .into_iter()
+        .filter(|name| {
+            let file_name =
+                checksums.0.get(*name).map_or(*name, String::as_str);
+            !wasm_directory.as_ref().join(file_name).is_file()
+        })
+        .collect())
+}
+
 pub fn read_wasm(
     wasm_directory: impl AsRef<Path>,
     file_path: impl AsRef<Path>,
@@ -93,6 +93,16 @@ impl<'de> Deserialize<'de> for PublicKey {
     }
 }
 
+impl PublicKey {
+    /// The scheme of this public key
+    pub fn scheme(&self) -> SchemeType {
+        match self {
+            Self::Ed25519(_) => SchemeType::Ed25519,
+            Self::Secp256k1(_) => SchemeType::Secp256k1,
+        }
+    }
+}
+
 impl super::PublicKey for PublicKey {
     const TYPE: SchemeType = SigScheme::TYPE;
 
@@ -116,6 +116,16 @@ impl FromStr for SchemeType {
     }
 }
 
+impl Display for SchemeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ed25519 => write!(f, "ed25519"),
+            Self::Secp256k1 => write!(f, "secp256k1"),
+            Self::Common => write!(f, "common"),
+        }
+    }
+}
+
 /// Represents a signature
 
 pub trait Signature:
@@ -14,6 +14,7 @@ use std::sync::{Arc, Once, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use borsh_ext::BorshSerializeExt;
+use color_eyre::owo_colors::Style;
 use masp_primitives::transaction::Transaction;
 use masp_primitives::zip32::ExtendedFullViewingKey;
 use masp_proofs::prover::LocalTxProver;
@@ -1098,7 +1099,19 @@ impl Default for BenchShieldedCtx {
                 is_pre_genesis: false,
                 chain_id: Some(shell_read.inner.chain_id.clone()),
                 base_dir,
+                profile: None,
                 wasm_dir: Some(WASM_DIR.into()),
+                no_color: false,
+                verbose: false,
+                redact: false,
+                redacted_base_dirs: vec![],
+                debug_apdu: false,
+                assume_tty: false,
+                no_tty: false,
+                require_integrity: false,
+                no_save: false,
+                wallet_file_name: None,
+                kdf_params: None,
             })
             .unwrap();
 
@@ -1194,6 +1207,7 @@ impl BenchShieldedCtx {
                     retry_strategy: RetryStrategy::Forever,
                 },
                 &StdIo,
+                Style::new().bold(),
             ))
             .unwrap();
         let native_token =
@@ -22,7 +22,19 @@ pub fn run(
             is_pre_genesis: false,
             chain_id: Some(locked.chain_id.clone()),
             base_dir: locked.base_dir.clone(),
+            profile: None,
             wasm_dir: Some(locked.wasm_dir.clone()),
+            no_color: false,
+            verbose: false,
+            redact: false,
+            redacted_base_dirs: vec![],
+            debug_apdu: false,
+            assume_tty: false,
+            no_tty: false,
+            require_integrity: false,
+            no_save: false,
+            wallet_file_name: None,
+            kdf_params: None,
         }
     };
     let ctx = Context::new::<TestingIo>(global.clone())?;
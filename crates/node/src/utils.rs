@@ -3,7 +3,6 @@
 use std::str::FromStr;
 
 use namada_apps_lib::cli::args::{self, TestGenesis};
-use namada_apps_lib::client::utils::PRE_GENESIS_DIR;
 use namada_apps_lib::config::genesis::{self, AddrOrPk};
 use namada_apps_lib::{cli, wallet};
 use namada_sdk::address::{Address, ImplicitAddress};
@@ -35,8 +34,8 @@ pub fn test_genesis(args: TestGenesis, global_args: args::Global) {
     crate::test_genesis_files(config.ledger, genesis.clone(), wasm_dir);
 
     if !check_can_sign.is_empty() {
-        let wallet_path = global_args.base_dir.join(PRE_GENESIS_DIR);
-        let mut wallet = if wallet::exists(&wallet_path) {
+        let wallet_path = wallet::pre_genesis_store_dir(&global_args.base_dir);
+        let mut wallet = if wallet::exists(&wallet_path, None) {
             wallet::load(&wallet_path).unwrap()
         } else {
             panic!(
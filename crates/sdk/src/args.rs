@@ -28,7 +28,7 @@ use zeroize::Zeroizing;
 use crate::eth_bridge::bridge_pool;
 use crate::ibc::core::host::types::identifiers::{ChannelId, PortId};
 use crate::signing::SigningTxData;
-use crate::wallet::{DatedSpendingKey, DatedViewingKey};
+use crate::wallet::{DatedSpendingKey, DatedViewingKey, KeyEncryptionPolicy};
 use crate::{rpc, tx, Namada};
 
 /// [`Duration`](StdDuration) wrapper that provides a
@@ -2263,6 +2263,18 @@ pub struct QueryFindValidator<C: NamadaTypes = SdkTypes> {
     pub validator_addr: Option<C::Address>,
 }
 
+/// Compare the keys of a validator on chain with those of the wallet
+#[derive(Clone, Debug)]
+pub struct QueryValidatorKeys<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Native validator address
+    pub validator: C::Address,
+    /// The alias of the validator, from which the aliases of its keys in the
+    /// wallet are derived
+    pub alias: String,
+}
+
 /// Query the raw bytes of given storage key
 #[derive(Clone, Debug)]
 pub struct QueryRawBytes<C: NamadaTypes = SdkTypes> {
@@ -2349,6 +2361,8 @@ pub struct Tx<C: NamadaTypes = SdkTypes> {
     pub use_device: bool,
     /// Hardware Wallet transport - HID (USB) or TCP
     pub device_transport: DeviceTransport,
+    /// Log the APDU exchanges with the hardware wallet
+    pub debug_apdu: bool,
 }
 
 /// Hardware Wallet transport - HID (USB) or TCP
@@ -2571,8 +2585,38 @@ pub struct KeyDerive {
     pub birthday: Option<BlockHeight>,
 }
 
+/// Wallet batch key derivation arguments
+#[derive(Clone, Debug)]
+pub struct KeyDeriveBatch {
+    /// Scheme type
+    pub scheme: SchemeType,
+    /// The keys to derive, each as `alias=derivation-path`
+    pub keys: Vec<String>,
+    /// Whether to force overwrite the aliases
+    pub alias_force: bool,
+    /// Don't encrypt the keypairs
+    pub unsafe_dont_encrypt: bool,
+    /// Allow non-compliant derivation paths
+    pub allow_non_compliant: bool,
+    /// Prompt for BIP39 passphrase
+    pub prompt_bip39_passphrase: bool,
+}
+
+/// Wallet mnemonic preview arguments
+#[derive(Clone, Debug)]
+pub struct KeyPreviewMnemonic {
+    /// Scheme type
+    pub scheme: SchemeType,
+    /// BIP44 derivation path
+    pub derivation_path: String,
+    /// Allow non-compliant derivation path
+    pub allow_non_compliant: bool,
+    /// Prompt for BIP39 passphrase
+    pub prompt_bip39_passphrase: bool,
+}
+
 /// Wallet list arguments
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct KeyAddressList {
     /// Whether to list transparent secret keys only
     pub transparent_only: bool,
@@ -2586,6 +2630,162 @@ pub struct KeyAddressList {
     pub decrypt: bool,
     /// Show secret keys to user
     pub unsafe_show_secret: bool,
+    /// The order of the listed entries
+    pub sort: WalletListSort,
+    /// The encoding of the listed addresses and public keys
+    pub encoding: WalletEncoding,
+    /// List only the entries with this tag
+    pub tag: Option<String>,
+    /// List only the entries created at or after this time
+    pub since: Option<WalletListTime>,
+    /// List only the entries created at or before this time
+    pub until: Option<WalletListTime>,
+}
+
+/// The order of the entries listed from a wallet. Entries that compare equal
+/// are ordered by alias.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WalletListSort {
+    /// Sort by alias
+    #[default]
+    Alias,
+    /// Sort by address, or by key for entries without an address
+    Address,
+    /// Sort by creation time, with entries of unknown creation time first
+    Created,
+}
+
+impl FromStr for WalletListSort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "alias" => Ok(Self::Alias),
+            "address" => Ok(Self::Address),
+            "created" => Ok(Self::Created),
+            raw => Err(format!(
+                "Unexpected sort order \"{raw}\". Valid options are \
+                 \"alias\", \"address\" or \"created\"."
+            )),
+        }
+    }
+}
+
+/// A bound on the creation time of the entries listed from a wallet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalletListTime {
+    /// An RFC3339 date and time, e.g. `2024-01-31T00:00:00Z`
+    At(DateTimeUtc),
+    /// The given number of seconds before now, e.g. `7d`
+    Ago(i64),
+}
+
+impl WalletListTime {
+    /// Converts the bound into a [`DateTimeUtc`], resolving a relative bound
+    /// against the given current time
+    pub fn to_datetime(self, now: DateTimeUtc) -> DateTimeUtc {
+        match self {
+            Self::At(time) => time,
+            Self::Ago(secs) => DateTimeUtc::from_unix_timestamp(
+                now.to_unix_timestamp().saturating_sub(secs),
+            )
+            .unwrap_or(namada_core::time::MIN_UTC),
+        }
+    }
+}
+
+impl FromStr for WalletListTime {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(time) = namada_core::time::DateTime::parse_from_rfc3339(s) {
+            return Ok(Self::At(
+                time.with_timezone(&namada_core::time::Utc).into(),
+            ));
+        }
+        let invalid = || {
+            format!(
+                "Unexpected time \"{s}\". Expected an RFC3339 date, e.g. \
+                 \"2024-01-31T00:00:00Z\", or a time ago in seconds, \
+                 minutes, hours, days or weeks, e.g. \"7d\"."
+            )
+        };
+        let unit_start =
+            s.find(|c: char| !c.is_ascii_digit()).ok_or_else(invalid)?;
+        let (amount, unit) = s.split_at(unit_start);
+        let amount: i64 = amount.parse().map_err(|_| invalid())?;
+        let unit_secs = match unit {
+            "s" => 1,
+            "m" => 60,
+            "h" => 3_600,
+            "d" => 86_400,
+            "w" => 604_800,
+            _ => return Err(invalid()),
+        };
+        amount
+            .checked_mul(unit_secs)
+            .map(Self::Ago)
+            .ok_or_else(invalid)
+    }
+}
+
+/// The encoding in which the wallet prints transparent addresses and public
+/// keys
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WalletEncoding {
+    /// The bech32m encoding
+    #[default]
+    Bech32m,
+    /// The hex encoding of the raw hash of addresses and of the borsh
+    /// serialized public keys
+    Hex,
+}
+
+impl FromStr for WalletEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "bech32m" => Ok(Self::Bech32m),
+            "hex" => Ok(Self::Hex),
+            raw => Err(format!(
+                "Unexpected encoding \"{raw}\". Valid options are \
+                 \"bech32m\" or \"hex\"."
+            )),
+        }
+    }
+}
+
+/// Wallet validator key rotation arguments
+#[derive(Clone, Debug)]
+pub struct ValidatorKeyRotate {
+    /// The validator key to keep, rotating the other one
+    pub keep: ValidatorKeyKind,
+}
+
+/// The kinds of validator keys stored in a validator's wallet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidatorKeyKind {
+    /// The key signing the protocol txs
+    Protocol,
+    /// The hot key signing the Ethereum bridge txs
+    EthBridge,
+}
+
+impl FromStr for ValidatorKeyKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "protocol" => Ok(Self::Protocol),
+            "eth-bridge" => Ok(Self::EthBridge),
+            raw => Err(format!(
+                "Unexpected validator key \"{raw}\". Valid options are \
+                 \"protocol\" or \"eth-bridge\"."
+            )),
+        }
+    }
 }
 
 /// Wallet key / address lookup arguments
@@ -2609,6 +2809,8 @@ pub struct KeyAddressFind {
     pub decrypt: bool,
     /// Show secret keys to user
     pub unsafe_show_secret: bool,
+    /// The encoding of the found addresses and public keys
+    pub encoding: WalletEncoding,
 }
 /// Wallet key export arguments
 #[derive(Clone, Debug)]
@@ -2637,6 +2839,24 @@ pub struct KeyImport {
     pub unsafe_dont_encrypt: bool,
 }
 
+/// Wallet mnemonics file import arguments
+#[derive(Clone, Debug)]
+pub struct KeyImportMnemonicFile {
+    /// Path to the TOML file of mnemonics
+    pub file_path: String,
+    /// Whether to force overwrite the aliases
+    pub alias_force: bool,
+    /// Don't encrypt the keys
+    pub unsafe_dont_encrypt: bool,
+}
+
+/// Wallet aliases file validation arguments
+#[derive(Clone, Debug)]
+pub struct KeyValidateAliases {
+    /// Path to the TOML file of the aliases to validate
+    pub file_path: String,
+}
+
 /// Wallet key / address add arguments
 #[derive(Clone, Debug)]
 pub struct KeyAddressAdd {
@@ -2662,6 +2882,117 @@ pub struct KeyAddressRemove {
     pub do_it: bool,
 }
 
+/// Wallet key encryption policy arguments
+#[derive(Clone, Debug)]
+pub struct KeySetPolicy {
+    /// Key alias
+    pub alias: String,
+    /// The encryption policy to enforce on the key
+    pub policy: KeyEncryptionPolicy,
+}
+
+/// Wallet entry tagging arguments
+#[derive(Clone, Debug)]
+pub struct KeyAddressTag {
+    /// Alias of the entries to tag
+    pub alias: String,
+    /// The tags to add
+    pub tags: Vec<String>,
+}
+
+/// Wallet store path arguments
+#[derive(Clone, Debug)]
+pub struct WalletPath {}
+
+/// Wallet store compaction arguments
+#[derive(Clone, Debug)]
+pub struct WalletCompact {}
+
+/// Wallet password check arguments
+#[derive(Clone, Debug)]
+pub struct KeyCheckPassword {
+    /// Alias of the key to decrypt
+    pub alias: Option<String>,
+}
+
+/// Wallet alias case arguments
+#[derive(Clone, Debug)]
+pub struct AliasCaseSet {
+    /// Preserve the case of the added aliases, rather than normalizing them
+    pub preserve: bool,
+}
+
+/// Wallet initialization wizard arguments
+#[derive(Clone, Debug)]
+pub struct WalletInit {
+    /// Scheme of the key, prompted for when not given
+    pub scheme: Option<SchemeType>,
+    /// Alias of the key, prompted for when not given
+    pub alias: Option<String>,
+    /// Restore the key from a mnemonic rather than generating a new one
+    pub restore: bool,
+    /// Don't encrypt the key
+    pub unsafe_dont_encrypt: bool,
+    /// Genesis txs file to add the established accounts of the key from
+    pub genesis_txs_path: Option<PathBuf>,
+    /// Don't prompt for the choices that aren't given in the args
+    pub non_interactive: bool,
+}
+
+/// Wallet encryption password test arguments
+#[derive(Clone, Debug)]
+pub struct KeyTestPassword {}
+
+/// Wallet message signing arguments
+#[derive(Clone, Debug)]
+pub struct KeySignMessage {
+    /// Alias of the key to sign with
+    pub alias: String,
+    /// The message to sign
+    pub message: String,
+}
+
+/// Wallet message signature verification arguments
+#[derive(Clone, Debug)]
+pub struct KeyVerifyMessage {
+    /// The public key that signed the message
+    pub public_key: common::PublicKey,
+    /// The signed message
+    pub message: String,
+    /// The signature over the message
+    pub signature: common::Signature,
+}
+
+/// Wallet store backup arguments
+#[derive(Clone, Debug)]
+pub struct WalletBackup {
+    /// Path to write the encrypted backup to
+    pub path: PathBuf,
+}
+
+/// Wallet store restore arguments
+#[derive(Clone, Debug)]
+pub struct WalletRestore {
+    /// Path to the encrypted backup
+    pub path: PathBuf,
+    /// Overwrite an existing wallet store
+    pub force: bool,
+}
+
+/// Wallet key encryption audit arguments
+#[derive(Clone, Debug)]
+pub struct KeyAuditEncryption {
+    /// Fail if any key is stored in plaintext
+    pub fail_on_plaintext: bool,
+}
+
+/// Wallet address book encryption arguments
+#[derive(Clone, Debug)]
+pub struct AddressBookEncrypt {
+    /// Store the address book in plaintext instead
+    pub decrypt: bool,
+}
+
 /// Generate payment address arguments
 #[derive(Clone, Debug)]
 pub struct PayAddressGen<C: NamadaTypes = SdkTypes> {
@@ -150,6 +150,7 @@ pub trait Namada: NamadaIo {
             memo: None,
             use_device: false,
             device_transport: DeviceTransport::default(),
+            debug_apdu: false,
         }
     }
 
@@ -728,6 +729,7 @@ where
                 memo: None,
                 use_device: false,
                 device_transport: DeviceTransport::default(),
+                debug_apdu: false,
             },
         }
     }
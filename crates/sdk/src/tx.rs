@@ -504,7 +504,9 @@ pub async fn save_initialized_accounts<N: Namada>(
                         format!("{}{}", initialized_account_alias, ix).into()
                     }
                 }
-                None => N::WalletUtils::read_alias(&encoded).into(),
+                None => {
+                    context.wallet().await.utils().read_alias(&encoded).into()
+                }
             };
             let alias = alias.into_owned();
             let added = context.wallet_mut().await.insert_address(
@@ -9,9 +9,11 @@
 //! To keep the temporary files created by a test, use env var
 //! `NAMADA_E2E_KEEP_TEMP=true`.
 
-use std::env;
+use std::{env, fs};
 
 use color_eyre::eyre::Result;
+use namada_apps_lib::client::utils::PRE_GENESIS_DIR;
+use namada_apps_lib::wallet;
 
 use super::setup;
 use crate::e2e::setup::Bin;
@@ -235,3 +237,56 @@ fn wallet_address_cmds() -> Result<()> {
 
     Ok(())
 }
+
+/// Test joining a network with a wallet file of a non-default name:
+/// 1. copy the pre-genesis wallet into a new base dir under the custom name
+/// 2. join the network with the custom wallet file name
+/// 3. load the derived chain wallet from the file of the custom name
+#[test]
+fn wallet_join_network_with_file_name() -> Result<()> {
+    let test = setup::single_node_net()?;
+    let file_name = "custom-wallet.toml";
+    let base_dir = setup::TestDir::new();
+    let chain_id = test.net.chain_id.as_str();
+
+    // 1. copy the pre-genesis wallet under the custom name
+    let pre_genesis_dir = wallet::pre_genesis_store_dir(base_dir.path());
+    fs::create_dir_all(&pre_genesis_dir)?;
+    fs::copy(
+        wallet::wallet_file(test.test_dir.path().join(PRE_GENESIS_DIR)),
+        pre_genesis_dir.join(file_name),
+    )?;
+
+    // 2. join-network
+    let mut join_network = setup::run_cmd(
+        Bin::Client,
+        [
+            "utils",
+            "join-network",
+            "--chain-id",
+            chain_id,
+            "--wallet-file-name",
+            file_name,
+        ],
+        Some(5),
+        &test.working_dir,
+        base_dir.path(),
+        format!("{}:{}", std::file!(), std::line!()),
+    )?;
+    join_network.exp_string("Successfully configured for chain")?;
+    join_network.assert_success();
+
+    // 3. load the derived wallet
+    let chain_dir =
+        wallet::chain_store_dir(base_dir.path(), &test.net.chain_id);
+    assert!(!wallet::exists(&chain_dir, None));
+    let options = wallet::WalletOptions {
+        file_name: Some(file_name.to_owned()),
+        ..Default::default()
+    };
+    let wallet = wallet::load_with_options(&chain_dir, &options)?;
+    assert!(wallet.find_address("albert").is_some());
+    assert!(wallet.find_address("nam").is_some());
+
+    Ok(())
+}
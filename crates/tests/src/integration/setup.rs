@@ -91,7 +91,19 @@ pub fn initialize_genesis(
         is_pre_genesis: true,
         chain_id: Some(chain_id.clone()),
         base_dir: test_dir.path().to_path_buf(),
+        profile: None,
         wasm_dir: Some(test_dir.path().join(chain_id.as_str()).join("wasm")),
+        no_color: false,
+        verbose: false,
+        redact: false,
+        redacted_base_dirs: vec![],
+        debug_apdu: false,
+        assume_tty: false,
+        no_tty: false,
+        require_integrity: false,
+        no_save: false,
+        wallet_file_name: None,
+        kdf_params: None,
     };
 
     // Create genesis chain release archive
@@ -157,23 +169,34 @@ fn finalize_wallet(
         validator_alias_and_dir.map(|(validator_alias, pre_genesis_dir)| {
             (
                 Alias::from(validator_alias),
-                pre_genesis::load(&pre_genesis_dir).unwrap_or_else(|err| {
+                pre_genesis::load(
+                    &pre_genesis_dir,
+                    &global_args.wallet_options(),
+                )
+                .unwrap_or_else(|err| {
                     panic!("Error loading validator pre-genesis wallet {err}")
                 }),
             )
         });
 
     // Load pre-genesis wallet
-    let pre_genesis_wallet =
-        namada_apps_lib::wallet::load(&pre_genesis_path).unwrap();
+    let wallet_options = global_args.wallet_options();
+    let pre_genesis_wallet = namada_apps_lib::wallet::load_with_options(
+        &pre_genesis_path,
+        &wallet_options,
+    )
+    .unwrap();
     let chain_dir = global_args
         .base_dir
         .join(global_args.chain_id.as_ref().unwrap().as_str());
     // Derive wallet from genesis
     let wallet = genesis.derive_wallet(
         &chain_dir,
+        &wallet_options,
         Some(pre_genesis_wallet),
         validator_alias_and_pre_genesis_wallet,
+        None,
+        &Default::default(),
     );
     namada_apps_lib::wallet::save(&wallet).unwrap();
 }
@@ -13,26 +13,46 @@ use namada_macros::BorshDeserializer;
 use namada_migrations::*;
 use serde::{Deserialize, Serialize};
 
-/// Aliases created from raw strings are kept in-memory as given, but their
-/// `Serialize` and `Display` instance converts them to lowercase. Their
-/// `PartialEq` instance is case-insensitive.
+/// Aliases created from raw strings are normalized to lowercase. Only the
+/// aliases created with [`Alias::preserving_case`] also keep the case they
+/// were given in, which is solely used for display. Their `PartialEq`
+/// instance is case-insensitive.
 #[derive(Clone, Debug, Default, Eq, BorshDeserializer)]
-pub struct Alias(String);
+pub struct Alias {
+    normalized: String,
+    given: Option<String>,
+}
 
 impl Alias {
+    /// Create an alias that keeps the case it's given in for display, in
+    /// the wallets that preserve the case of aliases
+    pub fn preserving_case(raw: impl AsRef<str>) -> Self {
+        let raw = raw.as_ref();
+        Self {
+            normalized: raw.to_lowercase(),
+            given: Some(raw.to_owned()),
+        }
+    }
+
     /// Normalize an alias to lower-case
     pub fn normalize(&self) -> String {
-        self.0.to_lowercase()
+        self.normalized.clone()
+    }
+
+    /// The alias in the case it was given in, if it was created with
+    /// [`Alias::preserving_case`], otherwise normalized to lower-case
+    pub fn as_given(&self) -> &str {
+        self.given.as_deref().unwrap_or(&self.normalized)
     }
 
     /// Returns the length of the underlying `String`.
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.normalized.len()
     }
 
     /// Is the underlying `String` empty?
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.normalized.is_empty()
     }
 
     /// If the alias is reserved for an internal address,
@@ -84,7 +104,7 @@ impl<'de> Deserialize<'de> for Alias {
 
 impl PartialEq for Alias {
     fn eq(&self, other: &Self) -> bool {
-        self.normalize() == other.normalize()
+        self.normalized == other.normalized
     }
 }
 
@@ -96,13 +116,13 @@ impl PartialOrd for Alias {
 
 impl Ord for Alias {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.normalize().cmp(&other.normalize())
+        self.normalized.cmp(&other.normalized)
     }
 }
 
 impl Hash for Alias {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.normalize().hash(state);
+        self.normalized.hash(state);
     }
 }
 
@@ -111,7 +131,10 @@ where
     T: AsRef<str>,
 {
     fn from(raw: T) -> Self {
-        Self(raw.as_ref().to_lowercase())
+        Self {
+            normalized: raw.as_ref().to_lowercase(),
+            given: None,
+        }
     }
 }
 
@@ -143,10 +166,16 @@ impl FromStr for Alias {
 
 impl AsRef<str> for &Alias {
     fn as_ref(&self) -> &str {
-        &self.0
+        &self.normalized
     }
 }
 
+/// Namespace an alias under the given prefix, e.g. `testnet/faucet`, to avoid
+/// collisions with the aliases already in a wallet
+pub fn with_prefix(prefix: &str, alias: &Alias) -> Alias {
+    format!("{prefix}/{alias}").into()
+}
+
 /// Default alias of a validator's account key
 pub fn validator_key(validator_alias: &Alias) -> Alias {
     format!("{validator_alias}-validator-key").into()
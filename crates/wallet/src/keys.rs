@@ -18,6 +18,17 @@ use crate::WalletIo;
 
 const ENCRYPTED_KEY_PREFIX: &str = "encrypted:";
 const UNENCRYPTED_KEY_PREFIX: &str = "unencrypted:";
+/// Prefix of encrypted keypairs whose encryption key was derived with
+/// non-default KDF parameters, which are then stored along with them
+const KDF_PARAMS_PREFIX: &str = "argon2i$";
+/// The smallest memory cost accepted by the KDF, in KiB
+const MIN_KDF_MEMORY_KIB: u32 = 8;
+/// The largest memory cost accepted by the KDF, in KiB (4 GiB), such that a
+/// crafted keypair can't make decrypting it exhaust the memory
+const MAX_KDF_MEMORY_KIB: u32 = 4 * 1024 * 1024;
+/// The largest number of iterations accepted by the KDF, such that a crafted
+/// keypair can't make decrypting it run for hours
+const MAX_KDF_ITERATIONS: u32 = 64;
 
 /// Type alias for a viewing key with a birthday.
 pub type DatedViewingKey = DatedKeypair<ExtendedViewingKey>;
@@ -245,27 +256,132 @@ where
     }
 }
 
+/// The cost parameters of the Argon2i KDF that derives the encryption key of
+/// a keypair from its password
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KdfParams {
+    /// The number of passes over the memory
+    pub iterations: u32,
+    /// The memory cost, in KiB
+    pub memory_kib: u32,
+}
+
+impl KdfParams {
+    /// The parameters that keypairs are encrypted with unless others are set
+    pub const DEFAULT: Self = Self {
+        iterations: 3,
+        memory_kib: 1 << 17,
+    };
+
+    /// Construct KDF parameters, checking that the KDF accepts them
+    pub fn new(
+        iterations: u32,
+        memory_kib: u32,
+    ) -> Result<Self, InvalidKdfParams> {
+        if iterations == 0 {
+            return Err(InvalidKdfParams::NoIterations);
+        }
+        if iterations > MAX_KDF_ITERATIONS {
+            return Err(InvalidKdfParams::TooManyIterations(iterations));
+        }
+        if memory_kib < MIN_KDF_MEMORY_KIB {
+            return Err(InvalidKdfParams::TooLittleMemory(memory_kib));
+        }
+        if memory_kib > MAX_KDF_MEMORY_KIB {
+            return Err(InvalidKdfParams::TooMuchMemory(memory_kib));
+        }
+        Ok(Self {
+            iterations,
+            memory_kib,
+        })
+    }
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum InvalidKdfParams {
+    #[error("The KDF must make at least one iteration")]
+    NoIterations,
+    #[error("The KDF iterations of {0} are above the maximum of 64")]
+    TooManyIterations(u32),
+    #[error("The KDF memory cost of {0} KiB is below the minimum of 8 KiB")]
+    TooLittleMemory(u32),
+    #[error(
+        "The KDF memory cost of {0} KiB is above the maximum of 4194304 KiB \
+         (4 GiB)"
+    )]
+    TooMuchMemory(u32),
+}
+
 /// An encrypted keypair stored in a wallet
 #[derive(Debug)]
 pub struct EncryptedKeypair<T: BorshSerialize + BorshDeserialize>(
     Vec<u8>,
+    KdfParams,
     PhantomData<T>,
 );
 
 impl<T: BorshSerialize + BorshDeserialize> Display for EncryptedKeypair<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The default parameters are left out, for the keypair to be encoded
+        // the same as before they could be chosen
+        if self.1 != KdfParams::DEFAULT {
+            write!(
+                f,
+                "{KDF_PARAMS_PREFIX}t={}$m={}$",
+                self.1.iterations, self.1.memory_kib
+            )?;
+        }
         write!(f, "{}", HEXLOWER.encode(self.0.as_ref()))
     }
 }
 
 impl<T: BorshSerialize + BorshDeserialize> FromStr for EncryptedKeypair<T> {
-    type Err = data_encoding::DecodeError;
+    type Err = ParseEncryptedKeypairError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        HEXLOWER.decode(s.as_ref()).map(|x| Self(x, PhantomData))
+        let Some(rest) = s.strip_prefix(KDF_PARAMS_PREFIX) else {
+            let data = HEXLOWER.decode(s.as_ref())?;
+            return Ok(Self(data, KdfParams::DEFAULT, PhantomData));
+        };
+        let mut pieces = rest.splitn(3, '$');
+        let mut param = |name: &str| {
+            pieces
+                .next()
+                .and_then(|piece| piece.strip_prefix(name))
+                .and_then(|value| value.parse().ok())
+                .ok_or_else(|| {
+                    ParseEncryptedKeypairError::InvalidKdfParamsEncoding(
+                        s.chars().take(32).collect(),
+                    )
+                })
+        };
+        let iterations = param("t=")?;
+        let memory_kib = param("m=")?;
+        let params = KdfParams::new(iterations, memory_kib)?;
+        let data = pieces.next().unwrap_or_default();
+        let data = HEXLOWER.decode(data.as_ref())?;
+        Ok(Self(data, params, PhantomData))
     }
 }
 
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum ParseEncryptedKeypairError {
+    #[error("Invalid hex encoding of the encrypted keypair: {0}")]
+    InvalidHex(#[from] data_encoding::DecodeError),
+    #[error("Invalid encoding of the KDF parameters in \"{0}\"")]
+    InvalidKdfParamsEncoding(String),
+    #[error("Invalid KDF parameters: {0}")]
+    InvalidKdfParams(#[from] InvalidKdfParams),
+}
+
 #[allow(missing_docs)]
 #[derive(Debug, Error)]
 pub enum DecryptionError {
@@ -290,9 +406,21 @@ where
     /// will be stored raw without encryption. Returns the key for storing and a
     /// reference-counting point to the raw key.
     pub fn new(keypair: T, password: Option<Zeroizing<String>>) -> (Self, T) {
+        Self::new_with_kdf_params(keypair, password, KdfParams::DEFAULT)
+    }
+
+    /// Construct a keypair for storage like [`StoredKeypair::new`], but
+    /// encrypted with the given KDF parameters if a password is provided.
+    pub fn new_with_kdf_params(
+        keypair: T,
+        password: Option<Zeroizing<String>>,
+        params: KdfParams,
+    ) -> (Self, T) {
         match password {
             Some(password) => (
-                Self::Encrypted(EncryptedKeypair::new(&keypair, password)),
+                Self::Encrypted(EncryptedKeypair::new_with_kdf_params(
+                    &keypair, password, params,
+                )),
                 keypair,
             ),
             None => (Self::Raw(keypair.clone()), keypair),
@@ -304,6 +432,7 @@ where
     /// from stdin.
     pub fn get<U: WalletIo>(
         &self,
+        utils: &U,
         decrypt: bool,
         password: Option<Zeroizing<String>>,
         target_key: Option<&str>,
@@ -311,8 +440,9 @@ where
         match self {
             StoredKeypair::Encrypted(encrypted_keypair) => {
                 if decrypt {
-                    let password = password
-                        .unwrap_or_else(|| U::read_password(false, target_key));
+                    let password = password.unwrap_or_else(|| {
+                        utils.read_password(false, target_key)
+                    });
                     let key = encrypted_keypair.decrypt(password)?;
                     Ok(key)
                 } else {
@@ -333,10 +463,22 @@ where
 }
 
 impl<T: BorshSerialize + BorshDeserialize> EncryptedKeypair<T> {
-    /// Encrypt a keypair and store it with its salt.
+    /// Encrypt a keypair with the default KDF parameters and store it with
+    /// its salt.
     pub fn new(keypair: &T, password: Zeroizing<String>) -> Self {
+        Self::new_with_kdf_params(keypair, password, KdfParams::DEFAULT)
+    }
+
+    /// Encrypt a keypair with the given KDF parameters and store it with its
+    /// salt and the parameters.
+    pub fn new_with_kdf_params(
+        keypair: &T,
+        password: Zeroizing<String>,
+        params: KdfParams,
+    ) -> Self {
         let salt = encryption_salt();
-        let encryption_key = encryption_key(&salt, &password);
+        let encryption_key = encryption_key(&salt, &password, params)
+            .expect("Generation of encryption secret key shouldn't fail");
 
         let data = keypair.serialize_to_vec();
 
@@ -345,7 +487,12 @@ impl<T: BorshSerialize + BorshDeserialize> EncryptedKeypair<T> {
 
         let encrypted_data = [salt.as_ref(), &encrypted_keypair].concat();
 
-        Self(encrypted_data, PhantomData)
+        Self(encrypted_data, params, PhantomData)
+    }
+
+    /// The KDF parameters that this keypair was encrypted with
+    pub fn kdf_params(&self) -> KdfParams {
+        self.1
     }
 
     /// Decrypt an encrypted keypair
@@ -358,12 +505,16 @@ impl<T: BorshSerialize + BorshDeserialize> EncryptedKeypair<T> {
         }
 
         let salt_len = encryption_salt().len();
+        if self.0.len() < salt_len {
+            return Err(DecryptionError::BadSalt);
+        }
         let (raw_salt, cipher) = self.0.split_at(salt_len);
 
         let salt = kdf::Salt::from_slice(raw_salt)
             .map_err(|_| DecryptionError::BadSalt)?;
 
-        let encryption_key = encryption_key(&salt, &password);
+        let encryption_key = encryption_key(&salt, &password, self.1)
+            .map_err(|_| DecryptionError::DecryptionError)?;
 
         let decrypted_data = aead::open(&encryption_key, cipher)
             .map_err(|_| DecryptionError::DecryptionError)?;
@@ -379,8 +530,18 @@ fn encryption_salt() -> kdf::Salt {
 }
 
 /// Make encryption secret key from a password.
-fn encryption_key(salt: &kdf::Salt, password: &str) -> kdf::SecretKey {
-    kdf::Password::from_slice(password.as_bytes())
-        .and_then(|password| kdf::derive_key(&password, salt, 3, 1 << 17, 32))
-        .expect("Generation of encryption secret key shouldn't fail")
+fn encryption_key(
+    salt: &kdf::Salt,
+    password: &str,
+    params: KdfParams,
+) -> Result<kdf::SecretKey, orion::errors::UnknownCryptoError> {
+    kdf::Password::from_slice(password.as_bytes()).and_then(|password| {
+        kdf::derive_key(
+            &password,
+            salt,
+            params.iterations,
+            params.memory_kib,
+            32,
+        )
+    })
 }
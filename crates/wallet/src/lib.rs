@@ -27,14 +27,14 @@ use namada_ibc::trace::is_ibc_denom;
 pub use pre_genesis::gen_key_to_store;
 use rand::CryptoRng;
 use rand_core::RngCore;
-pub use store::{AddressVpType, Store};
+pub use store::{AddressVpType, KeyEncryptionPolicy, Store};
 use thiserror::Error;
 use zeroize::Zeroizing;
 
 pub use self::derivation_path::{DerivationPath, DerivationPathError};
 pub use self::keys::{
     DatedKeypair, DatedSpendingKey, DatedViewingKey, DecryptionError,
-    StoredKeypair,
+    EncryptedKeypair, InvalidKdfParams, KdfParams, StoredKeypair,
 };
 pub use self::store::{ConfirmationResponse, ValidatorData, ValidatorKeys};
 use crate::store::{derive_hd_secret_key, derive_hd_spending_key};
@@ -63,6 +63,7 @@ pub trait WalletIo: Sized + Clone {
 
     /// Read the password for decryption from the file/env/stdin.
     fn read_password(
+        &self,
         _confirm: bool,
         _target_key: Option<&str>,
     ) -> Zeroizing<String> {
@@ -70,17 +71,17 @@ pub trait WalletIo: Sized + Clone {
     }
 
     /// Read an alias from the file/env/stdin.
-    fn read_alias(_prompt_msg: &str) -> String {
+    fn read_alias(&self, _prompt_msg: &str) -> String {
         panic!("attempted to prompt for alias in non-interactive mode");
     }
 
     /// Read mnemonic code from the file/env/stdin.
-    fn read_mnemonic_code() -> Option<Mnemonic> {
+    fn read_mnemonic_code(&self) -> Option<Mnemonic> {
         panic!("attempted to prompt for alias in non-interactive mode");
     }
 
     /// Read a mnemonic code from the file/env/stdin.
-    fn read_mnemonic_passphrase(_confirm: bool) -> Zeroizing<String> {
+    fn read_mnemonic_passphrase(&self, _confirm: bool) -> Zeroizing<String> {
         panic!("attempted to prompt for alias in non-interactive mode");
     }
 
@@ -88,12 +89,25 @@ pub trait WalletIo: Sized + Clone {
     /// the store. Offer the user to either replace existing mapping, alter the
     /// chosen alias to a name of their choice, or cancel the aliasing.
     fn show_overwrite_confirmation(
+        &self,
         _alias: &Alias,
         _alias_for: &str,
     ) -> store::ConfirmationResponse {
         // Automatically replace aliases in non-interactive mode
         store::ConfirmationResponse::Replace
     }
+
+    /// The KDF parameters that keys are encrypted with
+    fn encryption_kdf_params(&self) -> KdfParams {
+        KdfParams::DEFAULT
+    }
+
+    /// The current time, which keys are timestamped with when they're added
+    /// to the store. Override it to write deterministic stores in tests.
+    fn now() -> DateTimeUtc {
+        #[allow(clippy::disallowed_methods)]
+        DateTimeUtc::now()
+    }
 }
 
 /// Errors of wallet loading and storing
@@ -112,6 +126,21 @@ pub enum LoadStoreError {
     /// Wallet store writing error
     #[error("Failed to write the wallet store: {0}")]
     StoreNewWallet(String),
+    /// Encrypted address book decryption error
+    #[error("Failed to decrypt the wallet address book: {0}")]
+    DecryptAddressBook(DecryptionError),
+    /// Wallet store integrity check error
+    #[error(
+        "The integrity MAC of the wallet store at \"{path}\" doesn't match \
+         its contents. The store may have been tampered with or corrupted."
+    )]
+    IntegrityMismatch { path: String },
+    /// Wallet store without an integrity MAC, while one is required
+    #[error(
+        "The wallet store at \"{path}\" has no integrity MAC, which is \
+         required."
+    )]
+    MissingIntegrityMac { path: String },
 }
 
 /// Captures the permanent storage parts of the wallet's functioning
@@ -128,9 +157,10 @@ pub trait WalletStorage: Sized + Clone {
 pub mod fs {
     use std::fs;
     use std::io::{Read, Write};
-    use std::path::PathBuf;
+    use std::path::{Path, PathBuf};
 
     use fd_lock::RwLock;
+    use orion::hazardous::mac::hmac::sha256::{HmacSha256, SecretKey, Tag};
     use rand_core::OsRng;
 
     use super::*;
@@ -139,15 +169,185 @@ pub mod fs {
     pub trait FsWalletStorage: Clone {
         /// The directory in which the wallet is supposed to be stored
         fn store_dir(&self) -> &PathBuf;
+
+        /// Whether loading a wallet file without an integrity MAC fails,
+        /// rather than only warning
+        fn require_integrity(&self) -> bool {
+            false
+        }
+
+        /// Whether saving the wallet is skipped, so that the changes made to
+        /// it are only ever in memory
+        fn save_disabled(&self) -> bool {
+            false
+        }
+
+        /// The path of the wallet file, in the store dir
+        fn wallet_file(&self) -> PathBuf {
+            self.store_dir().join(FILE_NAME)
+        }
+
+        /// The password to unlock the encrypted address book of a loaded
+        /// wallet file with. Without one, the address book is left locked.
+        fn address_book_password(&self) -> Option<Zeroizing<String>> {
+            None
+        }
+    }
+
+    fn warn_save_skipped() {
+        eprintln!(
+            "Not saving the changes to the wallet, as saving is disabled."
+        );
     }
 
     /// Wallet file name
     const FILE_NAME: &str = "wallet.toml";
 
+    /// Env var holding the key of the integrity MAC of wallet files. Without
+    /// it, the MAC detects corruption and edits that don't update it, but
+    /// anyone can recompute it. With it, only the holders of the key can.
+    pub const ENV_VAR_WALLET_INTEGRITY_KEY: &str =
+        "NAMADA_WALLET_INTEGRITY_KEY";
+
+    /// The key of the integrity MAC of wallet files when
+    /// [`ENV_VAR_WALLET_INTEGRITY_KEY`] isn't set
+    const DEFAULT_INTEGRITY_KEY: &[u8] = b"namada-wallet-integrity";
+
+    /// The start of the first line of a wallet file, which holds the integrity
+    /// MAC of the rest of the file
+    const INTEGRITY_MAC_PREFIX: &[u8] = b"integrity_mac = \"";
+
+    fn integrity_key() -> SecretKey {
+        let key = std::env::var(ENV_VAR_WALLET_INTEGRITY_KEY)
+            .ok()
+            .filter(|key| !key.is_empty())
+            .map(String::into_bytes)
+            .unwrap_or_else(|| DEFAULT_INTEGRITY_KEY.to_vec());
+        SecretKey::from_slice(&key).expect("A non-empty MAC key is valid")
+    }
+
+    /// Prefix an encoded store with a line holding its integrity MAC,
+    /// which older versions read as a field of the store and ignore.
+    fn with_integrity_mac(data: Vec<u8>) -> Vec<u8> {
+        let tag = HmacSha256::hmac(&integrity_key(), &data)
+            .expect("Computing the MAC of the wallet store shouldn't fail");
+        let mut file = INTEGRITY_MAC_PREFIX.to_vec();
+        file.extend(
+            data_encoding::HEXLOWER
+                .encode(tag.unprotected_as_bytes())
+                .into_bytes(),
+        );
+        file.extend(b"\"\n");
+        file.extend(data);
+        file
+    }
+
+    /// Check the integrity MAC of a wallet file, if it has one, and strip
+    /// it off. A file without a MAC is only accepted with a warning, unless
+    /// a MAC is required.
+    fn verify_integrity_mac(
+        file: Vec<u8>,
+        path: &str,
+        require_integrity: bool,
+    ) -> Result<Vec<u8>, LoadStoreError> {
+        let Some(rest) = file.strip_prefix(INTEGRITY_MAC_PREFIX) else {
+            if require_integrity {
+                return Err(LoadStoreError::MissingIntegrityMac {
+                    path: path.to_string(),
+                });
+            }
+            eprintln!(
+                "Warning: the wallet store at \"{path}\" has no integrity \
+                 MAC. One is added the next time it is saved."
+            );
+            return Ok(file);
+        };
+        let mismatch = || LoadStoreError::IntegrityMismatch {
+            path: path.to_string(),
+        };
+        let (mac, data) = rest
+            .iter()
+            .position(|byte| *byte == b'\n')
+            .map(|line_end| rest.split_at(line_end))
+            .ok_or_else(mismatch)?;
+        let tag = mac
+            .strip_suffix(b"\"")
+            .and_then(|mac| data_encoding::HEXLOWER.decode(mac).ok())
+            .and_then(|mac| Tag::from_slice(&mac).ok())
+            .ok_or_else(mismatch)?;
+        // Skip the line break ending the MAC line
+        let data = data.strip_prefix(b"\n").ok_or_else(mismatch)?;
+        HmacSha256::verify(&tag, &integrity_key(), data)
+            .map_err(|_| mismatch())?;
+        Ok(data.to_vec())
+    }
+
+    /// The name the address book is referred to by when prompting for its
+    /// password
+    pub const ADDRESS_BOOK_TARGET: &str = "the address book";
+
+    /// Env var to override the permissions that wallet files are written
+    /// with, given in octal (e.g. `640` to also let the group read them)
+    pub const ENV_VAR_WALLET_FILE_MODE: &str = "NAMADA_WALLET_FILE_MODE";
+
+    /// The permissions that wallet files are written with by default
+    pub const DEFAULT_WALLET_FILE_MODE: u32 = 0o600;
+
+    /// Get the permissions that wallet files should be written with, taken
+    /// from [`ENV_VAR_WALLET_FILE_MODE`] when it's set.
+    pub fn wallet_file_mode() -> std::io::Result<u32> {
+        match std::env::var(ENV_VAR_WALLET_FILE_MODE) {
+            Ok(mode) => u32::from_str_radix(mode.trim(), 8).map_err(|err| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!(
+                        "Invalid {ENV_VAR_WALLET_FILE_MODE} \"{mode}\": {err}"
+                    ),
+                )
+            }),
+            Err(_) => Ok(DEFAULT_WALLET_FILE_MODE),
+        }
+    }
+
+    /// Set the permissions of a file holding wallet data to
+    /// [`wallet_file_mode`], so that it isn't left readable by others
+    /// regardless of the umask. This is a no-op on non-Unix platforms.
+    pub fn restrict_file_permissions(file: &fs::File) -> std::io::Result<()> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = wallet_file_mode()?;
+            file.set_permissions(fs::Permissions::from_mode(mode))?;
+        }
+        #[cfg(not(unix))]
+        let _ = file;
+        Ok(())
+    }
+
+    /// Write wallet data to a temporary file next to the given path with
+    /// [`restrict_file_permissions`], then move it over the path, which is
+    /// thus never left partially written.
+    pub fn write_file_atomically(
+        path: &Path,
+        data: &[u8],
+    ) -> std::io::Result<()> {
+        let mut tmp_path = path.as_os_str().to_owned();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+        let mut file = fs::File::create(&tmp_path)?;
+        restrict_file_permissions(&file)?;
+        file.write_all(data).and_then(|()| file.sync_all())?;
+        fs::rename(&tmp_path, path)
+    }
+
     impl<F: FsWalletStorage> WalletStorage for F {
         fn save<U>(&self, wallet: &Wallet<U>) -> Result<(), LoadStoreError> {
-            let data = wallet.store.encode();
-            let wallet_path = self.store_dir().join(FILE_NAME);
+            if self.save_disabled() {
+                warn_save_skipped();
+                return Ok(());
+            }
+            let data = with_integrity_mac(wallet.store.encode());
+            let wallet_path = self.wallet_file();
             // Make sure the dir exists
             let wallet_dir = wallet_path.parent().unwrap();
             fs::create_dir_all(wallet_dir).map_err(|err| {
@@ -163,6 +363,9 @@ pub mod fs {
             let mut guard = lock.write().map_err(|err| {
                 LoadStoreError::StoreNewWallet(err.to_string())
             })?;
+            restrict_file_permissions(&*guard).map_err(|err| {
+                LoadStoreError::StoreNewWallet(err.to_string())
+            })?;
             guard
                 .write_all(&data)
                 .map_err(|err| LoadStoreError::StoreNewWallet(err.to_string()))
@@ -172,7 +375,7 @@ pub mod fs {
             &self,
             wallet: &mut Wallet<U>,
         ) -> Result<(), LoadStoreError> {
-            let wallet_file = self.store_dir().join(FILE_NAME);
+            let wallet_file = self.wallet_file();
             if !wallet_file.exists() {
                 return Err(LoadStoreError::NotFound {
                     path: wallet_file.to_string_lossy().to_string(),
@@ -201,12 +404,80 @@ pub mod fs {
                     err.to_string(),
                 )
             })?;
-            wallet.store =
+            let store = verify_integrity_mac(
+                store,
+                &wallet_file.to_string_lossy(),
+                self.require_integrity(),
+            )?;
+            let mut store =
                 Store::decode(store).map_err(LoadStoreError::Decode)?;
+            if store.is_address_book_locked() {
+                if let Some(password) = self.address_book_password() {
+                    store
+                        .unlock_address_book(password)
+                        .map_err(LoadStoreError::DecryptAddressBook)?;
+                }
+            }
+            wallet.store = store;
             Ok(())
         }
     }
 
+    impl<U: FsWalletStorage> Wallet<U> {
+        /// Save the wallet store to a temporary file next to the wallet file,
+        /// then move it over the wallet file, which is thus never left
+        /// partially written.
+        pub fn save_atomically(&self) -> Result<(), LoadStoreError> {
+            if self.utils.save_disabled() {
+                warn_save_skipped();
+                return Ok(());
+            }
+            let store_err = |err: std::io::Error| {
+                LoadStoreError::StoreNewWallet(err.to_string())
+            };
+            let data = with_integrity_mac(self.store.encode());
+            let store_dir = self.utils.store_dir();
+            fs::create_dir_all(store_dir).map_err(store_err)?;
+            write_file_atomically(&self.utils.wallet_file(), &data)
+                .map_err(store_err)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_integrity_mac() {
+            let data = Store::default().encode();
+            let file = with_integrity_mac(data.clone());
+            assert!(Store::decode(file.clone()).is_ok());
+            assert_eq!(
+                verify_integrity_mac(file.clone(), "wallet.toml", true)
+                    .unwrap(),
+                data
+            );
+
+            // A store without a MAC is only accepted if none is required
+            assert_eq!(
+                verify_integrity_mac(data.clone(), "wallet.toml", false)
+                    .unwrap(),
+                data
+            );
+            assert!(matches!(
+                verify_integrity_mac(data, "wallet.toml", true),
+                Err(LoadStoreError::MissingIntegrityMac { .. })
+            ));
+
+            let mut tampered = file;
+            tampered.extend(b"[view_keys]\n");
+            assert!(matches!(
+                verify_integrity_mac(tampered, "wallet.toml", false),
+                Err(LoadStoreError::IntegrityMismatch { .. })
+            ));
+        }
+    }
+
     /// For a non-interactive filesystem based wallet
     #[derive(Debug, BorshSerialize, BorshDeserialize, Clone)]
     pub struct FsWalletUtils {
@@ -247,6 +518,30 @@ pub fn gen_secret_key(
     .unwrap()
 }
 
+/// Parse a raw public key. The key may be given in its bech32m encoding, as
+/// the hex encoding of its borsh serialization or as the hex encoding of the
+/// bare ed25519 or compressed secp256k1 key bytes.
+pub fn parse_public_key(raw: &str) -> Option<common::PublicKey> {
+    if let Ok(pk) = common::PublicKey::from_str(raw) {
+        return Some(pk);
+    }
+    let bytes = data_encoding::HEXLOWER_PERMISSIVE
+        .decode(raw.as_bytes())
+        .ok()?;
+    common::PublicKey::try_from_slice(&bytes)
+        .ok()
+        .or_else(|| {
+            ed25519::PublicKey::try_from_slice(&bytes)
+                .ok()
+                .map(common::PublicKey::Ed25519)
+        })
+        .or_else(|| {
+            secp256k1::PublicKey::try_from_slice(&bytes)
+                .ok()
+                .map(common::PublicKey::Secp256k1)
+        })
+}
+
 fn gen_spending_key(
     csprng: &mut (impl CryptoRng + RngCore),
 ) -> ExtendedSpendingKey {
@@ -294,6 +589,12 @@ impl<U> Wallet<U> {
         }
     }
 
+    /// Returns a reference to the utils that the wallet prompts and is stored
+    /// with.
+    pub fn utils(&self) -> &U {
+        &self.utils
+    }
+
     /// Add validator data to the store
     pub fn add_validator_data(
         &mut self,
@@ -539,6 +840,90 @@ impl<U> Wallet<U> {
             .find_spending_key(alias)
             .map(|stored_spend_key| stored_spend_key.is_encrypted())
     }
+
+    /// Check if alias is an encrypted secret or spending key. Returns `None`
+    /// if no such key is stored under the alias.
+    pub fn is_encrypted(&self, alias: impl AsRef<str>) -> Option<bool> {
+        let alias = alias.as_ref();
+        self.is_encrypted_secret_key(alias)
+            .or_else(|| self.is_encrypted_spending_key(alias))
+    }
+
+    /// Encrypt the address books of the wallet at rest under the given
+    /// password, or store them in plaintext again if no password is given.
+    /// Takes effect once the wallet is saved.
+    pub fn set_address_book_password(
+        &mut self,
+        password: Option<Zeroizing<String>>,
+    ) {
+        self.store.set_address_book_password(
+            password,
+            self.utils.encryption_kdf_params(),
+        )
+    }
+
+    /// Check if the address books of the wallet are encrypted at rest
+    pub fn is_address_book_encrypted(&self) -> bool {
+        self.store.is_address_book_encrypted()
+    }
+
+    /// Get the creation time of the key with the given alias, if it is known
+    pub fn get_key_created_at(
+        &self,
+        alias: impl AsRef<str>,
+    ) -> Option<DateTimeUtc> {
+        self.store.get_key_created_at(&Alias::from(alias.as_ref()))
+    }
+
+    /// Tag the wallet entries with the given alias. Returns `false` if no
+    /// entry has the alias.
+    pub fn add_tags(
+        &mut self,
+        alias: impl AsRef<str>,
+        tags: impl IntoIterator<Item = String>,
+    ) -> bool {
+        self.store.add_tags(&Alias::from(alias.as_ref()), tags)
+    }
+
+    /// Preserve the case of the aliases added from now on for display, or
+    /// display all the aliases in lower-case again. Takes effect once the
+    /// wallet is saved.
+    pub fn set_preserve_alias_case(&mut self, preserve: bool) {
+        self.store.set_preserve_alias_case(preserve)
+    }
+
+    /// Check if the case of the added aliases is preserved for display
+    pub fn preserves_alias_case(&self) -> bool {
+        self.store.preserves_alias_case()
+    }
+
+    /// Get the given alias as it should be displayed, i.e. in the case it
+    /// was added in if that's preserved, otherwise in lower-case
+    pub fn display_alias(&self, alias: impl AsRef<str>) -> String {
+        self.store.display_alias(&Alias::from(alias.as_ref()))
+    }
+
+    /// Check if the wallet entries with the given alias have the given tag
+    pub fn has_tag(&self, alias: impl AsRef<str>, tag: &str) -> bool {
+        self.store
+            .get_tags(&Alias::from(alias.as_ref()))
+            .is_some_and(|tags| tags.contains(tag))
+    }
+
+    /// Get the tags of the wallet entries with the given alias
+    pub fn get_tags(&self, alias: impl AsRef<str>) -> Vec<String> {
+        self.store
+            .get_tags(&Alias::from(alias.as_ref()))
+            .map(|tags| tags.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Remove the dead data of the store, leaving the live entries as they
+    /// are. Takes effect once the wallet is saved. Returns the number of
+    /// entries removed.
+    pub fn compact(&mut self) -> usize {
+        self.store.compact()
+    }
 }
 
 impl<U: WalletStorage> Wallet<U> {
@@ -576,9 +961,9 @@ impl<U: WalletIo> Wallet<U> {
             if let Some(mnemonic_passphrase) = mnemonic_passphrase {
                 mnemonic_passphrase
             } else {
-                let mnemonic = U::read_mnemonic_code()?;
+                let mnemonic = self.utils.read_mnemonic_code()?;
                 let passphrase = if prompt_bip39_passphrase {
-                    U::read_mnemonic_passphrase(false)
+                    self.utils.read_mnemonic_passphrase(false)
                 } else {
                     Zeroizing::default()
                 };
@@ -623,9 +1008,9 @@ impl<U: WalletIo> Wallet<U> {
             if let Some(mnemonic_passphrase) = mnemonic_passphrase {
                 mnemonic_passphrase
             } else {
-                let mnemonic = U::read_mnemonic_code()?;
+                let mnemonic = self.utils.read_mnemonic_code()?;
                 let passphrase = if prompt_bip39_passphrase {
-                    U::read_mnemonic_passphrase(false)
+                    self.utils.read_mnemonic_passphrase(false)
                 } else {
                     Zeroizing::default()
                 };
@@ -703,6 +1088,7 @@ impl<U: WalletIo> Wallet<U> {
     /// the given passphrase. If no passphrase is provided, optionally prompt
     /// for a passphrase.
     pub fn gen_hd_seed(
+        &self,
         passphrase: Option<Zeroizing<String>>,
         rng: &mut U::Rng,
         prompt_bip39_passphrase: bool,
@@ -717,7 +1103,7 @@ impl<U: WalletIo> Wallet<U> {
 
         let passphrase = passphrase.unwrap_or_else(|| {
             if prompt_bip39_passphrase {
-                U::read_mnemonic_passphrase(true)
+                self.utils.read_mnemonic_passphrase(true)
             } else {
                 Zeroizing::default()
             }
@@ -851,6 +1237,19 @@ impl<U: WalletIo> Wallet<U> {
         sk
     }
 
+    /// The secret keys that were decrypted and are held in the cache.
+    pub fn decrypted_secret_keys(
+        &self,
+    ) -> impl Iterator<Item = &common::SecretKey> {
+        self.decrypted_key_cache.values()
+    }
+
+    /// Drop the decrypted secret keys from the cache, which zeroizes them.
+    /// They are decrypted again on their next use.
+    pub fn clear_decrypted_key_cache(&mut self) {
+        self.decrypted_key_cache.clear();
+    }
+
     /// Find the stored key by an alias, a public key hash or a public key.
     /// If the key is encrypted and password not supplied, then password will be
     /// interactively prompted. Any keys that are decrypted are stored in and
@@ -875,6 +1274,7 @@ impl<U: WalletIo> Wallet<U> {
             FindKeyError::KeyNotFound(alias_pkh_or_pk.as_ref().to_string())
         })?;
         Self::decrypt_stored_key::<_>(
+            &self.utils,
             &mut self.decrypted_key_cache,
             stored_key,
             alias_pkh_or_pk.into(),
@@ -937,6 +1337,7 @@ impl<U: WalletIo> Wallet<U> {
                 FindKeyError::KeyNotFound(alias.as_ref().to_string())
             })?;
         Self::decrypt_stored_key::<_>(
+            &self.utils,
             &mut self.decrypted_spendkey_cache,
             stored_spendkey,
             alias.into(),
@@ -1006,6 +1407,7 @@ impl<U: WalletIo> Wallet<U> {
             .find_key_by_pkh(pkh)
             .ok_or_else(|| FindKeyError::KeyNotFound(pkh.to_string()))?;
         Self::decrypt_stored_key(
+            &self.utils,
             &mut self.decrypted_key_cache,
             stored_key,
             alias,
@@ -1020,6 +1422,7 @@ impl<U: WalletIo> Wallet<U> {
     fn decrypt_stored_key<
         T: FromStr + Display + BorshSerialize + BorshDeserialize + Clone,
     >(
+        utils: &U,
         decrypted_key_cache: &mut HashMap<Alias, T>,
         stored_key: &StoredKeypair<T>,
         alias: Alias,
@@ -1038,10 +1441,8 @@ impl<U: WalletIo> Wallet<U> {
                         let mut key_result =
                             Err(keys::DecryptionError::EmptyPassword);
                         for _ in 0..2 {
-                            let pwd = U::read_password(
-                                false,
-                                Some(&alias.to_string()),
-                            );
+                            let pwd = utils
+                                .read_password(false, Some(&alias.to_string()));
                             key_result = encrypted.decrypt(pwd);
                             if key_result.is_ok() {
                                 break;
@@ -1075,7 +1476,12 @@ impl<U: WalletIo> Wallet<U> {
         force_alias: bool,
     ) -> Option<String> {
         self.store
-            .insert_address::<U>(alias.into(), address, force_alias)
+            .insert_address(
+                &self.utils,
+                Alias::preserving_case(alias),
+                address,
+                force_alias,
+            )
             .map(Into::into)
     }
 
@@ -1094,8 +1500,9 @@ impl<U: WalletIo> Wallet<U> {
         path: Option<DerivationPath>,
     ) -> Option<String> {
         self.store
-            .insert_keypair::<U>(
-                alias.into(),
+            .insert_keypair(
+                &self.utils,
+                Alias::preserving_case(alias),
                 sk.clone(),
                 password,
                 address,
@@ -1120,8 +1527,9 @@ impl<U: WalletIo> Wallet<U> {
         force_alias: bool,
     ) -> Option<String> {
         self.store
-            .insert_public_key::<U>(
-                alias.into(),
+            .insert_public_key(
+                &self.utils,
+                Alias::preserving_case(alias),
                 pubkey,
                 address,
                 path,
@@ -1139,8 +1547,9 @@ impl<U: WalletIo> Wallet<U> {
         force_alias: bool,
     ) -> Option<String> {
         self.store
-            .insert_viewing_key::<U>(
-                alias.into(),
+            .insert_viewing_key(
+                &self.utils,
+                Alias::preserving_case(alias),
                 view_key,
                 birthday,
                 force_alias,
@@ -1159,8 +1568,9 @@ impl<U: WalletIo> Wallet<U> {
         path: Option<DerivationPath>,
     ) -> Option<String> {
         self.store
-            .insert_spending_key::<U>(
-                alias.into(),
+            .insert_spending_key(
+                &self.utils,
+                Alias::preserving_case(alias),
                 spend_key,
                 birthday,
                 password,
@@ -1185,7 +1595,12 @@ impl<U: WalletIo> Wallet<U> {
         force_alias: bool,
     ) -> Option<String> {
         self.store
-            .insert_payment_addr::<U>(alias.into(), payment_addr, force_alias)
+            .insert_payment_addr(
+                &self.utils,
+                Alias::preserving_case(alias),
+                payment_addr,
+                force_alias,
+            )
             .map(Into::into)
     }
 
@@ -1199,6 +1614,93 @@ impl<U: WalletIo> Wallet<U> {
     pub fn remove_all_by_alias(&mut self, alias: String) {
         self.store.remove_alias(&alias.into())
     }
+
+    /// Set the encryption policy of the secret or spending key with the given
+    /// alias and convert the stored key in place to satisfy it. If the key is
+    /// encrypted and `password` is not supplied, it will be interactively
+    /// prompted for. If the key needs to be encrypted and `new_password` is not
+    /// supplied, then a new password will be interactively prompted for.
+    /// Returns `true` if the stored key had to be converted.
+    pub fn set_key_policy(
+        &mut self,
+        alias: impl AsRef<str>,
+        policy: KeyEncryptionPolicy,
+        password: Option<Zeroizing<String>>,
+        new_password: Option<Zeroizing<String>>,
+    ) -> Result<bool, FindKeyError> {
+        let alias = Alias::from(alias.as_ref());
+        let converted = if let Some(stored_key) =
+            self.store.find_secret_key(&alias)
+        {
+            if policy.is_satisfied_by(stored_key) {
+                false
+            } else {
+                let key = self.find_secret_key(&alias, password)?;
+                let new_password =
+                    self.policy_password(&alias, policy, new_password)?;
+                if !self.store.replace_secret_key(
+                    &alias,
+                    StoredKeypair::new_with_kdf_params(
+                        key,
+                        new_password,
+                        self.utils.encryption_kdf_params(),
+                    )
+                    .0,
+                ) {
+                    return Err(FindKeyError::KeyNotFound(alias.to_string()));
+                }
+                true
+            }
+        } else if let Some(stored_key) = self.store.find_spending_key(&alias) {
+            if policy.is_satisfied_by(stored_key) {
+                false
+            } else {
+                let key = self.find_spending_key(&alias, password)?;
+                let new_password =
+                    self.policy_password(&alias, policy, new_password)?;
+                if !self.store.replace_spending_key(
+                    &alias,
+                    StoredKeypair::new_with_kdf_params(
+                        key,
+                        new_password,
+                        self.utils.encryption_kdf_params(),
+                    )
+                    .0,
+                ) {
+                    return Err(FindKeyError::KeyNotFound(alias.to_string()));
+                }
+                true
+            }
+        } else {
+            return Err(FindKeyError::KeyNotFound(alias.to_string()));
+        };
+        self.store.set_key_policy(alias, policy);
+        Ok(converted)
+    }
+
+    /// Get the password with which a key is to be stored under the given
+    /// policy.
+    fn policy_password(
+        &self,
+        alias: &Alias,
+        policy: KeyEncryptionPolicy,
+        new_password: Option<Zeroizing<String>>,
+    ) -> Result<Option<Zeroizing<String>>, FindKeyError> {
+        match policy {
+            KeyEncryptionPolicy::Plaintext => Ok(None),
+            KeyEncryptionPolicy::Encrypted => {
+                let password = new_password.unwrap_or_else(|| {
+                    self.utils.read_password(true, Some(&alias.to_string()))
+                });
+                if password.is_empty() {
+                    return Err(FindKeyError::KeyDecryptionError(
+                        keys::DecryptionError::EmptyPassword,
+                    ));
+                }
+                Ok(Some(password))
+            }
+        }
+    }
 }
 
 #[inline]
@@ -1232,6 +1734,7 @@ fn check_if_disposable_key_and<F: FnOnce(&PublicKeyHash, i64) -> bool>(
 
 #[cfg(test)]
 mod tests {
+    use namada_core::address::testing::established_address_1;
     use namada_core::key::testing::{keypair_1, keypair_2, keypair_3};
     use rand_core::OsRng;
 
@@ -1320,4 +1823,483 @@ mod tests {
                 .any(|pk| *pk == new_key_pk)
         );
     }
+
+    #[test]
+    fn test_set_key_policy_converts_key_in_place() {
+        let mut wallet = Wallet::new(TestWalletUtils, Store::default());
+        let sk = keypair_1();
+        let alias = wallet
+            .insert_keypair(
+                "hot".to_string(),
+                true,
+                sk.clone(),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(wallet.is_encrypted_secret_key(&alias), Some(false));
+
+        let password = Zeroizing::new("cold storage".to_string());
+        let converted = wallet
+            .set_key_policy(
+                &alias,
+                KeyEncryptionPolicy::Encrypted,
+                None,
+                Some(password.clone()),
+            )
+            .unwrap();
+        assert!(converted);
+        assert_eq!(wallet.is_encrypted_secret_key(&alias), Some(true));
+        assert_eq!(
+            wallet.store().get_key_policy(&alias.clone().into()),
+            Some(KeyEncryptionPolicy::Encrypted)
+        );
+        assert!(wallet.store().key_policy_violations().is_empty());
+
+        // Setting the same policy again is a no-op
+        let converted = wallet
+            .set_key_policy(&alias, KeyEncryptionPolicy::Encrypted, None, None)
+            .unwrap();
+        assert!(!converted);
+
+        let converted = wallet
+            .set_key_policy(
+                &alias,
+                KeyEncryptionPolicy::Plaintext,
+                Some(password),
+                None,
+            )
+            .unwrap();
+        assert!(converted);
+        assert_eq!(wallet.is_encrypted_secret_key(&alias), Some(false));
+        assert_eq!(
+            wallet.find_secret_key(&alias, None).unwrap().ref_to(),
+            sk.ref_to()
+        );
+
+        assert!(matches!(
+            wallet.set_key_policy(
+                "missing",
+                KeyEncryptionPolicy::Plaintext,
+                None,
+                None
+            ),
+            Err(FindKeyError::KeyNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_compact() {
+        let mut wallet = Wallet::new(TestWalletUtils, Store::default());
+        let alias = wallet
+            .insert_keypair(
+                "hot".to_string(),
+                true,
+                keypair_1(),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        assert!(wallet.add_tags(&alias, ["validator".to_string()]));
+        let live = wallet.store().encode();
+
+        // Nothing is removed from a store without dead data
+        assert_eq!(wallet.compact(), 0);
+        assert_eq!(wallet.store().encode(), live);
+
+        wallet.store_mut().set_key_policy(
+            Alias::from("removed"),
+            KeyEncryptionPolicy::Plaintext,
+        );
+        wallet.store_mut().add_vp_type_to_address(
+            AddressVpType::Token,
+            established_address_1(),
+        );
+        assert_eq!(wallet.compact(), 1);
+        assert!(wallet.store().get_key_policy(&"removed".into()).is_none());
+        assert!(wallet.has_tag(&alias, "validator"));
+        assert!(wallet.get_key_created_at(&alias).is_some());
+        assert!(wallet
+            .store()
+            .get_addresses_with_vp_type(AddressVpType::Token)
+            .contains(&established_address_1()));
+    }
+
+    #[test]
+    fn test_key_created_at() {
+        let mut wallet = Wallet::new(TestWalletUtils, Store::default());
+        let alias = wallet
+            .insert_keypair(
+                "hot".to_string(),
+                true,
+                keypair_1(),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        assert!(wallet.get_key_created_at(&alias).is_some());
+        assert!(wallet.get_key_created_at("missing").is_none());
+
+        wallet.remove_all_by_alias(alias.clone());
+        assert!(wallet.get_key_created_at(&alias).is_none());
+    }
+
+    #[test]
+    fn test_find_scheme_by_address() {
+        let mut wallet = Wallet::new(TestWalletUtils, Store::default());
+        let pk = keypair_1().to_public();
+        wallet
+            .insert_keypair(
+                "hot".to_string(),
+                true,
+                keypair_1(),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            wallet.store().find_scheme_by_address(&Address::from(&pk)),
+            Some(SchemeType::Ed25519)
+        );
+        assert_eq!(
+            wallet.store().find_scheme_by_address(&Address::from(
+                &keypair_2().to_public()
+            )),
+            None
+        );
+        assert_eq!(
+            wallet
+                .store()
+                .find_scheme_by_address(&established_address_1()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_key_created_at_uses_wallet_clock() {
+        #[derive(Clone)]
+        struct FixedClockWalletUtils;
+
+        impl WalletIo for FixedClockWalletUtils {
+            type Rng = OsRng;
+
+            fn now() -> DateTimeUtc {
+                DateTimeUtc::from_unix_timestamp(978310861).unwrap()
+            }
+        }
+
+        let mut wallet = Wallet::new(FixedClockWalletUtils, Store::default());
+        let alias = wallet
+            .insert_keypair(
+                "hot".to_string(),
+                true,
+                keypair_1(),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            wallet.get_key_created_at(&alias),
+            Some(FixedClockWalletUtils::now())
+        );
+    }
+
+    #[test]
+    fn test_is_encrypted() {
+        let mut wallet = Wallet::new(TestWalletUtils, Store::default());
+        wallet
+            .insert_keypair(
+                "plain".to_string(),
+                true,
+                keypair_1(),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        wallet
+            .insert_keypair(
+                "encrypted".to_string(),
+                true,
+                keypair_2(),
+                Some(Zeroizing::new("password".to_string())),
+                None,
+                None,
+            )
+            .unwrap();
+        wallet
+            .insert_address(
+                "address",
+                Address::from(&keypair_3().ref_to()),
+                true,
+            )
+            .unwrap();
+        assert_eq!(wallet.is_encrypted("plain"), Some(false));
+        assert_eq!(wallet.is_encrypted("Encrypted"), Some(true));
+        assert_eq!(wallet.is_encrypted("address"), None);
+        assert_eq!(wallet.is_encrypted("missing"), None);
+    }
+
+    #[test]
+    fn test_preserve_alias_case() {
+        assert_eq!(Alias::from("MixedCase").as_given(), "mixedcase");
+        assert_eq!(Alias::preserving_case("MixedCase").as_given(), "MixedCase");
+
+        let mut wallet = Wallet::new(TestWalletUtils, Store::default());
+        let address = Address::from(&keypair_1().ref_to());
+        wallet
+            .insert_address("Normalized", address.clone(), true)
+            .unwrap();
+        assert_eq!(wallet.display_alias("Normalized"), "normalized");
+
+        wallet.set_preserve_alias_case(true);
+        wallet
+            .insert_address("MixedCase", address.clone(), true)
+            .unwrap();
+        assert_eq!(wallet.display_alias("mixedcase"), "MixedCase");
+        assert_eq!(wallet.display_alias("Normalized"), "normalized");
+        assert_eq!(wallet.find_address("MIXEDCASE").as_deref(), Some(&address));
+
+        let store = Store::decode(wallet.store().encode()).unwrap();
+        assert!(store.preserves_alias_case());
+        assert_eq!(store.display_alias(&Alias::from("mixedcase")), "MixedCase");
+
+        wallet.set_preserve_alias_case(false);
+        assert_eq!(wallet.display_alias("MixedCase"), "mixedcase");
+    }
+
+    #[test]
+    fn test_tags() {
+        let mut wallet = Wallet::new(TestWalletUtils, Store::default());
+        assert!(!wallet.add_tags("hot", ["validator".to_string()]));
+        wallet
+            .insert_keypair(
+                "hot".to_string(),
+                true,
+                keypair_1(),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        assert!(wallet
+            .add_tags("HOT", ["validator".to_string(), "Ops".to_string()]));
+        assert!(wallet.has_tag("hot", "validator"));
+        assert!(!wallet.has_tag("hot", "ops"));
+        assert!(wallet.find_secret_key("Hot", None).is_ok());
+
+        let store = Store::decode(wallet.store().encode()).unwrap();
+        assert_eq!(
+            store.get_tags(&Alias::from("hot")),
+            Some(&std::collections::BTreeSet::from([
+                "Ops".to_string(),
+                "validator".to_string()
+            ]))
+        );
+
+        wallet.remove_all_by_alias("hot".to_string());
+        assert!(wallet.get_tags("hot").is_empty());
+    }
+
+    /// Test that stores written before key creation times were recorded can
+    /// still be loaded.
+    #[test]
+    fn test_decode_store_without_key_created_at() {
+        let encoded = String::from_utf8(Store::default().encode()).unwrap();
+        let legacy = encoded.replace("[key_created_at]\n", "");
+        assert!(!legacy.contains("key_created_at"));
+        let store = Store::decode(legacy.into_bytes()).unwrap();
+        assert!(store.get_key_created_at(&Alias::from("hot")).is_none());
+    }
+
+    /// Test that an encrypted address book is left out of the plaintext of
+    /// the encoded store, and restored once unlocked with its password.
+    #[test]
+    fn test_encrypted_address_book() {
+        let mut wallet = Wallet::new(TestWalletUtils, Store::default());
+        let address = Address::from(&keypair_2().ref_to());
+        wallet
+            .insert_address("counterparty", address.clone(), true)
+            .unwrap();
+        let pk = keypair_1().ref_to();
+        wallet
+            .insert_public_key(
+                "signer".to_string(),
+                pk.clone(),
+                None,
+                None,
+                true,
+            )
+            .unwrap();
+        let password = Zeroizing::new("password".to_string());
+        wallet.set_address_book_password(Some(password.clone()));
+
+        let encoded = wallet.store().encode();
+        let plaintext = String::from_utf8(encoded.clone()).unwrap();
+        assert!(!plaintext.contains(&address.encode()));
+        assert!(!plaintext.contains(&pk.to_string()));
+
+        let mut store = Store::decode(encoded).unwrap();
+        assert!(store.is_address_book_locked());
+        assert!(store.find_address("counterparty").is_none());
+        assert!(store.find_public_key("signer").is_none());
+        assert!(store
+            .unlock_address_book(Zeroizing::new("wrong".to_string()))
+            .is_err());
+        store.unlock_address_book(password).unwrap();
+        assert_eq!(store.find_address("counterparty"), Some(&address));
+        assert_eq!(store.find_public_key("signer"), Some(&pk));
+
+        // Migrate the address book back to plaintext
+        store.set_address_book_password(None, KdfParams::DEFAULT);
+        let store = Store::decode(store.encode()).unwrap();
+        assert!(!store.is_address_book_encrypted());
+        assert_eq!(store.find_address("counterparty"), Some(&address));
+    }
+
+    /// Test that the address book of a wallet added to another stays
+    /// encrypted, whether or not it is unlocked.
+    #[test]
+    fn test_extend_encrypted_address_book() {
+        let mut added = Wallet::new(TestWalletUtils, Store::default());
+        let address = Address::from(&keypair_2().ref_to());
+        added
+            .insert_address("counterparty", address.clone(), true)
+            .unwrap();
+        let password = Zeroizing::new("password".to_string());
+        added.set_address_book_password(Some(password.clone()));
+        let encoded = added.store().encode();
+
+        // An unlocked address book is merged, under its password
+        let mut wallet = Wallet::new(TestWalletUtils, Store::default());
+        wallet.extend(added);
+        assert!(wallet.is_address_book_encrypted());
+        assert_eq!(
+            wallet.find_address("counterparty").as_deref(),
+            Some(&address)
+        );
+        let plaintext = String::from_utf8(wallet.store().encode()).unwrap();
+        assert!(!plaintext.contains(&address.encode()));
+
+        // A locked address book is kept as it is
+        let locked =
+            Wallet::new(TestWalletUtils, Store::decode(encoded).unwrap());
+        let mut wallet = Wallet::new(TestWalletUtils, Store::default());
+        wallet.extend(locked);
+        let mut store = Store::decode(wallet.store().encode()).unwrap();
+        assert!(store.is_address_book_locked());
+        store.unlock_address_book(password).unwrap();
+        assert_eq!(store.find_address("counterparty"), Some(&address));
+    }
+
+    #[cfg(all(unix, feature = "std"))]
+    #[test]
+    fn test_saved_wallet_is_not_world_readable() {
+        use std::os::unix::fs::PermissionsExt;
+        use std::path::PathBuf;
+
+        #[derive(Clone)]
+        struct TestFsWalletUtils(PathBuf);
+
+        impl WalletIo for TestFsWalletUtils {
+            type Rng = OsRng;
+        }
+
+        impl fs::FsWalletStorage for TestFsWalletUtils {
+            fn store_dir(&self) -> &PathBuf {
+                &self.0
+            }
+        }
+
+        let saved_wallet_mode = || {
+            let dir = tempfile::tempdir().unwrap();
+            let wallet = Wallet::new(
+                TestFsWalletUtils(dir.path().to_path_buf()),
+                Store::default(),
+            );
+            wallet.save().unwrap();
+            std::fs::metadata(dir.path().join("wallet.toml"))
+                .unwrap()
+                .permissions()
+                .mode()
+                & 0o777
+        };
+
+        std::env::remove_var(fs::ENV_VAR_WALLET_FILE_MODE);
+        assert_eq!(saved_wallet_mode(), 0o600);
+
+        std::env::set_var(fs::ENV_VAR_WALLET_FILE_MODE, "640");
+        let overridden = saved_wallet_mode();
+        std::env::remove_var(fs::ENV_VAR_WALLET_FILE_MODE);
+        assert_eq!(overridden, 0o640);
+    }
+
+    #[test]
+    fn test_parse_public_key() {
+        let pk = keypair_1().ref_to();
+        let common::PublicKey::Ed25519(ed25519_pk) = &pk else {
+            panic!("Expected an ed25519 key");
+        };
+
+        assert_eq!(parse_public_key(&pk.to_string()), Some(pk.clone()));
+        let borsh_hex =
+            data_encoding::HEXLOWER.encode(&borsh::to_vec(&pk).unwrap());
+        assert_eq!(parse_public_key(&borsh_hex), Some(pk.clone()));
+        assert_eq!(
+            parse_public_key(&borsh_hex.to_uppercase()),
+            Some(pk.clone())
+        );
+        let raw_hex =
+            data_encoding::HEXLOWER.encode(&borsh::to_vec(ed25519_pk).unwrap());
+        assert_eq!(parse_public_key(&raw_hex), Some(pk));
+        assert_eq!(parse_public_key("albert"), None);
+    }
+
+    #[test]
+    fn test_kdf_params_are_stored_per_key() {
+        use crate::keys::EncryptedKeypair;
+
+        let sk = keypair_1();
+        let password = Zeroizing::new("password".to_string());
+
+        // Keys encrypted with the default parameters are encoded as before
+        let encrypted = EncryptedKeypair::new_with_kdf_params(
+            &sk,
+            password.clone(),
+            KdfParams::DEFAULT,
+        )
+        .to_string();
+        assert!(!encrypted.contains('$'));
+
+        // Other parameters are encoded with the key and used to decrypt it
+        let params = KdfParams::new(1, 64).unwrap();
+        let encrypted = EncryptedKeypair::new_with_kdf_params(
+            &sk,
+            password.clone(),
+            params,
+        )
+        .to_string();
+        assert!(encrypted.starts_with("argon2i$t=1$m=64$"));
+        let decoded: EncryptedKeypair<common::SecretKey> =
+            encrypted.parse().unwrap();
+        assert_eq!(decoded.kdf_params(), params);
+        assert_eq!(decoded.decrypt(password).unwrap(), sk);
+
+        assert!(KdfParams::new(0, 64).is_err());
+        assert!(KdfParams::new(1, 4).is_err());
+        assert!(KdfParams::new(64, 4 * 1024 * 1024).is_ok());
+        assert!(KdfParams::new(65, 64).is_err());
+        assert!(KdfParams::new(1, 4 * 1024 * 1024 + 1).is_err());
+        assert!("argon2i$t=0$m=64$00"
+            .parse::<EncryptedKeypair<common::SecretKey>>()
+            .is_err());
+        assert!("argon2i$t=1$m=4294967295$00"
+            .parse::<EncryptedKeypair<common::SecretKey>>()
+            .is_err());
+    }
 }
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use zeroize::Zeroizing;
 
-use crate::StoredKeypair;
+use crate::{KdfParams, StoredKeypair};
 
 /// Ways in which wallet store operations can fail
 #[derive(Error, Debug)]
@@ -67,14 +67,15 @@ impl ValidatorStore {
     }
 }
 
-/// Generate a key and then encrypt it
+/// Generate a key and then encrypt it with the given KDF parameters
 pub fn gen_key_to_store(
     scheme: SchemeType,
     password: Option<Zeroizing<String>>,
+    kdf_params: KdfParams,
     rng: &mut (impl CryptoRng + Rng),
 ) -> (StoredKeypair<common::SecretKey>, common::SecretKey) {
     let sk = crate::gen_secret_key(scheme, rng);
-    StoredKeypair::new(sk, password)
+    StoredKeypair::new_with_kdf_params(sk, password, kdf_params)
 }
 
 impl From<crate::keys::DecryptionError> for ReadError {
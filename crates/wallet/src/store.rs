@@ -2,11 +2,12 @@
 
 #![allow(clippy::print_stdout, clippy::print_stderr)]
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Display;
 use std::str::FromStr;
 
 use bimap::BiBTreeMap;
+use borsh::{BorshDeserialize, BorshSerialize};
 use itertools::Itertools;
 use masp_primitives::zip32;
 use namada_core::address::{Address, ImplicitAddress};
@@ -16,14 +17,18 @@ use namada_core::key::*;
 use namada_core::masp::{
     ExtendedSpendingKey, ExtendedViewingKey, PaymentAddress,
 };
+use namada_core::time::DateTimeUtc;
 use serde::{Deserialize, Serialize};
 use zeroize::Zeroizing;
 
 use super::alias::{self, Alias};
 use super::derivation_path::DerivationPath;
 use super::pre_genesis;
-use crate::keys::{DatedKeypair, DatedSpendingKey, DatedViewingKey};
-use crate::{StoredKeypair, WalletIo};
+use crate::keys::{
+    DatedKeypair, DatedSpendingKey, DatedViewingKey, EncryptedKeypair,
+    KdfParams,
+};
+use crate::{DecryptionError, StoredKeypair, WalletIo};
 
 /// Actions that can be taken when there is an alias conflict
 pub enum ConfirmationResponse {
@@ -63,6 +68,10 @@ pub struct ValidatorData {
 /// A Storage area for keys and addresses
 #[derive(Serialize, Deserialize, Debug, Default)]
 pub struct Store {
+    /// Whether the case of the added aliases is preserved for display.
+    /// Otherwise, aliases are displayed in lower-case.
+    #[serde(default)]
+    preserve_alias_case: bool,
     /// Known viewing keys
     view_keys: BTreeMap<Alias, DatedViewingKey>,
     /// Known spending keys
@@ -84,15 +93,104 @@ pub struct Store {
     pub(crate) validator_data: Option<ValidatorData>,
     /// Namada address vp type
     address_vp_types: BTreeMap<AddressVpType, HashSet<Address>>,
+    /// Encryption policies of stored secret and spending keys
+    #[serde(default)]
+    key_policies: BTreeMap<Alias, KeyEncryptionPolicy>,
+    /// Creation times of stored secret and spending keys. Keys added before
+    /// these were recorded have no entry.
+    #[serde(default)]
+    key_created_at: BTreeMap<Alias, DateTimeUtc>,
+    /// Free-form tags of the wallet entries, by their alias
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    tags: BTreeMap<Alias, BTreeSet<String>>,
+    /// The aliases added in mixed case while `preserve_alias_case` was set,
+    /// as they were given
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    alias_cases: BTreeMap<Alias, String>,
+    /// The address books, encrypted at rest with the public keys and the vp
+    /// types. While set, the `addresses`, `payment_addrs`, `public_keys`,
+    /// `pkhs` and `address_vp_types` read from a file stay empty until the
+    /// address book is unlocked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    encrypted_address_book: Option<String>,
+    /// The password under which the address books get encrypted on encoding
+    #[serde(skip)]
+    address_book_password: Option<AddressBookPassword>,
+}
+
+/// The address books of a store, as they get encrypted at rest, with the
+/// public keys and the vp types that would otherwise reveal the same
+/// counterparties
+#[derive(BorshSerialize, BorshDeserialize)]
+struct AddressBook {
+    addresses: Vec<(Alias, Address)>,
+    payment_addrs: Vec<(Alias, PaymentAddress)>,
+    public_keys: Vec<(Alias, common::PublicKey)>,
+    pkhs: Vec<(PublicKeyHash, Alias)>,
+    address_vp_types: Vec<(AddressVpType, Vec<Address>)>,
+}
+
+/// The fields of a store that are encrypted in its address book
+const ADDRESS_BOOK_FIELDS: [&str; 5] = [
+    "addresses",
+    "payment_addrs",
+    "public_keys",
+    "pkhs",
+    "address_vp_types",
+];
+
+/// Password and KDF parameters of an encrypted address book, kept out of
+/// `Debug` output
+#[derive(Clone)]
+struct AddressBookPassword(Zeroizing<String>, KdfParams);
+
+impl std::fmt::Debug for AddressBookPassword {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AddressBookPassword(..)")
+    }
 }
 
 /// Grouping of addresses by validity predicate.
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, PartialOrd, Ord)]
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Hash,
+    Debug,
+    PartialOrd,
+    Ord,
+    BorshSerialize,
+    BorshDeserialize,
+)]
 pub enum AddressVpType {
     /// The Token
     Token,
 }
 
+/// Whether a key stored under some alias is required to be encrypted.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, PartialOrd, Ord)]
+pub enum KeyEncryptionPolicy {
+    /// The key must be stored encrypted
+    Encrypted,
+    /// The key must be stored unencrypted
+    Plaintext,
+}
+
+impl KeyEncryptionPolicy {
+    /// Check if the given stored key satisfies this policy
+    pub fn is_satisfied_by<T>(&self, stored_key: &StoredKeypair<T>) -> bool
+    where
+        T: BorshSerialize + BorshDeserialize + Display + FromStr + Clone,
+        <T as FromStr>::Err: Display,
+    {
+        match self {
+            KeyEncryptionPolicy::Encrypted => stored_key.is_encrypted(),
+            KeyEncryptionPolicy::Plaintext => !stored_key.is_encrypted(),
+        }
+    }
+}
+
 impl Store {
     /// Find the stored key by an alias, a public key hash or a public key.
     pub fn find_secret_key(
@@ -202,6 +300,21 @@ impl Store {
         self.public_keys.get(self.pkhs.get(pkh)?)
     }
 
+    /// Find the scheme of the stored key of an implicit address. Returns
+    /// `None` for other addresses and for implicit addresses whose key isn't
+    /// stored.
+    pub fn find_scheme_by_address(
+        &self,
+        address: &Address,
+    ) -> Option<SchemeType> {
+        match address {
+            Address::Implicit(ImplicitAddress(pkh)) => self
+                .find_public_key_by_pkh(pkh)
+                .map(common::PublicKey::scheme),
+            _ => None,
+        }
+    }
+
     /// Find the stored address by an alias.
     pub fn find_address(&self, alias: impl AsRef<str>) -> Option<&Address> {
         self.addresses.get_by_left(&alias.into())
@@ -300,6 +413,7 @@ impl Store {
     /// alias is returned.
     pub fn insert_keypair<U: WalletIo>(
         &mut self,
+        utils: &U,
         mut alias: Alias,
         keypair: common::SecretKey,
         password: Option<Zeroizing<String>>,
@@ -338,10 +452,14 @@ impl Store {
             println!("Empty alias given, defaulting to {}.", alias);
         }
         if self.contains_alias(&alias) && !force {
-            match U::show_overwrite_confirmation(&alias, "a key") {
+            match utils.show_overwrite_confirmation(
+                &self.confirmation_alias(&alias),
+                "a key",
+            ) {
                 ConfirmationResponse::Replace => {}
                 ConfirmationResponse::Reselect(new_alias) => {
-                    return self.insert_keypair::<U>(
+                    return self.insert_keypair(
+                        utils,
                         new_alias,
                         keypair,
                         password,
@@ -355,9 +473,21 @@ impl Store {
                 }
             }
         }
+        let policy = self.key_policies.get(&alias).copied();
         self.remove_alias(&alias);
-        self.secret_keys
-            .insert(alias.clone(), StoredKeypair::new(keypair, password).0);
+        self.record_alias_case(&alias);
+        let stored_key = StoredKeypair::new_with_kdf_params(
+            keypair,
+            password,
+            utils.encryption_kdf_params(),
+        )
+        .0;
+        if let Some(policy) = policy {
+            warn_on_policy_violation(&alias, policy, &stored_key);
+            self.key_policies.insert(alias.clone(), policy);
+        }
+        self.secret_keys.insert(alias.clone(), stored_key);
+        self.key_created_at.insert(alias.clone(), U::now());
         self.public_keys.insert(alias.clone(), pubkey);
         self.pkhs.insert(pkh, alias.clone());
         self.addresses.insert(alias.clone(), address);
@@ -368,6 +498,7 @@ impl Store {
     /// Insert spending keys similarly to how it's done for keypairs
     pub fn insert_spending_key<U: WalletIo>(
         &mut self,
+        utils: &U,
         alias: Alias,
         spendkey: ExtendedSpendingKey,
         birthday: Option<BlockHeight>,
@@ -387,21 +518,36 @@ impl Store {
         }
 
         if self.contains_alias(&alias) && !force {
-            match U::show_overwrite_confirmation(&alias, "a spending key") {
+            match utils.show_overwrite_confirmation(
+                &self.confirmation_alias(&alias),
+                "a spending key",
+            ) {
                 ConfirmationResponse::Replace => {}
                 ConfirmationResponse::Reselect(new_alias) => {
-                    return self.insert_spending_key::<U>(
-                        new_alias, spendkey, birthday, password, path, false,
+                    return self.insert_spending_key(
+                        utils, new_alias, spendkey, birthday, password, path,
+                        false,
                     );
                 }
                 ConfirmationResponse::Skip => return None,
             }
         }
+        let policy = self.key_policies.get(&alias).copied();
         self.remove_alias(&alias);
+        self.record_alias_case(&alias);
 
         let (spendkey_to_store, _raw_spendkey) =
-            StoredKeypair::new(DatedKeypair::new(spendkey, birthday), password);
+            StoredKeypair::new_with_kdf_params(
+                DatedKeypair::new(spendkey, birthday),
+                password,
+                utils.encryption_kdf_params(),
+            );
+        if let Some(policy) = policy {
+            warn_on_policy_violation(&alias, policy, &spendkey_to_store);
+            self.key_policies.insert(alias.clone(), policy);
+        }
         self.spend_keys.insert(alias.clone(), spendkey_to_store);
+        self.key_created_at.insert(alias.clone(), U::now());
         // Simultaneously add the derived viewing key to ease balance viewing
         let viewkey = DatedKeypair::new(
             zip32::ExtendedFullViewingKey::from(&spendkey.into()).into(),
@@ -415,6 +561,7 @@ impl Store {
     /// Insert viewing keys similarly to how it's done for keypairs
     pub fn insert_viewing_key<U: WalletIo>(
         &mut self,
+        utils: &U,
         alias: Alias,
         viewkey: ExtendedViewingKey,
         birthday: Option<BlockHeight>,
@@ -431,17 +578,21 @@ impl Store {
             return None;
         }
         if self.contains_alias(&alias) && !force {
-            match U::show_overwrite_confirmation(&alias, "a viewing key") {
+            match utils.show_overwrite_confirmation(
+                &self.confirmation_alias(&alias),
+                "a viewing key",
+            ) {
                 ConfirmationResponse::Replace => {}
                 ConfirmationResponse::Reselect(new_alias) => {
-                    return self.insert_viewing_key::<U>(
-                        new_alias, viewkey, birthday, false,
+                    return self.insert_viewing_key(
+                        utils, new_alias, viewkey, birthday, false,
                     );
                 }
                 ConfirmationResponse::Skip => return None,
             }
         }
         self.remove_alias(&alias);
+        self.record_alias_case(&alias);
         self.view_keys
             .insert(alias.clone(), DatedKeypair::new(viewkey, birthday));
         Some(alias)
@@ -450,6 +601,7 @@ impl Store {
     /// Insert public keys
     pub fn insert_public_key<U: WalletIo>(
         &mut self,
+        utils: &U,
         mut alias: Alias,
         pubkey: common::PublicKey,
         address: Option<Address>,
@@ -478,10 +630,14 @@ impl Store {
             println!("Empty alias given, defaulting to {}.", alias);
         }
         if self.contains_alias(&alias) && !force {
-            match U::show_overwrite_confirmation(&alias, "a public key") {
+            match utils.show_overwrite_confirmation(
+                &self.confirmation_alias(&alias),
+                "a public key",
+            ) {
                 ConfirmationResponse::Replace => {}
                 ConfirmationResponse::Reselect(new_alias) => {
-                    return self.insert_public_key::<U>(
+                    return self.insert_public_key(
+                        utils,
                         new_alias,
                         pubkey,
                         Some(address),
@@ -493,6 +649,7 @@ impl Store {
             }
         }
         self.remove_alias(&alias);
+        self.record_alias_case(&alias);
         self.public_keys.insert(alias.clone(), pubkey);
         path.map(|x| self.derivation_paths.insert(alias.clone(), x));
         self.pkhs.insert(pkh, alias.clone());
@@ -503,6 +660,7 @@ impl Store {
     /// Insert payment addresses similarly to how it's done for keypairs
     pub fn insert_payment_addr<U: WalletIo>(
         &mut self,
+        utils: &U,
         alias: Alias,
         payment_addr: PaymentAddress,
         force: bool,
@@ -518,10 +676,14 @@ impl Store {
             return None;
         }
         if self.contains_alias(&alias) && !force {
-            match U::show_overwrite_confirmation(&alias, "a payment address") {
+            match utils.show_overwrite_confirmation(
+                &self.confirmation_alias(&alias),
+                "a payment address",
+            ) {
                 ConfirmationResponse::Replace => {}
                 ConfirmationResponse::Reselect(new_alias) => {
-                    return self.insert_payment_addr::<U>(
+                    return self.insert_payment_addr(
+                        utils,
                         new_alias,
                         payment_addr,
                         false,
@@ -531,6 +693,7 @@ impl Store {
             }
         }
         self.remove_alias(&alias);
+        self.record_alias_case(&alias);
         self.payment_addrs.insert(alias.clone(), payment_addr);
         Some(alias)
     }
@@ -541,6 +704,7 @@ impl Store {
     /// been added.
     pub fn insert_address<U: WalletIo>(
         &mut self,
+        utils: &U,
         mut alias: Alias,
         address: Address,
         force: bool,
@@ -560,10 +724,14 @@ impl Store {
             println!("Empty alias given, defaulting to {}.", alias);
         }
         if self.contains_alias(&alias) && !force {
-            match U::show_overwrite_confirmation(&alias, "an address") {
+            match utils.show_overwrite_confirmation(
+                &self.confirmation_alias(&alias),
+                "an address",
+            ) {
                 ConfirmationResponse::Replace => {}
                 ConfirmationResponse::Reselect(new_alias) => {
-                    return self.insert_address::<U>(new_alias, address, false);
+                    return self
+                        .insert_address(utils, new_alias, address, false);
                 }
                 ConfirmationResponse::Skip => {
                     return None;
@@ -571,6 +739,7 @@ impl Store {
             }
         }
         self.remove_alias(&alias);
+        self.record_alias_case(&alias);
         self.addresses.insert(alias.clone(), address);
         Some(alias)
     }
@@ -597,12 +766,231 @@ impl Store {
         self.pkhs.retain(|_key, val| val != alias);
         self.public_keys.remove(alias);
         self.derivation_paths.remove(alias);
+        self.key_policies.remove(alias);
+        self.key_created_at.remove(alias);
+        self.tags.remove(alias);
+        self.alias_cases.remove(alias);
+    }
+
+    /// Remove the dead data of the store: the public key hashes, derivation
+    /// paths, encryption policies, creation times, tags and alias cases of
+    /// aliases that have no key or address anymore, and the VP types without
+    /// any address. The live entries are left as they are. Returns the number
+    /// of entries removed.
+    pub fn compact(&mut self) -> usize {
+        // The addresses, and so the aliases of the address book, are unknown
+        if self.is_address_book_locked() {
+            return 0;
+        }
+        let num_entries = |store: &Self| {
+            [
+                store.pkhs.len(),
+                store.derivation_paths.len(),
+                store.key_policies.len(),
+                store.key_created_at.len(),
+                store.tags.len(),
+                store.alias_cases.len(),
+                store.address_vp_types.len(),
+            ]
+            .into_iter()
+            .sum::<usize>()
+        };
+        let num_before = num_entries(self);
+
+        let key_aliases: BTreeSet<Alias> = self
+            .secret_keys
+            .keys()
+            .chain(self.spend_keys.keys())
+            .cloned()
+            .collect();
+        let pk_aliases: BTreeSet<Alias> = self
+            .secret_keys
+            .keys()
+            .chain(self.public_keys.keys())
+            .cloned()
+            .collect();
+        let derived_aliases: BTreeSet<Alias> = key_aliases
+            .iter()
+            .chain(self.public_keys.keys())
+            .chain(self.view_keys.keys())
+            .cloned()
+            .collect();
+        let live_aliases: BTreeSet<Alias> = derived_aliases
+            .iter()
+            .chain(self.payment_addrs.left_values())
+            .chain(self.addresses.left_values())
+            .cloned()
+            .collect();
+        self.pkhs.retain(|_pkh, alias| pk_aliases.contains(alias));
+        self.derivation_paths
+            .retain(|alias, _path| derived_aliases.contains(alias));
+        self.key_policies
+            .retain(|alias, _policy| key_aliases.contains(alias));
+        self.key_created_at
+            .retain(|alias, _created_at| key_aliases.contains(alias));
+        self.tags
+            .retain(|alias, _tags| live_aliases.contains(alias));
+        self.alias_cases
+            .retain(|alias, _case| live_aliases.contains(alias));
+        self.address_vp_types
+            .retain(|_vp_type, addresses| !addresses.is_empty());
+
+        num_before.saturating_sub(num_entries(self))
+    }
+
+    /// Preserve the case of the aliases added from now on for display, or
+    /// display all the aliases in lower-case again. Either way, aliases are
+    /// looked up case-insensitively.
+    pub fn set_preserve_alias_case(&mut self, preserve: bool) {
+        self.preserve_alias_case = preserve;
+        if !preserve {
+            self.alias_cases.clear();
+        }
+    }
+
+    /// Check if the case of the added aliases is preserved for display
+    pub fn preserves_alias_case(&self) -> bool {
+        self.preserve_alias_case
+    }
+
+    /// Get the given alias as it should be displayed: as it was added if its
+    /// case is preserved, otherwise normalized to lower-case.
+    pub fn display_alias(&self, alias: &Alias) -> String {
+        self.alias_cases
+            .get(alias)
+            .filter(|_| self.preserve_alias_case)
+            .cloned()
+            .unwrap_or_else(|| alias.normalize())
+    }
+
+    /// Remember the case of a newly added alias, if it's preserved.
+    fn record_alias_case(&mut self, alias: &Alias) {
+        if self.preserve_alias_case && alias.as_given() != alias.normalize() {
+            self.alias_cases
+                .insert(alias.clone(), alias.as_given().to_owned());
+        }
+    }
+
+    /// The alias to confirm overwriting with, in the case it'd be displayed
+    /// in once added.
+    fn confirmation_alias(&self, alias: &Alias) -> Alias {
+        if self.preserve_alias_case {
+            alias.clone()
+        } else {
+            Alias::from(alias.normalize())
+        }
+    }
+
+    /// Tag the wallet entries with the given alias. Returns `false` if no
+    /// entry has the alias.
+    pub fn add_tags(
+        &mut self,
+        alias: &Alias,
+        tags: impl IntoIterator<Item = String>,
+    ) -> bool {
+        if !self.contains_alias(alias) {
+            return false;
+        }
+        self.tags.entry(alias.clone()).or_default().extend(tags);
+        true
+    }
+
+    /// Get the tags of the wallet entries with the given alias, if any
+    pub fn get_tags(&self, alias: &Alias) -> Option<&BTreeSet<String>> {
+        self.tags.get(alias)
+    }
+
+    /// Get the encryption policy of the key with the given alias, if any
+    pub fn get_key_policy(&self, alias: &Alias) -> Option<KeyEncryptionPolicy> {
+        self.key_policies.get(alias).copied()
+    }
+
+    /// Get all the key encryption policies by their alias.
+    pub fn get_key_policies(&self) -> &BTreeMap<Alias, KeyEncryptionPolicy> {
+        &self.key_policies
+    }
+
+    /// Set the encryption policy of the key with the given alias
+    pub fn set_key_policy(
+        &mut self,
+        alias: Alias,
+        policy: KeyEncryptionPolicy,
+    ) {
+        self.key_policies.insert(alias, policy);
+    }
+
+    /// Get the creation time of the key with the given alias. Returns `None`
+    /// for keys stored before creation times were recorded.
+    pub fn get_key_created_at(&self, alias: &Alias) -> Option<DateTimeUtc> {
+        self.key_created_at.get(alias).copied()
+    }
+
+    /// Replace the stored secret key under an existing alias, leaving the
+    /// other entries associated with the alias intact. Returns `false` if no
+    /// secret key is stored under the alias.
+    pub fn replace_secret_key(
+        &mut self,
+        alias: &Alias,
+        stored_key: StoredKeypair<common::SecretKey>,
+    ) -> bool {
+        match self.secret_keys.get_mut(alias) {
+            Some(key) => {
+                *key = stored_key;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replace the stored spending key under an existing alias, leaving the
+    /// other entries associated with the alias intact. Returns `false` if no
+    /// spending key is stored under the alias.
+    pub fn replace_spending_key(
+        &mut self,
+        alias: &Alias,
+        stored_key: StoredKeypair<DatedSpendingKey>,
+    ) -> bool {
+        match self.spend_keys.get_mut(alias) {
+            Some(key) => {
+                *key = stored_key;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get the aliases of all the stored keys that violate their encryption
+    /// policy.
+    pub fn key_policy_violations(&self) -> Vec<(Alias, KeyEncryptionPolicy)> {
+        self.key_policies
+            .iter()
+            .filter(|(alias, policy)| {
+                let secret_key_ok = self
+                    .secret_keys
+                    .get(*alias)
+                    .map(|key| policy.is_satisfied_by(key))
+                    .unwrap_or(true);
+                let spend_key_ok = self
+                    .spend_keys
+                    .get(*alias)
+                    .map(|key| policy.is_satisfied_by(key))
+                    .unwrap_or(true);
+                !(secret_key_ok && spend_key_ok)
+            })
+            .map(|(alias, policy)| (alias.clone(), *policy))
+            .collect()
     }
 
     /// Extend this store from another store (typically pre-genesis).
     /// Note that this method ignores `validator_data` if any.
+    ///
+    /// The address book of the other store is merged if it isn't locked, and
+    /// stays encrypted if it was. A locked address book can't be merged with
+    /// another, so it is only kept if this store has no encrypted address
+    /// book of its own.
     pub fn extend(&mut self, store: Store) {
         let Self {
+            preserve_alias_case: _,
             view_keys,
             spend_keys,
             payment_addrs,
@@ -613,6 +1001,12 @@ impl Store {
             pkhs,
             validator_data: _,
             address_vp_types,
+            key_policies,
+            key_created_at,
+            tags,
+            alias_cases,
+            encrypted_address_book,
+            address_book_password,
         } = self;
         view_keys.extend(store.view_keys);
         spend_keys.extend(store.spend_keys);
@@ -623,6 +1017,28 @@ impl Store {
         addresses.extend(store.addresses);
         pkhs.extend(store.pkhs);
         address_vp_types.extend(store.address_vp_types);
+        key_policies.extend(store.key_policies);
+        key_created_at.extend(store.key_created_at);
+        tags.extend(store.tags);
+        alias_cases.extend(store.alias_cases);
+        let is_encrypted =
+            encrypted_address_book.is_some() || address_book_password.is_some();
+        match (store.encrypted_address_book, store.address_book_password) {
+            (_, Some(password)) if !is_encrypted => {
+                *address_book_password = Some(password);
+            }
+            (Some(encrypted), None) if !is_encrypted => {
+                *encrypted_address_book = Some(encrypted);
+            }
+            (Some(_), None) => {
+                eprintln!(
+                    "Warning: the locked address book of the wallet being \
+                     added can't be merged with the encrypted address book \
+                     of this wallet, and is left out."
+                );
+            }
+            _ => {}
+        }
     }
 
     /// Extend this store from pre-genesis validator wallet.
@@ -705,14 +1121,153 @@ impl Store {
             .insert(address);
     }
 
-    /// Decode a Store from the given bytes
+    /// Check if the address books are encrypted at rest
+    pub fn is_address_book_encrypted(&self) -> bool {
+        self.encrypted_address_book.is_some()
+            || self.address_book_password.is_some()
+    }
+
+    /// Check if the address books are encrypted and still have to be
+    /// unlocked to be read
+    pub fn is_address_book_locked(&self) -> bool {
+        self.encrypted_address_book.is_some()
+            && self.address_book_password.is_none()
+    }
+
+    /// Decrypt the address books read from a file with the given password.
+    /// The same password and KDF parameters are used to encrypt them again
+    /// on encoding.
+    pub fn unlock_address_book(
+        &mut self,
+        password: Zeroizing<String>,
+    ) -> Result<(), DecryptionError> {
+        let Some(encrypted) = &self.encrypted_address_book else {
+            return Ok(());
+        };
+        let encrypted = EncryptedKeypair::<AddressBook>::from_str(encrypted)
+            .map_err(|_| DecryptionError::DeserializingError)?;
+        let kdf_params = encrypted.kdf_params();
+        let AddressBook {
+            addresses,
+            payment_addrs,
+            public_keys,
+            pkhs,
+            address_vp_types,
+        } = encrypted.decrypt(password.clone())?;
+        self.addresses.extend(addresses);
+        self.payment_addrs.extend(payment_addrs);
+        self.public_keys.extend(public_keys);
+        self.pkhs.extend(pkhs);
+        for (vp_type, addresses) in address_vp_types {
+            self.address_vp_types
+                .entry(vp_type)
+                .or_default()
+                .extend(addresses);
+        }
+        self.address_book_password =
+            Some(AddressBookPassword(password, kdf_params));
+        Ok(())
+    }
+
+    /// Encrypt the address books at rest under the given password with the
+    /// given KDF parameters, or store them in plaintext if no password is
+    /// given. Must not be called while the address books are locked.
+    pub fn set_address_book_password(
+        &mut self,
+        password: Option<Zeroizing<String>>,
+        kdf_params: KdfParams,
+    ) {
+        debug_assert!(!self.is_address_book_locked());
+        self.address_book_password =
+            password.map(|password| AddressBookPassword(password, kdf_params));
+        if self.address_book_password.is_none() {
+            self.encrypted_address_book = None;
+        }
+    }
+
+    /// Decode a Store from the given bytes. Encrypted address books are left
+    /// locked, see [`Store::unlock_address_book`].
     pub fn decode(data: Vec<u8>) -> Result<Self, toml::de::Error> {
         toml::from_slice(&data)
     }
 
     /// Encode a store into a string of bytes
     pub fn encode(&self) -> Vec<u8> {
-        toml::to_vec(self).expect("Serializing of store shouldn't fail")
+        let Some(AddressBookPassword(password, kdf_params)) =
+            &self.address_book_password
+        else {
+            return toml::to_vec(self)
+                .expect("Serializing of store shouldn't fail");
+        };
+        let address_book = AddressBook {
+            addresses: self
+                .addresses
+                .iter()
+                .map(|(alias, address)| (alias.clone(), address.clone()))
+                .collect(),
+            payment_addrs: self
+                .payment_addrs
+                .iter()
+                .map(|(alias, address)| (alias.clone(), *address))
+                .collect(),
+            public_keys: self
+                .public_keys
+                .iter()
+                .map(|(alias, pk)| (alias.clone(), pk.clone()))
+                .collect(),
+            pkhs: self
+                .pkhs
+                .iter()
+                .map(|(pkh, alias)| (pkh.clone(), alias.clone()))
+                .collect(),
+            address_vp_types: self
+                .address_vp_types
+                .iter()
+                .map(|(vp_type, addresses)| {
+                    (*vp_type, addresses.iter().cloned().collect())
+                })
+                .collect(),
+        };
+        let encrypted = EncryptedKeypair::new_with_kdf_params(
+            &address_book,
+            password.clone(),
+            *kdf_params,
+        )
+        .to_string();
+        let mut store = toml::Value::try_from(self)
+            .expect("Serializing of store shouldn't fail");
+        let table = store
+            .as_table_mut()
+            .expect("The store should serialize to a table");
+        for field in ADDRESS_BOOK_FIELDS {
+            table.insert(
+                field.to_string(),
+                toml::Value::Table(Default::default()),
+            );
+        }
+        table.insert(
+            "encrypted_address_book".to_string(),
+            toml::Value::String(encrypted),
+        );
+        toml::to_vec(&store).expect("Serializing of store shouldn't fail")
+    }
+}
+
+/// Print a warning if the given stored key violates the given policy.
+fn warn_on_policy_violation<T>(
+    alias: &Alias,
+    policy: KeyEncryptionPolicy,
+    stored_key: &StoredKeypair<T>,
+) where
+    T: BorshSerialize + BorshDeserialize + Display + FromStr + Clone,
+    <T as FromStr>::Err: Display,
+{
+    if !policy.is_satisfied_by(stored_key) {
+        eprintln!(
+            "Warning: the key with alias {} violates its encryption policy \
+             \"{}\".",
+            alias, policy
+        );
     }
 }
 
@@ -786,6 +1341,48 @@ impl FromStr for AddressVpType {
     }
 }
 
+impl Display for KeyEncryptionPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeyEncryptionPolicy::Encrypted => write!(f, "encrypted"),
+            KeyEncryptionPolicy::Plaintext => write!(f, "plaintext"),
+        }
+    }
+}
+
+impl FromStr for KeyEncryptionPolicy {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "encrypted" => Ok(Self::Encrypted),
+            "plaintext" => Ok(Self::Plaintext),
+            _ => Err("unexpected key encryption policy"),
+        }
+    }
+}
+
+impl Serialize for KeyEncryptionPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.to_string().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for KeyEncryptionPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let raw: String = Deserialize::deserialize(deserializer)?;
+        Self::from_str(&raw).map_err(D::Error::custom)
+    }
+}
+
 impl Serialize for AddressVpType {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use namada_apps_lib::config::genesis::transactions::parse_unsigned;
+
+fuzz_target!(|bytes: &[u8]| {
+    // Any input must be either parsed or rejected with an error
+    let _ = parse_unsigned(bytes);
+});